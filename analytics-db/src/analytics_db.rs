@@ -0,0 +1,273 @@
+use crate::{AnalyticsDbError, PAIR_SIZE_IN_BYTES, STATS_PER_PAIR, STATS_SIZE_IN_BYTES};
+use nacho_data_structures::{ByteConversion, TradeStats, U256};
+use nacho_dynamic_list::DynamicList;
+use std::{cmp::Ordering, collections::HashMap, path::Path};
+
+type Result<T> = std::result::Result<T, AnalyticsDbError>;
+
+/// The database that stores the hourly trade stats of each AMM pool for the last `STATS_PER_PAIR` hours.
+///
+/// Each pair owns a fixed range of slots in the stats list, an hour's stats are stored in the slot of the hour modulo `STATS_PER_PAIR`.
+pub struct AnalyticsDb {
+    pairs: DynamicList<PAIR_SIZE_IN_BYTES>,
+    list: DynamicList<STATS_SIZE_IN_BYTES>,
+    first_slots: HashMap<(U256, U256), u64>,
+}
+
+impl AnalyticsDb {
+    pub async fn new(path: impl AsRef<Path>) -> Result<AnalyticsDb> {
+        let path = path.as_ref();
+
+        let mut pairs = DynamicList::new(path.join("pairs")).await?;
+        let list = DynamicList::new(path.join("dynamic_list")).await?;
+        let mut first_slots = HashMap::<(U256, U256), u64>::new();
+
+        pairs
+            .for_each(&mut first_slots, |buf, _, first_slots| {
+                let (base_token_id, quote_token_id, first_slot) = pair_from_bytes(&buf);
+
+                first_slots.insert((base_token_id, quote_token_id), first_slot);
+
+                Ok::<(), AnalyticsDbError>(())
+            })
+            .await?;
+
+        Ok(AnalyticsDb {
+            pairs,
+            list,
+            first_slots,
+        })
+    }
+
+    /// Adds the given stats to the pair's stats of the same hour.
+    ///
+    /// Stats older than the ones stored in their slot are ignored, as their hour isn't kept anymore.
+    pub async fn push(
+        &mut self,
+        base_token_id: &U256,
+        quote_token_id: &U256,
+        stats: &TradeStats,
+    ) -> Result<()> {
+        let key = (base_token_id.clone(), quote_token_id.clone());
+
+        let first_slot = match self.first_slots.get(&key) {
+            Some(first_slot) => *first_slot,
+            None => {
+                let first_slot = self.list.push([0; STATS_SIZE_IN_BYTES]).await?;
+
+                for _ in 1..STATS_PER_PAIR {
+                    self.list.push([0; STATS_SIZE_IN_BYTES]).await?;
+                }
+
+                self.pairs
+                    .push(pair_to_bytes(base_token_id, quote_token_id, first_slot))
+                    .await?;
+
+                self.first_slots.insert(key, first_slot);
+
+                first_slot
+            }
+        };
+
+        let slot = first_slot + stats.hour % STATS_PER_PAIR;
+
+        let mut stored_stats = TradeStats::from_bytes(&self.list.get(slot).await?);
+
+        match stored_stats.hour.cmp(&stats.hour) {
+            Ordering::Equal => stored_stats.merge(stats),
+            Ordering::Less => stored_stats = stats.clone(),
+            Ordering::Greater => return Ok(()),
+        }
+
+        self.list.set(slot, stored_stats.to_bytes()).await?;
+
+        Ok(())
+    }
+
+    /// Returns the sum of the pair's stats from the given hour on.
+    ///
+    /// Pairs that have no stats, like the pools nobody has swapped in yet, have zero stats.
+    pub async fn get_sum(
+        &mut self,
+        base_token_id: &U256,
+        quote_token_id: &U256,
+        since_hour: u64,
+    ) -> Result<TradeStats> {
+        let mut sum = TradeStats {
+            hour: since_hour,
+            ..Default::default()
+        };
+
+        let first_slot = match self
+            .first_slots
+            .get(&(base_token_id.clone(), quote_token_id.clone()))
+        {
+            Some(first_slot) => *first_slot,
+            None => return Ok(sum),
+        };
+
+        for slot in first_slot..first_slot + STATS_PER_PAIR {
+            let stats = TradeStats::from_bytes(&self.list.get(slot).await?);
+
+            if stats.hour >= since_hour {
+                sum.merge(&stats);
+            }
+        }
+
+        Ok(sum)
+    }
+
+    /// Returns the pairs that have stats.
+    pub fn get_pairs(&self) -> Vec<(U256, U256)> {
+        self.first_slots.keys().cloned().collect()
+    }
+}
+
+fn pair_to_bytes(
+    base_token_id: &U256,
+    quote_token_id: &U256,
+    first_slot: u64,
+) -> [u8; PAIR_SIZE_IN_BYTES] {
+    let mut bytes = [0u8; PAIR_SIZE_IN_BYTES];
+
+    bytes[0..32].copy_from_slice(&base_token_id.to_bytes());
+    bytes[32..64].copy_from_slice(&quote_token_id.to_bytes());
+    bytes[64..72].copy_from_slice(&first_slot.to_bytes());
+
+    bytes
+}
+
+fn pair_from_bytes(bytes: &[u8; PAIR_SIZE_IN_BYTES]) -> (U256, U256, u64) {
+    (
+        U256::from_bytes(bytes[0..32].try_into().unwrap()),
+        U256::from_bytes(bytes[32..64].try_into().unwrap()),
+        u64::from_bytes(bytes[64..72].try_into().unwrap()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::fs::remove_dir_all;
+
+    use super::*;
+
+    fn stats(hour: u64, volume: u64) -> TradeStats {
+        TradeStats {
+            hour,
+            base_token_volume: volume,
+            quote_token_volume: volume * 2,
+            base_token_fees: 0,
+            quote_token_fees: volume / 100,
+            trade_count: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn sums_stats_by_hour() {
+        let dir = "/tmp/nacho/tests/analytics_db/sums_stats_by_hour";
+
+        let mut analytics_db = AnalyticsDb::new(dir).await.unwrap();
+
+        let base_token_id = U256([1; 32]);
+        let quote_token_id = U256([0; 32]);
+
+        let sum = analytics_db
+            .get_sum(&base_token_id, &quote_token_id, 1_000)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sum,
+            TradeStats {
+                hour: 1_000,
+                ..Default::default()
+            }
+        );
+
+        analytics_db
+            .push(&base_token_id, &quote_token_id, &stats(1_000, 100))
+            .await
+            .unwrap();
+        analytics_db
+            .push(&base_token_id, &quote_token_id, &stats(1_010, 200))
+            .await
+            .unwrap();
+        analytics_db
+            .push(&base_token_id, &quote_token_id, &stats(1_010, 300))
+            .await
+            .unwrap();
+
+        let sum = analytics_db
+            .get_sum(&base_token_id, &quote_token_id, 1_005)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sum,
+            TradeStats {
+                hour: 1_005,
+                base_token_volume: 500,
+                quote_token_volume: 1_000,
+                base_token_fees: 0,
+                quote_token_fees: 5,
+                trade_count: 2,
+            }
+        );
+
+        assert_eq!(
+            analytics_db.get_pairs(),
+            vec![(base_token_id.clone(), quote_token_id.clone())]
+        );
+
+        drop(analytics_db);
+
+        let mut analytics_db = AnalyticsDb::new(dir).await.unwrap();
+
+        let sum = analytics_db
+            .get_sum(&base_token_id, &quote_token_id, 1_000)
+            .await
+            .unwrap();
+
+        assert_eq!(sum.base_token_volume, 600);
+        assert_eq!(sum.trade_count, 3);
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn overwrites_the_stats_of_old_hours() {
+        let dir = "/tmp/nacho/tests/analytics_db/overwrites_the_stats_of_old_hours";
+
+        let mut analytics_db = AnalyticsDb::new(dir).await.unwrap();
+
+        let base_token_id = U256([1; 32]);
+        let quote_token_id = U256([0; 32]);
+
+        analytics_db
+            .push(&base_token_id, &quote_token_id, &stats(1_000, 100))
+            .await
+            .unwrap();
+        analytics_db
+            .push(
+                &base_token_id,
+                &quote_token_id,
+                &stats(1_000 + STATS_PER_PAIR, 200),
+            )
+            .await
+            .unwrap();
+        analytics_db
+            .push(&base_token_id, &quote_token_id, &stats(1_000, 400))
+            .await
+            .unwrap();
+
+        let sum = analytics_db
+            .get_sum(&base_token_id, &quote_token_id, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(sum.base_token_volume, 200);
+        assert_eq!(sum.trade_count, 1);
+
+        remove_dir_all(dir).await.unwrap();
+    }
+}
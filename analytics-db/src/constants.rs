@@ -0,0 +1,3 @@
+pub const STATS_PER_PAIR: u64 = 168;
+pub const STATS_SIZE_IN_BYTES: usize = 48;
+pub const PAIR_SIZE_IN_BYTES: usize = 72;
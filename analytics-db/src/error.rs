@@ -0,0 +1,8 @@
+use nacho_dynamic_list::DynamicListError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AnalyticsDbError {
+    #[error(transparent)]
+    DynamicList(#[from] DynamicListError),
+}
@@ -0,0 +1,7 @@
+mod analytics_db;
+mod constants;
+mod error;
+
+pub use analytics_db::AnalyticsDb;
+pub use constants::{PAIR_SIZE_IN_BYTES, STATS_PER_PAIR, STATS_SIZE_IN_BYTES};
+pub use error::AnalyticsDbError;
@@ -1,8 +1,11 @@
 use crate::{
-    BalancesDbError, DoubleBalanceWitness, SingleBalanceWitness, BALANCES_TREE_HEIGHT,
-    BALANCES_TREE_SIBLING_COUNT, BALANCE_SIZE_IN_BYTES,
+    BalancesDbError, DoubleBalanceWitness, SingleBalanceWitness, SingleNonceWitness,
+    BALANCES_TREE_HEIGHT, BALANCES_TREE_SIBLING_COUNT, BALANCE_SIZE_IN_BYTES, NONCES_TREE_HEIGHT,
+    NONCES_TREE_SIBLING_COUNT, NONCE_SIZE_IN_BYTES,
+};
+use nacho_data_structures::{
+    Address, Balance, ByteConversion, Field, FieldConversion, Nonce, U256,
 };
-use nacho_data_structures::{Address, Balance, ByteConversion, Field, FieldConversion, U256};
 use nacho_dynamic_list::DynamicList;
 use nacho_dynamic_merkle_tree::DynamicMerkleTree;
 use nacho_poseidon_hash::{create_poseidon_hasher, poseidon_hash, PoseidonHasher};
@@ -14,6 +17,9 @@ pub struct BalancesDb {
     list: DynamicList<BALANCE_SIZE_IN_BYTES>,
     tree: DynamicMerkleTree<BALANCES_TREE_HEIGHT, BALANCES_TREE_SIBLING_COUNT>,
     indexes: HashMap<Address, Vec<(u64, U256)>>,
+    nonce_list: DynamicList<NONCE_SIZE_IN_BYTES>,
+    nonce_tree: DynamicMerkleTree<NONCES_TREE_HEIGHT, NONCES_TREE_SIBLING_COUNT>,
+    nonce_indexes: HashMap<Address, (u64, u64)>,
     hasher: PoseidonHasher,
 }
 
@@ -50,10 +56,27 @@ impl BalancesDb {
         })
         .await?;
 
+        let mut nonce_list = DynamicList::new(path.join("nonces_dynamic_list")).await?;
+        let nonce_tree = DynamicMerkleTree::new(path.join("nonces_dynamic_merkle_tree")).await?;
+        let mut nonce_indexes = HashMap::<Address, (u64, u64)>::new();
+
+        nonce_list
+            .for_each(&mut nonce_indexes, |buf, index, nonce_indexes| {
+                let nonce = Nonce::from_bytes(&buf);
+
+                nonce_indexes.insert(nonce.owner, (index, nonce.value));
+
+                Ok::<(), BalancesDbError>(())
+            })
+            .await?;
+
         Ok(BalancesDb {
             list,
             tree,
             indexes,
+            nonce_list,
+            nonce_tree,
+            nonce_indexes,
             hasher,
         })
     }
@@ -88,6 +111,25 @@ impl BalancesDb {
         Ok(())
     }
 
+    /// Removes the last pushed balance and returns it, the balance's leaf must not be pushed yet.
+    ///
+    /// It is used to undo the push of a transaction that couldn't be committed.
+    pub async fn pop(&mut self) -> Result<Balance> {
+        let buf = self.list.pop().await?;
+
+        let balance = Balance::from_bytes(&buf);
+
+        if let Some(indexes) = self.indexes.get_mut(&balance.owner) {
+            indexes.retain(|(_, token_id)| *token_id != balance.token_id);
+
+            if indexes.is_empty() {
+                self.indexes.remove(&balance.owner);
+            }
+        }
+
+        Ok(balance)
+    }
+
     pub async fn push_leaf(&mut self, balance: &Balance) -> Result<()> {
         let fields = balance.to_fields();
 
@@ -236,6 +278,116 @@ impl BalancesDb {
 
         Ok(root)
     }
+
+    pub async fn get_nonce(&mut self, address: &Address) -> Result<u64> {
+        let nonce = self
+            .nonce_indexes
+            .get(address)
+            .map(|&(_, nonce)| nonce)
+            .unwrap_or(0);
+
+        Ok(nonce)
+    }
+
+    pub async fn increment_nonce(&mut self, address: &Address) -> Result<u64> {
+        match self.nonce_indexes.get_mut(address) {
+            Some((index, nonce)) => {
+                *nonce += 1;
+
+                let buf = Nonce {
+                    owner: address.clone(),
+                    value: *nonce,
+                }
+                .to_bytes();
+
+                self.nonce_list.set(*index, buf).await?;
+
+                Ok(*nonce)
+            }
+            None => {
+                let buf = Nonce {
+                    owner: address.clone(),
+                    value: 1,
+                }
+                .to_bytes();
+
+                let index = self.nonce_list.push(buf).await?;
+
+                self.nonce_indexes.insert(address.clone(), (index, 1));
+
+                Ok(1)
+            }
+        }
+    }
+
+    /// Decrements the nonce of the given address and returns the new nonce.
+    ///
+    /// It is used to undo the nonce increment of a transaction that couldn't be committed.
+    /// If the nonce goes back to zero and it is the last pushed one, it is removed so that the nonces keep the order of their leaves.
+    pub async fn decrement_nonce(&mut self, address: &Address) -> Result<u64> {
+        let (index, nonce) = self
+            .nonce_indexes
+            .get_mut(address)
+            .filter(|(_, nonce)| *nonce != 0)
+            .ok_or(BalancesDbError::NonceIsZero)?;
+
+        *nonce -= 1;
+
+        let (index, nonce) = (*index, *nonce);
+
+        if nonce == 0 && index + 1 == self.nonce_list.get_item_count().await? {
+            self.nonce_list.pop().await?;
+            self.nonce_indexes.remove(address);
+
+            return Ok(0);
+        }
+
+        let buf = Nonce {
+            owner: address.clone(),
+            value: nonce,
+        }
+        .to_bytes();
+
+        self.nonce_list.set(index, buf).await?;
+
+        Ok(nonce)
+    }
+
+    /// Returns the Merkle witness of the given address' nonce leaf.
+    ///
+    /// The nonce leaves lag behind the nonces like the balance leaves, so it is the witness of an unused leaf until the first nonce of the address is proved.
+    pub async fn get_nonce_witness(&mut self, address: &Address) -> Result<SingleNonceWitness> {
+        let &(index, _) = self
+            .nonce_indexes
+            .get(address)
+            .ok_or(BalancesDbError::NonceDoesntExist)?;
+
+        let single_witness = self.nonce_tree.get_single_witness(index).await?;
+
+        Ok(single_witness)
+    }
+
+    /// Sets the given nonce as the leaf of its owner, the leaf is pushed if it is the owner's first nonce.
+    pub async fn set_nonce_leaf(&mut self, nonce: &Nonce) -> Result<()> {
+        let &(index, _) = self
+            .nonce_indexes
+            .get(&nonce.owner)
+            .ok_or(BalancesDbError::NonceDoesntExist)?;
+
+        let fields = nonce.to_fields();
+
+        let hash = poseidon_hash(&mut self.hasher, &fields);
+
+        self.nonce_tree.set_leaf(index, hash).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_nonces_root(&mut self) -> Result<Field> {
+        let root = self.nonce_tree.get_root().await?;
+
+        Ok(root)
+    }
 }
 
 #[cfg(test)]
@@ -365,6 +517,197 @@ mod tests {
         remove_dir_all(dir).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn gets_and_increments_nonces_correctly() {
+        let dir = "/tmp/nacho/tests/balances_db/gets_and_increments_nonces_correctly";
+
+        let mut balances_db = BalancesDb::new(dir).await.unwrap();
+
+        let address_1 = Address::from_bytes(
+            "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let address_2 = Address::from_bytes(
+            "B62qiiGxLsqNemiKFKiD19JdTHmqbE5YKAkMuXGachSdYkTi8xR2dfY"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        assert_eq!(balances_db.get_nonce(&address_1).await.unwrap(), 0);
+
+        assert_eq!(balances_db.increment_nonce(&address_1).await.unwrap(), 1);
+        assert_eq!(balances_db.increment_nonce(&address_1).await.unwrap(), 2);
+        assert_eq!(balances_db.increment_nonce(&address_2).await.unwrap(), 1);
+
+        assert_eq!(balances_db.get_nonce(&address_1).await.unwrap(), 2);
+        assert_eq!(balances_db.get_nonce(&address_2).await.unwrap(), 1);
+
+        let mut balances_db = BalancesDb::new(dir).await.unwrap();
+
+        assert_eq!(balances_db.get_nonce(&address_1).await.unwrap(), 2);
+        assert_eq!(balances_db.get_nonce(&address_2).await.unwrap(), 1);
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn pops_balances_and_decrements_nonces() {
+        let dir = "/tmp/nacho/tests/balances_db/pops_balances_and_decrements_nonces";
+
+        let mut balances_db = BalancesDb::new(dir).await.unwrap();
+
+        let address = Address::from_bytes(
+            "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let balance_1 = Balance {
+            owner: address.clone(),
+            token_id: U256([0; 32]),
+            token_amount: 100,
+        };
+
+        let balance_2 = Balance {
+            owner: address.clone(),
+            token_id: U256([1; 32]),
+            token_amount: 200,
+        };
+
+        balances_db.push(&balance_1).await.unwrap();
+        balances_db.push(&balance_2).await.unwrap();
+
+        assert_eq!(balances_db.pop().await.unwrap(), balance_2);
+
+        let err = balances_db
+            .get(&address, &balance_2.token_id)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, BalancesDbError::BalanceDoesntExist));
+
+        assert_eq!(
+            balances_db.get_many(&address).await.unwrap(),
+            vec![balance_1.clone()]
+        );
+
+        balances_db.push(&balance_2).await.unwrap();
+
+        assert_eq!(
+            balances_db
+                .get(&address, &balance_2.token_id)
+                .await
+                .unwrap(),
+            balance_2
+        );
+
+        let err = balances_db.decrement_nonce(&address).await.unwrap_err();
+
+        assert!(matches!(err, BalancesDbError::NonceIsZero));
+
+        balances_db.increment_nonce(&address).await.unwrap();
+        balances_db.increment_nonce(&address).await.unwrap();
+
+        assert_eq!(balances_db.decrement_nonce(&address).await.unwrap(), 1);
+
+        let mut balances_db = BalancesDb::new(dir).await.unwrap();
+
+        assert_eq!(balances_db.get_nonce(&address).await.unwrap(), 1);
+        assert_eq!(
+            balances_db.get_many(&address).await.unwrap(),
+            vec![balance_1, balance_2]
+        );
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn commits_nonces_to_their_tree() {
+        let dir = "/tmp/nacho/tests/balances_db/commits_nonces_to_their_tree";
+
+        let mut balances_db = BalancesDb::new(dir).await.unwrap();
+
+        let address_1 = Address::from_bytes(
+            "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let address_2 = Address::from_bytes(
+            "B62qiiGxLsqNemiKFKiD19JdTHmqbE5YKAkMuXGachSdYkTi8xR2dfY"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let address_3 = Address::from_bytes(
+            "B62qr1H2QvZVSz7jBEyr91LXFvFTLfHB1W2S9TcMrBiZPHnPQ7yGohY"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let err = balances_db.get_nonce_witness(&address_1).await.unwrap_err();
+
+        assert!(matches!(err, BalancesDbError::NonceDoesntExist));
+
+        let empty_root = balances_db.get_nonces_root().await.unwrap();
+        let balances_root = balances_db.get_root().await.unwrap();
+
+        balances_db.increment_nonce(&address_1).await.unwrap();
+
+        assert_eq!(balances_db.get_nonces_root().await.unwrap(), empty_root);
+
+        balances_db
+            .set_nonce_leaf(&Nonce {
+                owner: address_1.clone(),
+                value: 1,
+            })
+            .await
+            .unwrap();
+
+        let root = balances_db.get_nonces_root().await.unwrap();
+
+        assert_ne!(root, empty_root);
+        assert_eq!(balances_db.get_root().await.unwrap(), balances_root);
+
+        balances_db.increment_nonce(&address_2).await.unwrap();
+
+        let witness = balances_db.get_nonce_witness(&address_2).await.unwrap();
+
+        // The undone first nonce is removed, so the next address takes its leaf.
+        assert_eq!(balances_db.decrement_nonce(&address_2).await.unwrap(), 0);
+
+        let err = balances_db.get_nonce_witness(&address_2).await.unwrap_err();
+
+        assert!(matches!(err, BalancesDbError::NonceDoesntExist));
+
+        balances_db.increment_nonce(&address_3).await.unwrap();
+
+        assert_eq!(
+            balances_db
+                .get_nonce_witness(&address_3)
+                .await
+                .unwrap()
+                .to_bytes(),
+            witness.to_bytes()
+        );
+
+        let mut balances_db = BalancesDb::new(dir).await.unwrap();
+
+        assert_eq!(balances_db.get_nonce(&address_2).await.unwrap(), 0);
+        assert_eq!(balances_db.get_nonce(&address_3).await.unwrap(), 1);
+        assert_eq!(balances_db.get_nonces_root().await.unwrap(), root);
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
     #[tokio::test]
     async fn calculates_correct_roots() {
         let dir = "/tmp/nacho/tests/balances_db/calculates_correct_roots";
@@ -1,3 +1,6 @@
 pub const BALANCES_TREE_HEIGHT: usize = 23;
 pub const BALANCES_TREE_SIBLING_COUNT: usize = BALANCES_TREE_HEIGHT - 1;
+pub const NONCES_TREE_HEIGHT: usize = 22;
+pub const NONCES_TREE_SIBLING_COUNT: usize = NONCES_TREE_HEIGHT - 1;
 pub const BALANCE_SIZE_IN_BYTES: usize = 95;
+pub const NONCE_SIZE_IN_BYTES: usize = 63;
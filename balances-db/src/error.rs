@@ -12,4 +12,8 @@ pub enum BalancesDbError {
     BalanceDoesntExist,
     #[error("Balance already exists.")]
     BalanceAlreadyExists,
+    #[error("Nonce doesn't exist.")]
+    NonceDoesntExist,
+    #[error("Nonce is zero.")]
+    NonceIsZero,
 }
@@ -3,9 +3,14 @@ mod constants;
 mod double_balance_witness;
 mod error;
 mod single_balance_witness;
+mod single_nonce_witness;
 
 pub use balances_db::BalancesDb;
-pub use constants::{BALANCES_TREE_HEIGHT, BALANCES_TREE_SIBLING_COUNT, BALANCE_SIZE_IN_BYTES};
+pub use constants::{
+    BALANCES_TREE_HEIGHT, BALANCES_TREE_SIBLING_COUNT, BALANCE_SIZE_IN_BYTES, NONCES_TREE_HEIGHT,
+    NONCES_TREE_SIBLING_COUNT, NONCE_SIZE_IN_BYTES,
+};
 pub use double_balance_witness::DoubleBalanceWitness;
 pub use error::BalancesDbError;
 pub use single_balance_witness::SingleBalanceWitness;
+pub use single_nonce_witness::SingleNonceWitness;
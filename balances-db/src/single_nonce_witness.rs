@@ -0,0 +1,4 @@
+use crate::NONCES_TREE_SIBLING_COUNT;
+use nacho_data_structures::SingleMerkleWitness;
+
+pub type SingleNonceWitness = SingleMerkleWitness<NONCES_TREE_SIBLING_COUNT>;
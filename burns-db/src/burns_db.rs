@@ -86,6 +86,25 @@ impl BurnsDb {
         Ok(())
     }
 
+    /// Removes the last pushed burn and returns it, the burn's leaf must not be pushed yet.
+    ///
+    /// It is used to undo the push of a transaction that couldn't be committed.
+    pub async fn pop(&mut self) -> Result<Burn> {
+        let buf = self.list.pop().await?;
+
+        let burn = Burn::from_bytes(&buf);
+
+        if let Some(indexes) = self.indexes.get_mut(&burn.burner) {
+            indexes.retain(|(_, token_id)| *token_id != burn.token_id);
+
+            if indexes.is_empty() {
+                self.indexes.remove(&burn.burner);
+            }
+        }
+
+        Ok(burn)
+    }
+
     pub async fn push_leaf(&mut self, burn: &Burn) -> Result<()> {
         let fields = burn.to_fields();
 
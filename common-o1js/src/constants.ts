@@ -1,5 +1,29 @@
+import { PublicKey } from "o1js"
+
 export const BALANCES_TREE_HEIGHT = 23
 export const BURNS_TREE_HEIGHT = 20
 export const LIQUIDITIES_TREE_HEIGHT = 22
+export const NONCES_TREE_HEIGHT = 22
+export const ORDERS_TREE_HEIGHT = 22
 export const POOLS_TREE_HEIGHT = 21
 export const WITHDRAWALS_TREE_HEIGHT = 19
+
+/**
+ * The share of each swap fee in basis points that is accrued as the protocol fee, it is set with `NACHO_PROTOCOL_FEE_SHARE`.
+ *
+ * It is a constant of the circuits, so the verification key commits to it and it has to match the sequencer's.
+ */
+export const PROTOCOL_FEE_SHARE = BigInt(process.env.NACHO_PROTOCOL_FEE_SHARE ?? 0)
+
+if (PROTOCOL_FEE_SHARE > 10000n) {
+    throw Error("NACHO_PROTOCOL_FEE_SHARE can't be greater than 10000.")
+}
+
+/**
+ * The address of the treasury, which is the only user that can claim the protocol fees, it is set with `NACHO_TREASURY_ADDRESS`.
+ *
+ * It is a constant of the circuits like `PROTOCOL_FEE_SHARE`, protocol fees can't be claimed when it isn't set.
+ */
+export const TREASURY_ADDRESS = process.env.NACHO_TREASURY_ADDRESS
+    ? PublicKey.fromBase58(process.env.NACHO_TREASURY_ADDRESS)
+    : PublicKey.empty()
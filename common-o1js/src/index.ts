@@ -1,10 +1,22 @@
 export type { Sibling } from "./types"
-export { choose, putInOrder, addOnePerMilFee, normalDiv } from "./utils"
+export {
+    choose,
+    putInOrder,
+    addFee,
+    subtractFee,
+    protocolFeeOf,
+    normalDiv,
+    stableReserveOf,
+    incrementNonce,
+} from "./utils"
 export {
     DoubleBalanceWitness,
+    DoublePoolWitness,
     SingleBalanceWitness,
     SingleBurnWitness,
     SingleLiquidityWitness,
+    SingleNonceWitness,
+    SingleOrderWitness,
     SinglePoolWitness,
     SingleWithdrawalWitness,
 } from "./witnesses"
@@ -13,12 +25,18 @@ export { Burn } from "./structs/burn"
 export { Deposit } from "./structs/deposit"
 export { Withdrawal } from "./structs/withdrawal"
 export { Liquidity } from "./structs/liquidity"
+export { Nonce } from "./structs/nonce"
+export { Order } from "./structs/order"
 export { Pool } from "./structs/pool"
 export { StateRoots } from "./structs/state-roots"
 export {
     BALANCES_TREE_HEIGHT,
     BURNS_TREE_HEIGHT,
     LIQUIDITIES_TREE_HEIGHT,
+    NONCES_TREE_HEIGHT,
+    ORDERS_TREE_HEIGHT,
     POOLS_TREE_HEIGHT,
+    PROTOCOL_FEE_SHARE,
+    TREASURY_ADDRESS,
     WITHDRAWALS_TREE_HEIGHT,
 } from "./constants"
@@ -0,0 +1,11 @@
+import { Field, PublicKey, Struct, UInt64 } from "o1js"
+
+/** The provable data structure that represents a user's nonce, which is the count of the user's signed transactions. */
+export class Nonce extends Struct({
+    owner: PublicKey,
+    value: UInt64,
+}) {
+    toFields(): [Field, Field, Field] {
+        return [this.owner.x, this.owner.isOdd.toField(), this.value.value]
+    }
+}
@@ -0,0 +1,25 @@
+import { Field, PublicKey, Struct, UInt64 } from "o1js"
+
+/** The provable data structure that represents a user's limit order that is matched against an AMM pool. */
+export class Order extends Struct({
+    id: UInt64,
+    owner: PublicKey,
+    tokenInId: Field,
+    tokenOutId: Field,
+    tokenAmountIn: UInt64,
+    tokenAmountOutLimit: UInt64,
+    status: UInt64,
+}) {
+    toFields(): [Field, Field, Field, Field, Field, Field, Field, Field] {
+        return [
+            this.id.value,
+            this.owner.x,
+            this.owner.isOdd.toField(),
+            this.tokenInId,
+            this.tokenOutId,
+            this.tokenAmountIn.value,
+            this.tokenAmountOutLimit.value,
+            this.status.value,
+        ]
+    }
+}
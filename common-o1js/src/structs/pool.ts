@@ -7,14 +7,22 @@ export class Pool extends Struct({
     baseTokenAmount: UInt64,
     quoteTokenAmount: UInt64,
     totalLiquidityPoints: Field,
+    feeRate: UInt64,
+    protocolFeeBaseTokenAmount: UInt64,
+    protocolFeeQuoteTokenAmount: UInt64,
+    amplification: UInt64,
 }) {
-    toFields(): [Field, Field, Field, Field, Field] {
+    toFields(): [Field, Field, Field, Field, Field, Field, Field, Field, Field] {
         return [
             this.baseTokenId,
             this.quoteTokenId,
             this.baseTokenAmount.value,
             this.quoteTokenAmount.value,
             this.totalLiquidityPoints,
+            this.feeRate.value,
+            this.protocolFeeBaseTokenAmount.value,
+            this.protocolFeeQuoteTokenAmount.value,
+            this.amplification.value,
         ]
     }
 }
@@ -10,6 +10,8 @@ export class StateRoots extends Struct({
     liquidities: Field,
     pools: Field,
     burns: Field,
+    orders: Field,
+    nonces: Field,
 }) {
     /** Returns true if this `StateRoots` is equal to `other`. */
     equals(other: StateRoots): Bool {
@@ -18,6 +20,8 @@ export class StateRoots extends Struct({
             .and(this.liquidities.equals(other.liquidities))
             .and(this.pools.equals(other.pools))
             .and(this.burns.equals(other.burns))
+            .and(this.orders.equals(other.orders))
+            .and(this.nonces.equals(other.nonces))
     }
 
     /** Proves that this `StateRoots` is equal to `other`. */
@@ -41,6 +45,8 @@ export class StateRoots extends Struct({
             liquidities: Field(ROOT_22),
             pools: Field(ROOT_21),
             burns: Field(ROOT_20),
+            orders: Field(ROOT_22),
+            nonces: Field(ROOT_22),
         })
     }
 }
@@ -1,11 +1,158 @@
-import { Bool, Field, Provable, UInt64 } from "o1js"
+import { Bool, Field, Poseidon, Provable, PublicKey, UInt64 } from "o1js"
+import { Nonce } from "./structs/nonce"
+import type { SingleNonceWitness } from "./witnesses"
 
 /**
- * Multiplies `x` with `1001` then divides the result with `1000`.
- * Make sure that `x * 1.001` won't result an error.
+ * Multiplies `x` with `10000 + feeRate` then divides the result with `10000`.
+ * The fee rate is in basis points, make sure that `x * (1 + feeRate / 10000)` won't result an error.
  */
-export const addOnePerMilFee = (x: UInt64): UInt64 => {
-    return UInt64.fromFields([normalDiv(x.value.mul(Field(1001)), Field(1000))])
+export const addFee = (x: UInt64, feeRate: UInt64): UInt64 => {
+    return UInt64.fromFields([
+        normalDiv(x.value.mul(Field(10000).add(feeRate.value)), Field(10000)),
+    ])
+}
+
+/**
+ * Multiplies `x` with `10000` then divides the result with `10000 + feeRate`.
+ * The fee rate is in basis points, it is the inverse of `addFee`.
+ */
+export const subtractFee = (x: Field, feeRate: UInt64): Field => {
+    return normalDiv(x.mul(Field(10000)), Field(10000).add(feeRate.value))
+}
+
+/**
+ * Multiplies `fee` with `protocolFeeShare` then divides the result with `10000`.
+ * The protocol fee share is in basis points of the fee, make sure that it is not greater than `10000`.
+ */
+export const protocolFeeOf = (fee: Field, protocolFeeShare: UInt64): Field => {
+    return normalDiv(fee.mul(protocolFeeShare.value), Field(10000))
+}
+
+/**
+ * Returns the reserve of the other token of a StableSwap pool after one of its reserves changes from `reserveX` to `newReserveX`.
+ * The result keeps the invariant of the pool and is rounded up, so the pool never loses value because of rounding.
+ * Returns zero if the amplification coefficient is zero, make sure that it is only used for StableSwap pools.
+ *
+ * The invariant `D` and the result are witnessed, then proved to be the smallest integers that satisfy the invariant, same as the sequencer computes them.
+ */
+export const stableReserveOf = (
+    reserveX: Field,
+    reserveY: Field,
+    newReserveX: Field,
+    amplification: UInt64,
+): Field => {
+    const isStableSwap = amplification.equals(UInt64.zero).not()
+
+    // NOTE: Constant product pools are checked as a balanced StableSwap pool of ones, whose invariant is `2` and other reserve is `1`.
+    const x = Provable.if(isStableSwap, Field, reserveX, Field(1))
+    const y = Provable.if(isStableSwap, Field, reserveY, Field(1))
+    const newX = Provable.if(isStableSwap, Field, newReserveX, Field(1))
+    const ann = Provable.if(isStableSwap, Field, amplification.value, Field(1)).mul(4)
+
+    x.mul(y).mul(newX).assertNotEquals(Field(0))
+
+    const d = Provable.witness(Field, () =>
+        Field(stableInvariant(x.toBigInt(), y.toBigInt(), ann.toBigInt())),
+    )
+
+    const newY = Provable.witness(Field, () =>
+        Field(stableOtherReserve(newX.toBigInt(), d.toBigInt(), ann.toBigInt())),
+    )
+
+    // NOTE: The reserves are less than 2^65 and `4A` is at most 40000, so the terms below stay far below 2^253 and can be compared.
+    d.assertLessThanOrEqual(x.add(y))
+    newY.assertLessThan(Field(2n ** 64n))
+
+    const fourXY = x.mul(y).mul(4)
+
+    // `D^3 + 4xy(4A - 1)D >= 16Axy(x + y)`
+    const isAtLeastInvariant = (d: Field) =>
+        d
+            .mul(d)
+            .mul(d)
+            .add(fourXY.mul(ann.sub(1)).mul(d))
+            .greaterThanOrEqual(fourXY.mul(ann).mul(x.add(y)))
+
+    isAtLeastInvariant(d).assertTrue()
+    isAtLeastInvariant(d.sub(1)).assertFalse()
+
+    // `4xy(4A(x + y) + D) >= 16AxyD + D^3`
+    const keepsInvariant = (y: Field) => {
+        const fourNewXY = newX.mul(y).mul(4)
+
+        return fourNewXY
+            .mul(ann.mul(newX.add(y)).add(d))
+            .greaterThanOrEqual(fourNewXY.mul(ann).mul(d).add(d.mul(d).mul(d)))
+    }
+
+    keepsInvariant(newY).assertTrue()
+    keepsInvariant(newY.sub(1)).assertFalse()
+
+    return choose(isStableSwap, newY, Field(0))
+}
+
+/**
+ * Returns the smallest integer between `low` and `high` that satisfies the given predicate.
+ * The predicate must be satisfied by `high` and by every integer after the first satisfying one.
+ */
+const smallestSatisfying = (low: bigint, high: bigint, predicate: (n: bigint) => boolean): bigint => {
+    while (low < high) {
+        const middle = low + (high - low) / 2n
+
+        if (predicate(middle)) {
+            high = middle
+        } else {
+            low = middle + 1n
+        }
+    }
+
+    return high
+}
+
+const stableInvariant = (x: bigint, y: bigint, ann: bigint): bigint => {
+    const fourXY = x * y * 4n
+
+    return smallestSatisfying(
+        0n,
+        x + y,
+        (d) => d * d * d + fourXY * (ann - 1n) * d >= fourXY * ann * (x + y),
+    )
+}
+
+const stableOtherReserve = (x: bigint, d: bigint, ann: bigint): bigint => {
+    return smallestSatisfying(0n, 2n ** 64n - 1n, (y) => {
+        const fourXY = x * y * 4n
+
+        return fourXY * (ann * (x + y) + d) >= fourXY * ann * d + d * d * d
+    })
+}
+
+/**
+ * Proves that `nonce` is the nonce of `owner` in the nonces tree with the given root, then increments it and returns the new root.
+ * A user has no nonce leaf until their first signed transaction, so the witness must be of the first unused leaf if `nonce` is zero.
+ */
+export const incrementNonce = (
+    noncesRoot: Field,
+    singleNonceWitness: SingleNonceWitness,
+    owner: PublicKey,
+    nonce: UInt64,
+): Field => {
+    const userNonce = new Nonce({ owner, value: nonce })
+
+    // NOTE: Like first balances, it isn't proved that the owner has no other leaf when the nonce is zero.
+    noncesRoot.assertEquals(
+        singleNonceWitness.calculateRoot(
+            Provable.if(
+                nonce.equals(UInt64.zero),
+                Field(0),
+                Poseidon.hash(userNonce.toFields()),
+            ),
+        ),
+    )
+
+    userNonce.value = nonce.add(1)
+
+    return singleNonceWitness.calculateRoot(Poseidon.hash(userNonce.toFields()))
 }
 
 /**
@@ -56,6 +56,16 @@ pub trait ByteConversion<const L: usize> {
     fn from_bytes(bytes: &[u8; L]) -> Self;
 }
 
+impl ByteConversion<16> for u128 {
+    fn to_bytes(&self) -> [u8; 16] {
+        self.to_le_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; 16]) -> Self {
+        u128::from_le_bytes(bytes.to_owned())
+    }
+}
+
 impl ByteConversion<8> for u64 {
     fn to_bytes(&self) -> [u8; 8] {
         self.to_le_bytes()
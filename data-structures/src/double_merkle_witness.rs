@@ -109,6 +109,7 @@ macro_rules! impl_byte_conversion_trait_for_height {
 }
 
 impl_byte_conversion_trait_for_height!(23);
+impl_byte_conversion_trait_for_height!(21);
 
 impl<const L: usize> From<(SingleMerkleWitness<L>, SingleMerkleWitness<L>)>
     for DoubleMerkleWitness<L>
@@ -7,14 +7,22 @@ mod double_merkle_witness;
 mod field;
 mod field_conversion;
 mod liquidity;
+mod nonce;
+mod observation;
+mod order;
+mod order_status;
 mod pool;
+mod pool_kind;
 mod prover_method;
+mod rejection_reason;
 mod scalar;
 mod sibling;
 mod signature;
 mod single_merkle_witness;
 mod state_roots;
 mod stateful_transaction;
+mod swap_side;
+mod trade_stats;
 mod transaction;
 mod tx_status;
 mod u256;
@@ -29,21 +37,34 @@ pub use double_merkle_witness::DoubleMerkleWitness;
 pub use field::Field;
 pub use field_conversion::FieldConversion;
 pub use liquidity::Liquidity;
+pub use nonce::Nonce;
+pub use observation::Observation;
+pub use order::Order;
+pub use order_status::OrderStatus;
 pub use pool::Pool;
+pub use pool_kind::PoolKind;
 pub use prover_method::ProverMethod;
+pub use rejection_reason::RejectionReason;
 pub(crate) use scalar::{field_to_scalar, scalar_to_field, Scalar};
 pub use sibling::Sibling;
 pub use signature::Signature;
 pub use single_merkle_witness::SingleMerkleWitness;
 pub use state_roots::StateRoots;
 pub use stateful_transaction::{
-    BurnTokensTransactionState, BuyTokensTransactionState, CreatePoolTransactionState,
-    DepositTokensTransactionState, ProvideLiquidityTransactionState,
+    BurnTokensTransactionState, BuyTokensTransactionState, CancelOrderTransactionState,
+    ClaimProtocolFeesTransactionState, CreatePoolTransactionState, DepositTokensTransactionState,
+    FillOrderTransactionState, PlaceOrderTransactionState, ProvideLiquidityTransactionState,
     RemoveLiquidityTransactionState, SellTokensTransactionState, StatefulTransaction,
+    SwapRouteTransactionState, TransferTokensTransactionState,
 };
+pub use swap_side::SwapSide;
+pub use trade_stats::TradeStats;
 pub use transaction::{
-    BurnTokensTransaction, BuyTokensTransaction, CreatePoolTransaction, DepositTokensTransaction,
-    ProvideLiquidityTransaction, RemoveLiquidityTransaction, SellTokensTransaction, Transaction,
+    BurnTokensTransaction, BuyTokensTransaction, CancelOrderTransaction,
+    ClaimProtocolFeesTransaction, CreatePoolTransaction, DepositTokensTransaction,
+    FillOrderTransaction, PlaceOrderTransaction, ProvideLiquidityTransaction,
+    RemoveLiquidityTransaction, SellTokensTransaction, SwapRouteTransaction, Transaction,
+    TransferTokensTransaction,
 };
 pub use tx_status::TxStatus;
 pub use u256::U256;
@@ -0,0 +1,41 @@
+use crate::{Address, ByteConversion, Field, FieldConversion};
+
+/// The data structure that represents a user's nonce, which is the count of the user's signed transactions.
+///
+/// It is stored inside Balances DB.
+///
+/// - The `owner` property represents the related user's address.
+/// - The `value` property represents the nonce the user's next transaction must be signed with.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Nonce {
+    pub owner: Address,
+    pub value: u64,
+}
+
+impl FieldConversion<3> for Nonce {
+    fn to_fields(&self) -> [Field; 3] {
+        let [field_0, field_1] = self.owner.to_fields();
+        let [field_2] = self.value.to_fields();
+
+        [field_0, field_1, field_2]
+    }
+}
+
+impl ByteConversion<63> for Nonce {
+    fn to_bytes(&self) -> [u8; 63] {
+        let mut bytes = [0u8; 63];
+
+        bytes[0..55].copy_from_slice(&self.owner.to_bytes());
+        bytes[55..63].copy_from_slice(&self.value.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 63]) -> Self {
+        Self {
+            owner: Address::from_bytes(bytes[0..55].try_into().unwrap()),
+            value: u64::from_bytes(bytes[55..63].try_into().unwrap()),
+        }
+    }
+}
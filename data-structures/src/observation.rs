@@ -0,0 +1,42 @@
+use crate::ByteConversion;
+
+/// The data structure that represents a price observation of an AMM pool, which is used to compute time-weighted average prices.
+///
+/// It is stored inside Observations DB.
+///
+/// - The `timestamp` property represents the Unix timestamp in seconds the observation is made at.
+/// - The `price_cumulative` property represents the sum of the pool's prices multiplied by the seconds they lasted until the timestamp, it is allowed to overflow.
+/// - The `price` property represents the pool's price from the timestamp on.
+/// - The `points_per_liquidity` property represents the pool's total liquidity points per unit of its invariant, which only goes down as swap fees are added to the reserves.
+///
+/// Prices are in quote tokens per base token. Prices and points per liquidity are stored as 64.64 fixed point numbers.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Observation {
+    pub timestamp: u64,
+    pub price_cumulative: u128,
+    pub price: u128,
+    pub points_per_liquidity: u128,
+}
+
+impl ByteConversion<56> for Observation {
+    fn to_bytes(&self) -> [u8; 56] {
+        let mut bytes = [0u8; 56];
+
+        bytes[0..8].copy_from_slice(&self.timestamp.to_bytes());
+        bytes[8..24].copy_from_slice(&self.price_cumulative.to_bytes());
+        bytes[24..40].copy_from_slice(&self.price.to_bytes());
+        bytes[40..56].copy_from_slice(&self.points_per_liquidity.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 56]) -> Self {
+        Self {
+            timestamp: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            price_cumulative: u128::from_bytes(bytes[8..24].try_into().unwrap()),
+            price: u128::from_bytes(bytes[24..40].try_into().unwrap()),
+            points_per_liquidity: u128::from_bytes(bytes[40..56].try_into().unwrap()),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{Address, ByteConversion, Field, FieldConversion, OrderStatus, U256};
+
+/// The data structure that represents a user's limit order that is matched against an AMM pool.
+///
+/// It is stored inside Orders DB.
+///
+/// - The `id` property represents the index of the order in Orders DB, which is used to refer to the order.
+/// - The `owner` property represents the related user's address.
+/// - The `token_in_id` property represents the identifier of the token the order sells.
+/// - The `token_out_id` property represents the identifier of the token the order buys.
+/// - The `token_amount_in` property represents the token amount the order sells, which is escrowed from the user's balance.
+/// - The `token_amount_out_limit` property represents the minimum token amount the order has to buy, which sets its limit price.
+/// - The `status` property represents whether the order is open, filled or cancelled.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Order {
+    pub id: u64,
+    pub owner: Address,
+    pub token_in_id: U256,
+    pub token_out_id: U256,
+    pub token_amount_in: u64,
+    pub token_amount_out_limit: u64,
+    pub status: OrderStatus,
+}
+
+impl FieldConversion<8> for Order {
+    fn to_fields(&self) -> [Field; 8] {
+        let [field_0] = self.id.to_fields();
+        let [field_1, field_2] = self.owner.to_fields();
+        let [field_3] = self.token_in_id.to_fields();
+        let [field_4] = self.token_out_id.to_fields();
+        let [field_5] = self.token_amount_in.to_fields();
+        let [field_6] = self.token_amount_out_limit.to_fields();
+        let [field_7] = self.status.to_fields();
+
+        [
+            field_0, field_1, field_2, field_3, field_4, field_5, field_6, field_7,
+        ]
+    }
+}
+
+impl ByteConversion<144> for Order {
+    fn to_bytes(&self) -> [u8; 144] {
+        let mut bytes = [0u8; 144];
+
+        bytes[0..8].copy_from_slice(&self.id.to_bytes());
+        bytes[8..63].copy_from_slice(&self.owner.to_bytes());
+        bytes[63..95].copy_from_slice(&self.token_in_id.to_bytes());
+        bytes[95..127].copy_from_slice(&self.token_out_id.to_bytes());
+        bytes[127..135].copy_from_slice(&self.token_amount_in.to_bytes());
+        bytes[135..143].copy_from_slice(&self.token_amount_out_limit.to_bytes());
+        bytes[143..144].copy_from_slice(&self.status.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 144]) -> Self {
+        Self {
+            id: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            owner: Address::from_bytes(bytes[8..63].try_into().unwrap()),
+            token_in_id: U256::from_bytes(bytes[63..95].try_into().unwrap()),
+            token_out_id: U256::from_bytes(bytes[95..127].try_into().unwrap()),
+            token_amount_in: u64::from_bytes(bytes[127..135].try_into().unwrap()),
+            token_amount_out_limit: u64::from_bytes(bytes[135..143].try_into().unwrap()),
+            status: OrderStatus::from_bytes(bytes[143..144].try_into().unwrap()),
+        }
+    }
+}
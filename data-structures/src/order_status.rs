@@ -0,0 +1,32 @@
+use crate::{ByteConversion, Field, FieldConversion};
+
+/// The enum that represents the status of a limit order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OrderStatus {
+    /// The status which means the order is waiting for the price of its pool to reach its limit.
+    Open,
+    /// The status which means the order is filled against its pool.
+    Filled,
+    /// The status which means the order is cancelled by its owner and its tokens are refunded.
+    Cancelled,
+}
+
+impl FieldConversion<1> for OrderStatus {
+    fn to_fields(&self) -> [Field; 1] {
+        (self.to_owned() as u64).to_fields()
+    }
+}
+
+impl ByteConversion<1> for OrderStatus {
+    fn to_bytes(&self) -> [u8; 1] {
+        [self.to_owned() as u8]
+    }
+
+    fn from_bytes(bytes: &[u8; 1]) -> Self {
+        match bytes[0] {
+            0 => Self::Open,
+            1 => Self::Filled,
+            _ => Self::Cancelled,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ByteConversion, Field, FieldConversion, U256};
+use crate::{ByteConversion, Field, FieldConversion, PoolKind, U256};
 
 /// The data structure that represents a specific AMM pool.
 ///
@@ -9,6 +9,10 @@ use crate::{ByteConversion, Field, FieldConversion, U256};
 /// - The `base_token_amount` property represents the total base token amount inside the AMM pool.
 /// - The `quote_token_amount` property represents the total quote token amount inside the AMM pool.
 /// - The `total_liqudity_points` property represents the total liquidity points created for the AMM pool.
+/// - The `fee_rate` property represents the swap fee of the AMM pool in basis points, it is one of `Pool::FEE_RATES`.
+/// - The `protocol_fee_base_token_amount` property represents the base token amount accrued as protocol fees, which isn't a part of the reserves.
+/// - The `protocol_fee_quote_token_amount` property represents the quote token amount accrued as protocol fees, which isn't a part of the reserves.
+/// - The `amplification` property represents the amplification coefficient of StableSwap pools, it is zero for constant product pools.
 ///
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Pool {
@@ -17,40 +21,87 @@ pub struct Pool {
     pub base_token_amount: u64,
     pub quote_token_amount: u64,
     pub total_liqudity_points: U256,
+    pub fee_rate: u64,
+    pub protocol_fee_base_token_amount: u64,
+    pub protocol_fee_quote_token_amount: u64,
+    pub amplification: u64,
 }
 
-impl FieldConversion<5> for Pool {
-    fn to_fields(&self) -> [Field; 5] {
+impl Pool {
+    /// The fee rates in basis points an AMM pool can be created with.
+    ///
+    /// The lowest one is meant for stable pairs and the highest one for volatile pairs.
+    pub const FEE_RATES: [u64; 3] = [5, 30, 100];
+
+    /// The maximum protocol fee share, which is the part of each swap fee in basis points that is taken as the protocol fee.
+    pub const MAX_PROTOCOL_FEE_SHARE: u64 = 10_000;
+
+    /// The maximum amplification coefficient a StableSwap pool can be created with.
+    pub const MAX_AMPLIFICATION: u64 = 10_000;
+
+    /// Returns the invariant the AMM pool prices its swaps with.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let kind = pool.kind();
+    /// ```
+    ///
+    pub fn kind(&self) -> PoolKind {
+        if self.amplification == 0 {
+            PoolKind::ConstantProduct
+        } else {
+            PoolKind::StableSwap
+        }
+    }
+}
+
+impl FieldConversion<9> for Pool {
+    fn to_fields(&self) -> [Field; 9] {
         let [field_0] = self.base_token_id.to_fields();
         let [field_1] = self.quote_token_id.to_fields();
         let [field_2] = self.base_token_amount.to_fields();
         let [field_3] = self.quote_token_amount.to_fields();
         let [field_4] = self.total_liqudity_points.to_fields();
+        let [field_5] = self.fee_rate.to_fields();
+        let [field_6] = self.protocol_fee_base_token_amount.to_fields();
+        let [field_7] = self.protocol_fee_quote_token_amount.to_fields();
+        let [field_8] = self.amplification.to_fields();
 
-        [field_0, field_1, field_2, field_3, field_4]
+        [
+            field_0, field_1, field_2, field_3, field_4, field_5, field_6, field_7, field_8,
+        ]
     }
 }
 
-impl ByteConversion<112> for Pool {
-    fn to_bytes(&self) -> [u8; 112] {
-        let mut bytes = [0u8; 112];
+impl ByteConversion<144> for Pool {
+    fn to_bytes(&self) -> [u8; 144] {
+        let mut bytes = [0u8; 144];
 
         bytes[0..32].copy_from_slice(&self.base_token_id.to_bytes());
         bytes[32..64].copy_from_slice(&self.quote_token_id.to_bytes());
         bytes[64..72].copy_from_slice(&self.base_token_amount.to_bytes());
         bytes[72..80].copy_from_slice(&self.quote_token_amount.to_bytes());
         bytes[80..112].copy_from_slice(&self.total_liqudity_points.to_bytes());
+        bytes[112..120].copy_from_slice(&self.fee_rate.to_bytes());
+        bytes[120..128].copy_from_slice(&self.protocol_fee_base_token_amount.to_bytes());
+        bytes[128..136].copy_from_slice(&self.protocol_fee_quote_token_amount.to_bytes());
+        bytes[136..144].copy_from_slice(&self.amplification.to_bytes());
 
         bytes
     }
 
-    fn from_bytes(bytes: &[u8; 112]) -> Self {
+    fn from_bytes(bytes: &[u8; 144]) -> Self {
         Self {
             base_token_id: U256::from_bytes(bytes[0..32].try_into().unwrap()),
             quote_token_id: U256::from_bytes(bytes[32..64].try_into().unwrap()),
             base_token_amount: u64::from_bytes(bytes[64..72].try_into().unwrap()),
             quote_token_amount: u64::from_bytes(bytes[72..80].try_into().unwrap()),
             total_liqudity_points: U256::from_bytes(bytes[80..112].try_into().unwrap()),
+            fee_rate: u64::from_bytes(bytes[112..120].try_into().unwrap()),
+            protocol_fee_base_token_amount: u64::from_bytes(bytes[120..128].try_into().unwrap()),
+            protocol_fee_quote_token_amount: u64::from_bytes(bytes[128..136].try_into().unwrap()),
+            amplification: u64::from_bytes(bytes[136..144].try_into().unwrap()),
         }
     }
 }
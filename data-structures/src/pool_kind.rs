@@ -0,0 +1,43 @@
+use crate::{ByteConversion, Pool};
+
+/// The enum that represents the invariant an AMM pool prices its swaps with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolKind {
+    /// The kind which means the pool keeps the product of its reserves constant, it is meant for volatile pairs.
+    ConstantProduct,
+    /// The kind which means the pool uses the StableSwap invariant with an amplification coefficient, it is meant for pegged pairs.
+    StableSwap,
+}
+
+impl PoolKind {
+    /// Returns `true` if an AMM pool of this kind can be created with the given amplification coefficient.
+    ///
+    /// Constant product pools don't have an amplification coefficient, so it has to be zero.
+    /// StableSwap pools have to have one between `1` and `Pool::MAX_AMPLIFICATION`.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let is_valid = pool_kind.accepts_amplification(100);
+    /// ```
+    ///
+    pub fn accepts_amplification(&self, amplification: u64) -> bool {
+        match self {
+            PoolKind::ConstantProduct => amplification == 0,
+            PoolKind::StableSwap => (1..=Pool::MAX_AMPLIFICATION).contains(&amplification),
+        }
+    }
+}
+
+impl ByteConversion<1> for PoolKind {
+    fn to_bytes(&self) -> [u8; 1] {
+        [self.to_owned() as u8]
+    }
+
+    fn from_bytes(bytes: &[u8; 1]) -> Self {
+        match bytes[0] {
+            0 => Self::ConstantProduct,
+            _ => Self::StableSwap,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    Address, ByteConversion, DoubleMerkleWitness, Signature, SingleMerkleWitness, StateRoots, U256,
+    Address, ByteConversion, DoubleMerkleWitness, Order, Pool, Signature, SingleMerkleWitness,
+    StateRoots, U256,
 };
 
 /// The enum that represents prover methods.
@@ -26,12 +27,14 @@ pub enum ProverMethod {
         earlier_proof_index: u64,
         single_balance_witness: SingleMerkleWitness<22>,
         single_burn_witness: SingleMerkleWitness<19>,
+        single_nonce_witness: SingleMerkleWitness<21>,
         user_address: Address,
         token_id: U256,
         user_burn_token_amount: u64,
         user_balance_token_amount: u64,
         amount_to_burn: u64,
         user_signature: Signature,
+        user_nonce: u64,
     },
     CreatePool {
         state_roots: StateRoots,
@@ -39,6 +42,7 @@ pub enum ProverMethod {
         single_pool_witness: SingleMerkleWitness<20>,
         single_liquidity_witness: SingleMerkleWitness<21>,
         double_balance_witness: DoubleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
         base_token_id: U256,
         quote_token_id: U256,
         user_address: Address,
@@ -47,6 +51,9 @@ pub enum ProverMethod {
         user_balance_base_token_amount: u64,
         user_balance_quote_token_amount: u64,
         user_signature: Signature,
+        user_nonce: u64,
+        pool_fee_rate: u64,
+        pool_amplification: u64,
     },
     ProvideLiquidity {
         state_roots: StateRoots,
@@ -54,6 +61,7 @@ pub enum ProverMethod {
         single_pool_witness: SingleMerkleWitness<20>,
         single_liquidity_witness: SingleMerkleWitness<21>,
         double_balance_witness: DoubleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
         base_token_id: U256,
         quote_token_id: U256,
         user_address: Address,
@@ -66,6 +74,11 @@ pub enum ProverMethod {
         user_base_token_amount_to_provide: u64,
         user_quote_token_amount_limit_to_provide: u64,
         user_signature: Signature,
+        user_nonce: u64,
+        pool_fee_rate: u64,
+        pool_protocol_fee_base_token_amount: u64,
+        pool_protocol_fee_quote_token_amount: u64,
+        pool_amplification: u64,
     },
     RemoveLiquidity {
         state_roots: StateRoots,
@@ -73,6 +86,7 @@ pub enum ProverMethod {
         single_pool_witness: SingleMerkleWitness<20>,
         single_liquidity_witness: SingleMerkleWitness<21>,
         double_balance_witness: DoubleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
         base_token_id: U256,
         quote_token_id: U256,
         user_address: Address,
@@ -86,12 +100,18 @@ pub enum ProverMethod {
         user_base_token_amount_limit_to_remove: u64,
         user_quote_token_amount_limit_to_remove: u64,
         user_signature: Signature,
+        user_nonce: u64,
+        pool_fee_rate: u64,
+        pool_protocol_fee_base_token_amount: u64,
+        pool_protocol_fee_quote_token_amount: u64,
+        pool_amplification: u64,
     },
     BuyTokens {
         state_roots: StateRoots,
         earlier_proof_index: u64,
         single_pool_witness: SingleMerkleWitness<20>,
         double_balance_witness: DoubleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
         user_address: Address,
         base_token_id: U256,
         quote_token_id: U256,
@@ -103,12 +123,19 @@ pub enum ProverMethod {
         user_base_token_amount_to_swap: u64,
         user_quote_token_amount_limit_to_swap: u64,
         user_signature: Signature,
+        user_nonce: u64,
+        pool_fee_rate: u64,
+        pool_protocol_fee_base_token_amount: u64,
+        pool_protocol_fee_quote_token_amount: u64,
+        protocol_fee_share: u64,
+        pool_amplification: u64,
     },
     SellTokens {
         state_roots: StateRoots,
         earlier_proof_index: u64,
         single_pool_witness: SingleMerkleWitness<20>,
         double_balance_witness: DoubleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
         user_address: Address,
         base_token_id: U256,
         quote_token_id: U256,
@@ -120,17 +147,106 @@ pub enum ProverMethod {
         user_base_token_amount_limit_to_swap: u64,
         user_quote_token_amount_to_swap: u64,
         user_signature: Signature,
+        user_nonce: u64,
+        pool_fee_rate: u64,
+        pool_protocol_fee_base_token_amount: u64,
+        pool_protocol_fee_quote_token_amount: u64,
+        protocol_fee_share: u64,
+        pool_amplification: u64,
+    },
+    SwapRoute {
+        state_roots: StateRoots,
+        earlier_proof_index: u64,
+        double_pool_witness: DoubleMerkleWitness<20>,
+        double_balance_witness: DoubleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
+        user_address: Address,
+        path: [U256; 3],
+        user_balance_token_in_amount: u64,
+        user_balance_token_out_amount: u64,
+        first_pool: Pool,
+        second_pool: Pool,
+        user_token_amount_in: u64,
+        user_token_amount_out_limit: u64,
+        user_signature: Signature,
+        user_nonce: u64,
+        protocol_fee_share: u64,
+    },
+    TransferTokens {
+        state_roots: StateRoots,
+        earlier_proof_index: u64,
+        double_balance_witness: DoubleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
+        user_address: Address,
+        receiver_address: Address,
+        token_id: U256,
+        user_balance_token_amount: u64,
+        receiver_balance_token_amount: u64,
+        amount_to_transfer: u64,
+        user_signature: Signature,
+        user_nonce: u64,
+    },
+    PlaceOrder {
+        state_roots: StateRoots,
+        earlier_proof_index: u64,
+        single_order_witness: SingleMerkleWitness<21>,
+        single_balance_witness: SingleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
+        user_address: Address,
+        token_in_id: U256,
+        token_out_id: U256,
+        user_balance_token_in_amount: u64,
+        user_token_amount_in: u64,
+        user_token_amount_out_limit: u64,
+        order_id: u64,
+        user_signature: Signature,
+        user_nonce: u64,
+    },
+    CancelOrder {
+        state_roots: StateRoots,
+        earlier_proof_index: u64,
+        single_order_witness: SingleMerkleWitness<21>,
+        single_balance_witness: SingleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
+        order: Order,
+        user_balance_token_in_amount: u64,
+        user_signature: Signature,
+        user_nonce: u64,
+    },
+    FillOrder {
+        state_roots: StateRoots,
+        earlier_proof_index: u64,
+        single_order_witness: SingleMerkleWitness<21>,
+        single_pool_witness: SingleMerkleWitness<20>,
+        single_balance_witness: SingleMerkleWitness<22>,
+        order: Order,
+        pool: Pool,
+        user_balance_token_out_amount: u64,
+        protocol_fee_share: u64,
+    },
+    ClaimProtocolFees {
+        state_roots: StateRoots,
+        earlier_proof_index: u64,
+        single_pool_witness: SingleMerkleWitness<20>,
+        double_balance_witness: DoubleMerkleWitness<22>,
+        single_nonce_witness: SingleMerkleWitness<21>,
+        user_address: Address,
+        pool: Pool,
+        user_balance_base_token_amount: u64,
+        user_balance_quote_token_amount: u64,
+        user_signature: Signature,
+        user_nonce: u64,
     },
 }
 
-impl ByteConversion<3291> for ProverMethod {
-    fn to_bytes(&self) -> [u8; 3291] {
-        let mut buf = [0; 3291];
+impl ByteConversion<4259> for ProverMethod {
+    fn to_bytes(&self) -> [u8; 4259] {
+        let mut buf = [0; 4259];
 
         match self {
             ProverMethod::CreateGenesis { state_roots } => {
                 buf[0] = 0;
-                buf[1..129].copy_from_slice(&state_roots.to_bytes());
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
             }
             ProverMethod::DepositTokens {
                 state_roots,
@@ -144,39 +260,43 @@ impl ByteConversion<3291> for ProverMethod {
                 user_balance_token_amount,
             } => {
                 buf[0] = 1;
-                buf[1..129].copy_from_slice(&state_roots.to_bytes());
-                buf[129..137].copy_from_slice(&earlier_proof_index.to_bytes());
-                buf[137..863].copy_from_slice(&single_balance_witness.to_bytes());
-                buf[863..895].copy_from_slice(&current_deposits_merkle_list_hash.to_bytes());
-                buf[895..927].copy_from_slice(&expected_deposits_merkle_list_hash.to_bytes());
-                buf[927..982].copy_from_slice(&user_address.to_bytes());
-                buf[982..1014].copy_from_slice(&token_id.to_bytes());
-                buf[1014..1022].copy_from_slice(&user_deposit_token_amount.to_bytes());
-                buf[1022..1030].copy_from_slice(&user_balance_token_amount.to_bytes());
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..927].copy_from_slice(&single_balance_witness.to_bytes());
+                buf[927..959].copy_from_slice(&current_deposits_merkle_list_hash.to_bytes());
+                buf[959..991].copy_from_slice(&expected_deposits_merkle_list_hash.to_bytes());
+                buf[991..1046].copy_from_slice(&user_address.to_bytes());
+                buf[1046..1078].copy_from_slice(&token_id.to_bytes());
+                buf[1078..1086].copy_from_slice(&user_deposit_token_amount.to_bytes());
+                buf[1086..1094].copy_from_slice(&user_balance_token_amount.to_bytes());
             }
             ProverMethod::BurnTokens {
                 state_roots,
                 earlier_proof_index,
                 single_balance_witness,
                 single_burn_witness,
+                single_nonce_witness,
                 user_address,
                 token_id,
                 user_burn_token_amount,
                 user_balance_token_amount,
                 amount_to_burn,
                 user_signature,
+                user_nonce,
             } => {
                 buf[0] = 2;
-                buf[1..129].copy_from_slice(&state_roots.to_bytes());
-                buf[129..137].copy_from_slice(&earlier_proof_index.to_bytes());
-                buf[137..863].copy_from_slice(&single_balance_witness.to_bytes());
-                buf[863..1490].copy_from_slice(&single_burn_witness.to_bytes());
-                buf[1490..1545].copy_from_slice(&user_address.to_bytes());
-                buf[1545..1577].copy_from_slice(&token_id.to_bytes());
-                buf[1577..1585].copy_from_slice(&user_burn_token_amount.to_bytes());
-                buf[1585..1593].copy_from_slice(&user_balance_token_amount.to_bytes());
-                buf[1593..1601].copy_from_slice(&amount_to_burn.to_bytes());
-                buf[1601..1665].copy_from_slice(&user_signature.to_bytes());
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..927].copy_from_slice(&single_balance_witness.to_bytes());
+                buf[927..1554].copy_from_slice(&single_burn_witness.to_bytes());
+                buf[1554..2247].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[2247..2302].copy_from_slice(&user_address.to_bytes());
+                buf[2302..2334].copy_from_slice(&token_id.to_bytes());
+                buf[2334..2342].copy_from_slice(&user_burn_token_amount.to_bytes());
+                buf[2342..2350].copy_from_slice(&user_balance_token_amount.to_bytes());
+                buf[2350..2358].copy_from_slice(&amount_to_burn.to_bytes());
+                buf[2358..2422].copy_from_slice(&user_signature.to_bytes());
+                buf[2422..2430].copy_from_slice(&user_nonce.to_bytes());
             }
             ProverMethod::CreatePool {
                 state_roots,
@@ -184,6 +304,7 @@ impl ByteConversion<3291> for ProverMethod {
                 single_pool_witness,
                 single_liquidity_witness,
                 double_balance_witness,
+                single_nonce_witness,
                 base_token_id,
                 quote_token_id,
                 user_address,
@@ -192,21 +313,28 @@ impl ByteConversion<3291> for ProverMethod {
                 user_balance_base_token_amount,
                 user_balance_quote_token_amount,
                 user_signature,
+                user_nonce,
+                pool_fee_rate,
+                pool_amplification,
             } => {
                 buf[0] = 3;
-                buf[1..129].copy_from_slice(&state_roots.to_bytes());
-                buf[129..137].copy_from_slice(&earlier_proof_index.to_bytes());
-                buf[137..797].copy_from_slice(&single_pool_witness.to_bytes());
-                buf[797..1490].copy_from_slice(&single_liquidity_witness.to_bytes());
-                buf[1490..2964].copy_from_slice(&double_balance_witness.to_bytes());
-                buf[2964..2996].copy_from_slice(&base_token_id.to_bytes());
-                buf[2996..3028].copy_from_slice(&quote_token_id.to_bytes());
-                buf[3028..3083].copy_from_slice(&user_address.to_bytes());
-                buf[3083..3091].copy_from_slice(&user_liquidity_base_token_amount.to_bytes());
-                buf[3091..3099].copy_from_slice(&user_liquidity_quote_token_amount.to_bytes());
-                buf[3099..3107].copy_from_slice(&user_balance_base_token_amount.to_bytes());
-                buf[3107..3115].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
-                buf[3115..3179].copy_from_slice(&user_signature.to_bytes());
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..861].copy_from_slice(&single_pool_witness.to_bytes());
+                buf[861..1554].copy_from_slice(&single_liquidity_witness.to_bytes());
+                buf[1554..3028].copy_from_slice(&double_balance_witness.to_bytes());
+                buf[3028..3721].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[3721..3753].copy_from_slice(&base_token_id.to_bytes());
+                buf[3753..3785].copy_from_slice(&quote_token_id.to_bytes());
+                buf[3785..3840].copy_from_slice(&user_address.to_bytes());
+                buf[3840..3848].copy_from_slice(&user_liquidity_base_token_amount.to_bytes());
+                buf[3848..3856].copy_from_slice(&user_liquidity_quote_token_amount.to_bytes());
+                buf[3856..3864].copy_from_slice(&user_balance_base_token_amount.to_bytes());
+                buf[3864..3872].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
+                buf[3872..3936].copy_from_slice(&user_signature.to_bytes());
+                buf[3936..3944].copy_from_slice(&user_nonce.to_bytes());
+                buf[3944..3952].copy_from_slice(&pool_fee_rate.to_bytes());
+                buf[3952..3960].copy_from_slice(&pool_amplification.to_bytes());
             }
             ProverMethod::ProvideLiquidity {
                 state_roots,
@@ -214,6 +342,7 @@ impl ByteConversion<3291> for ProverMethod {
                 single_pool_witness,
                 single_liquidity_witness,
                 double_balance_witness,
+                single_nonce_witness,
                 base_token_id,
                 quote_token_id,
                 user_address,
@@ -226,26 +355,37 @@ impl ByteConversion<3291> for ProverMethod {
                 user_base_token_amount_to_provide,
                 user_quote_token_amount_limit_to_provide,
                 user_signature,
+                user_nonce,
+                pool_fee_rate,
+                pool_protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount,
+                pool_amplification,
             } => {
                 buf[0] = 4;
-                buf[1..129].copy_from_slice(&state_roots.to_bytes());
-                buf[129..137].copy_from_slice(&earlier_proof_index.to_bytes());
-                buf[137..797].copy_from_slice(&single_pool_witness.to_bytes());
-                buf[797..1490].copy_from_slice(&single_liquidity_witness.to_bytes());
-                buf[1490..2964].copy_from_slice(&double_balance_witness.to_bytes());
-                buf[2964..2996].copy_from_slice(&base_token_id.to_bytes());
-                buf[2996..3028].copy_from_slice(&quote_token_id.to_bytes());
-                buf[3028..3083].copy_from_slice(&user_address.to_bytes());
-                buf[3083..3115].copy_from_slice(&user_liquidity_points.to_bytes());
-                buf[3115..3123].copy_from_slice(&user_balance_base_token_amount.to_bytes());
-                buf[3123..3131].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
-                buf[3131..3139].copy_from_slice(&pool_base_token_amount.to_bytes());
-                buf[3139..3147].copy_from_slice(&pool_quote_token_amount.to_bytes());
-                buf[3147..3179].copy_from_slice(&pool_total_liquidity_points.to_bytes());
-                buf[3179..3187].copy_from_slice(&user_base_token_amount_to_provide.to_bytes());
-                buf[3187..3195]
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..861].copy_from_slice(&single_pool_witness.to_bytes());
+                buf[861..1554].copy_from_slice(&single_liquidity_witness.to_bytes());
+                buf[1554..3028].copy_from_slice(&double_balance_witness.to_bytes());
+                buf[3028..3721].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[3721..3753].copy_from_slice(&base_token_id.to_bytes());
+                buf[3753..3785].copy_from_slice(&quote_token_id.to_bytes());
+                buf[3785..3840].copy_from_slice(&user_address.to_bytes());
+                buf[3840..3872].copy_from_slice(&user_liquidity_points.to_bytes());
+                buf[3872..3880].copy_from_slice(&user_balance_base_token_amount.to_bytes());
+                buf[3880..3888].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
+                buf[3888..3896].copy_from_slice(&pool_base_token_amount.to_bytes());
+                buf[3896..3904].copy_from_slice(&pool_quote_token_amount.to_bytes());
+                buf[3904..3936].copy_from_slice(&pool_total_liquidity_points.to_bytes());
+                buf[3936..3944].copy_from_slice(&user_base_token_amount_to_provide.to_bytes());
+                buf[3944..3952]
                     .copy_from_slice(&user_quote_token_amount_limit_to_provide.to_bytes());
-                buf[3195..3259].copy_from_slice(&user_signature.to_bytes());
+                buf[3952..4016].copy_from_slice(&user_signature.to_bytes());
+                buf[4016..4024].copy_from_slice(&user_nonce.to_bytes());
+                buf[4024..4032].copy_from_slice(&pool_fee_rate.to_bytes());
+                buf[4032..4040].copy_from_slice(&pool_protocol_fee_base_token_amount.to_bytes());
+                buf[4040..4048].copy_from_slice(&pool_protocol_fee_quote_token_amount.to_bytes());
+                buf[4048..4056].copy_from_slice(&pool_amplification.to_bytes());
             }
             ProverMethod::RemoveLiquidity {
                 state_roots,
@@ -253,6 +393,7 @@ impl ByteConversion<3291> for ProverMethod {
                 single_pool_witness,
                 single_liquidity_witness,
                 double_balance_witness,
+                single_nonce_witness,
                 base_token_id,
                 quote_token_id,
                 user_address,
@@ -266,33 +407,45 @@ impl ByteConversion<3291> for ProverMethod {
                 user_base_token_amount_limit_to_remove,
                 user_quote_token_amount_limit_to_remove,
                 user_signature,
+                user_nonce,
+                pool_fee_rate,
+                pool_protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount,
+                pool_amplification,
             } => {
                 buf[0] = 5;
-                buf[1..129].copy_from_slice(&state_roots.to_bytes());
-                buf[129..137].copy_from_slice(&earlier_proof_index.to_bytes());
-                buf[137..797].copy_from_slice(&single_pool_witness.to_bytes());
-                buf[797..1490].copy_from_slice(&single_liquidity_witness.to_bytes());
-                buf[1490..2964].copy_from_slice(&double_balance_witness.to_bytes());
-                buf[2964..2996].copy_from_slice(&base_token_id.to_bytes());
-                buf[2996..3028].copy_from_slice(&quote_token_id.to_bytes());
-                buf[3028..3083].copy_from_slice(&user_address.to_bytes());
-                buf[3083..3115].copy_from_slice(&user_liquidity_points.to_bytes());
-                buf[3115..3123].copy_from_slice(&user_balance_base_token_amount.to_bytes());
-                buf[3123..3131].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
-                buf[3131..3139].copy_from_slice(&pool_base_token_amount.to_bytes());
-                buf[3139..3147].copy_from_slice(&pool_quote_token_amount.to_bytes());
-                buf[3147..3179].copy_from_slice(&pool_total_liquidity_points.to_bytes());
-                buf[3179..3211].copy_from_slice(&user_liquidity_points_to_remove.to_bytes());
-                buf[3211..3219].copy_from_slice(&user_base_token_amount_limit_to_remove.to_bytes());
-                buf[3219..3227]
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..861].copy_from_slice(&single_pool_witness.to_bytes());
+                buf[861..1554].copy_from_slice(&single_liquidity_witness.to_bytes());
+                buf[1554..3028].copy_from_slice(&double_balance_witness.to_bytes());
+                buf[3028..3721].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[3721..3753].copy_from_slice(&base_token_id.to_bytes());
+                buf[3753..3785].copy_from_slice(&quote_token_id.to_bytes());
+                buf[3785..3840].copy_from_slice(&user_address.to_bytes());
+                buf[3840..3872].copy_from_slice(&user_liquidity_points.to_bytes());
+                buf[3872..3880].copy_from_slice(&user_balance_base_token_amount.to_bytes());
+                buf[3880..3888].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
+                buf[3888..3896].copy_from_slice(&pool_base_token_amount.to_bytes());
+                buf[3896..3904].copy_from_slice(&pool_quote_token_amount.to_bytes());
+                buf[3904..3936].copy_from_slice(&pool_total_liquidity_points.to_bytes());
+                buf[3936..3968].copy_from_slice(&user_liquidity_points_to_remove.to_bytes());
+                buf[3968..3976].copy_from_slice(&user_base_token_amount_limit_to_remove.to_bytes());
+                buf[3976..3984]
                     .copy_from_slice(&user_quote_token_amount_limit_to_remove.to_bytes());
-                buf[3227..3291].copy_from_slice(&user_signature.to_bytes());
+                buf[3984..4048].copy_from_slice(&user_signature.to_bytes());
+                buf[4048..4056].copy_from_slice(&user_nonce.to_bytes());
+                buf[4056..4064].copy_from_slice(&pool_fee_rate.to_bytes());
+                buf[4064..4072].copy_from_slice(&pool_protocol_fee_base_token_amount.to_bytes());
+                buf[4072..4080].copy_from_slice(&pool_protocol_fee_quote_token_amount.to_bytes());
+                buf[4080..4088].copy_from_slice(&pool_amplification.to_bytes());
             }
             ProverMethod::BuyTokens {
                 state_roots,
                 earlier_proof_index,
                 single_pool_witness,
                 double_balance_witness,
+                single_nonce_witness,
                 user_address,
                 base_token_id,
                 quote_token_id,
@@ -304,29 +457,43 @@ impl ByteConversion<3291> for ProverMethod {
                 user_base_token_amount_to_swap,
                 user_quote_token_amount_limit_to_swap,
                 user_signature,
+                user_nonce,
+                pool_fee_rate,
+                pool_protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount,
+                protocol_fee_share,
+                pool_amplification,
             } => {
                 buf[0] = 6;
-                buf[1..129].copy_from_slice(&state_roots.to_bytes());
-                buf[129..137].copy_from_slice(&earlier_proof_index.to_bytes());
-                buf[137..797].copy_from_slice(&single_pool_witness.to_bytes());
-                buf[797..2271].copy_from_slice(&double_balance_witness.to_bytes());
-                buf[2271..2326].copy_from_slice(&user_address.to_bytes());
-                buf[2326..2358].copy_from_slice(&base_token_id.to_bytes());
-                buf[2358..2390].copy_from_slice(&quote_token_id.to_bytes());
-                buf[2390..2398].copy_from_slice(&user_balance_base_token_amount.to_bytes());
-                buf[2398..2406].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
-                buf[2406..2414].copy_from_slice(&pool_base_token_amount.to_bytes());
-                buf[2414..2422].copy_from_slice(&pool_quote_token_amount.to_bytes());
-                buf[2422..2454].copy_from_slice(&pool_total_liquidity_points.to_bytes());
-                buf[2454..2462].copy_from_slice(&user_base_token_amount_to_swap.to_bytes());
-                buf[2462..2470].copy_from_slice(&user_quote_token_amount_limit_to_swap.to_bytes());
-                buf[2470..2534].copy_from_slice(&user_signature.to_bytes());
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..861].copy_from_slice(&single_pool_witness.to_bytes());
+                buf[861..2335].copy_from_slice(&double_balance_witness.to_bytes());
+                buf[2335..3028].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[3028..3083].copy_from_slice(&user_address.to_bytes());
+                buf[3083..3115].copy_from_slice(&base_token_id.to_bytes());
+                buf[3115..3147].copy_from_slice(&quote_token_id.to_bytes());
+                buf[3147..3155].copy_from_slice(&user_balance_base_token_amount.to_bytes());
+                buf[3155..3163].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
+                buf[3163..3171].copy_from_slice(&pool_base_token_amount.to_bytes());
+                buf[3171..3179].copy_from_slice(&pool_quote_token_amount.to_bytes());
+                buf[3179..3211].copy_from_slice(&pool_total_liquidity_points.to_bytes());
+                buf[3211..3219].copy_from_slice(&user_base_token_amount_to_swap.to_bytes());
+                buf[3219..3227].copy_from_slice(&user_quote_token_amount_limit_to_swap.to_bytes());
+                buf[3227..3291].copy_from_slice(&user_signature.to_bytes());
+                buf[3291..3299].copy_from_slice(&user_nonce.to_bytes());
+                buf[3299..3307].copy_from_slice(&pool_fee_rate.to_bytes());
+                buf[3307..3315].copy_from_slice(&pool_protocol_fee_base_token_amount.to_bytes());
+                buf[3315..3323].copy_from_slice(&pool_protocol_fee_quote_token_amount.to_bytes());
+                buf[3323..3331].copy_from_slice(&protocol_fee_share.to_bytes());
+                buf[3331..3339].copy_from_slice(&pool_amplification.to_bytes());
             }
             ProverMethod::SellTokens {
                 state_roots,
                 earlier_proof_index,
                 single_pool_witness,
                 double_balance_witness,
+                single_nonce_witness,
                 user_address,
                 base_token_id,
                 quote_token_id,
@@ -338,30 +505,211 @@ impl ByteConversion<3291> for ProverMethod {
                 user_base_token_amount_limit_to_swap,
                 user_quote_token_amount_to_swap,
                 user_signature,
+                user_nonce,
+                pool_fee_rate,
+                pool_protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount,
+                protocol_fee_share,
+                pool_amplification,
             } => {
                 buf[0] = 7;
-                buf[1..129].copy_from_slice(&state_roots.to_bytes());
-                buf[129..137].copy_from_slice(&earlier_proof_index.to_bytes());
-                buf[137..797].copy_from_slice(&single_pool_witness.to_bytes());
-                buf[797..2271].copy_from_slice(&double_balance_witness.to_bytes());
-                buf[2271..2326].copy_from_slice(&user_address.to_bytes());
-                buf[2326..2358].copy_from_slice(&base_token_id.to_bytes());
-                buf[2358..2390].copy_from_slice(&quote_token_id.to_bytes());
-                buf[2390..2398].copy_from_slice(&user_balance_base_token_amount.to_bytes());
-                buf[2398..2406].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
-                buf[2406..2414].copy_from_slice(&pool_base_token_amount.to_bytes());
-                buf[2414..2422].copy_from_slice(&pool_quote_token_amount.to_bytes());
-                buf[2422..2454].copy_from_slice(&pool_total_liquidity_points.to_bytes());
-                buf[2454..2462].copy_from_slice(&user_base_token_amount_limit_to_swap.to_bytes());
-                buf[2462..2470].copy_from_slice(&user_quote_token_amount_to_swap.to_bytes());
-                buf[2470..2534].copy_from_slice(&user_signature.to_bytes());
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..861].copy_from_slice(&single_pool_witness.to_bytes());
+                buf[861..2335].copy_from_slice(&double_balance_witness.to_bytes());
+                buf[2335..3028].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[3028..3083].copy_from_slice(&user_address.to_bytes());
+                buf[3083..3115].copy_from_slice(&base_token_id.to_bytes());
+                buf[3115..3147].copy_from_slice(&quote_token_id.to_bytes());
+                buf[3147..3155].copy_from_slice(&user_balance_base_token_amount.to_bytes());
+                buf[3155..3163].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
+                buf[3163..3171].copy_from_slice(&pool_base_token_amount.to_bytes());
+                buf[3171..3179].copy_from_slice(&pool_quote_token_amount.to_bytes());
+                buf[3179..3211].copy_from_slice(&pool_total_liquidity_points.to_bytes());
+                buf[3211..3219].copy_from_slice(&user_base_token_amount_limit_to_swap.to_bytes());
+                buf[3219..3227].copy_from_slice(&user_quote_token_amount_to_swap.to_bytes());
+                buf[3227..3291].copy_from_slice(&user_signature.to_bytes());
+                buf[3291..3299].copy_from_slice(&user_nonce.to_bytes());
+                buf[3299..3307].copy_from_slice(&pool_fee_rate.to_bytes());
+                buf[3307..3315].copy_from_slice(&pool_protocol_fee_base_token_amount.to_bytes());
+                buf[3315..3323].copy_from_slice(&pool_protocol_fee_quote_token_amount.to_bytes());
+                buf[3323..3331].copy_from_slice(&protocol_fee_share.to_bytes());
+                buf[3331..3339].copy_from_slice(&pool_amplification.to_bytes());
+            }
+            ProverMethod::SwapRoute {
+                state_roots,
+                earlier_proof_index,
+                double_pool_witness,
+                double_balance_witness,
+                single_nonce_witness,
+                user_address,
+                path,
+                user_balance_token_in_amount,
+                user_balance_token_out_amount,
+                first_pool,
+                second_pool,
+                user_token_amount_in,
+                user_token_amount_out_limit,
+                user_signature,
+                user_nonce,
+                protocol_fee_share,
+            } => {
+                buf[0] = 8;
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..1541].copy_from_slice(&double_pool_witness.to_bytes());
+                buf[1541..3015].copy_from_slice(&double_balance_witness.to_bytes());
+                buf[3015..3708].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[3708..3763].copy_from_slice(&user_address.to_bytes());
+                buf[3763..3795].copy_from_slice(&path[0].to_bytes());
+                buf[3795..3827].copy_from_slice(&path[1].to_bytes());
+                buf[3827..3859].copy_from_slice(&path[2].to_bytes());
+                buf[3859..3867].copy_from_slice(&user_balance_token_in_amount.to_bytes());
+                buf[3867..3875].copy_from_slice(&user_balance_token_out_amount.to_bytes());
+                buf[3875..4019].copy_from_slice(&first_pool.to_bytes());
+                buf[4019..4163].copy_from_slice(&second_pool.to_bytes());
+                buf[4163..4171].copy_from_slice(&user_token_amount_in.to_bytes());
+                buf[4171..4179].copy_from_slice(&user_token_amount_out_limit.to_bytes());
+                buf[4179..4243].copy_from_slice(&user_signature.to_bytes());
+                buf[4243..4251].copy_from_slice(&user_nonce.to_bytes());
+                buf[4251..4259].copy_from_slice(&protocol_fee_share.to_bytes());
+            }
+            ProverMethod::TransferTokens {
+                state_roots,
+                earlier_proof_index,
+                double_balance_witness,
+                single_nonce_witness,
+                user_address,
+                receiver_address,
+                token_id,
+                user_balance_token_amount,
+                receiver_balance_token_amount,
+                amount_to_transfer,
+                user_signature,
+                user_nonce,
+            } => {
+                buf[0] = 9;
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..1675].copy_from_slice(&double_balance_witness.to_bytes());
+                buf[1675..2368].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[2368..2423].copy_from_slice(&user_address.to_bytes());
+                buf[2423..2478].copy_from_slice(&receiver_address.to_bytes());
+                buf[2478..2510].copy_from_slice(&token_id.to_bytes());
+                buf[2510..2518].copy_from_slice(&user_balance_token_amount.to_bytes());
+                buf[2518..2526].copy_from_slice(&receiver_balance_token_amount.to_bytes());
+                buf[2526..2534].copy_from_slice(&amount_to_transfer.to_bytes());
+                buf[2534..2598].copy_from_slice(&user_signature.to_bytes());
+                buf[2598..2606].copy_from_slice(&user_nonce.to_bytes());
+            }
+            ProverMethod::PlaceOrder {
+                state_roots,
+                earlier_proof_index,
+                single_order_witness,
+                single_balance_witness,
+                single_nonce_witness,
+                user_address,
+                token_in_id,
+                token_out_id,
+                user_balance_token_in_amount,
+                user_token_amount_in,
+                user_token_amount_out_limit,
+                order_id,
+                user_signature,
+                user_nonce,
+            } => {
+                buf[0] = 10;
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..894].copy_from_slice(&single_order_witness.to_bytes());
+                buf[894..1620].copy_from_slice(&single_balance_witness.to_bytes());
+                buf[1620..2313].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[2313..2368].copy_from_slice(&user_address.to_bytes());
+                buf[2368..2400].copy_from_slice(&token_in_id.to_bytes());
+                buf[2400..2432].copy_from_slice(&token_out_id.to_bytes());
+                buf[2432..2440].copy_from_slice(&user_balance_token_in_amount.to_bytes());
+                buf[2440..2448].copy_from_slice(&user_token_amount_in.to_bytes());
+                buf[2448..2456].copy_from_slice(&user_token_amount_out_limit.to_bytes());
+                buf[2456..2464].copy_from_slice(&order_id.to_bytes());
+                buf[2464..2528].copy_from_slice(&user_signature.to_bytes());
+                buf[2528..2536].copy_from_slice(&user_nonce.to_bytes());
+            }
+            ProverMethod::CancelOrder {
+                state_roots,
+                earlier_proof_index,
+                single_order_witness,
+                single_balance_witness,
+                single_nonce_witness,
+                order,
+                user_balance_token_in_amount,
+                user_signature,
+                user_nonce,
+            } => {
+                buf[0] = 11;
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..894].copy_from_slice(&single_order_witness.to_bytes());
+                buf[894..1620].copy_from_slice(&single_balance_witness.to_bytes());
+                buf[1620..2313].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[2313..2457].copy_from_slice(&order.to_bytes());
+                buf[2457..2465].copy_from_slice(&user_balance_token_in_amount.to_bytes());
+                buf[2465..2529].copy_from_slice(&user_signature.to_bytes());
+                buf[2529..2537].copy_from_slice(&user_nonce.to_bytes());
+            }
+            ProverMethod::FillOrder {
+                state_roots,
+                earlier_proof_index,
+                single_order_witness,
+                single_pool_witness,
+                single_balance_witness,
+                order,
+                pool,
+                user_balance_token_out_amount,
+                protocol_fee_share,
+            } => {
+                buf[0] = 12;
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..894].copy_from_slice(&single_order_witness.to_bytes());
+                buf[894..1554].copy_from_slice(&single_pool_witness.to_bytes());
+                buf[1554..2280].copy_from_slice(&single_balance_witness.to_bytes());
+                buf[2280..2424].copy_from_slice(&order.to_bytes());
+                buf[2424..2568].copy_from_slice(&pool.to_bytes());
+                buf[2568..2576].copy_from_slice(&user_balance_token_out_amount.to_bytes());
+                buf[2576..2584].copy_from_slice(&protocol_fee_share.to_bytes());
+            }
+            ProverMethod::ClaimProtocolFees {
+                state_roots,
+                earlier_proof_index,
+                single_pool_witness,
+                double_balance_witness,
+                single_nonce_witness,
+                user_address,
+                pool,
+                user_balance_base_token_amount,
+                user_balance_quote_token_amount,
+                user_signature,
+                user_nonce,
+            } => {
+                buf[0] = 13;
+                buf[1..193].copy_from_slice(&state_roots.to_bytes());
+                buf[193..201].copy_from_slice(&earlier_proof_index.to_bytes());
+                buf[201..861].copy_from_slice(&single_pool_witness.to_bytes());
+                buf[861..2335].copy_from_slice(&double_balance_witness.to_bytes());
+                buf[2335..3028].copy_from_slice(&single_nonce_witness.to_bytes());
+                buf[3028..3083].copy_from_slice(&user_address.to_bytes());
+                buf[3083..3227].copy_from_slice(&pool.to_bytes());
+                buf[3227..3235].copy_from_slice(&user_balance_base_token_amount.to_bytes());
+                buf[3235..3243].copy_from_slice(&user_balance_quote_token_amount.to_bytes());
+                buf[3243..3307].copy_from_slice(&user_signature.to_bytes());
+                buf[3307..3315].copy_from_slice(&user_nonce.to_bytes());
             }
         }
 
         buf
     }
 
-    fn from_bytes(_: &[u8; 3291]) -> Self {
+    fn from_bytes(_: &[u8; 4259]) -> Self {
         panic!("this function is not intended for use")
     }
 }
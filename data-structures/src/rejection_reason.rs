@@ -0,0 +1,95 @@
+use crate::ByteConversion;
+
+/// The enum that represents the reason a transaction is rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The reason which means the failure couldn't be attributed to the transaction itself.
+    Internal,
+    /// The reason which means the transaction's signature is invalid.
+    InvalidSignature,
+    /// The reason which means the transaction's nonce isn't the next nonce of the user.
+    InvalidNonce,
+    /// The reason which means the user doesn't have enough balance.
+    NotEnoughBalance,
+    /// The reason which means the user doesn't have enough liquidity in the pool.
+    NotEnoughLiquidity,
+    /// The reason which means the pool doesn't have enough tokens.
+    NotEnoughInPool,
+    /// The reason which means an arithmetic operation overflowed.
+    Overflow,
+    /// The reason which means the price moved beyond the limit given by the user.
+    LimitExceeded,
+    /// The reason which means the user doesn't have a balance of the token.
+    BalanceDoesntExist,
+    /// The reason which means the pool doesn't exist.
+    PoolDoesntExist,
+    /// The reason which means the pool already exists.
+    PoolAlreadyExists,
+    /// The reason which means the user doesn't have liquidity in the pool.
+    LiquidityDoesntExist,
+    /// The reason which means the transaction's address isn't a valid public key.
+    InvalidAddress,
+    /// The reason which means an amount in the transaction is zero.
+    ZeroAmount,
+    /// The reason which means the base and quote token IDs of the transaction are the same.
+    IdenticalTokenIds,
+    /// The reason which means the path of the transaction doesn't go through existing pools.
+    InvalidRoute,
+    /// The reason which means the sender and the receiver of the transaction are the same.
+    IdenticalAddresses,
+    /// The reason which means the fee rate of the transaction isn't one of the fee rates pools can have.
+    InvalidFeeRate,
+    /// The reason which means the amplification coefficient of the transaction doesn't fit the kind of the pool.
+    InvalidAmplification,
+    /// The reason which means the order doesn't exist.
+    OrderDoesntExist,
+    /// The reason which means the order is already filled or cancelled.
+    OrderIsntOpen,
+    /// The reason which means the user isn't the owner of the order.
+    NotOrderOwner,
+    /// The reason which means the pool's price history doesn't cover the requested window.
+    NotEnoughPriceHistory,
+    /// The reason which means the user made more state changing requests than the RPC server's rate limit.
+    RateLimited,
+    /// The reason which means the user isn't the treasury the protocol fees are claimed by.
+    NotTreasury,
+    /// The reason which means the pool doesn't have any protocol fees to claim.
+    NoProtocolFees,
+}
+
+impl ByteConversion<1> for RejectionReason {
+    fn to_bytes(&self) -> [u8; 1] {
+        [self.to_owned() as u8]
+    }
+
+    fn from_bytes(bytes: &[u8; 1]) -> Self {
+        match bytes[0] {
+            1 => Self::InvalidSignature,
+            2 => Self::InvalidNonce,
+            3 => Self::NotEnoughBalance,
+            4 => Self::NotEnoughLiquidity,
+            5 => Self::NotEnoughInPool,
+            6 => Self::Overflow,
+            7 => Self::LimitExceeded,
+            8 => Self::BalanceDoesntExist,
+            9 => Self::PoolDoesntExist,
+            10 => Self::PoolAlreadyExists,
+            11 => Self::LiquidityDoesntExist,
+            12 => Self::InvalidAddress,
+            13 => Self::ZeroAmount,
+            14 => Self::IdenticalTokenIds,
+            15 => Self::InvalidRoute,
+            16 => Self::IdenticalAddresses,
+            17 => Self::InvalidFeeRate,
+            18 => Self::InvalidAmplification,
+            19 => Self::OrderDoesntExist,
+            20 => Self::OrderIsntOpen,
+            21 => Self::NotOrderOwner,
+            22 => Self::NotEnoughPriceHistory,
+            23 => Self::RateLimited,
+            24 => Self::NotTreasury,
+            25 => Self::NoProtocolFees,
+            _ => Self::Internal,
+        }
+    }
+}
@@ -6,6 +6,8 @@ use crate::{ByteConversion, U256};
 /// - The `liquidities` property represents the Merkle root of Liquidities DB.
 /// - The `pools` property represents the Merkle root of Pools DB.
 /// - The `burns` property represents the Merkle root of Burns DB.
+/// - The `orders` property represents the Merkle root of Orders DB.
+/// - The `nonces` property represents the Merkle root of the nonces in Balances DB.
 ///
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct StateRoots {
@@ -13,26 +15,32 @@ pub struct StateRoots {
     pub liquidities: U256,
     pub pools: U256,
     pub burns: U256,
+    pub orders: U256,
+    pub nonces: U256,
 }
 
-impl ByteConversion<128> for StateRoots {
-    fn to_bytes(&self) -> [u8; 128] {
-        let mut buf = [0u8; 128];
+impl ByteConversion<192> for StateRoots {
+    fn to_bytes(&self) -> [u8; 192] {
+        let mut buf = [0u8; 192];
 
         buf[0..32].copy_from_slice(&self.balances.to_bytes());
         buf[32..64].copy_from_slice(&self.liquidities.to_bytes());
         buf[64..96].copy_from_slice(&self.pools.to_bytes());
         buf[96..128].copy_from_slice(&self.burns.to_bytes());
+        buf[128..160].copy_from_slice(&self.orders.to_bytes());
+        buf[160..192].copy_from_slice(&self.nonces.to_bytes());
 
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 128]) -> Self {
+    fn from_bytes(bytes: &[u8; 192]) -> Self {
         Self {
             balances: U256::from_bytes(bytes[0..32].try_into().unwrap()),
             liquidities: U256::from_bytes(bytes[32..64].try_into().unwrap()),
             pools: U256::from_bytes(bytes[64..96].try_into().unwrap()),
             burns: U256::from_bytes(bytes[96..128].try_into().unwrap()),
+            orders: U256::from_bytes(bytes[128..160].try_into().unwrap()),
+            nonces: U256::from_bytes(bytes[160..192].try_into().unwrap()),
         }
     }
 }
@@ -1,10 +1,12 @@
 use crate::{
     transaction::{
-        BurnTokensTransaction, BuyTokensTransaction, CreatePoolTransaction,
-        DepositTokensTransaction, ProvideLiquidityTransaction, RemoveLiquidityTransaction,
-        SellTokensTransaction,
+        BurnTokensTransaction, BuyTokensTransaction, CancelOrderTransaction,
+        ClaimProtocolFeesTransaction, CreatePoolTransaction, DepositTokensTransaction,
+        FillOrderTransaction, PlaceOrderTransaction, ProvideLiquidityTransaction,
+        RemoveLiquidityTransaction, SellTokensTransaction, SwapRouteTransaction,
+        TransferTokensTransaction,
     },
-    ByteConversion, U256,
+    ByteConversion, Order, Pool, U256,
 };
 
 /// The enum that represents stateful transaction types.
@@ -45,6 +47,30 @@ pub enum StatefulTransaction {
         transaction: SellTokensTransaction,
         state: SellTokensTransactionState,
     },
+    SwapRoute {
+        transaction: SwapRouteTransaction,
+        state: SwapRouteTransactionState,
+    },
+    TransferTokens {
+        transaction: TransferTokensTransaction,
+        state: TransferTokensTransactionState,
+    },
+    PlaceOrder {
+        transaction: PlaceOrderTransaction,
+        state: PlaceOrderTransactionState,
+    },
+    CancelOrder {
+        transaction: CancelOrderTransaction,
+        state: CancelOrderTransactionState,
+    },
+    FillOrder {
+        transaction: FillOrderTransaction,
+        state: FillOrderTransactionState,
+    },
+    ClaimProtocolFees {
+        transaction: ClaimProtocolFeesTransaction,
+        state: ClaimProtocolFeesTransactionState,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -72,6 +98,10 @@ pub struct ProvideLiquidityTransactionState {
     pub pool_base_token_amount: u64,
     pub pool_quote_token_amount: u64,
     pub pool_total_liquidity_points: U256,
+    pub pool_fee_rate: u64,
+    pub pool_protocol_fee_base_token_amount: u64,
+    pub pool_protocol_fee_quote_token_amount: u64,
+    pub pool_amplification: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -82,6 +112,10 @@ pub struct RemoveLiquidityTransactionState {
     pub pool_base_token_amount: u64,
     pub pool_quote_token_amount: u64,
     pub pool_total_liquidity_points: U256,
+    pub pool_fee_rate: u64,
+    pub pool_protocol_fee_base_token_amount: u64,
+    pub pool_protocol_fee_quote_token_amount: u64,
+    pub pool_amplification: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -91,6 +125,11 @@ pub struct BuyTokensTransactionState {
     pub pool_base_token_amount: u64,
     pub pool_quote_token_amount: u64,
     pub pool_total_liquidity_points: U256,
+    pub pool_fee_rate: u64,
+    pub pool_protocol_fee_base_token_amount: u64,
+    pub pool_protocol_fee_quote_token_amount: u64,
+    pub protocol_fee_share: u64,
+    pub pool_amplification: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -100,11 +139,71 @@ pub struct SellTokensTransactionState {
     pub pool_base_token_amount: u64,
     pub pool_quote_token_amount: u64,
     pub pool_total_liquidity_points: U256,
+    pub pool_fee_rate: u64,
+    pub pool_protocol_fee_base_token_amount: u64,
+    pub pool_protocol_fee_quote_token_amount: u64,
+    pub protocol_fee_share: u64,
+    pub pool_amplification: u64,
+}
+
+/// The state of a swap route transaction before it is executed.
+///
+/// The pools are stored as a whole, because a hop can go through a pool in either direction.
+#[derive(Clone, Debug)]
+pub struct SwapRouteTransactionState {
+    pub user_balance_token_in_amount: u64,
+    pub user_balance_token_out_amount: u64,
+    pub first_pool: Pool,
+    pub second_pool: Pool,
+    pub protocol_fee_share: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransferTokensTransactionState {
+    pub user_balance_token_amount: u64,
+    pub receiver_balance_token_amount: u64,
+}
+
+/// The state of an order placement transaction before it is executed.
+///
+/// The `order_id` property is the ID the placed order gets, which is the index of the next order in Orders DB.
+#[derive(Clone, Debug)]
+pub struct PlaceOrderTransactionState {
+    pub user_balance_token_in_amount: u64,
+    pub order_id: u64,
+}
+
+/// The state of an order cancellation transaction before it is executed.
+#[derive(Clone, Debug)]
+pub struct CancelOrderTransactionState {
+    pub user_balance_token_in_amount: u64,
+    pub order: Order,
+}
+
+/// The state of an order fill transaction before it is executed.
+///
+/// The pool is stored as a whole, because an order can go through its pool in either direction.
+#[derive(Clone, Debug)]
+pub struct FillOrderTransactionState {
+    pub user_balance_token_out_amount: u64,
+    pub order: Order,
+    pub pool: Pool,
+    pub protocol_fee_share: u64,
 }
 
-impl ByteConversion<328> for StatefulTransaction {
-    fn to_bytes(&self) -> [u8; 328] {
-        let mut buf = [0u8; 328];
+/// The state of a protocol fee claim transaction before it is executed.
+///
+/// The pool is stored as a whole, because the claim only changes its protocol fees.
+#[derive(Clone, Debug)]
+pub struct ClaimProtocolFeesTransactionState {
+    pub user_balance_base_token_amount: u64,
+    pub user_balance_quote_token_amount: u64,
+    pub pool: Pool,
+}
+
+impl ByteConversion<552> for StatefulTransaction {
+    fn to_bytes(&self) -> [u8; 552] {
+        let mut buf = [0u8; 552];
         match self {
             StatefulTransaction::CreateGenesis {
                 transaction: _,
@@ -119,39 +218,69 @@ impl ByteConversion<328> for StatefulTransaction {
             }
             StatefulTransaction::BurnTokens { transaction, state } => {
                 buf[0] = 2;
-                buf[1..160].copy_from_slice(&transaction.to_bytes());
-                buf[160..176].copy_from_slice(&state.to_bytes());
+                buf[1..168].copy_from_slice(&transaction.to_bytes());
+                buf[168..184].copy_from_slice(&state.to_bytes());
             }
             StatefulTransaction::CreatePool { transaction, state } => {
                 buf[0] = 3;
-                buf[1..200].copy_from_slice(&transaction.to_bytes());
-                buf[200..216].copy_from_slice(&state.to_bytes());
+                buf[1..225].copy_from_slice(&transaction.to_bytes());
+                buf[225..241].copy_from_slice(&state.to_bytes());
             }
             StatefulTransaction::ProvideLiquidity { transaction, state } => {
                 buf[0] = 4;
-                buf[1..200].copy_from_slice(&transaction.to_bytes());
-                buf[200..296].copy_from_slice(&state.to_bytes());
+                buf[1..208].copy_from_slice(&transaction.to_bytes());
+                buf[208..336].copy_from_slice(&state.to_bytes());
             }
             StatefulTransaction::RemoveLiquidity { transaction, state } => {
                 buf[0] = 5;
-                buf[1..232].copy_from_slice(&transaction.to_bytes());
-                buf[232..328].copy_from_slice(&state.to_bytes());
+                buf[1..240].copy_from_slice(&transaction.to_bytes());
+                buf[240..368].copy_from_slice(&state.to_bytes());
             }
             StatefulTransaction::BuyTokens { transaction, state } => {
                 buf[0] = 6;
-                buf[1..200].copy_from_slice(&transaction.to_bytes());
-                buf[200..264].copy_from_slice(&state.to_bytes());
+                buf[1..208].copy_from_slice(&transaction.to_bytes());
+                buf[208..312].copy_from_slice(&state.to_bytes());
             }
             StatefulTransaction::SellTokens { transaction, state } => {
                 buf[0] = 7;
-                buf[1..200].copy_from_slice(&transaction.to_bytes());
-                buf[200..264].copy_from_slice(&state.to_bytes());
+                buf[1..208].copy_from_slice(&transaction.to_bytes());
+                buf[208..312].copy_from_slice(&state.to_bytes());
+            }
+            StatefulTransaction::SwapRoute { transaction, state } => {
+                buf[0] = 8;
+                buf[1..240].copy_from_slice(&transaction.to_bytes());
+                buf[240..552].copy_from_slice(&state.to_bytes());
+            }
+            StatefulTransaction::TransferTokens { transaction, state } => {
+                buf[0] = 9;
+                buf[1..223].copy_from_slice(&transaction.to_bytes());
+                buf[223..239].copy_from_slice(&state.to_bytes());
+            }
+            StatefulTransaction::PlaceOrder { transaction, state } => {
+                buf[0] = 10;
+                buf[1..208].copy_from_slice(&transaction.to_bytes());
+                buf[208..224].copy_from_slice(&state.to_bytes());
+            }
+            StatefulTransaction::CancelOrder { transaction, state } => {
+                buf[0] = 11;
+                buf[1..136].copy_from_slice(&transaction.to_bytes());
+                buf[136..288].copy_from_slice(&state.to_bytes());
+            }
+            StatefulTransaction::FillOrder { transaction, state } => {
+                buf[0] = 12;
+                buf[1..9].copy_from_slice(&transaction.to_bytes());
+                buf[9..313].copy_from_slice(&state.to_bytes());
+            }
+            StatefulTransaction::ClaimProtocolFees { transaction, state } => {
+                buf[0] = 13;
+                buf[1..192].copy_from_slice(&transaction.to_bytes());
+                buf[192..352].copy_from_slice(&state.to_bytes());
             }
         }
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 328]) -> Self {
+    fn from_bytes(bytes: &[u8; 552]) -> Self {
         match bytes[0] {
             0 => StatefulTransaction::CreateGenesis {
                 transaction: (),
@@ -164,36 +293,68 @@ impl ByteConversion<328> for StatefulTransaction {
                 ),
             },
             2 => StatefulTransaction::BurnTokens {
-                transaction: BurnTokensTransaction::from_bytes(bytes[1..160].try_into().unwrap()),
-                state: BurnTokensTransactionState::from_bytes(bytes[160..176].try_into().unwrap()),
+                transaction: BurnTokensTransaction::from_bytes(bytes[1..168].try_into().unwrap()),
+                state: BurnTokensTransactionState::from_bytes(bytes[168..184].try_into().unwrap()),
             },
             3 => StatefulTransaction::CreatePool {
-                transaction: CreatePoolTransaction::from_bytes(bytes[1..200].try_into().unwrap()),
-                state: CreatePoolTransactionState::from_bytes(bytes[200..216].try_into().unwrap()),
+                transaction: CreatePoolTransaction::from_bytes(bytes[1..225].try_into().unwrap()),
+                state: CreatePoolTransactionState::from_bytes(bytes[225..241].try_into().unwrap()),
             },
             4 => StatefulTransaction::ProvideLiquidity {
                 transaction: ProvideLiquidityTransaction::from_bytes(
-                    bytes[1..200].try_into().unwrap(),
+                    bytes[1..208].try_into().unwrap(),
                 ),
                 state: ProvideLiquidityTransactionState::from_bytes(
-                    bytes[200..296].try_into().unwrap(),
+                    bytes[208..336].try_into().unwrap(),
                 ),
             },
             5 => StatefulTransaction::RemoveLiquidity {
                 transaction: RemoveLiquidityTransaction::from_bytes(
-                    bytes[1..232].try_into().unwrap(),
+                    bytes[1..240].try_into().unwrap(),
                 ),
                 state: RemoveLiquidityTransactionState::from_bytes(
-                    bytes[232..328].try_into().unwrap(),
+                    bytes[240..368].try_into().unwrap(),
                 ),
             },
             6 => StatefulTransaction::BuyTokens {
-                transaction: BuyTokensTransaction::from_bytes(bytes[1..200].try_into().unwrap()),
-                state: BuyTokensTransactionState::from_bytes(bytes[200..264].try_into().unwrap()),
+                transaction: BuyTokensTransaction::from_bytes(bytes[1..208].try_into().unwrap()),
+                state: BuyTokensTransactionState::from_bytes(bytes[208..312].try_into().unwrap()),
+            },
+            7 => StatefulTransaction::SellTokens {
+                transaction: SellTokensTransaction::from_bytes(bytes[1..208].try_into().unwrap()),
+                state: SellTokensTransactionState::from_bytes(bytes[208..312].try_into().unwrap()),
             },
-            _ => StatefulTransaction::SellTokens {
-                transaction: SellTokensTransaction::from_bytes(bytes[1..200].try_into().unwrap()),
-                state: SellTokensTransactionState::from_bytes(bytes[200..264].try_into().unwrap()),
+            8 => StatefulTransaction::SwapRoute {
+                transaction: SwapRouteTransaction::from_bytes(bytes[1..240].try_into().unwrap()),
+                state: SwapRouteTransactionState::from_bytes(bytes[240..552].try_into().unwrap()),
+            },
+            9 => StatefulTransaction::TransferTokens {
+                transaction: TransferTokensTransaction::from_bytes(
+                    bytes[1..223].try_into().unwrap(),
+                ),
+                state: TransferTokensTransactionState::from_bytes(
+                    bytes[223..239].try_into().unwrap(),
+                ),
+            },
+            10 => StatefulTransaction::PlaceOrder {
+                transaction: PlaceOrderTransaction::from_bytes(bytes[1..208].try_into().unwrap()),
+                state: PlaceOrderTransactionState::from_bytes(bytes[208..224].try_into().unwrap()),
+            },
+            11 => StatefulTransaction::CancelOrder {
+                transaction: CancelOrderTransaction::from_bytes(bytes[1..136].try_into().unwrap()),
+                state: CancelOrderTransactionState::from_bytes(bytes[136..288].try_into().unwrap()),
+            },
+            12 => StatefulTransaction::FillOrder {
+                transaction: FillOrderTransaction::from_bytes(bytes[1..9].try_into().unwrap()),
+                state: FillOrderTransactionState::from_bytes(bytes[9..313].try_into().unwrap()),
+            },
+            _ => StatefulTransaction::ClaimProtocolFees {
+                transaction: ClaimProtocolFeesTransaction::from_bytes(
+                    bytes[1..192].try_into().unwrap(),
+                ),
+                state: ClaimProtocolFeesTransactionState::from_bytes(
+                    bytes[192..352].try_into().unwrap(),
+                ),
             },
         }
     }
@@ -245,19 +406,23 @@ impl ByteConversion<16> for CreatePoolTransactionState {
     }
 }
 
-impl ByteConversion<96> for ProvideLiquidityTransactionState {
-    fn to_bytes(&self) -> [u8; 96] {
-        let mut buf = [0u8; 96];
+impl ByteConversion<128> for ProvideLiquidityTransactionState {
+    fn to_bytes(&self) -> [u8; 128] {
+        let mut buf = [0u8; 128];
         buf[0..32].copy_from_slice(&self.user_liquidity_points.to_bytes());
         buf[32..40].copy_from_slice(&self.user_balance_base_token_amount.to_bytes());
         buf[40..48].copy_from_slice(&self.user_balance_quote_token_amount.to_bytes());
         buf[48..56].copy_from_slice(&self.pool_base_token_amount.to_bytes());
         buf[56..64].copy_from_slice(&self.pool_quote_token_amount.to_bytes());
         buf[64..96].copy_from_slice(&self.pool_total_liquidity_points.to_bytes());
+        buf[96..104].copy_from_slice(&self.pool_fee_rate.to_bytes());
+        buf[104..112].copy_from_slice(&self.pool_protocol_fee_base_token_amount.to_bytes());
+        buf[112..120].copy_from_slice(&self.pool_protocol_fee_quote_token_amount.to_bytes());
+        buf[120..128].copy_from_slice(&self.pool_amplification.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 96]) -> Self {
+    fn from_bytes(bytes: &[u8; 128]) -> Self {
         ProvideLiquidityTransactionState {
             user_liquidity_points: U256::from_bytes(bytes[0..32].try_into().unwrap()),
             user_balance_base_token_amount: u64::from_bytes(bytes[32..40].try_into().unwrap()),
@@ -265,23 +430,35 @@ impl ByteConversion<96> for ProvideLiquidityTransactionState {
             pool_base_token_amount: u64::from_bytes(bytes[48..56].try_into().unwrap()),
             pool_quote_token_amount: u64::from_bytes(bytes[56..64].try_into().unwrap()),
             pool_total_liquidity_points: U256::from_bytes(bytes[64..96].try_into().unwrap()),
+            pool_fee_rate: u64::from_bytes(bytes[96..104].try_into().unwrap()),
+            pool_protocol_fee_base_token_amount: u64::from_bytes(
+                bytes[104..112].try_into().unwrap(),
+            ),
+            pool_protocol_fee_quote_token_amount: u64::from_bytes(
+                bytes[112..120].try_into().unwrap(),
+            ),
+            pool_amplification: u64::from_bytes(bytes[120..128].try_into().unwrap()),
         }
     }
 }
 
-impl ByteConversion<96> for RemoveLiquidityTransactionState {
-    fn to_bytes(&self) -> [u8; 96] {
-        let mut buf = [0u8; 96];
+impl ByteConversion<128> for RemoveLiquidityTransactionState {
+    fn to_bytes(&self) -> [u8; 128] {
+        let mut buf = [0u8; 128];
         buf[0..32].copy_from_slice(&self.user_liquidity_points.to_bytes());
         buf[32..40].copy_from_slice(&self.user_balance_base_token_amount.to_bytes());
         buf[40..48].copy_from_slice(&self.user_balance_quote_token_amount.to_bytes());
         buf[48..56].copy_from_slice(&self.pool_base_token_amount.to_bytes());
         buf[56..64].copy_from_slice(&self.pool_quote_token_amount.to_bytes());
         buf[64..96].copy_from_slice(&self.pool_total_liquidity_points.to_bytes());
+        buf[96..104].copy_from_slice(&self.pool_fee_rate.to_bytes());
+        buf[104..112].copy_from_slice(&self.pool_protocol_fee_base_token_amount.to_bytes());
+        buf[112..120].copy_from_slice(&self.pool_protocol_fee_quote_token_amount.to_bytes());
+        buf[120..128].copy_from_slice(&self.pool_amplification.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 96]) -> Self {
+    fn from_bytes(bytes: &[u8; 128]) -> Self {
         RemoveLiquidityTransactionState {
             user_liquidity_points: U256::from_bytes(bytes[0..32].try_into().unwrap()),
             user_balance_base_token_amount: u64::from_bytes(bytes[32..40].try_into().unwrap()),
@@ -289,50 +466,190 @@ impl ByteConversion<96> for RemoveLiquidityTransactionState {
             pool_base_token_amount: u64::from_bytes(bytes[48..56].try_into().unwrap()),
             pool_quote_token_amount: u64::from_bytes(bytes[56..64].try_into().unwrap()),
             pool_total_liquidity_points: U256::from_bytes(bytes[64..96].try_into().unwrap()),
+            pool_fee_rate: u64::from_bytes(bytes[96..104].try_into().unwrap()),
+            pool_protocol_fee_base_token_amount: u64::from_bytes(
+                bytes[104..112].try_into().unwrap(),
+            ),
+            pool_protocol_fee_quote_token_amount: u64::from_bytes(
+                bytes[112..120].try_into().unwrap(),
+            ),
+            pool_amplification: u64::from_bytes(bytes[120..128].try_into().unwrap()),
         }
     }
 }
 
-impl ByteConversion<64> for BuyTokensTransactionState {
-    fn to_bytes(&self) -> [u8; 64] {
-        let mut buf = [0u8; 64];
+impl ByteConversion<104> for BuyTokensTransactionState {
+    fn to_bytes(&self) -> [u8; 104] {
+        let mut buf = [0u8; 104];
         buf[0..8].copy_from_slice(&self.user_balance_base_token_amount.to_bytes());
         buf[8..16].copy_from_slice(&self.user_balance_quote_token_amount.to_bytes());
         buf[16..24].copy_from_slice(&self.pool_base_token_amount.to_bytes());
         buf[24..32].copy_from_slice(&self.pool_quote_token_amount.to_bytes());
         buf[32..64].copy_from_slice(&self.pool_total_liquidity_points.to_bytes());
+        buf[64..72].copy_from_slice(&self.pool_fee_rate.to_bytes());
+        buf[72..80].copy_from_slice(&self.pool_protocol_fee_base_token_amount.to_bytes());
+        buf[80..88].copy_from_slice(&self.pool_protocol_fee_quote_token_amount.to_bytes());
+        buf[88..96].copy_from_slice(&self.protocol_fee_share.to_bytes());
+        buf[96..104].copy_from_slice(&self.pool_amplification.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 64]) -> Self {
+    fn from_bytes(bytes: &[u8; 104]) -> Self {
         BuyTokensTransactionState {
             user_balance_base_token_amount: u64::from_bytes(bytes[0..8].try_into().unwrap()),
             user_balance_quote_token_amount: u64::from_bytes(bytes[8..16].try_into().unwrap()),
             pool_base_token_amount: u64::from_bytes(bytes[16..24].try_into().unwrap()),
             pool_quote_token_amount: u64::from_bytes(bytes[24..32].try_into().unwrap()),
             pool_total_liquidity_points: U256::from_bytes(bytes[32..64].try_into().unwrap()),
+            pool_fee_rate: u64::from_bytes(bytes[64..72].try_into().unwrap()),
+            pool_protocol_fee_base_token_amount: u64::from_bytes(bytes[72..80].try_into().unwrap()),
+            pool_protocol_fee_quote_token_amount: u64::from_bytes(
+                bytes[80..88].try_into().unwrap(),
+            ),
+            protocol_fee_share: u64::from_bytes(bytes[88..96].try_into().unwrap()),
+            pool_amplification: u64::from_bytes(bytes[96..104].try_into().unwrap()),
         }
     }
 }
 
-impl ByteConversion<64> for SellTokensTransactionState {
-    fn to_bytes(&self) -> [u8; 64] {
-        let mut buf = [0u8; 64];
+impl ByteConversion<104> for SellTokensTransactionState {
+    fn to_bytes(&self) -> [u8; 104] {
+        let mut buf = [0u8; 104];
         buf[0..8].copy_from_slice(&self.user_balance_base_token_amount.to_bytes());
         buf[8..16].copy_from_slice(&self.user_balance_quote_token_amount.to_bytes());
         buf[16..24].copy_from_slice(&self.pool_base_token_amount.to_bytes());
         buf[24..32].copy_from_slice(&self.pool_quote_token_amount.to_bytes());
         buf[32..64].copy_from_slice(&self.pool_total_liquidity_points.to_bytes());
+        buf[64..72].copy_from_slice(&self.pool_fee_rate.to_bytes());
+        buf[72..80].copy_from_slice(&self.pool_protocol_fee_base_token_amount.to_bytes());
+        buf[80..88].copy_from_slice(&self.pool_protocol_fee_quote_token_amount.to_bytes());
+        buf[88..96].copy_from_slice(&self.protocol_fee_share.to_bytes());
+        buf[96..104].copy_from_slice(&self.pool_amplification.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 64]) -> Self {
+    fn from_bytes(bytes: &[u8; 104]) -> Self {
         SellTokensTransactionState {
             user_balance_base_token_amount: u64::from_bytes(bytes[0..8].try_into().unwrap()),
             user_balance_quote_token_amount: u64::from_bytes(bytes[8..16].try_into().unwrap()),
             pool_base_token_amount: u64::from_bytes(bytes[16..24].try_into().unwrap()),
             pool_quote_token_amount: u64::from_bytes(bytes[24..32].try_into().unwrap()),
             pool_total_liquidity_points: U256::from_bytes(bytes[32..64].try_into().unwrap()),
+            pool_fee_rate: u64::from_bytes(bytes[64..72].try_into().unwrap()),
+            pool_protocol_fee_base_token_amount: u64::from_bytes(bytes[72..80].try_into().unwrap()),
+            pool_protocol_fee_quote_token_amount: u64::from_bytes(
+                bytes[80..88].try_into().unwrap(),
+            ),
+            protocol_fee_share: u64::from_bytes(bytes[88..96].try_into().unwrap()),
+            pool_amplification: u64::from_bytes(bytes[96..104].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<312> for SwapRouteTransactionState {
+    fn to_bytes(&self) -> [u8; 312] {
+        let mut buf = [0u8; 312];
+        buf[0..8].copy_from_slice(&self.user_balance_token_in_amount.to_bytes());
+        buf[8..16].copy_from_slice(&self.user_balance_token_out_amount.to_bytes());
+        buf[16..160].copy_from_slice(&self.first_pool.to_bytes());
+        buf[160..304].copy_from_slice(&self.second_pool.to_bytes());
+        buf[304..312].copy_from_slice(&self.protocol_fee_share.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 312]) -> Self {
+        SwapRouteTransactionState {
+            user_balance_token_in_amount: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            user_balance_token_out_amount: u64::from_bytes(bytes[8..16].try_into().unwrap()),
+            first_pool: Pool::from_bytes(bytes[16..160].try_into().unwrap()),
+            second_pool: Pool::from_bytes(bytes[160..304].try_into().unwrap()),
+            protocol_fee_share: u64::from_bytes(bytes[304..312].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<16> for TransferTokensTransactionState {
+    fn to_bytes(&self) -> [u8; 16] {
+        let mut buf = [0u8; 16];
+        buf[0..8].copy_from_slice(&self.user_balance_token_amount.to_bytes());
+        buf[8..16].copy_from_slice(&self.receiver_balance_token_amount.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 16]) -> Self {
+        TransferTokensTransactionState {
+            user_balance_token_amount: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            receiver_balance_token_amount: u64::from_bytes(bytes[8..16].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<16> for PlaceOrderTransactionState {
+    fn to_bytes(&self) -> [u8; 16] {
+        let mut buf = [0u8; 16];
+        buf[0..8].copy_from_slice(&self.user_balance_token_in_amount.to_bytes());
+        buf[8..16].copy_from_slice(&self.order_id.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 16]) -> Self {
+        PlaceOrderTransactionState {
+            user_balance_token_in_amount: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            order_id: u64::from_bytes(bytes[8..16].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<152> for CancelOrderTransactionState {
+    fn to_bytes(&self) -> [u8; 152] {
+        let mut buf = [0u8; 152];
+        buf[0..8].copy_from_slice(&self.user_balance_token_in_amount.to_bytes());
+        buf[8..152].copy_from_slice(&self.order.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 152]) -> Self {
+        CancelOrderTransactionState {
+            user_balance_token_in_amount: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            order: Order::from_bytes(bytes[8..152].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<304> for FillOrderTransactionState {
+    fn to_bytes(&self) -> [u8; 304] {
+        let mut buf = [0u8; 304];
+        buf[0..8].copy_from_slice(&self.user_balance_token_out_amount.to_bytes());
+        buf[8..152].copy_from_slice(&self.order.to_bytes());
+        buf[152..296].copy_from_slice(&self.pool.to_bytes());
+        buf[296..304].copy_from_slice(&self.protocol_fee_share.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 304]) -> Self {
+        FillOrderTransactionState {
+            user_balance_token_out_amount: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            order: Order::from_bytes(bytes[8..152].try_into().unwrap()),
+            pool: Pool::from_bytes(bytes[152..296].try_into().unwrap()),
+            protocol_fee_share: u64::from_bytes(bytes[296..304].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<160> for ClaimProtocolFeesTransactionState {
+    fn to_bytes(&self) -> [u8; 160] {
+        let mut buf = [0u8; 160];
+        buf[0..8].copy_from_slice(&self.user_balance_base_token_amount.to_bytes());
+        buf[8..16].copy_from_slice(&self.user_balance_quote_token_amount.to_bytes());
+        buf[16..160].copy_from_slice(&self.pool.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 160]) -> Self {
+        ClaimProtocolFeesTransactionState {
+            user_balance_base_token_amount: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            user_balance_quote_token_amount: u64::from_bytes(bytes[8..16].try_into().unwrap()),
+            pool: Pool::from_bytes(bytes[16..160].try_into().unwrap()),
         }
     }
 }
@@ -0,0 +1,23 @@
+use crate::ByteConversion;
+
+/// The enum that represents the side of a swap in an AMM pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapSide {
+    /// The side which means the user buys the base token of the pool with its quote token.
+    Buy,
+    /// The side which means the user sells the base token of the pool for its quote token.
+    Sell,
+}
+
+impl ByteConversion<1> for SwapSide {
+    fn to_bytes(&self) -> [u8; 1] {
+        [self.to_owned() as u8]
+    }
+
+    fn from_bytes(bytes: &[u8; 1]) -> Self {
+        match bytes[0] {
+            0 => Self::Buy,
+            _ => Self::Sell,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::ByteConversion;
+
+/// The data structure that represents the swaps made in an AMM pool during an hour, which is used to compute pool analytics.
+///
+/// It is stored inside Analytics DB.
+///
+/// - The `hour` property represents the hour the swaps are made in, which is the Unix timestamp in seconds divided by `TradeStats::PERIOD`.
+/// - The `base_token_volume` property represents the total base token amount bought and sold in the pool.
+/// - The `quote_token_volume` property represents the total quote token amount bought and sold in the pool.
+/// - The `base_token_fees` property represents the total swap fees paid in base tokens, including the protocol fees.
+/// - The `quote_token_fees` property represents the total swap fees paid in quote tokens, including the protocol fees.
+/// - The `trade_count` property represents the count of the swaps.
+///
+/// Summed stats of longer periods are represented with the same structure, their `hour` is the first hour of the period.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TradeStats {
+    pub hour: u64,
+    pub base_token_volume: u64,
+    pub quote_token_volume: u64,
+    pub base_token_fees: u64,
+    pub quote_token_fees: u64,
+    pub trade_count: u64,
+}
+
+impl TradeStats {
+    /// The length of the period the stats are grouped by in seconds.
+    pub const PERIOD: u64 = 3600;
+
+    /// Adds the given stats to the stats, saturating instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// stats.merge(&trade_stats);
+    /// ```
+    ///
+    pub fn merge(&mut self, other: &TradeStats) {
+        self.base_token_volume = self
+            .base_token_volume
+            .saturating_add(other.base_token_volume);
+        self.quote_token_volume = self
+            .quote_token_volume
+            .saturating_add(other.quote_token_volume);
+        self.base_token_fees = self.base_token_fees.saturating_add(other.base_token_fees);
+        self.quote_token_fees = self.quote_token_fees.saturating_add(other.quote_token_fees);
+        self.trade_count = self.trade_count.saturating_add(other.trade_count);
+    }
+}
+
+impl ByteConversion<48> for TradeStats {
+    fn to_bytes(&self) -> [u8; 48] {
+        let mut bytes = [0u8; 48];
+
+        bytes[0..8].copy_from_slice(&self.hour.to_bytes());
+        bytes[8..16].copy_from_slice(&self.base_token_volume.to_bytes());
+        bytes[16..24].copy_from_slice(&self.quote_token_volume.to_bytes());
+        bytes[24..32].copy_from_slice(&self.base_token_fees.to_bytes());
+        bytes[32..40].copy_from_slice(&self.quote_token_fees.to_bytes());
+        bytes[40..48].copy_from_slice(&self.trade_count.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 48]) -> Self {
+        Self {
+            hour: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            base_token_volume: u64::from_bytes(bytes[8..16].try_into().unwrap()),
+            quote_token_volume: u64::from_bytes(bytes[16..24].try_into().unwrap()),
+            base_token_fees: u64::from_bytes(bytes[24..32].try_into().unwrap()),
+            quote_token_fees: u64::from_bytes(bytes[32..40].try_into().unwrap()),
+            trade_count: u64::from_bytes(bytes[40..48].try_into().unwrap()),
+        }
+    }
+}
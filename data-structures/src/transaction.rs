@@ -1,4 +1,4 @@
-use crate::{Address, ByteConversion, Signature, U256};
+use crate::{Address, ByteConversion, Field, FieldConversion, PoolKind, Signature, U256};
 
 /// The enum that represents transaction types.
 ///
@@ -13,6 +13,202 @@ pub enum Transaction {
     RemoveLiquidity(RemoveLiquidityTransaction),
     BuyTokens(BuyTokensTransaction),
     SellTokens(SellTokensTransaction),
+    SwapRoute(SwapRouteTransaction),
+    TransferTokens(TransferTokensTransaction),
+    PlaceOrder(PlaceOrderTransaction),
+    CancelOrder(CancelOrderTransaction),
+    FillOrder(FillOrderTransaction),
+    ClaimProtocolFees(ClaimProtocolFeesTransaction),
+}
+
+impl Transaction {
+    /// Returns the address of the user the transaction belongs to.
+    ///
+    /// Returns `None` for the genesis and order fill transactions as they don't belong to any user.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let maybe_address = tx.address();
+    /// ```
+    ///
+    pub fn address(&self) -> Option<&Address> {
+        match self {
+            Transaction::CreateGenesis() => None,
+            Transaction::DepositTokens(tx) => Some(&tx.user_address),
+            Transaction::BurnTokens(tx) => Some(&tx.address),
+            Transaction::CreatePool(tx) => Some(&tx.address),
+            Transaction::ProvideLiquidity(tx) => Some(&tx.address),
+            Transaction::RemoveLiquidity(tx) => Some(&tx.address),
+            Transaction::BuyTokens(tx) => Some(&tx.address),
+            Transaction::SellTokens(tx) => Some(&tx.address),
+            Transaction::SwapRoute(tx) => Some(&tx.address),
+            Transaction::TransferTokens(tx) => Some(&tx.address),
+            Transaction::PlaceOrder(tx) => Some(&tx.address),
+            Transaction::CancelOrder(tx) => Some(&tx.address),
+            Transaction::FillOrder(_) => None,
+            Transaction::ClaimProtocolFees(tx) => Some(&tx.address),
+        }
+    }
+
+    /// Returns the nonce the transaction is signed with.
+    ///
+    /// Returns `None` for the genesis, deposit and order fill transactions as they aren't signed by users.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let maybe_nonce = tx.nonce();
+    /// ```
+    ///
+    pub fn nonce(&self) -> Option<u64> {
+        match self {
+            Transaction::CreateGenesis() => None,
+            Transaction::DepositTokens(_) => None,
+            Transaction::BurnTokens(tx) => Some(tx.nonce),
+            Transaction::CreatePool(tx) => Some(tx.nonce),
+            Transaction::ProvideLiquidity(tx) => Some(tx.nonce),
+            Transaction::RemoveLiquidity(tx) => Some(tx.nonce),
+            Transaction::BuyTokens(tx) => Some(tx.nonce),
+            Transaction::SellTokens(tx) => Some(tx.nonce),
+            Transaction::SwapRoute(tx) => Some(tx.nonce),
+            Transaction::TransferTokens(tx) => Some(tx.nonce),
+            Transaction::PlaceOrder(tx) => Some(tx.nonce),
+            Transaction::CancelOrder(tx) => Some(tx.nonce),
+            Transaction::FillOrder(_) => None,
+            Transaction::ClaimProtocolFees(tx) => Some(tx.nonce),
+        }
+    }
+
+    /// Returns the signature and the message the user signed for the transaction.
+    ///
+    /// The message consists of the receiver address if there is one, the token IDs, the amounts, the pool parameters if there are any and the nonce in the order they are stored.
+    /// Order cancellations sign the order ID and the nonce.
+    ///
+    /// Returns `None` for the genesis, deposit and order fill transactions as they aren't signed by users.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let (signature, msg) = tx.signed_message().unwrap();
+    /// ```
+    ///
+    pub fn signed_message(&self) -> Option<(&Signature, Vec<Field>)> {
+        match self {
+            Transaction::CreateGenesis() => None,
+            Transaction::DepositTokens(_) => None,
+            Transaction::BurnTokens(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.token_id.to_fields()[0],
+                    tx.token_amount.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+            Transaction::CreatePool(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.base_token_id.to_fields()[0],
+                    tx.quote_token_id.to_fields()[0],
+                    tx.base_token_amount.to_fields()[0],
+                    tx.quote_token_amount.to_fields()[0],
+                    tx.fee_rate.to_fields()[0],
+                    (tx.pool_kind as u64).to_fields()[0],
+                    tx.amplification.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+            Transaction::ProvideLiquidity(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.base_token_id.to_fields()[0],
+                    tx.quote_token_id.to_fields()[0],
+                    tx.base_token_amount.to_fields()[0],
+                    tx.quote_token_amount_limit.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+            Transaction::RemoveLiquidity(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.base_token_id.to_fields()[0],
+                    tx.quote_token_id.to_fields()[0],
+                    tx.base_token_amount_limit.to_fields()[0],
+                    tx.quote_token_amount_limit.to_fields()[0],
+                    tx.points.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+            Transaction::BuyTokens(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.base_token_id.to_fields()[0],
+                    tx.quote_token_id.to_fields()[0],
+                    tx.base_token_amount.to_fields()[0],
+                    tx.quote_token_amount_limit.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+            Transaction::SellTokens(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.base_token_id.to_fields()[0],
+                    tx.quote_token_id.to_fields()[0],
+                    tx.base_token_amount_limit.to_fields()[0],
+                    tx.quote_token_amount.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+            Transaction::SwapRoute(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.path[0].to_fields()[0],
+                    tx.path[1].to_fields()[0],
+                    tx.path[2].to_fields()[0],
+                    tx.token_amount_in.to_fields()[0],
+                    tx.token_amount_out_limit.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+            Transaction::TransferTokens(tx) => {
+                let [receiver_x, receiver_is_odd] = tx.receiver_address.to_fields();
+
+                Some((
+                    &tx.signature,
+                    vec![
+                        receiver_x,
+                        receiver_is_odd,
+                        tx.token_id.to_fields()[0],
+                        tx.token_amount.to_fields()[0],
+                        tx.nonce.to_fields()[0],
+                    ],
+                ))
+            }
+            Transaction::PlaceOrder(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.token_in_id.to_fields()[0],
+                    tx.token_out_id.to_fields()[0],
+                    tx.token_amount_in.to_fields()[0],
+                    tx.token_amount_out_limit.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+            Transaction::CancelOrder(tx) => Some((
+                &tx.signature,
+                vec![tx.order_id.to_fields()[0], tx.nonce.to_fields()[0]],
+            )),
+            Transaction::FillOrder(_) => None,
+            Transaction::ClaimProtocolFees(tx) => Some((
+                &tx.signature,
+                vec![
+                    tx.base_token_id.to_fields()[0],
+                    tx.quote_token_id.to_fields()[0],
+                    tx.nonce.to_fields()[0],
+                ],
+            )),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -28,6 +224,7 @@ pub struct BurnTokensTransaction {
     pub signature: Signature,
     pub token_id: U256,
     pub token_amount: u64,
+    pub nonce: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -38,6 +235,10 @@ pub struct CreatePoolTransaction {
     pub quote_token_id: U256,
     pub base_token_amount: u64,
     pub quote_token_amount: u64,
+    pub fee_rate: u64,
+    pub pool_kind: PoolKind,
+    pub amplification: u64,
+    pub nonce: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -48,6 +249,7 @@ pub struct ProvideLiquidityTransaction {
     pub quote_token_id: U256,
     pub base_token_amount: u64,
     pub quote_token_amount_limit: u64,
+    pub nonce: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -59,6 +261,7 @@ pub struct RemoveLiquidityTransaction {
     pub base_token_amount_limit: u64,
     pub quote_token_amount_limit: u64,
     pub points: U256,
+    pub nonce: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -69,6 +272,7 @@ pub struct BuyTokensTransaction {
     pub quote_token_id: U256,
     pub base_token_amount: u64,
     pub quote_token_amount_limit: u64,
+    pub nonce: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -79,11 +283,85 @@ pub struct SellTokensTransaction {
     pub quote_token_id: U256,
     pub base_token_amount_limit: u64,
     pub quote_token_amount: u64,
+    pub nonce: u64,
 }
 
-impl ByteConversion<232> for Transaction {
-    fn to_bytes(&self) -> [u8; 232] {
-        let mut buf = [0u8; 232];
+/// The transaction that swaps an exact amount of tokens through consecutive AMM pools.
+///
+/// The `path` property holds the token IDs the tokens are swapped through, from the token paid to the token received.
+///
+/// The pool of each hop is the one whose base token is the token paid in that hop, if there is no such pool the one whose quote token is the token paid is used.
+///
+/// The `token_amount_out_limit` property is the minimum amount of the last token of the path the user accepts to receive.
+#[derive(Clone, Debug)]
+pub struct SwapRouteTransaction {
+    pub address: Address,
+    pub signature: Signature,
+    pub path: [U256; 3],
+    pub token_amount_in: u64,
+    pub token_amount_out_limit: u64,
+    pub nonce: u64,
+}
+
+/// The transaction that moves tokens from the user's balance to the balance of another user.
+///
+/// The balance of the receiver is created if the receiver doesn't have a balance of the token yet.
+#[derive(Clone, Debug)]
+pub struct TransferTokensTransaction {
+    pub address: Address,
+    pub signature: Signature,
+    pub receiver_address: Address,
+    pub token_id: U256,
+    pub token_amount: u64,
+    pub nonce: u64,
+}
+
+/// The transaction that places a limit order which sells an exact amount of tokens once the AMM pool of the token pair gives at least the given amount for them.
+///
+/// The tokens to sell are escrowed from the user's balance until the order is filled or cancelled.
+#[derive(Clone, Debug)]
+pub struct PlaceOrderTransaction {
+    pub address: Address,
+    pub signature: Signature,
+    pub token_in_id: U256,
+    pub token_out_id: U256,
+    pub token_amount_in: u64,
+    pub token_amount_out_limit: u64,
+    pub nonce: u64,
+}
+
+/// The transaction that cancels an open limit order of the user and refunds its escrowed tokens.
+#[derive(Clone, Debug)]
+pub struct CancelOrderTransaction {
+    pub address: Address,
+    pub signature: Signature,
+    pub order_id: u64,
+    pub nonce: u64,
+}
+
+/// The transaction that fills an open limit order against the AMM pool of its token pair.
+///
+/// It isn't signed by users, the sequencer creates it when a swap moves the price of the pool beyond the limit of the order.
+#[derive(Clone, Debug)]
+pub struct FillOrderTransaction {
+    pub order_id: u64,
+}
+
+/// The transaction that moves the protocol fees accrued in an AMM pool to the treasury's balances.
+///
+/// Only the treasury can sign it, which is set with `NACHO_TREASURY_ADDRESS`.
+#[derive(Clone, Debug)]
+pub struct ClaimProtocolFeesTransaction {
+    pub address: Address,
+    pub signature: Signature,
+    pub base_token_id: U256,
+    pub quote_token_id: U256,
+    pub nonce: u64,
+}
+
+impl ByteConversion<240> for Transaction {
+    fn to_bytes(&self) -> [u8; 240] {
+        let mut buf = [0u8; 240];
 
         match self {
             Transaction::CreateGenesis {} => {
@@ -95,56 +373,98 @@ impl ByteConversion<232> for Transaction {
             }
             Transaction::BurnTokens(burn_tokens_transaction) => {
                 buf[0] = 2;
-                buf[1..160].copy_from_slice(&burn_tokens_transaction.to_bytes());
+                buf[1..168].copy_from_slice(&burn_tokens_transaction.to_bytes());
             }
             Transaction::CreatePool(create_pool_transaction) => {
                 buf[0] = 3;
-                buf[1..200].copy_from_slice(&create_pool_transaction.to_bytes());
+                buf[1..225].copy_from_slice(&create_pool_transaction.to_bytes());
             }
             Transaction::ProvideLiquidity(provide_liquidity_transaction) => {
                 buf[0] = 4;
-                buf[1..200].copy_from_slice(&provide_liquidity_transaction.to_bytes());
+                buf[1..208].copy_from_slice(&provide_liquidity_transaction.to_bytes());
             }
             Transaction::RemoveLiquidity(remove_liquidity_transaction) => {
                 buf[0] = 5;
-                buf[1..232].copy_from_slice(&remove_liquidity_transaction.to_bytes());
+                buf[1..240].copy_from_slice(&remove_liquidity_transaction.to_bytes());
             }
             Transaction::BuyTokens(buy_tokens_transaction) => {
                 buf[0] = 6;
-                buf[1..200].copy_from_slice(&buy_tokens_transaction.to_bytes());
+                buf[1..208].copy_from_slice(&buy_tokens_transaction.to_bytes());
             }
             Transaction::SellTokens(sell_tokens_transaction) => {
                 buf[0] = 7;
-                buf[1..200].copy_from_slice(&sell_tokens_transaction.to_bytes());
+                buf[1..208].copy_from_slice(&sell_tokens_transaction.to_bytes());
+            }
+            Transaction::SwapRoute(swap_route_transaction) => {
+                buf[0] = 8;
+                buf[1..240].copy_from_slice(&swap_route_transaction.to_bytes());
+            }
+            Transaction::TransferTokens(transfer_tokens_transaction) => {
+                buf[0] = 9;
+                buf[1..223].copy_from_slice(&transfer_tokens_transaction.to_bytes());
+            }
+            Transaction::PlaceOrder(place_order_transaction) => {
+                buf[0] = 10;
+                buf[1..208].copy_from_slice(&place_order_transaction.to_bytes());
+            }
+            Transaction::CancelOrder(cancel_order_transaction) => {
+                buf[0] = 11;
+                buf[1..136].copy_from_slice(&cancel_order_transaction.to_bytes());
+            }
+            Transaction::FillOrder(fill_order_transaction) => {
+                buf[0] = 12;
+                buf[1..9].copy_from_slice(&fill_order_transaction.to_bytes());
+            }
+            Transaction::ClaimProtocolFees(claim_protocol_fees_transaction) => {
+                buf[0] = 13;
+                buf[1..192].copy_from_slice(&claim_protocol_fees_transaction.to_bytes());
             }
         }
 
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 232]) -> Self {
+    fn from_bytes(bytes: &[u8; 240]) -> Self {
         match bytes[0] {
             0 => Self::CreateGenesis(),
             1 => Self::DepositTokens(DepositTokensTransaction::from_bytes(
                 bytes[1..96].try_into().unwrap(),
             )),
             2 => Self::BurnTokens(BurnTokensTransaction::from_bytes(
-                bytes[1..160].try_into().unwrap(),
+                bytes[1..168].try_into().unwrap(),
             )),
             3 => Self::CreatePool(CreatePoolTransaction::from_bytes(
-                bytes[1..200].try_into().unwrap(),
+                bytes[1..225].try_into().unwrap(),
             )),
             4 => Self::ProvideLiquidity(ProvideLiquidityTransaction::from_bytes(
-                bytes[1..200].try_into().unwrap(),
+                bytes[1..208].try_into().unwrap(),
             )),
             5 => Self::RemoveLiquidity(RemoveLiquidityTransaction::from_bytes(
-                bytes[1..232].try_into().unwrap(),
+                bytes[1..240].try_into().unwrap(),
             )),
             6 => Self::BuyTokens(BuyTokensTransaction::from_bytes(
-                bytes[1..200].try_into().unwrap(),
+                bytes[1..208].try_into().unwrap(),
+            )),
+            7 => Self::SellTokens(SellTokensTransaction::from_bytes(
+                bytes[1..208].try_into().unwrap(),
+            )),
+            8 => Self::SwapRoute(SwapRouteTransaction::from_bytes(
+                bytes[1..240].try_into().unwrap(),
+            )),
+            9 => Self::TransferTokens(TransferTokensTransaction::from_bytes(
+                bytes[1..223].try_into().unwrap(),
             )),
-            _ => Self::SellTokens(SellTokensTransaction::from_bytes(
-                bytes[1..200].try_into().unwrap(),
+            10 => Self::PlaceOrder(PlaceOrderTransaction::from_bytes(
+                bytes[1..208].try_into().unwrap(),
+            )),
+            11 => Self::CancelOrder(CancelOrderTransaction::from_bytes(
+                bytes[1..136].try_into().unwrap(),
+            )),
+            12 => Self::FillOrder(FillOrderTransaction::from_bytes(
+                bytes[1..9].try_into().unwrap(),
+            )),
+            _ => Self::ClaimProtocolFees(ClaimProtocolFeesTransaction::from_bytes(
+                bytes[1..192].try_into().unwrap(),
             )),
         }
     }
@@ -168,39 +488,45 @@ impl ByteConversion<95> for DepositTokensTransaction {
     }
 }
 
-impl ByteConversion<159> for BurnTokensTransaction {
-    fn to_bytes(&self) -> [u8; 159] {
-        let mut buf = [0u8; 159];
+impl ByteConversion<167> for BurnTokensTransaction {
+    fn to_bytes(&self) -> [u8; 167] {
+        let mut buf = [0u8; 167];
         buf[0..55].copy_from_slice(&self.address.to_bytes());
         buf[55..119].copy_from_slice(&self.signature.to_bytes());
         buf[119..151].copy_from_slice(&self.token_id.to_bytes());
         buf[151..159].copy_from_slice(&self.token_amount.to_bytes());
+        buf[159..167].copy_from_slice(&self.nonce.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 159]) -> Self {
+    fn from_bytes(bytes: &[u8; 167]) -> Self {
         BurnTokensTransaction {
             address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
             signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
             token_id: U256::from_bytes(bytes[119..151].try_into().unwrap()),
             token_amount: u64::from_bytes(bytes[151..159].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[159..167].try_into().unwrap()),
         }
     }
 }
 
-impl ByteConversion<199> for CreatePoolTransaction {
-    fn to_bytes(&self) -> [u8; 199] {
-        let mut buf = [0u8; 199];
+impl ByteConversion<224> for CreatePoolTransaction {
+    fn to_bytes(&self) -> [u8; 224] {
+        let mut buf = [0u8; 224];
         buf[0..55].copy_from_slice(&self.address.to_bytes());
         buf[55..119].copy_from_slice(&self.signature.to_bytes());
         buf[119..151].copy_from_slice(&self.base_token_id.to_bytes());
         buf[151..183].copy_from_slice(&self.quote_token_id.to_bytes());
         buf[183..191].copy_from_slice(&self.base_token_amount.to_bytes());
         buf[191..199].copy_from_slice(&self.quote_token_amount.to_bytes());
+        buf[199..207].copy_from_slice(&self.fee_rate.to_bytes());
+        buf[207..208].copy_from_slice(&self.pool_kind.to_bytes());
+        buf[208..216].copy_from_slice(&self.amplification.to_bytes());
+        buf[216..224].copy_from_slice(&self.nonce.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 199]) -> Self {
+    fn from_bytes(bytes: &[u8; 224]) -> Self {
         CreatePoolTransaction {
             address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
             signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
@@ -208,23 +534,28 @@ impl ByteConversion<199> for CreatePoolTransaction {
             quote_token_id: U256::from_bytes(bytes[151..183].try_into().unwrap()),
             base_token_amount: u64::from_bytes(bytes[183..191].try_into().unwrap()),
             quote_token_amount: u64::from_bytes(bytes[191..199].try_into().unwrap()),
+            fee_rate: u64::from_bytes(bytes[199..207].try_into().unwrap()),
+            pool_kind: PoolKind::from_bytes(bytes[207..208].try_into().unwrap()),
+            amplification: u64::from_bytes(bytes[208..216].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[216..224].try_into().unwrap()),
         }
     }
 }
 
-impl ByteConversion<199> for ProvideLiquidityTransaction {
-    fn to_bytes(&self) -> [u8; 199] {
-        let mut buf = [0u8; 199];
+impl ByteConversion<207> for ProvideLiquidityTransaction {
+    fn to_bytes(&self) -> [u8; 207] {
+        let mut buf = [0u8; 207];
         buf[0..55].copy_from_slice(&self.address.to_bytes());
         buf[55..119].copy_from_slice(&self.signature.to_bytes());
         buf[119..151].copy_from_slice(&self.base_token_id.to_bytes());
         buf[151..183].copy_from_slice(&self.quote_token_id.to_bytes());
         buf[183..191].copy_from_slice(&self.base_token_amount.to_bytes());
         buf[191..199].copy_from_slice(&self.quote_token_amount_limit.to_bytes());
+        buf[199..207].copy_from_slice(&self.nonce.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 199]) -> Self {
+    fn from_bytes(bytes: &[u8; 207]) -> Self {
         ProvideLiquidityTransaction {
             address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
             signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
@@ -232,13 +563,14 @@ impl ByteConversion<199> for ProvideLiquidityTransaction {
             quote_token_id: U256::from_bytes(bytes[151..183].try_into().unwrap()),
             base_token_amount: u64::from_bytes(bytes[183..191].try_into().unwrap()),
             quote_token_amount_limit: u64::from_bytes(bytes[191..199].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[199..207].try_into().unwrap()),
         }
     }
 }
 
-impl ByteConversion<231> for RemoveLiquidityTransaction {
-    fn to_bytes(&self) -> [u8; 231] {
-        let mut buf = [0u8; 231];
+impl ByteConversion<239> for RemoveLiquidityTransaction {
+    fn to_bytes(&self) -> [u8; 239] {
+        let mut buf = [0u8; 239];
         buf[0..55].copy_from_slice(&self.address.to_bytes());
         buf[55..119].copy_from_slice(&self.signature.to_bytes());
         buf[119..151].copy_from_slice(&self.base_token_id.to_bytes());
@@ -246,10 +578,11 @@ impl ByteConversion<231> for RemoveLiquidityTransaction {
         buf[183..191].copy_from_slice(&self.base_token_amount_limit.to_bytes());
         buf[191..199].copy_from_slice(&self.quote_token_amount_limit.to_bytes());
         buf[199..231].copy_from_slice(&self.points.to_bytes());
+        buf[231..239].copy_from_slice(&self.nonce.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 231]) -> Self {
+    fn from_bytes(bytes: &[u8; 239]) -> Self {
         RemoveLiquidityTransaction {
             address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
             signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
@@ -257,24 +590,26 @@ impl ByteConversion<231> for RemoveLiquidityTransaction {
             quote_token_id: U256::from_bytes(bytes[151..183].try_into().unwrap()),
             base_token_amount_limit: u64::from_bytes(bytes[183..191].try_into().unwrap()),
             quote_token_amount_limit: u64::from_bytes(bytes[191..199].try_into().unwrap()),
-            points: U256::from_bytes(bytes[199..131].try_into().unwrap()),
+            points: U256::from_bytes(bytes[199..231].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[231..239].try_into().unwrap()),
         }
     }
 }
 
-impl ByteConversion<199> for BuyTokensTransaction {
-    fn to_bytes(&self) -> [u8; 199] {
-        let mut buf = [0u8; 199];
-        buf[1..55].copy_from_slice(&self.address.to_bytes());
+impl ByteConversion<207> for BuyTokensTransaction {
+    fn to_bytes(&self) -> [u8; 207] {
+        let mut buf = [0u8; 207];
+        buf[0..55].copy_from_slice(&self.address.to_bytes());
         buf[55..119].copy_from_slice(&self.signature.to_bytes());
         buf[119..151].copy_from_slice(&self.base_token_id.to_bytes());
         buf[151..183].copy_from_slice(&self.quote_token_id.to_bytes());
         buf[183..191].copy_from_slice(&self.base_token_amount.to_bytes());
         buf[191..199].copy_from_slice(&self.quote_token_amount_limit.to_bytes());
+        buf[199..207].copy_from_slice(&self.nonce.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 199]) -> Self {
+    fn from_bytes(bytes: &[u8; 207]) -> Self {
         BuyTokensTransaction {
             address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
             signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
@@ -282,30 +617,172 @@ impl ByteConversion<199> for BuyTokensTransaction {
             quote_token_id: U256::from_bytes(bytes[151..183].try_into().unwrap()),
             base_token_amount: u64::from_bytes(bytes[183..191].try_into().unwrap()),
             quote_token_amount_limit: u64::from_bytes(bytes[191..199].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[199..207].try_into().unwrap()),
         }
     }
 }
 
-impl ByteConversion<199> for SellTokensTransaction {
-    fn to_bytes(&self) -> [u8; 199] {
-        let mut buf = [0u8; 199];
+impl ByteConversion<207> for SellTokensTransaction {
+    fn to_bytes(&self) -> [u8; 207] {
+        let mut buf = [0u8; 207];
         buf[0..55].copy_from_slice(&self.address.to_bytes());
         buf[55..119].copy_from_slice(&self.signature.to_bytes());
         buf[119..151].copy_from_slice(&self.base_token_id.to_bytes());
-        buf[151..193].copy_from_slice(&self.quote_token_id.to_bytes());
+        buf[151..183].copy_from_slice(&self.quote_token_id.to_bytes());
         buf[183..191].copy_from_slice(&self.base_token_amount_limit.to_bytes());
         buf[191..199].copy_from_slice(&self.quote_token_amount.to_bytes());
+        buf[199..207].copy_from_slice(&self.nonce.to_bytes());
         buf
     }
 
-    fn from_bytes(bytes: &[u8; 199]) -> Self {
+    fn from_bytes(bytes: &[u8; 207]) -> Self {
         SellTokensTransaction {
             address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
-            signature: Signature::from_bytes(bytes[55..199].try_into().unwrap()),
+            signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
             base_token_id: U256::from_bytes(bytes[119..151].try_into().unwrap()),
             quote_token_id: U256::from_bytes(bytes[151..183].try_into().unwrap()),
             base_token_amount_limit: u64::from_bytes(bytes[183..191].try_into().unwrap()),
             quote_token_amount: u64::from_bytes(bytes[191..199].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[199..207].try_into().unwrap()),
+        }
+    }
+}
+
+impl SwapRouteTransaction {
+    /// The maximum count of pools a swap route goes through, which is the length of its path minus one.
+    pub const MAX_HOPS: usize = 2;
+}
+
+impl ByteConversion<239> for SwapRouteTransaction {
+    fn to_bytes(&self) -> [u8; 239] {
+        let mut buf = [0u8; 239];
+        buf[0..55].copy_from_slice(&self.address.to_bytes());
+        buf[55..119].copy_from_slice(&self.signature.to_bytes());
+        buf[119..151].copy_from_slice(&self.path[0].to_bytes());
+        buf[151..183].copy_from_slice(&self.path[1].to_bytes());
+        buf[183..215].copy_from_slice(&self.path[2].to_bytes());
+        buf[215..223].copy_from_slice(&self.token_amount_in.to_bytes());
+        buf[223..231].copy_from_slice(&self.token_amount_out_limit.to_bytes());
+        buf[231..239].copy_from_slice(&self.nonce.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 239]) -> Self {
+        SwapRouteTransaction {
+            address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
+            signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
+            path: [
+                U256::from_bytes(bytes[119..151].try_into().unwrap()),
+                U256::from_bytes(bytes[151..183].try_into().unwrap()),
+                U256::from_bytes(bytes[183..215].try_into().unwrap()),
+            ],
+            token_amount_in: u64::from_bytes(bytes[215..223].try_into().unwrap()),
+            token_amount_out_limit: u64::from_bytes(bytes[223..231].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[231..239].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<222> for TransferTokensTransaction {
+    fn to_bytes(&self) -> [u8; 222] {
+        let mut buf = [0u8; 222];
+        buf[0..55].copy_from_slice(&self.address.to_bytes());
+        buf[55..119].copy_from_slice(&self.signature.to_bytes());
+        buf[119..174].copy_from_slice(&self.receiver_address.to_bytes());
+        buf[174..206].copy_from_slice(&self.token_id.to_bytes());
+        buf[206..214].copy_from_slice(&self.token_amount.to_bytes());
+        buf[214..222].copy_from_slice(&self.nonce.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 222]) -> Self {
+        TransferTokensTransaction {
+            address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
+            signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
+            receiver_address: Address::from_bytes(bytes[119..174].try_into().unwrap()),
+            token_id: U256::from_bytes(bytes[174..206].try_into().unwrap()),
+            token_amount: u64::from_bytes(bytes[206..214].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[214..222].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<207> for PlaceOrderTransaction {
+    fn to_bytes(&self) -> [u8; 207] {
+        let mut buf = [0u8; 207];
+        buf[0..55].copy_from_slice(&self.address.to_bytes());
+        buf[55..119].copy_from_slice(&self.signature.to_bytes());
+        buf[119..151].copy_from_slice(&self.token_in_id.to_bytes());
+        buf[151..183].copy_from_slice(&self.token_out_id.to_bytes());
+        buf[183..191].copy_from_slice(&self.token_amount_in.to_bytes());
+        buf[191..199].copy_from_slice(&self.token_amount_out_limit.to_bytes());
+        buf[199..207].copy_from_slice(&self.nonce.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 207]) -> Self {
+        PlaceOrderTransaction {
+            address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
+            signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
+            token_in_id: U256::from_bytes(bytes[119..151].try_into().unwrap()),
+            token_out_id: U256::from_bytes(bytes[151..183].try_into().unwrap()),
+            token_amount_in: u64::from_bytes(bytes[183..191].try_into().unwrap()),
+            token_amount_out_limit: u64::from_bytes(bytes[191..199].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[199..207].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<135> for CancelOrderTransaction {
+    fn to_bytes(&self) -> [u8; 135] {
+        let mut buf = [0u8; 135];
+        buf[0..55].copy_from_slice(&self.address.to_bytes());
+        buf[55..119].copy_from_slice(&self.signature.to_bytes());
+        buf[119..127].copy_from_slice(&self.order_id.to_bytes());
+        buf[127..135].copy_from_slice(&self.nonce.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 135]) -> Self {
+        CancelOrderTransaction {
+            address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
+            signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
+            order_id: u64::from_bytes(bytes[119..127].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[127..135].try_into().unwrap()),
+        }
+    }
+}
+
+impl ByteConversion<8> for FillOrderTransaction {
+    fn to_bytes(&self) -> [u8; 8] {
+        self.order_id.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; 8]) -> Self {
+        FillOrderTransaction {
+            order_id: u64::from_bytes(bytes),
+        }
+    }
+}
+
+impl ByteConversion<191> for ClaimProtocolFeesTransaction {
+    fn to_bytes(&self) -> [u8; 191] {
+        let mut buf = [0u8; 191];
+        buf[0..55].copy_from_slice(&self.address.to_bytes());
+        buf[55..119].copy_from_slice(&self.signature.to_bytes());
+        buf[119..151].copy_from_slice(&self.base_token_id.to_bytes());
+        buf[151..183].copy_from_slice(&self.quote_token_id.to_bytes());
+        buf[183..191].copy_from_slice(&self.nonce.to_bytes());
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 191]) -> Self {
+        ClaimProtocolFeesTransaction {
+            address: Address::from_bytes(bytes[0..55].try_into().unwrap()),
+            signature: Signature::from_bytes(bytes[55..119].try_into().unwrap()),
+            base_token_id: U256::from_bytes(bytes[119..151].try_into().unwrap()),
+            quote_token_id: U256::from_bytes(bytes[151..183].try_into().unwrap()),
+            nonce: u64::from_bytes(bytes[183..191].try_into().unwrap()),
         }
     }
 }
@@ -1,6 +1,10 @@
-use std::{collections::HashMap, io::SeekFrom, path::Path};
+use std::{
+    collections::HashMap,
+    io::{ErrorKind, SeekFrom},
+    path::Path,
+};
 use tokio::{
-    fs::{create_dir_all, File, OpenOptions},
+    fs::{create_dir_all, read, write, File, OpenOptions},
     io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
 };
 
@@ -36,6 +40,11 @@ impl<const C: usize> DynamicList<C> {
             .open(path)
             .await?;
 
+        let mut item_size_path = path.as_os_str().to_owned();
+        item_size_path.push(".item_size");
+
+        check_item_size::<C>(&file, Path::new(&item_size_path)).await?;
+
         Ok(Self { file })
     }
 
@@ -84,6 +93,32 @@ impl<const C: usize> DynamicList<C> {
         Ok(buf)
     }
 
+    /// Removes the last buffer of the list and returns it.
+    pub async fn pop(&mut self) -> Result<[u8; C]> {
+        let len = self.file.metadata().await?.len();
+
+        if len < C as u64 {
+            return Err(DynamicListError::IndexOutOfBounds);
+        }
+
+        let padding = len - C as u64;
+
+        let mut buf = [0u8; C];
+
+        self.file.seek(SeekFrom::Start(padding)).await?;
+        self.file.read_exact(&mut buf).await?;
+        self.file.set_len(padding).await?;
+
+        Ok(buf)
+    }
+
+    /// Returns the number of buffers stored in the list.
+    pub async fn get_item_count(&mut self) -> Result<u64> {
+        let len = self.file.metadata().await?.len();
+
+        Ok(len / C as u64)
+    }
+
     pub async fn for_each<F, K, V, E>(
         &mut self,
         map: &mut HashMap<K, V>,
@@ -122,6 +157,32 @@ impl<const C: usize> DynamicList<C> {
     }
 }
 
+/// Checks that the buffers in the given file are `C` bytes long, their size is stored in a file next to it.
+///
+/// The size is stored when the file is empty. Otherwise, a file that was written with another size, or before the size was stored, is rejected instead of being misread.
+async fn check_item_size<const C: usize>(file: &File, item_size_path: &Path) -> Result<()> {
+    if file.metadata().await?.len() == 0 {
+        write(item_size_path, (C as u64).to_le_bytes()).await?;
+
+        return Ok(());
+    }
+
+    let item_size = match read(item_size_path).await {
+        Ok(bytes) => bytes.try_into().map(u64::from_le_bytes).ok(),
+        Err(err) if err.kind() == ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+
+    if item_size != Some(C as u64) {
+        return Err(DynamicListError::ItemSizeMismatch {
+            expected: C as u64,
+            found: item_size,
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,6 +247,89 @@ mod tests {
         remove_file(dir).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn pops_buffers() {
+        let dir = "/tmp/nacho/tests/dynamic_list/pops_buffers";
+
+        let mut list = DynamicList::<2>::new(dir).await.unwrap();
+
+        list.push([100, 90]).await.unwrap();
+        list.push([80, 70]).await.unwrap();
+
+        let buf = list.pop().await.unwrap();
+        assert_eq!(buf, [80, 70]);
+
+        let err = list.get(1).await.unwrap_err();
+        assert!(matches!(err, DynamicListError::IndexOutOfBounds));
+
+        let index = list.push([60, 50]).await.unwrap();
+        assert_eq!(index, 1);
+
+        list.pop().await.unwrap();
+        list.pop().await.unwrap();
+
+        let err = list.pop().await.unwrap_err();
+        assert!(matches!(err, DynamicListError::IndexOutOfBounds));
+
+        remove_file(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn counts_buffers() {
+        let dir = "/tmp/nacho/tests/dynamic_list/counts_buffers";
+
+        let mut list = DynamicList::<2>::new(dir).await.unwrap();
+
+        assert_eq!(list.get_item_count().await.unwrap(), 0);
+
+        list.push([100, 90]).await.unwrap();
+        list.push([80, 70]).await.unwrap();
+
+        assert_eq!(list.get_item_count().await.unwrap(), 2);
+
+        list.pop().await.unwrap();
+
+        assert_eq!(list.get_item_count().await.unwrap(), 1);
+
+        remove_file(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_other_item_sizes() {
+        let dir = "/tmp/nacho/tests/dynamic_list/rejects_other_item_sizes";
+
+        let mut list = DynamicList::<2>::new(dir).await.unwrap();
+
+        list.push([100, 90]).await.unwrap();
+        list.push([80, 70]).await.unwrap();
+
+        drop(list);
+
+        DynamicList::<2>::new(dir).await.unwrap();
+
+        let err = DynamicList::<4>::new(dir).await.unwrap_err();
+        assert!(matches!(
+            err,
+            DynamicListError::ItemSizeMismatch {
+                expected: 4,
+                found: Some(2)
+            }
+        ));
+
+        remove_file(format!("{dir}.item_size")).await.unwrap();
+
+        let err = DynamicList::<2>::new(dir).await.unwrap_err();
+        assert!(matches!(
+            err,
+            DynamicListError::ItemSizeMismatch {
+                expected: 2,
+                found: None
+            }
+        ));
+
+        remove_file(dir).await.unwrap();
+    }
+
     #[tokio::test]
     async fn runs_given_function_for_each_buffer_correctly() {
         let dir = "/tmp/nacho/tests/dynamic_list/runs_given_function_for_each_buffer_correctly";
@@ -9,4 +9,6 @@ pub enum DynamicListError {
     IndexOutOfBounds,
     #[error("Parent directory of the list isn't specified.")]
     ParentDirectoryNotSpecified,
+    #[error("The list's buffers aren't {expected} bytes long, it was written by another version.")]
+    ItemSizeMismatch { expected: u64, found: Option<u64> },
 }
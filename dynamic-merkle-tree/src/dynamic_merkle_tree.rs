@@ -447,6 +447,124 @@ impl<const H: usize, const L: usize> DynamicMerkleTree<H, L> {
 
         Ok(u256.to_fields()[0])
     }
+
+    /// Returns the count of used leaves in the Merkle tree.
+    ///
+    /// # Examples
+    ///
+    /// Get the count of used leaves:
+    ///
+    /// ```rs
+    /// let leaf_count = dmt.get_leaf_count().await?;
+    /// ```
+    ///
+    pub async fn get_leaf_count(&mut self) -> Result<u64> {
+        let leaves_file_len = self.files[0].metadata().await?.len();
+
+        Ok(leaves_file_len / 32)
+    }
+
+    /// Returns the internal nodes that aren't the hash of their children, as their heights and indexes.
+    ///
+    /// Missing nodes above used leaves are returned too.
+    ///
+    /// # Examples
+    ///
+    /// Check the nodes of the Merkle tree:
+    ///
+    /// ```rs
+    /// let invalid_nodes = dmt.find_invalid_nodes().await?;
+    /// ```
+    ///
+    pub async fn find_invalid_nodes(&mut self) -> Result<Vec<(usize, u64)>> {
+        let mut invalid_nodes = Vec::new();
+
+        let mut children = self.read_level(0).await?;
+
+        for h in 1..H {
+            let nodes = self.read_level(h).await?;
+
+            let node_count = nodes.len().max(children.len().div_ceil(2));
+
+            for i in 0..node_count {
+                let left = children.get(i * 2).copied().unwrap_or(self.zeroes[h - 1]);
+                let right = children
+                    .get(i * 2 + 1)
+                    .copied()
+                    .unwrap_or(self.zeroes[h - 1]);
+
+                let expected_node = poseidon_hash(&mut self.hasher, &[left, right]);
+
+                if nodes.get(i) != Some(&expected_node) {
+                    invalid_nodes.push((h, i as u64));
+                }
+            }
+
+            children = nodes;
+        }
+
+        Ok(invalid_nodes)
+    }
+
+    /// Replaces the leaves of the Merkle tree with the given values and recalculates all of its nodes.
+    ///
+    /// # Examples
+    ///
+    /// Rebuild the Merkle tree from the given values:
+    ///
+    /// ```rs
+    /// dmt.rebuild(&values).await?;
+    /// ```
+    ///
+    pub async fn rebuild(&mut self, values: &[Field]) -> Result<()> {
+        if values.len() as u64 > Self::MAX_NUMBER_OF_LEAVES {
+            return Err(DynamicMerkleTreeError::IndexDoesntExist);
+        }
+
+        let mut level = values.to_vec();
+
+        for h in 0..H {
+            let mut buf = Vec::with_capacity(level.len() * 32);
+
+            for value in &level {
+                buf.extend_from_slice(&U256::from(*value).0);
+            }
+
+            let file = &mut self.files[h];
+
+            file.set_len(0).await?;
+            file.seek(SeekFrom::Start(0)).await?;
+            file.write_all(&buf).await?;
+            file.flush().await?;
+
+            level = level
+                .chunks(2)
+                .map(|pair| {
+                    let right = pair.get(1).copied().unwrap_or(self.zeroes[h]);
+                    poseidon_hash(&mut self.hasher, &[pair[0], right])
+                })
+                .collect();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the values of the nodes at the given height.
+    async fn read_level(&mut self, height: usize) -> Result<Vec<Field>> {
+        let file = &mut self.files[height];
+
+        let mut buf = Vec::new();
+
+        file.seek(SeekFrom::Start(0)).await?;
+        file.read_to_end(&mut buf).await?;
+
+        let values = buf
+            .chunks_exact(32)
+            .map(|chunk| U256(chunk.try_into().unwrap()).to_fields()[0])
+            .collect();
+
+        Ok(values)
+    }
 }
 
 #[cfg(test)]
@@ -730,4 +848,42 @@ mod tests {
 
         Ok(remove_dir_all(dir).await?)
     }
+
+    #[tokio::test]
+    async fn finds_invalid_nodes_and_rebuilds(
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let dir = "/tmp/nacho/tests/dynamic_merkle_tree/finds_invalid_nodes_and_rebuilds";
+        let mut dmt = DynamicMerkleTree::<4, 3>::new(dir).await?;
+
+        let values = [Field::from(42), Field::from(5), Field::from(7)];
+
+        for value in values {
+            dmt.push_leaf(value).await?;
+        }
+
+        let root = dmt.get_root().await?;
+
+        assert_eq!(dmt.get_leaf_count().await?, 3);
+        assert_eq!(dmt.find_invalid_nodes().await?, vec![]);
+
+        let leaves_file = &mut dmt.files[0];
+        leaves_file.seek(SeekFrom::Start(32)).await?;
+        leaves_file.write_all(&U256::from(Field::from(6)).0).await?;
+        leaves_file.flush().await?;
+
+        assert_eq!(dmt.find_invalid_nodes().await?, vec![(1, 0)]);
+
+        dmt.rebuild(&values).await?;
+
+        assert_eq!(dmt.find_invalid_nodes().await?, vec![]);
+        assert_eq!(dmt.get_leaf(1).await?, Field::from(5));
+        assert_eq!(dmt.get_root().await?, root);
+
+        dmt.rebuild(&values[0..1]).await?;
+
+        assert_eq!(dmt.get_leaf_count().await?, 1);
+        assert_eq!(dmt.find_invalid_nodes().await?, vec![]);
+
+        Ok(remove_dir_all(dir).await?)
+    }
 }
@@ -1,8 +1,12 @@
 use crate::error::DynamicQueueError;
 use nacho_data_structures::ByteConversion;
-use std::{io::SeekFrom, marker::PhantomData, path::Path};
+use std::{
+    io::{ErrorKind, SeekFrom},
+    marker::PhantomData,
+    path::Path,
+};
 use tokio::{
-    fs::{create_dir_all, File, OpenOptions},
+    fs::{create_dir_all, read, write, File, OpenOptions},
     io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
 };
 
@@ -118,10 +122,14 @@ where
             .open(path.join("file"))
             .await?;
 
-        Ok(Self {
+        let mut queue = Self {
             file,
             phantom: PhantomData,
-        })
+        };
+
+        queue.check_item_size(&path.join("item_size")).await?;
+
+        Ok(queue)
     }
 
     /// Pushes the given item to the end of the queue.
@@ -192,6 +200,76 @@ where
         Ok(item)
     }
 
+    /// Returns the first item of the queue without popping it.
+    ///
+    /// It returns `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// Peek an item:
+    ///
+    /// ```rs
+    /// let item: Option<T> = queue.peek().await?;
+    /// ```
+    ///
+    pub async fn peek(&mut self) -> Result<Option<T>> {
+        let file_len = self.get_file_len().await?;
+
+        if file_len == 0 {
+            return Ok(None);
+        }
+
+        let pointer = self.get_pointer().await?;
+
+        self.read_oldest_item(file_len, pointer).await
+    }
+
+    /// Returns the count of the items stored in the file, including the popped items the garbage collector hasn't removed yet.
+    ///
+    /// It only increases when an item is pushed and decreases by 128 when the garbage collector runs.
+    ///
+    /// # Examples
+    ///
+    /// Get the stored item count:
+    ///
+    /// ```rs
+    /// let stored_item_count = queue.get_stored_item_count().await?;
+    /// ```
+    ///
+    pub async fn get_stored_item_count(&mut self) -> Result<u64> {
+        let file_len = self.get_file_len().await?;
+
+        Ok(file_len.saturating_sub(8) / L as u64)
+    }
+
+    /// Checks that the items in the queue's file are `L` bytes long, their size is stored in the file at the given path.
+    ///
+    /// The size is stored when the file has no items. Otherwise, a file that was written with another size, or before the size was stored, is rejected instead of being misread.
+    ///
+    /// This function is for internal use, do not use it outside of this crate.
+    async fn check_item_size(&mut self, item_size_path: &Path) -> Result<()> {
+        if self.get_file_len().await? <= 8 {
+            write(item_size_path, (L as u64).to_le_bytes()).await?;
+
+            return Ok(());
+        }
+
+        let item_size = match read(item_size_path).await {
+            Ok(bytes) => bytes.try_into().map(u64::from_le_bytes).ok(),
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        if item_size != Some(L as u64) {
+            return Err(DynamicQueueError::ItemSizeMismatch {
+                expected: L as u64,
+                found: item_size,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Initializes the value of the pointer as 8 which is the size of the pointer in bytes.
     ///
     /// This function is for internal use, do not use it outside of this crate.
@@ -370,7 +448,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tokio::fs::remove_dir_all;
+    use tokio::fs::{remove_dir_all, remove_file};
 
     #[derive(Debug, PartialEq)]
     struct T {
@@ -389,6 +467,23 @@ mod tests {
         }
     }
 
+    #[derive(Debug, PartialEq)]
+    struct U {
+        num: u16,
+    }
+
+    impl ByteConversion<2> for U {
+        fn to_bytes(&self) -> [u8; 2] {
+            self.num.to_le_bytes()
+        }
+
+        fn from_bytes(bytes: &[u8; 2]) -> Self {
+            Self {
+                num: u16::from_le_bytes(bytes.to_owned()),
+            }
+        }
+    }
+
     #[tokio::test]
     pub async fn creates_dynamic_queue() -> std::result::Result<(), Box<dyn std::error::Error>> {
         let dir = "/tmp/nacho/tests/dynamic_queue/creates_queue";
@@ -465,6 +560,61 @@ mod tests {
         Ok(remove_dir_all(dir).await?)
     }
 
+    #[tokio::test]
+    pub async fn peeks_items() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let dir = "/tmp/nacho/tests/dynamic_queue/peeks_items";
+
+        let mut queue = DynamicQueue::<4, T>::new(dir).await?;
+
+        assert_eq!(queue.peek().await?, None);
+
+        queue.push(&T { num: 5 }).await?;
+        queue.push(&T { num: 6 }).await?;
+
+        assert_eq!(queue.peek().await?, Some(T { num: 5 }));
+        assert_eq!(queue.peek().await?, Some(T { num: 5 }));
+
+        assert_eq!(queue.pop().await?, Some(T { num: 5 }));
+
+        assert_eq!(queue.peek().await?, Some(T { num: 6 }));
+
+        assert_eq!(queue.pop().await?, Some(T { num: 6 }));
+
+        assert_eq!(queue.peek().await?, None);
+
+        Ok(remove_dir_all(dir).await?)
+    }
+
+    #[tokio::test]
+    pub async fn counts_stored_items() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let dir = "/tmp/nacho/tests/dynamic_queue/counts_stored_items";
+
+        let mut queue = DynamicQueue::<4, T>::new(dir).await?;
+
+        assert_eq!(queue.get_stored_item_count().await?, 0);
+
+        queue.push(&T { num: 5 }).await?;
+        queue.push(&T { num: 6 }).await?;
+
+        assert_eq!(queue.get_stored_item_count().await?, 2);
+
+        queue.pop().await?;
+
+        assert_eq!(queue.get_stored_item_count().await?, 2);
+
+        for i in 0..127 {
+            queue.push(&T { num: i }).await?;
+        }
+
+        for _ in 0..127 {
+            queue.pop().await?;
+        }
+
+        assert_eq!(queue.get_stored_item_count().await?, 1);
+
+        Ok(remove_dir_all(dir).await?)
+    }
+
     #[tokio::test]
     pub async fn collects_garbage() -> std::result::Result<(), Box<dyn std::error::Error>> {
         let dir = "/tmp/nacho/tests/dynamic_queue/collects_garbage";
@@ -543,4 +693,39 @@ mod tests {
 
         Ok(remove_dir_all(dir).await?)
     }
+
+    #[tokio::test]
+    pub async fn rejects_other_item_sizes() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let dir = "/tmp/nacho/tests/dynamic_queue/rejects_other_item_sizes";
+
+        let mut queue = DynamicQueue::<4, T>::new(dir).await?;
+
+        queue.push(&T { num: 5 }).await?;
+
+        drop(queue);
+
+        DynamicQueue::<4, T>::new(dir).await?;
+
+        let err = DynamicQueue::<2, U>::new(dir).await.err().unwrap();
+        assert!(matches!(
+            err,
+            DynamicQueueError::ItemSizeMismatch {
+                expected: 2,
+                found: Some(4)
+            }
+        ));
+
+        remove_file(format!("{dir}/item_size")).await?;
+
+        let err = DynamicQueue::<4, T>::new(dir).await.err().unwrap();
+        assert!(matches!(
+            err,
+            DynamicQueueError::ItemSizeMismatch {
+                expected: 4,
+                found: None
+            }
+        ));
+
+        Ok(remove_dir_all(dir).await?)
+    }
 }
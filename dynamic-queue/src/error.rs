@@ -5,4 +5,6 @@ use thiserror::Error;
 pub enum DynamicQueueError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error("The queue's items aren't {expected} bytes long, it was written by another version.")]
+    ItemSizeMismatch { expected: u64, found: Option<u64> },
 }
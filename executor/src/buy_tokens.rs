@@ -1,6 +1,9 @@
 use nacho_data_structures::{Balance, Pool, U256};
 
-use crate::{ExecutorError, Result};
+use crate::{
+    quote::{amount_in_plus_fee, protocol_fee},
+    ExecutorError, Result,
+};
 
 pub fn buy_tokens(
     base_token_id: U256,
@@ -9,27 +12,15 @@ pub fn buy_tokens(
     pool: &mut Pool,
     base_token_amount: u64,
     quote_token_amount_limit: u64,
+    protocol_fee_share: u64,
 ) -> Result<Option<Balance>> {
-    if base_token_amount >= pool.base_token_amount {
-        return Err(ExecutorError::NotEnoughInPool);
-    }
-
-    let k: U256 = U256::from(pool.base_token_amount) * pool.quote_token_amount.into();
-
-    let new_pool_base_token_amount = pool.base_token_amount - base_token_amount;
-
-    let new_pool_quote_token_amount = k / new_pool_base_token_amount.into();
-    let new_pool_quote_token_amount: u64 = (&new_pool_quote_token_amount)
-        .try_into()
-        .map_err(|_| ExecutorError::Overflow)?;
-
-    let quote_token_amount = new_pool_quote_token_amount - pool.quote_token_amount;
-
-    let quote_token_amount_plus_fee =
-        (U256::from(quote_token_amount) * 1001u64.into()) / 1000u64.into();
-    let quote_token_amount_plus_fee: u64 = (&quote_token_amount_plus_fee)
-        .try_into()
-        .map_err(|_| ExecutorError::Overflow)?;
+    let (quote_token_amount_plus_fee, fee) = amount_in_plus_fee(
+        pool.quote_token_amount,
+        pool.base_token_amount,
+        base_token_amount,
+        pool.fee_rate,
+        pool.amplification,
+    )?;
 
     if quote_token_amount_plus_fee > quote_token_balance.token_amount {
         return Err(ExecutorError::NotEnoughBalance);
@@ -39,9 +30,17 @@ pub fn buy_tokens(
         return Err(ExecutorError::LimitExceeded);
     }
 
+    let protocol_fee = protocol_fee(fee, protocol_fee_share);
+
+    let protocol_fee_quote_token_amount = pool
+        .protocol_fee_quote_token_amount
+        .checked_add(protocol_fee)
+        .ok_or(ExecutorError::Overflow)?;
+
     quote_token_balance.token_amount -= quote_token_amount_plus_fee;
     pool.base_token_amount -= base_token_amount;
-    pool.quote_token_amount += quote_token_amount_plus_fee;
+    pool.quote_token_amount += quote_token_amount_plus_fee - protocol_fee;
+    pool.protocol_fee_quote_token_amount = protocol_fee_quote_token_amount;
 
     match maybe_base_token_balance {
         Some(base_token_balance) => {
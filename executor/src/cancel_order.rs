@@ -0,0 +1,77 @@
+use nacho_data_structures::{Balance, Order, OrderStatus};
+
+use crate::{ExecutorError, Result};
+
+/// Refunds the escrowed tokens of the given order to its owner's balance and marks it as cancelled.
+pub fn cancel_order(order: &mut Order, balance: &mut Balance) -> Result<()> {
+    if order.owner != balance.owner || order.token_in_id != balance.token_id {
+        return Err(ExecutorError::NotOrderOwner);
+    }
+
+    if order.status != OrderStatus::Open {
+        return Err(ExecutorError::OrderIsntOpen);
+    }
+
+    balance.token_amount = balance
+        .token_amount
+        .checked_add(order.token_amount_in)
+        .ok_or(ExecutorError::Overflow)?;
+
+    order.status = OrderStatus::Cancelled;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::{Address, ByteConversion, U256};
+
+    fn order() -> Order {
+        Order {
+            id: 0,
+            owner: Address::from_bytes(&[1u8; 55]),
+            token_in_id: U256::from(1u64),
+            token_out_id: U256::from(2u64),
+            token_amount_in: 1_000,
+            token_amount_out_limit: 2_000,
+            status: OrderStatus::Open,
+        }
+    }
+
+    #[test]
+    fn refunds_escrowed_tokens_only_once() {
+        let mut order = order();
+        let mut balance = Balance {
+            owner: Address::from_bytes(&[1u8; 55]),
+            token_id: U256::from(1u64),
+            token_amount: 500,
+        };
+
+        cancel_order(&mut order, &mut balance).unwrap();
+
+        assert_eq!(balance.token_amount, 1_500);
+        assert_eq!(order.status, OrderStatus::Cancelled);
+
+        let err = cancel_order(&mut order, &mut balance).unwrap_err();
+
+        assert!(matches!(err, ExecutorError::OrderIsntOpen));
+        assert_eq!(balance.token_amount, 1_500);
+    }
+
+    #[test]
+    fn does_not_cancel_orders_of_other_users() {
+        let mut order = order();
+        let mut balance = Balance {
+            owner: Address::from_bytes(&[2u8; 55]),
+            token_id: U256::from(1u64),
+            token_amount: 500,
+        };
+
+        let err = cancel_order(&mut order, &mut balance).unwrap_err();
+
+        assert!(matches!(err, ExecutorError::NotOrderOwner));
+        assert_eq!(balance.token_amount, 500);
+        assert_eq!(order.status, OrderStatus::Open);
+    }
+}
@@ -0,0 +1,78 @@
+use nacho_data_structures::{Balance, Pool};
+
+use crate::{ExecutorError, Result};
+
+/// Moves the protocol fees accrued in the given pool to the given balances of the treasury.
+///
+/// Fails with `NoProtocolFees` if the pool hasn't accrued any protocol fees since they were last claimed.
+pub fn claim_protocol_fees(
+    base_token_balance: &mut Balance,
+    quote_token_balance: &mut Balance,
+    pool: &mut Pool,
+) -> Result<()> {
+    if pool.protocol_fee_base_token_amount == 0 && pool.protocol_fee_quote_token_amount == 0 {
+        return Err(ExecutorError::NoProtocolFees);
+    }
+
+    let base_token_amount = base_token_balance
+        .token_amount
+        .checked_add(pool.protocol_fee_base_token_amount)
+        .ok_or(ExecutorError::Overflow)?;
+
+    let quote_token_amount = quote_token_balance
+        .token_amount
+        .checked_add(pool.protocol_fee_quote_token_amount)
+        .ok_or(ExecutorError::Overflow)?;
+
+    base_token_balance.token_amount = base_token_amount;
+    quote_token_balance.token_amount = quote_token_amount;
+    pool.protocol_fee_base_token_amount = 0;
+    pool.protocol_fee_quote_token_amount = 0;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::{Address, ByteConversion, U256};
+
+    fn balance(token_id: u64, token_amount: u64) -> Balance {
+        Balance {
+            owner: Address::from_bytes(&[1u8; 55]),
+            token_id: U256::from(token_id),
+            token_amount,
+        }
+    }
+
+    #[test]
+    fn claims_protocol_fees_once() {
+        let mut base_token_balance = balance(1, 100);
+        let mut quote_token_balance = balance(2, 0);
+        let mut pool = Pool {
+            base_token_id: U256::from(1u64),
+            quote_token_id: U256::from(2u64),
+            base_token_amount: 1_000_000,
+            quote_token_amount: 2_000_000,
+            total_liqudity_points: U256::from(1_000_000u64),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 15,
+            protocol_fee_quote_token_amount: 40,
+            amplification: 0,
+        };
+
+        claim_protocol_fees(&mut base_token_balance, &mut quote_token_balance, &mut pool).unwrap();
+
+        assert_eq!(base_token_balance.token_amount, 115);
+        assert_eq!(quote_token_balance.token_amount, 40);
+        assert_eq!(pool.protocol_fee_base_token_amount, 0);
+        assert_eq!(pool.protocol_fee_quote_token_amount, 0);
+        assert_eq!(pool.base_token_amount, 1_000_000);
+        assert_eq!(pool.quote_token_amount, 2_000_000);
+
+        let err = claim_protocol_fees(&mut base_token_balance, &mut quote_token_balance, &mut pool)
+            .unwrap_err();
+
+        assert!(matches!(err, ExecutorError::NoProtocolFees));
+    }
+}
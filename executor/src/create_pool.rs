@@ -1,4 +1,4 @@
-use nacho_data_structures::{Balance, Liquidity, Pool};
+use nacho_data_structures::{Balance, Liquidity, Pool, PoolKind};
 
 use crate::{ExecutorError, Result};
 
@@ -7,7 +7,18 @@ pub fn create_pool(
     quote_token_balance: &mut Balance,
     base_token_amount: u64,
     quote_token_amount: u64,
+    fee_rate: u64,
+    pool_kind: PoolKind,
+    amplification: u64,
 ) -> Result<(Pool, Liquidity)> {
+    if !Pool::FEE_RATES.contains(&fee_rate) {
+        return Err(ExecutorError::InvalidFeeRate);
+    }
+
+    if !pool_kind.accepts_amplification(amplification) {
+        return Err(ExecutorError::InvalidAmplification);
+    }
+
     if base_token_amount > base_token_balance.token_amount {
         return Err(ExecutorError::NotEnoughBalance);
     }
@@ -25,6 +36,10 @@ pub fn create_pool(
         base_token_amount,
         quote_token_amount,
         total_liqudity_points: (base_token_amount as u128 * quote_token_amount as u128).into(),
+        fee_rate,
+        protocol_fee_base_token_amount: 0,
+        protocol_fee_quote_token_amount: 0,
+        amplification,
     };
 
     let liquidity = Liquidity {
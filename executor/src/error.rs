@@ -1,3 +1,4 @@
+use nacho_data_structures::RejectionReason;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,6 +13,39 @@ pub enum ExecutorError {
     Overflow,
     #[error("Limit exceeded.")]
     LimitExceeded,
+    #[error("Invalid route.")]
+    InvalidRoute,
+    #[error("Invalid fee rate.")]
+    InvalidFeeRate,
+    #[error("Invalid amplification.")]
+    InvalidAmplification,
+    #[error("Order isn't open.")]
+    OrderIsntOpen,
+    #[error("Not order owner.")]
+    NotOrderOwner,
+    #[error("Not enough price history.")]
+    NotEnoughPriceHistory,
+    #[error("No protocol fees.")]
+    NoProtocolFees,
+}
+
+impl From<ExecutorError> for RejectionReason {
+    fn from(value: ExecutorError) -> Self {
+        match value {
+            ExecutorError::NotEnoughBalance => RejectionReason::NotEnoughBalance,
+            ExecutorError::NotEnoughLiquidty => RejectionReason::NotEnoughLiquidity,
+            ExecutorError::NotEnoughInPool => RejectionReason::NotEnoughInPool,
+            ExecutorError::Overflow => RejectionReason::Overflow,
+            ExecutorError::LimitExceeded => RejectionReason::LimitExceeded,
+            ExecutorError::InvalidRoute => RejectionReason::InvalidRoute,
+            ExecutorError::InvalidFeeRate => RejectionReason::InvalidFeeRate,
+            ExecutorError::InvalidAmplification => RejectionReason::InvalidAmplification,
+            ExecutorError::OrderIsntOpen => RejectionReason::OrderIsntOpen,
+            ExecutorError::NotOrderOwner => RejectionReason::NotOrderOwner,
+            ExecutorError::NotEnoughPriceHistory => RejectionReason::NotEnoughPriceHistory,
+            ExecutorError::NoProtocolFees => RejectionReason::NoProtocolFees,
+        }
+    }
 }
 
 pub(crate) type Result<T> = std::result::Result<T, ExecutorError>;
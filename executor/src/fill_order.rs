@@ -0,0 +1,128 @@
+use nacho_data_structures::{Balance, Order, OrderStatus, Pool};
+
+use crate::{swap_route::swap_in_pool, ExecutorError, Result};
+
+/// Swaps the escrowed tokens of the given order in the given pool and marks it as filled.
+///
+/// Fails with `LimitExceeded` if the pool's price doesn't give the order at least its limit amount.
+pub fn fill_order(
+    order: &mut Order,
+    maybe_token_out_balance: Option<&mut Balance>,
+    pool: &mut Pool,
+    protocol_fee_share: u64,
+) -> Result<Option<Balance>> {
+    if order.status != OrderStatus::Open {
+        return Err(ExecutorError::OrderIsntOpen);
+    }
+
+    let mut new_pool = pool.clone();
+
+    let token_amount_out = swap_in_pool(
+        &mut new_pool,
+        &order.token_in_id,
+        &order.token_out_id,
+        order.token_amount_in,
+        protocol_fee_share,
+    )?;
+
+    if token_amount_out < order.token_amount_out_limit {
+        return Err(ExecutorError::LimitExceeded);
+    }
+
+    let maybe_new_balance = match maybe_token_out_balance {
+        Some(token_out_balance) => {
+            token_out_balance.token_amount = token_out_balance
+                .token_amount
+                .checked_add(token_amount_out)
+                .ok_or(ExecutorError::Overflow)?;
+
+            None
+        }
+        None => Some(Balance {
+            owner: order.owner.clone(),
+            token_id: order.token_out_id.clone(),
+            token_amount: token_amount_out,
+        }),
+    };
+
+    *pool = new_pool;
+    order.status = OrderStatus::Filled;
+
+    Ok(maybe_new_balance)
+}
+
+/// Checks if the given order can be filled at the given pool's current price.
+pub fn is_order_fillable(order: &Order, pool: &Pool) -> bool {
+    order.status == OrderStatus::Open
+        && swap_in_pool(
+            &mut pool.clone(),
+            &order.token_in_id,
+            &order.token_out_id,
+            order.token_amount_in,
+            0,
+        )
+        .is_ok_and(|token_amount_out| token_amount_out >= order.token_amount_out_limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::place_order;
+    use nacho_data_structures::{Address, ByteConversion, U256};
+
+    fn pool() -> Pool {
+        Pool {
+            base_token_id: U256::from(1u64),
+            quote_token_id: U256::from(2u64),
+            base_token_amount: 1_000_000,
+            quote_token_amount: 2_000_000,
+            total_liqudity_points: U256::from(1_000_000u64),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
+        }
+    }
+
+    #[test]
+    fn fills_orders_once_the_price_crosses_their_limit() {
+        let mut pool = pool();
+        let mut balance = Balance {
+            owner: Address::from_bytes(&[1u8; 55]),
+            token_id: U256::from(2u64),
+            token_amount: 100_000,
+        };
+
+        // Buys base tokens for at most 21,000 / 11,000 quote tokens each, which is below the pool's price.
+        let mut order = place_order(&mut balance, 0, &U256::from(1u64), 21_000, 11_000).unwrap();
+
+        assert_eq!(balance.token_amount, 79_000);
+        assert!(!is_order_fillable(&order, &pool));
+
+        let err = fill_order(&mut order, None, &mut pool, 0).unwrap_err();
+
+        assert!(matches!(err, ExecutorError::LimitExceeded));
+        assert_eq!(order.status, OrderStatus::Open);
+        assert_eq!(pool, self::pool());
+
+        // Someone sells base tokens and the price of the base token falls.
+        pool.base_token_amount = 1_100_000;
+        pool.quote_token_amount = 1_820_000;
+
+        assert!(is_order_fillable(&order, &pool));
+
+        let token_out_balance = fill_order(&mut order, None, &mut pool, 0).unwrap().unwrap();
+
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(token_out_balance.owner, order.owner);
+        assert_eq!(token_out_balance.token_id, U256::from(1u64));
+        assert!(token_out_balance.token_amount >= 11_000);
+        assert_eq!(pool.quote_token_amount, 1_841_000);
+        assert_eq!(
+            pool.base_token_amount,
+            1_100_000 - token_out_balance.token_amount
+        );
+
+        assert!(!is_order_fillable(&order, &pool));
+    }
+}
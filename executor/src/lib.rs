@@ -1,19 +1,42 @@
 mod burn_tokens;
 mod buy_tokens;
+mod cancel_order;
+mod claim_protocol_fees;
 mod create_pool;
 mod deposit_tokens;
 mod error;
+mod fill_order;
+mod place_order;
+mod position;
 mod provide_liqudity;
+mod quote;
 mod remove_liquidity;
+mod router;
 mod sell_tokens;
+mod stable_swap;
+mod swap_route;
+mod trade_stats;
+mod transfer_tokens;
+mod twap;
 
 pub use burn_tokens::burn_tokens;
 pub use buy_tokens::buy_tokens;
+pub use cancel_order::cancel_order;
+pub use claim_protocol_fees::claim_protocol_fees;
 pub use create_pool::create_pool;
 pub use deposit_tokens::deposit_tokens;
 pub use error::ExecutorError;
+pub use fill_order::{fill_order, is_order_fillable};
+pub use place_order::place_order;
+pub use position::{value_position, Position};
 pub use provide_liqudity::provide_liquidity;
+pub use quote::{quote_swap, Quote};
 pub use remove_liquidity::remove_liquidity;
+pub use router::{find_best_route, find_hop_pool, Route};
 pub use sell_tokens::sell_tokens;
+pub use swap_route::swap_route;
+pub use trade_stats::observe_trade;
+pub use transfer_tokens::transfer_tokens;
+pub use twap::{observe_pool, time_weighted_average_price};
 
 pub(crate) use error::Result;
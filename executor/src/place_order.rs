@@ -0,0 +1,30 @@
+use nacho_data_structures::{Balance, Order, OrderStatus, U256};
+
+use crate::{ExecutorError, Result};
+
+/// Escrows the sold tokens from the given balance and returns the placed order.
+///
+/// The escrowed tokens are paid to the pool when the order is filled, or refunded when it is cancelled.
+pub fn place_order(
+    balance: &mut Balance,
+    order_id: u64,
+    token_out_id: &U256,
+    token_amount_in: u64,
+    token_amount_out_limit: u64,
+) -> Result<Order> {
+    if token_amount_in > balance.token_amount {
+        return Err(ExecutorError::NotEnoughBalance);
+    }
+
+    balance.token_amount -= token_amount_in;
+
+    Ok(Order {
+        id: order_id,
+        owner: balance.owner.clone(),
+        token_in_id: balance.token_id.clone(),
+        token_out_id: token_out_id.clone(),
+        token_amount_in,
+        token_amount_out_limit,
+        status: OrderStatus::Open,
+    })
+}
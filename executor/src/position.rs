@@ -0,0 +1,217 @@
+use nacho_data_structures::{Liquidity, Observation, Pool, U256};
+
+use crate::{
+    remove_liquidity::amounts_of_points, stable_swap, twap::FIXED_POINT_SCALE, ExecutorError,
+    Result,
+};
+
+/// The struct that represents the value of a user's liquidity in an AMM pool computed from the pool's current reserves.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+    /// The part of the pool's total liquidity points the user has, from zero to one.
+    pub share: f64,
+    /// The base token amount removing all of the user's liquidity points gives.
+    pub base_token_amount: u64,
+    /// The quote token amount removing all of the user's liquidity points gives.
+    pub quote_token_amount: u64,
+    /// The part of `base_token_amount` that is estimated to come from swap fees.
+    pub fee_base_token_amount: u64,
+    /// The part of `quote_token_amount` that is estimated to come from swap fees.
+    pub fee_quote_token_amount: u64,
+}
+
+/// Computes the value of the given liquidity in the given pool without modifying either.
+///
+/// The amounts are the ones `remove_liquidity` would give for all of the liquidity's points.
+///
+/// The fees are estimated from how much the pool's invariant per liquidity point has grown since the given observation,
+/// which should be the oldest observation of the pool. Providing and removing liquidity doesn't change it, only swap fees do.
+/// So the estimate is exact for the liquidity that has been in the pool since the observation, and too high for the rest.
+///
+/// # Examples
+///
+/// ```rs
+/// let position = value_position(&liquidity, &pool, maybe_oldest_observation.as_ref())?;
+/// ```
+///
+pub fn value_position(
+    liquidity: &Liquidity,
+    pool: &Pool,
+    maybe_oldest_observation: Option<&Observation>,
+) -> Result<Position> {
+    // NOTE: All the liquidity of the pool is removed, so there is nothing to value.
+    if pool.total_liqudity_points == U256::from(0u64) {
+        return Ok(Position::default());
+    }
+
+    let (base_token_amount, quote_token_amount) = amounts_of_points(pool, &liquidity.points)?;
+
+    let share = to_f64(&liquidity.points)? / to_f64(&pool.total_liqudity_points)?;
+
+    let fee_ratio = match maybe_oldest_observation {
+        Some(observation) if observation.points_per_liquidity != 0 => {
+            let points_per_liquidity = points_per_liquidity(pool)?;
+
+            (1.0 - points_per_liquidity as f64 / observation.points_per_liquidity as f64).max(0.0)
+        }
+        _ => 0.0,
+    };
+
+    Ok(Position {
+        share,
+        base_token_amount,
+        quote_token_amount,
+        fee_base_token_amount: (base_token_amount as f64 * fee_ratio) as u64,
+        fee_quote_token_amount: (quote_token_amount as f64 * fee_ratio) as u64,
+    })
+}
+
+/// Returns the pool's total liquidity points per unit of its invariant as a 64.64 fixed point number.
+///
+/// The invariant is the square root of the reserves' product for constant product pools, and `D` for StableSwap pools.
+pub(crate) fn points_per_liquidity(pool: &Pool) -> Result<u128> {
+    let invariant = if pool.amplification == 0 {
+        (pool.base_token_amount as f64 * pool.quote_token_amount as f64).sqrt()
+    } else {
+        stable_swap::invariant(
+            pool.base_token_amount,
+            pool.quote_token_amount,
+            pool.amplification,
+        )? as f64
+    };
+
+    if invariant == 0.0 {
+        return Err(ExecutorError::NotEnoughInPool);
+    }
+
+    // NOTE: Float to integer casts saturate, so extreme values are clamped instead of wrapped.
+    Ok((to_f64(&pool.total_liqudity_points)? / invariant * FIXED_POINT_SCALE) as u128)
+}
+
+fn to_f64(points: &U256) -> Result<f64> {
+    let points: u128 = points.try_into().map_err(|_| ExecutorError::Overflow)?;
+
+    Ok(points as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, observe_pool, provide_liquidity, remove_liquidity, sell_tokens};
+    use nacho_data_structures::{Address, Balance, ByteConversion, PoolKind};
+
+    fn balance(owner: u8, token_id: u64) -> Balance {
+        Balance {
+            owner: Address::from_bytes(&[owner; 55]),
+            token_id: U256::from(token_id),
+            token_amount: 1_000_000_000,
+        }
+    }
+
+    #[test]
+    fn values_positions_like_remove_liquidity() {
+        let (mut pool, liquidity_0) = create_pool(
+            &mut balance(0, 1),
+            &mut balance(0, 0),
+            1_000_000,
+            2_000_000,
+            30,
+            PoolKind::ConstantProduct,
+            0,
+        )
+        .unwrap();
+
+        let liquidity_1 = provide_liquidity(
+            &mut balance(1, 1),
+            &mut balance(1, 0),
+            None,
+            &mut pool,
+            1_000_000,
+            u64::MAX,
+        )
+        .unwrap()
+        .unwrap();
+
+        let position = value_position(&liquidity_1, &pool, None).unwrap();
+
+        assert!((position.share - 0.5).abs() < 1e-9);
+        assert_eq!(position.fee_base_token_amount, 0);
+        assert_eq!(position.fee_quote_token_amount, 0);
+
+        let mut base_token_balance = balance(0, 1);
+        let mut quote_token_balance = balance(0, 0);
+        let mut liquidity = liquidity_0.clone();
+
+        remove_liquidity(
+            &mut base_token_balance,
+            &mut quote_token_balance,
+            &mut liquidity,
+            &mut pool.clone(),
+            liquidity_0.points.clone(),
+            u64::MAX,
+            u64::MAX,
+        )
+        .unwrap();
+
+        let position = value_position(&liquidity_0, &pool, None).unwrap();
+
+        assert_eq!(
+            position.base_token_amount,
+            base_token_balance.token_amount - 1_000_000_000
+        );
+        assert_eq!(
+            position.quote_token_amount,
+            quote_token_balance.token_amount - 1_000_000_000
+        );
+    }
+
+    #[test]
+    fn estimates_fees_from_swaps_only() {
+        let (mut pool, liquidity) = create_pool(
+            &mut balance(0, 1),
+            &mut balance(0, 0),
+            1_000_000,
+            2_000_000,
+            100,
+            PoolKind::ConstantProduct,
+            0,
+        )
+        .unwrap();
+
+        let observation = observe_pool(&pool, None, 100).unwrap();
+
+        provide_liquidity(
+            &mut balance(1, 1),
+            &mut balance(1, 0),
+            None,
+            &mut pool,
+            1_000_000,
+            u64::MAX,
+        )
+        .unwrap();
+
+        let position = value_position(&liquidity, &pool, Some(&observation)).unwrap();
+
+        assert_eq!(position.fee_base_token_amount, 0);
+        assert_eq!(position.fee_quote_token_amount, 0);
+
+        sell_tokens(
+            U256::from(0u64),
+            &mut balance(2, 1),
+            None,
+            &mut pool,
+            u64::MAX,
+            1_000_000,
+            0,
+        )
+        .unwrap();
+
+        let position = value_position(&liquidity, &pool, Some(&observation)).unwrap();
+
+        // The fee is 1% of the sold amount, which grows the pool's invariant by about 0.1%.
+        assert!(position.fee_base_token_amount > 0);
+        assert!(position.fee_quote_token_amount > 0);
+        assert!(position.fee_base_token_amount < position.base_token_amount / 100);
+        assert!(position.fee_quote_token_amount < position.quote_token_amount / 100);
+    }
+}
@@ -0,0 +1,344 @@
+use nacho_data_structures::{Pool, SwapSide, U256};
+
+use crate::{stable_swap, ExecutorError, Result};
+
+/// The struct that represents the outcome of a swap in an AMM pool computed from the pool's current reserves.
+///
+/// Prices are in quote tokens per base token.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quote {
+    /// The amount of tokens the user pays including the fee. Quote tokens for buys, base tokens for sells.
+    pub amount_in: u64,
+    /// The amount of tokens the user receives. Base tokens for buys, quote tokens for sells.
+    pub amount_out: u64,
+    /// The part of `amount_in` that is paid as the fee.
+    pub fee: u64,
+    /// The price of the base token in the pool before the swap.
+    pub spot_price: f64,
+    /// The price the user gets including the fee.
+    pub effective_price: f64,
+    /// The relative difference between the spot price and the price the user gets excluding the fee.
+    pub price_impact: f64,
+}
+
+/// Computes the outcome of a swap in the given pool without modifying it.
+///
+/// The amount is the base token amount to buy for buys and the base token amount to sell for sells.
+///
+/// The returned amounts are the ones `buy_tokens` and `sell_tokens` would use, so `amount_in` can be used as the limit of a transaction.
+///
+/// # Examples
+///
+/// ```rs
+/// let quote = quote_swap(&pool, SwapSide::Buy, 100)?;
+/// ```
+///
+pub fn quote_swap(pool: &Pool, side: SwapSide, amount: u64) -> Result<Quote> {
+    let spot_price = spot_price_of(pool)?;
+
+    match side {
+        SwapSide::Buy => {
+            let (amount_in, fee) = amount_in_plus_fee(
+                pool.quote_token_amount,
+                pool.base_token_amount,
+                amount,
+                pool.fee_rate,
+                pool.amplification,
+            )?;
+
+            let price_without_fee = ratio(amount_in - fee, amount);
+
+            Ok(Quote {
+                amount_in,
+                amount_out: amount,
+                fee,
+                spot_price,
+                effective_price: ratio(amount_in, amount),
+                price_impact: price_without_fee / spot_price - 1.0,
+            })
+        }
+        SwapSide::Sell => {
+            let (amount_out, _) = amount_out_minus_fee(
+                pool.base_token_amount,
+                pool.quote_token_amount,
+                amount,
+                pool.fee_rate,
+                pool.amplification,
+            )?;
+
+            let (amount_in, fee) = amount_in_plus_fee(
+                pool.base_token_amount,
+                pool.quote_token_amount,
+                amount_out,
+                pool.fee_rate,
+                pool.amplification,
+            )?;
+
+            let price_without_fee = ratio(amount_out, amount_in - fee);
+
+            Ok(Quote {
+                amount_in,
+                amount_out,
+                fee,
+                spot_price,
+                effective_price: ratio(amount_out, amount_in),
+                price_impact: 1.0 - price_without_fee / spot_price,
+            })
+        }
+    }
+}
+
+/// Returns the price of the base token in quote tokens in the given pool, which is the price of a swap with no size and no fee.
+pub(crate) fn spot_price_of(pool: &Pool) -> Result<f64> {
+    if pool.base_token_amount == 0 || pool.quote_token_amount == 0 {
+        return Err(ExecutorError::NotEnoughInPool);
+    }
+
+    if pool.amplification == 0 {
+        Ok(pool.quote_token_amount as f64 / pool.base_token_amount as f64)
+    } else {
+        stable_swap::spot_price(
+            pool.base_token_amount,
+            pool.quote_token_amount,
+            pool.amplification,
+        )
+    }
+}
+
+/// The denominator of the fee rates of AMM pools, which are in basis points.
+const FEE_RATE_DENOMINATOR: u64 = 10_000;
+
+/// Returns the amount of tokens that has to be paid to take the given amount of tokens out of a pool and the fee included in it.
+///
+/// The amplification coefficient is zero for constant product pools.
+pub(crate) fn amount_in_plus_fee(
+    reserve_in: u64,
+    reserve_out: u64,
+    amount_out: u64,
+    fee_rate: u64,
+    amplification: u64,
+) -> Result<(u64, u64)> {
+    if amount_out >= reserve_out {
+        return Err(ExecutorError::NotEnoughInPool);
+    }
+
+    let new_reserve_out = reserve_out - amount_out;
+
+    let new_reserve_in: u64 = if amplification == 0 {
+        let k: U256 = U256::from(reserve_out) * reserve_in.into();
+
+        (&(k / new_reserve_out.into()))
+            .try_into()
+            .map_err(|_| ExecutorError::Overflow)?
+    } else {
+        let d = stable_swap::invariant(reserve_in, reserve_out, amplification)?;
+
+        // NOTE: The new reserve in is rounded up, so the pool never loses value because of rounding.
+        stable_swap::other_reserve(new_reserve_out, d, amplification)?
+    };
+
+    let amount_in = new_reserve_in.saturating_sub(reserve_in);
+
+    let amount_in_plus_fee = (U256::from(amount_in) * (FEE_RATE_DENOMINATOR + fee_rate).into())
+        / FEE_RATE_DENOMINATOR.into();
+    let amount_in_plus_fee: u64 = (&amount_in_plus_fee)
+        .try_into()
+        .map_err(|_| ExecutorError::Overflow)?;
+
+    Ok((amount_in_plus_fee, amount_in_plus_fee - amount_in))
+}
+
+/// Returns the amount of tokens that is taken out of a pool when the given amount of tokens is paid and the fee included in the paid amount.
+///
+/// The amount taken out is rounded down, so that the pool never gives more than `amount_in_plus_fee` would charge for.
+///
+/// The amplification coefficient is zero for constant product pools.
+pub(crate) fn amount_out_minus_fee(
+    reserve_in: u64,
+    reserve_out: u64,
+    amount_in: u64,
+    fee_rate: u64,
+    amplification: u64,
+) -> Result<(u64, u64)> {
+    if reserve_in == 0 || reserve_out == 0 {
+        return Err(ExecutorError::NotEnoughInPool);
+    }
+
+    let amount_in_without_fee = (U256::from(amount_in) * FEE_RATE_DENOMINATOR.into())
+        / (FEE_RATE_DENOMINATOR + fee_rate).into();
+    let amount_in_without_fee: u64 = (&amount_in_without_fee)
+        .try_into()
+        .map_err(|_| ExecutorError::Overflow)?;
+
+    let new_reserve_out: u64 = if amplification == 0 {
+        let k: U256 = U256::from(reserve_in) * reserve_out.into();
+
+        let new_reserve_in = U256::from(reserve_in) + amount_in_without_fee.into();
+
+        (&((k + new_reserve_in.clone() - 1u64.into()) / new_reserve_in))
+            .try_into()
+            .map_err(|_| ExecutorError::Overflow)?
+    } else {
+        let d = stable_swap::invariant(reserve_in, reserve_out, amplification)?;
+
+        let new_reserve_in = reserve_in
+            .checked_add(amount_in_without_fee)
+            .ok_or(ExecutorError::Overflow)?;
+
+        // NOTE: The new reserve out is rounded up, so the pool never loses value because of rounding.
+        stable_swap::other_reserve(new_reserve_in, d, amplification)?
+    };
+
+    Ok((
+        reserve_out.saturating_sub(new_reserve_out),
+        amount_in - amount_in_without_fee,
+    ))
+}
+
+/// Returns the part of the given swap fee that is taken as the protocol fee instead of being added to the reserves.
+///
+/// The protocol fee share is in basis points of the fee and is capped at `Pool::MAX_PROTOCOL_FEE_SHARE`.
+pub(crate) fn protocol_fee(fee: u64, protocol_fee_share: u64) -> u64 {
+    let protocol_fee_share = protocol_fee_share.min(Pool::MAX_PROTOCOL_FEE_SHARE);
+
+    (fee as u128 * protocol_fee_share as u128 / Pool::MAX_PROTOCOL_FEE_SHARE as u128) as u64
+}
+
+fn ratio(numerator: u64, denominator: u64) -> f64 {
+    if denominator == 0 {
+        return 0.0;
+    }
+
+    numerator as f64 / denominator as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{buy_tokens, sell_tokens};
+    use nacho_data_structures::{Address, Balance, ByteConversion};
+
+    fn pool() -> Pool {
+        Pool {
+            base_token_id: U256::from(1u64),
+            quote_token_id: U256::from(0u64),
+            base_token_amount: 1_000_000,
+            quote_token_amount: 2_000_000,
+            total_liqudity_points: U256::from(1_000_000u64),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
+        }
+    }
+
+    fn balance(token_id: u64) -> Balance {
+        Balance {
+            owner: Address::from_bytes(&[1u8; 55]),
+            token_id: U256::from(token_id),
+            token_amount: 1_000_000_000,
+        }
+    }
+
+    #[test]
+    fn quotes_buys_like_buy_tokens() {
+        let quote = quote_swap(&pool(), SwapSide::Buy, 10_000).unwrap();
+
+        let mut quote_token_balance = balance(0);
+        let mut pool = pool();
+
+        buy_tokens(
+            U256::from(1u64),
+            None,
+            &mut quote_token_balance,
+            &mut pool,
+            10_000,
+            quote.amount_in,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(quote.amount_out, 10_000);
+        assert_eq!(
+            quote.amount_in,
+            1_000_000_000 - quote_token_balance.token_amount
+        );
+        assert_eq!(quote.fee, 60);
+        assert_eq!(quote.spot_price, 2.0);
+        assert!(quote.effective_price > quote.spot_price);
+        assert!(quote.price_impact > 0.0 && quote.price_impact < 0.02);
+    }
+
+    #[test]
+    fn quotes_sells_like_sell_tokens() {
+        let quote = quote_swap(&pool(), SwapSide::Sell, 10_000).unwrap();
+
+        let mut base_token_balance = balance(1);
+        let mut pool = pool();
+
+        sell_tokens(
+            U256::from(0u64),
+            &mut base_token_balance,
+            None,
+            &mut pool,
+            quote.amount_in,
+            quote.amount_out,
+            2_000,
+        )
+        .unwrap();
+
+        assert!(quote.amount_in <= 10_000);
+        assert_eq!(
+            quote.amount_in,
+            1_000_000_000 - base_token_balance.token_amount
+        );
+        assert!(quote.effective_price < quote.spot_price);
+        assert!(quote.price_impact > 0.0 && quote.price_impact < 0.02);
+    }
+
+    #[test]
+    fn does_not_quote_more_than_the_pool_has() {
+        let err = quote_swap(&pool(), SwapSide::Buy, 1_000_000).unwrap_err();
+
+        assert!(matches!(err, ExecutorError::NotEnoughInPool));
+    }
+
+    #[test]
+    fn quotes_stable_swap_pools_with_less_price_impact() {
+        let stable_pool = Pool {
+            quote_token_amount: 1_000_000,
+            fee_rate: 5,
+            amplification: 100,
+            ..pool()
+        };
+        let constant_product_pool = Pool {
+            amplification: 0,
+            ..stable_pool.clone()
+        };
+
+        let stable_quote = quote_swap(&stable_pool, SwapSide::Sell, 100_000).unwrap();
+        let constant_product_quote =
+            quote_swap(&constant_product_pool, SwapSide::Sell, 100_000).unwrap();
+
+        assert!((stable_quote.spot_price - 1.0).abs() < 1e-9);
+        assert!(stable_quote.amount_out > constant_product_quote.amount_out);
+        assert!(stable_quote.amount_out < 100_000);
+        assert!(stable_quote.price_impact < constant_product_quote.price_impact);
+
+        let mut base_token_balance = balance(1);
+        let mut pool = stable_pool;
+
+        sell_tokens(
+            U256::from(0u64),
+            &mut base_token_balance,
+            None,
+            &mut pool,
+            stable_quote.amount_in,
+            stable_quote.amount_out,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(pool.quote_token_amount, 1_000_000 - stable_quote.amount_out);
+    }
+}
@@ -11,17 +11,7 @@ pub fn remove_liquidity(
     base_token_amount_limit: u64,
     quote_token_amount_limit: u64,
 ) -> Result<()> {
-    let base_token_amount =
-        (points.clone() * pool.base_token_amount.into()) / pool.total_liqudity_points.clone();
-    let base_token_amount: u64 = (&base_token_amount)
-        .try_into()
-        .map_err(|_| ExecutorError::Overflow)?;
-
-    let quote_token_amount =
-        (points.clone() * pool.quote_token_amount.into()) / pool.total_liqudity_points.clone();
-    let quote_token_amount: u64 = (&quote_token_amount)
-        .try_into()
-        .map_err(|_| ExecutorError::Overflow)?;
+    let (base_token_amount, quote_token_amount) = amounts_of_points(pool, &points)?;
 
     if points > liquidity.points {
         return Err(ExecutorError::NotEnoughLiquidty);
@@ -54,3 +44,20 @@ pub fn remove_liquidity(
 
     Ok(())
 }
+
+/// Returns the base and quote token amounts that removing the given liquidity points from the pool gives.
+pub(crate) fn amounts_of_points(pool: &Pool, points: &U256) -> Result<(u64, u64)> {
+    let base_token_amount =
+        (points.clone() * pool.base_token_amount.into()) / pool.total_liqudity_points.clone();
+    let base_token_amount: u64 = (&base_token_amount)
+        .try_into()
+        .map_err(|_| ExecutorError::Overflow)?;
+
+    let quote_token_amount =
+        (points.clone() * pool.quote_token_amount.into()) / pool.total_liqudity_points.clone();
+    let quote_token_amount: u64 = (&quote_token_amount)
+        .try_into()
+        .map_err(|_| ExecutorError::Overflow)?;
+
+    Ok((base_token_amount, quote_token_amount))
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+
+use nacho_data_structures::{Pool, U256};
+
+use crate::swap_route::swap_in_pool;
+
+/// The struct that represents the best path found to swap a token to another one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    /// The token IDs the tokens are swapped through, from the token paid to the token received.
+    pub path: Vec<U256>,
+    /// The amount of the last token of the path the user receives.
+    pub amount_out: u64,
+}
+
+/// Returns the pool a hop from `token_in_id` to `token_out_id` goes through.
+///
+/// The pool whose base token is the token paid is preferred, the same way `swap_route` transactions are executed.
+///
+/// # Examples
+///
+/// ```rs
+/// let maybe_pool = find_hop_pool(&pools, &token_in_id, &token_out_id);
+/// ```
+///
+pub fn find_hop_pool<'a>(
+    pools: &'a [Pool],
+    token_in_id: &U256,
+    token_out_id: &U256,
+) -> Option<&'a Pool> {
+    pools
+        .iter()
+        .find(|pool| &pool.base_token_id == token_in_id && &pool.quote_token_id == token_out_id)
+        .or_else(|| {
+            pools.iter().find(|pool| {
+                &pool.base_token_id == token_out_id && &pool.quote_token_id == token_in_id
+            })
+        })
+}
+
+/// Finds the path with at most `max_hops` pools that gives the most tokens for the given amount.
+///
+/// Shorter paths are preferred when two paths give the same amount.
+///
+/// Returns `None` if there is no path between the tokens.
+///
+/// # Examples
+///
+/// ```rs
+/// let maybe_route = find_best_route(&pools, &token_in_id, &token_out_id, 1000, 2);
+/// ```
+///
+pub fn find_best_route(
+    pools: &[Pool],
+    token_in_id: &U256,
+    token_out_id: &U256,
+    token_amount_in: u64,
+    max_hops: usize,
+) -> Option<Route> {
+    let mut neighbors: BTreeMap<&U256, Vec<&U256>> = BTreeMap::new();
+
+    for pool in pools {
+        neighbors
+            .entry(&pool.base_token_id)
+            .or_default()
+            .push(&pool.quote_token_id);
+        neighbors
+            .entry(&pool.quote_token_id)
+            .or_default()
+            .push(&pool.base_token_id);
+    }
+
+    for token_ids in neighbors.values_mut() {
+        token_ids.sort();
+        token_ids.dedup();
+    }
+
+    let mut best_route: Option<Route> = None;
+    let mut path = vec![token_in_id.clone()];
+
+    search(
+        pools,
+        &neighbors,
+        token_out_id,
+        token_amount_in,
+        max_hops,
+        &mut path,
+        &mut best_route,
+    );
+
+    best_route
+}
+
+fn search(
+    pools: &[Pool],
+    neighbors: &BTreeMap<&U256, Vec<&U256>>,
+    token_out_id: &U256,
+    token_amount: u64,
+    max_hops: usize,
+    path: &mut Vec<U256>,
+    best_route: &mut Option<Route>,
+) {
+    if path.len() > max_hops {
+        return;
+    }
+
+    let current_token_id = path[path.len() - 1].clone();
+
+    for &next_token_id in neighbors.get(&current_token_id).into_iter().flatten() {
+        if path.contains(next_token_id) {
+            continue;
+        }
+
+        let mut pool = match find_hop_pool(pools, &current_token_id, next_token_id) {
+            Some(pool) => pool.clone(),
+            None => continue,
+        };
+
+        // The protocol fee share doesn't change the amount of tokens taken out of a pool.
+        let next_token_amount =
+            match swap_in_pool(&mut pool, &current_token_id, next_token_id, token_amount, 0) {
+                Ok(next_token_amount) => next_token_amount,
+                Err(_) => continue,
+            };
+
+        path.push(next_token_id.clone());
+
+        if next_token_id == token_out_id {
+            let is_better = match best_route {
+                Some(route) => {
+                    next_token_amount > route.amount_out
+                        || (next_token_amount == route.amount_out && path.len() < route.path.len())
+                }
+                None => true,
+            };
+
+            if is_better {
+                *best_route = Some(Route {
+                    path: path.clone(),
+                    amount_out: next_token_amount,
+                });
+            }
+        } else {
+            search(
+                pools,
+                neighbors,
+                token_out_id,
+                next_token_amount,
+                max_hops,
+                path,
+                best_route,
+            );
+        }
+
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(base_token_id: u64, quote_token_id: u64, base: u64, quote: u64) -> Pool {
+        Pool {
+            base_token_id: U256::from(base_token_id),
+            quote_token_id: U256::from(quote_token_id),
+            base_token_amount: base,
+            quote_token_amount: quote,
+            total_liqudity_points: U256::from(1_000_000u64),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
+        }
+    }
+
+    #[test]
+    fn finds_the_best_route() {
+        let pools = [
+            pool(1, 3, 1_000_000, 1_000_000),
+            pool(1, 2, 1_000_000, 2_000_000),
+            pool(3, 2, 1_000_000, 1_000_000),
+            pool(4, 5, 1_000_000, 1_000_000),
+        ];
+
+        let route =
+            find_best_route(&pools, &U256::from(1u64), &U256::from(3u64), 1_000, 2).unwrap();
+
+        assert_eq!(
+            route.path,
+            vec![U256::from(1u64), U256::from(2u64), U256::from(3u64)]
+        );
+        assert!(route.amount_out > 1_900);
+
+        let route =
+            find_best_route(&pools, &U256::from(1u64), &U256::from(3u64), 1_000, 1).unwrap();
+
+        assert_eq!(route.path, vec![U256::from(1u64), U256::from(3u64)]);
+        assert!(route.amount_out < 1_000);
+
+        assert_eq!(
+            find_best_route(&pools, &U256::from(1u64), &U256::from(5u64), 1_000, 2),
+            None
+        );
+    }
+}
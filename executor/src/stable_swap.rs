@@ -0,0 +1,152 @@
+use std::cmp::Ordering;
+
+use nacho_data_structures::U256;
+
+use crate::{ExecutorError, Result};
+
+/// Returns the StableSwap invariant `D` of a pool with the given reserves and amplification coefficient.
+///
+/// It is the solution of `4A(x + y) + D = 4AD + D^3 / 4xy` rounded up, which is the smallest `D` where `D^3 + 4xy(4A - 1)D >= 16Axy(x + y)`.
+/// The circuits check the same inequalities, so it is found with bisection instead of Newton's method to be exact.
+pub(crate) fn invariant(reserve_x: u64, reserve_y: u64, amplification: u64) -> Result<u128> {
+    if reserve_x == 0 || reserve_y == 0 {
+        return Err(ExecutorError::NotEnoughInPool);
+    }
+
+    let sum = reserve_x as u128 + reserve_y as u128;
+    let four_xy = U256::from(reserve_x) * U256::from(reserve_y) * U256::from(4u64);
+    let ann = amplification as u128 * 4;
+
+    let is_at_least_invariant = |d: u128| {
+        let lhs = U256::from(d) * U256::from(d) * U256::from(d)
+            + four_xy.clone() * U256::from(ann - 1) * U256::from(d);
+        let rhs = four_xy.clone() * U256::from(ann) * U256::from(sum);
+
+        is_at_least(&lhs, &rhs)
+    };
+
+    // NOTE: The invariant is never greater than the sum of the reserves, where `D^3 + 4xy(4A - 1)D - 16Axy(x + y)` is `(x + y)(x - y)^2`.
+    Ok(smallest_satisfying(0, sum, is_at_least_invariant))
+}
+
+/// Returns the reserve of the other token of a StableSwap pool that keeps the invariant `D` for the given reserve.
+///
+/// It is the solution of `4A(x + y) + D = 4AD + D^3 / 4xy` for `y` rounded up, which is the smallest `y` where `4xy(4A(x + y) + D) >= 16AxyD + D^3`.
+/// It is rounded up, so the pool never loses value because of rounding.
+pub(crate) fn other_reserve(reserve: u64, d: u128, amplification: u64) -> Result<u64> {
+    if reserve == 0 {
+        return Err(ExecutorError::NotEnoughInPool);
+    }
+
+    let ann = amplification as u128 * 4;
+    let d_cubed = U256::from(d) * U256::from(d) * U256::from(d);
+
+    let keeps_invariant = |y: u128| {
+        let four_xy = U256::from(reserve) * U256::from(y) * U256::from(4u64);
+
+        let lhs = four_xy.clone() * (U256::from(ann * (reserve as u128 + y)) + U256::from(d));
+        let rhs = four_xy * U256::from(ann) * U256::from(d) + d_cubed.clone();
+
+        is_at_least(&lhs, &rhs)
+    };
+
+    if !keeps_invariant(u64::MAX as u128) {
+        return Err(ExecutorError::Overflow);
+    }
+
+    Ok(smallest_satisfying(0, u64::MAX as u128, keeps_invariant) as u64)
+}
+
+/// Returns the smallest value between `low` and `high` that satisfies the given predicate, which must be satisfied by `high` and by every value after the first satisfying one.
+fn smallest_satisfying(mut low: u128, mut high: u128, predicate: impl Fn(u128) -> bool) -> u128 {
+    while low < high {
+        let middle = low + (high - low) / 2;
+
+        if predicate(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    high
+}
+
+/// Returns true if `x` is greater than or equal to `y`.
+///
+/// `U256` stores its bytes in little endian order, so they are compared from the most significant one.
+fn is_at_least(x: &U256, y: &U256) -> bool {
+    x.0.iter().rev().cmp(y.0.iter().rev()) != Ordering::Less
+}
+
+/// Returns the marginal price of token X in token Y of a StableSwap pool, which is `(16Ax^2y^2 + D^3y) / (16Ax^2y^2 + D^3x)`.
+pub(crate) fn spot_price(reserve_x: u64, reserve_y: u64, amplification: u64) -> Result<f64> {
+    let d = invariant(reserve_x, reserve_y, amplification)? as f64;
+    let (x, y) = (reserve_x as f64, reserve_y as f64);
+
+    let balanced_part = 16.0 * amplification as f64 * x * x * y * y;
+    let d_cubed = d * d * d;
+
+    Ok((balanced_part + d_cubed * y) / (balanced_part + d_cubed * x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_invariant_of_balanced_pools() {
+        let d = invariant(1_000_000, 1_000_000, 100).unwrap();
+
+        assert_eq!(d, 2_000_000);
+        assert_eq!(other_reserve(1_000_000, d, 100).unwrap(), 1_000_000);
+        assert!((spot_price(1_000_000, 1_000_000, 100).unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn has_less_slippage_than_constant_product() {
+        let d = invariant(1_000_000, 1_000_000, 100).unwrap();
+
+        let new_reserve_y = other_reserve(1_100_000, d, 100).unwrap();
+        let stable_amount_out = 1_000_000 - new_reserve_y;
+
+        let constant_product_amount_out = 1_000_000 - 1_000_000_000_000 / 1_100_000;
+
+        assert!(stable_amount_out > constant_product_amount_out);
+        assert!(stable_amount_out < 100_000);
+        assert!(stable_amount_out > 99_000);
+    }
+
+    #[test]
+    fn rounds_up_the_exact_solutions() {
+        // The expected values are the exact solutions of the invariant rounded up, computed with arbitrary precision integers.
+        let cases = [
+            (1_000_000, 1_000_000, 100, 1_100_000, 2_000_000, 900_051),
+            (
+                123_456_789,
+                987_654_321,
+                85,
+                200_000_000,
+                1_106_201_751,
+                908_426_498,
+            ),
+            (5, 1_000_000_000_000, 1, 7, 430_840_523, 845_179_279_416),
+            (
+                1_000_000_000_000_000_000,
+                300_000_000_000_000_000,
+                10_000,
+                900_000_000_000_000_000,
+                1_299_986_730_236_527_040,
+                399_992_372_488_698_345,
+            ),
+        ];
+
+        for (reserve_x, reserve_y, amplification, new_reserve_x, expected_d, expected_y) in cases {
+            let d = invariant(reserve_x, reserve_y, amplification).unwrap();
+            assert_eq!(d, expected_d);
+
+            let new_reserve_y = other_reserve(new_reserve_x, d, amplification).unwrap();
+            assert_eq!(new_reserve_y, expected_y);
+        }
+    }
+}
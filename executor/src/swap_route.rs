@@ -0,0 +1,274 @@
+use nacho_data_structures::{Balance, Pool, U256};
+
+use crate::{
+    quote::{amount_out_minus_fee, protocol_fee},
+    ExecutorError, Result,
+};
+
+pub fn swap_route(
+    path: &[U256],
+    token_in_balance: &mut Balance,
+    maybe_token_out_balance: Option<&mut Balance>,
+    pools: &mut [Pool],
+    token_amount_in: u64,
+    token_amount_out_limit: u64,
+    protocol_fee_share: u64,
+) -> Result<Option<Balance>> {
+    if path.len() < 2 || pools.len() != path.len() - 1 {
+        return Err(ExecutorError::InvalidRoute);
+    }
+
+    // A token can't appear twice, as its balance would be both paid and received.
+    for (index, token_id) in path.iter().enumerate() {
+        if path[index + 1..].contains(token_id) {
+            return Err(ExecutorError::InvalidRoute);
+        }
+    }
+
+    if token_amount_in > token_in_balance.token_amount {
+        return Err(ExecutorError::NotEnoughBalance);
+    }
+
+    let mut new_pools = pools.to_vec();
+    let mut token_amount = token_amount_in;
+
+    for (hop, pool) in new_pools.iter_mut().enumerate() {
+        token_amount = swap_in_pool(
+            pool,
+            &path[hop],
+            &path[hop + 1],
+            token_amount,
+            protocol_fee_share,
+        )?;
+    }
+
+    if token_amount < token_amount_out_limit {
+        return Err(ExecutorError::LimitExceeded);
+    }
+
+    pools.clone_from_slice(&new_pools);
+    token_in_balance.token_amount -= token_amount_in;
+
+    match maybe_token_out_balance {
+        Some(token_out_balance) => {
+            token_out_balance.token_amount += token_amount;
+
+            Ok(None)
+        }
+        None => Ok(Some(Balance {
+            owner: token_in_balance.owner.clone(),
+            token_id: path[path.len() - 1].clone(),
+            token_amount,
+        })),
+    }
+}
+
+/// Pays the given amount of tokens to the pool and returns the amount of tokens taken out of it.
+///
+/// The pool is used as a sell if its base token is the token paid, and as a buy if its quote token is.
+///
+/// The protocol's share of the fee is accrued in the pool instead of being added to its reserves.
+pub(crate) fn swap_in_pool(
+    pool: &mut Pool,
+    token_in_id: &U256,
+    token_out_id: &U256,
+    token_amount_in: u64,
+    protocol_fee_share: u64,
+) -> Result<u64> {
+    let fee_rate = pool.fee_rate;
+    let amplification = pool.amplification;
+
+    let (reserve_in, reserve_out, protocol_fee_in) =
+        if &pool.base_token_id == token_in_id && &pool.quote_token_id == token_out_id {
+            (
+                &mut pool.base_token_amount,
+                &mut pool.quote_token_amount,
+                &mut pool.protocol_fee_base_token_amount,
+            )
+        } else if &pool.quote_token_id == token_in_id && &pool.base_token_id == token_out_id {
+            (
+                &mut pool.quote_token_amount,
+                &mut pool.base_token_amount,
+                &mut pool.protocol_fee_quote_token_amount,
+            )
+        } else {
+            return Err(ExecutorError::InvalidRoute);
+        };
+
+    let (token_amount_out, fee) = amount_out_minus_fee(
+        *reserve_in,
+        *reserve_out,
+        token_amount_in,
+        fee_rate,
+        amplification,
+    )?;
+
+    let protocol_fee = protocol_fee(fee, protocol_fee_share);
+
+    let new_reserve_in = reserve_in
+        .checked_add(token_amount_in - protocol_fee)
+        .ok_or(ExecutorError::Overflow)?;
+    let new_protocol_fee_in = protocol_fee_in
+        .checked_add(protocol_fee)
+        .ok_or(ExecutorError::Overflow)?;
+
+    *reserve_in = new_reserve_in;
+    *reserve_out -= token_amount_out;
+    *protocol_fee_in = new_protocol_fee_in;
+
+    Ok(token_amount_out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::{Address, ByteConversion};
+
+    fn pools() -> [Pool; 2] {
+        [
+            Pool {
+                base_token_id: U256::from(1u64),
+                quote_token_id: U256::from(2u64),
+                base_token_amount: 1_000_000,
+                quote_token_amount: 2_000_000,
+                total_liqudity_points: U256::from(1_000_000u64),
+                fee_rate: 30,
+                protocol_fee_base_token_amount: 0,
+                protocol_fee_quote_token_amount: 0,
+                amplification: 0,
+            },
+            Pool {
+                base_token_id: U256::from(3u64),
+                quote_token_id: U256::from(2u64),
+                base_token_amount: 4_000_000,
+                quote_token_amount: 1_000_000,
+                total_liqudity_points: U256::from(1_000_000u64),
+                fee_rate: 30,
+                protocol_fee_base_token_amount: 0,
+                protocol_fee_quote_token_amount: 0,
+                amplification: 0,
+            },
+        ]
+    }
+
+    fn balance(token_id: u64) -> Balance {
+        Balance {
+            owner: Address::from_bytes(&[1u8; 55]),
+            token_id: U256::from(token_id),
+            token_amount: 1_000_000,
+        }
+    }
+
+    #[test]
+    fn swaps_through_both_directions_of_pools() {
+        let path = [U256::from(1u64), U256::from(2u64), U256::from(3u64)];
+        let mut pools = pools();
+        let mut token_in_balance = balance(1);
+
+        let token_out_balance = swap_route(
+            &path,
+            &mut token_in_balance,
+            None,
+            &mut pools,
+            10_000,
+            70_000,
+            0,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(token_in_balance.token_amount, 990_000);
+        assert_eq!(token_out_balance.token_id, U256::from(3u64));
+        assert!(token_out_balance.token_amount >= 70_000);
+        assert!(token_out_balance.token_amount < 80_000);
+
+        assert_eq!(pools[0].base_token_amount, 1_010_000);
+        assert_eq!(
+            pools[1].quote_token_amount - 1_000_000,
+            2_000_000 - pools[0].quote_token_amount
+        );
+        assert_eq!(
+            pools[1].base_token_amount,
+            4_000_000 - token_out_balance.token_amount
+        );
+    }
+
+    #[test]
+    fn does_not_modify_anything_when_limit_is_exceeded() {
+        let path = [U256::from(1u64), U256::from(2u64), U256::from(3u64)];
+        let mut pools = pools();
+        let mut token_in_balance = balance(1);
+        let mut token_out_balance = balance(3);
+
+        let err = swap_route(
+            &path,
+            &mut token_in_balance,
+            Some(&mut token_out_balance),
+            &mut pools,
+            10_000,
+            80_000,
+            0,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ExecutorError::LimitExceeded));
+        assert_eq!(pools[0], self::pools()[0]);
+        assert_eq!(pools[1], self::pools()[1]);
+        assert_eq!(token_in_balance.token_amount, 1_000_000);
+        assert_eq!(token_out_balance.token_amount, 1_000_000);
+    }
+
+    #[test]
+    fn does_not_swap_through_unrelated_pools() {
+        let path = [U256::from(1u64), U256::from(3u64), U256::from(2u64)];
+        let mut pools = pools();
+        let mut token_in_balance = balance(1);
+
+        let err =
+            swap_route(&path, &mut token_in_balance, None, &mut pools, 10_000, 0, 0).unwrap_err();
+
+        assert!(matches!(err, ExecutorError::InvalidRoute));
+    }
+
+    #[test]
+    fn accrues_protocol_fees_outside_of_reserves() {
+        let path = [U256::from(1u64), U256::from(2u64), U256::from(3u64)];
+        let mut pools_without_protocol_fee = pools();
+        let mut pools = pools();
+
+        let token_out_balance_without_protocol_fee = swap_route(
+            &path,
+            &mut balance(1),
+            None,
+            &mut pools_without_protocol_fee,
+            10_000,
+            0,
+            0,
+        )
+        .unwrap()
+        .unwrap();
+
+        let token_out_balance =
+            swap_route(&path, &mut balance(1), None, &mut pools, 10_000, 0, 5_000)
+                .unwrap()
+                .unwrap();
+
+        assert_eq!(
+            token_out_balance.token_amount,
+            token_out_balance_without_protocol_fee.token_amount
+        );
+
+        // The fee of the first hop is 30 base tokens, half of it is taken as the protocol fee.
+        assert_eq!(pools[0].protocol_fee_base_token_amount, 15);
+        assert_eq!(pools[0].protocol_fee_quote_token_amount, 0);
+        assert_eq!(
+            pools[0].base_token_amount + pools[0].protocol_fee_base_token_amount,
+            pools_without_protocol_fee[0].base_token_amount
+        );
+        assert!(pools[1].protocol_fee_quote_token_amount > 0);
+        assert_eq!(
+            pools[1].quote_token_amount + pools[1].protocol_fee_quote_token_amount,
+            pools_without_protocol_fee[1].quote_token_amount
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use nacho_data_structures::{Pool, SwapSide, TradeStats};
+
+use crate::{quote::amount_in_plus_fee, Result};
+
+/// Creates the stats of a single swap in the given pool at the given timestamp, computed from the pool's reserves before the swap.
+///
+/// The amount is the base token amount bought for buys and the quote token amount bought for sells, like in `BuyTokens` and `SellTokens` transactions.
+/// So the amounts are exactly the ones `buy_tokens` and `sell_tokens` move.
+///
+/// # Examples
+///
+/// ```rs
+/// let trade_stats = observe_trade(&pool, SwapSide::Buy, base_token_amount, timestamp)?;
+/// ```
+///
+pub fn observe_trade(
+    pool: &Pool,
+    side: SwapSide,
+    amount_out: u64,
+    timestamp: u64,
+) -> Result<TradeStats> {
+    let hour = timestamp / TradeStats::PERIOD;
+
+    match side {
+        SwapSide::Buy => {
+            let (quote_token_amount_plus_fee, fee) = amount_in_plus_fee(
+                pool.quote_token_amount,
+                pool.base_token_amount,
+                amount_out,
+                pool.fee_rate,
+                pool.amplification,
+            )?;
+
+            Ok(TradeStats {
+                hour,
+                base_token_volume: amount_out,
+                quote_token_volume: quote_token_amount_plus_fee,
+                base_token_fees: 0,
+                quote_token_fees: fee,
+                trade_count: 1,
+            })
+        }
+        SwapSide::Sell => {
+            let (base_token_amount_plus_fee, fee) = amount_in_plus_fee(
+                pool.base_token_amount,
+                pool.quote_token_amount,
+                amount_out,
+                pool.fee_rate,
+                pool.amplification,
+            )?;
+
+            Ok(TradeStats {
+                hour,
+                base_token_volume: base_token_amount_plus_fee,
+                quote_token_volume: amount_out,
+                base_token_fees: fee,
+                quote_token_fees: 0,
+                trade_count: 1,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{buy_tokens, sell_tokens};
+    use nacho_data_structures::{Address, Balance, ByteConversion, U256};
+
+    fn pool() -> Pool {
+        Pool {
+            base_token_id: U256::from(1u64),
+            quote_token_id: U256::from(0u64),
+            base_token_amount: 1_000_000,
+            quote_token_amount: 2_000_000,
+            total_liqudity_points: U256::from(1_000_000u64),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
+        }
+    }
+
+    fn balance(token_id: u64) -> Balance {
+        Balance {
+            owner: Address::from_bytes(&[1u8; 55]),
+            token_id: U256::from(token_id),
+            token_amount: 1_000_000_000,
+        }
+    }
+
+    #[test]
+    fn observes_the_amounts_swaps_move() {
+        let buy_stats = observe_trade(&pool(), SwapSide::Buy, 10_000, 7_300).unwrap();
+
+        let mut quote_token_balance = balance(0);
+
+        buy_tokens(
+            U256::from(1u64),
+            None,
+            &mut quote_token_balance,
+            &mut pool(),
+            10_000,
+            u64::MAX,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(buy_stats.hour, 2);
+        assert_eq!(buy_stats.base_token_volume, 10_000);
+        assert_eq!(
+            buy_stats.quote_token_volume,
+            1_000_000_000 - quote_token_balance.token_amount
+        );
+        assert_eq!(buy_stats.quote_token_fees, 60);
+        assert_eq!(buy_stats.trade_count, 1);
+
+        let sell_stats = observe_trade(&pool(), SwapSide::Sell, 10_000, 7_300).unwrap();
+
+        let mut base_token_balance = balance(1);
+
+        sell_tokens(
+            U256::from(0u64),
+            &mut base_token_balance,
+            None,
+            &mut pool(),
+            u64::MAX,
+            10_000,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(
+            sell_stats.base_token_volume,
+            1_000_000_000 - base_token_balance.token_amount
+        );
+        assert_eq!(sell_stats.quote_token_volume, 10_000);
+        assert!(sell_stats.base_token_fees > 0);
+        assert_eq!(sell_stats.quote_token_fees, 0);
+    }
+}
@@ -0,0 +1,102 @@
+use nacho_data_structures::{Address, Balance};
+
+use crate::{ExecutorError, Result};
+
+pub fn transfer_tokens(
+    balance: &mut Balance,
+    maybe_receiver_balance: Option<&mut Balance>,
+    receiver_address: &Address,
+    amount_to_transfer: u64,
+) -> Result<Option<Balance>> {
+    if amount_to_transfer > balance.token_amount {
+        return Err(ExecutorError::NotEnoughBalance);
+    }
+
+    match maybe_receiver_balance {
+        Some(receiver_balance) => {
+            receiver_balance.token_amount = receiver_balance
+                .token_amount
+                .checked_add(amount_to_transfer)
+                .ok_or(ExecutorError::Overflow)?;
+
+            balance.token_amount -= amount_to_transfer;
+
+            Ok(None)
+        }
+        None => {
+            balance.token_amount -= amount_to_transfer;
+
+            Ok(Some(Balance {
+                owner: receiver_address.clone(),
+                token_id: balance.token_id.clone(),
+                token_amount: amount_to_transfer,
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::{ByteConversion, U256};
+
+    fn balance(owner: u8, token_amount: u64) -> Balance {
+        Balance {
+            owner: Address::from_bytes(&[owner; 55]),
+            token_id: U256::from(1u64),
+            token_amount,
+        }
+    }
+
+    #[test]
+    fn transfers_to_existing_and_new_balances() {
+        let mut sender_balance = balance(1, 1_000);
+        let mut receiver_balance = balance(2, 500);
+        let receiver_address = receiver_balance.owner.clone();
+
+        let result = transfer_tokens(
+            &mut sender_balance,
+            Some(&mut receiver_balance),
+            &receiver_address,
+            300,
+        )
+        .unwrap();
+
+        assert!(result.is_none());
+        assert_eq!(sender_balance.token_amount, 700);
+        assert_eq!(receiver_balance.token_amount, 800);
+
+        let new_receiver_address = Address::from_bytes(&[3u8; 55]);
+
+        let new_balance = transfer_tokens(&mut sender_balance, None, &new_receiver_address, 700)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(sender_balance.token_amount, 0);
+        assert_eq!(new_balance.owner, new_receiver_address);
+        assert_eq!(new_balance.token_id, U256::from(1u64));
+        assert_eq!(new_balance.token_amount, 700);
+    }
+
+    #[test]
+    fn does_not_modify_anything_when_transfer_fails() {
+        let mut sender_balance = balance(1, 1_000);
+        let mut receiver_balance = balance(2, u64::MAX);
+        let receiver_address = receiver_balance.owner.clone();
+
+        let err = transfer_tokens(&mut sender_balance, None, &receiver_address, 1_001).unwrap_err();
+        assert!(matches!(err, ExecutorError::NotEnoughBalance));
+
+        let err = transfer_tokens(
+            &mut sender_balance,
+            Some(&mut receiver_balance),
+            &receiver_address,
+            1,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ExecutorError::Overflow));
+
+        assert_eq!(sender_balance.token_amount, 1_000);
+        assert_eq!(receiver_balance.token_amount, u64::MAX);
+    }
+}
@@ -0,0 +1,160 @@
+use nacho_data_structures::{Observation, Pool};
+
+use crate::{position::points_per_liquidity, quote::spot_price_of, ExecutorError, Result};
+
+/// The scale of the 64.64 fixed point numbers of observations, which is `2^64`.
+pub(crate) const FIXED_POINT_SCALE: f64 = 18_446_744_073_709_551_616.0;
+
+/// Creates the observation of the given pool at the given timestamp, which accumulates the price of the last observation until the timestamp.
+///
+/// The timestamp is never taken earlier than the last observation's, so a clock that goes back doesn't break the accumulator.
+///
+/// # Examples
+///
+/// ```rs
+/// let observation = observe_pool(&pool, maybe_last_observation.as_ref(), timestamp)?;
+/// ```
+///
+pub fn observe_pool(
+    pool: &Pool,
+    maybe_last_observation: Option<&Observation>,
+    timestamp: u64,
+) -> Result<Observation> {
+    // NOTE: Float to integer casts saturate, so extreme prices are clamped instead of wrapped.
+    let price = (spot_price_of(pool)? * FIXED_POINT_SCALE) as u128;
+    let points_per_liquidity = points_per_liquidity(pool)?;
+
+    let observation = match maybe_last_observation {
+        Some(last_observation) => {
+            let timestamp = timestamp.max(last_observation.timestamp);
+
+            Observation {
+                timestamp,
+                price_cumulative: cumulative_at(last_observation, timestamp),
+                price,
+                points_per_liquidity,
+            }
+        }
+        None => Observation {
+            timestamp,
+            price_cumulative: 0,
+            price,
+            points_per_liquidity,
+        },
+    };
+
+    Ok(observation)
+}
+
+/// Returns the time-weighted average price of a pool over the `window` seconds until the given timestamp, as a 64.64 fixed point number.
+///
+/// The observations must be the pool's observations ordered from the oldest to the latest.
+///
+/// Fails with `NotEnoughPriceHistory` if the observations don't go back to the start of the window.
+///
+/// # Examples
+///
+/// ```rs
+/// let price = time_weighted_average_price(&observations, 3600, timestamp)?;
+/// ```
+///
+pub fn time_weighted_average_price(
+    observations: &[Observation],
+    window: u64,
+    timestamp: u64,
+) -> Result<u128> {
+    let last_observation = observations
+        .last()
+        .ok_or(ExecutorError::NotEnoughPriceHistory)?;
+
+    if window == 0 {
+        return Ok(last_observation.price);
+    }
+
+    let end = timestamp.max(last_observation.timestamp);
+    let start = end
+        .checked_sub(window)
+        .ok_or(ExecutorError::NotEnoughPriceHistory)?;
+
+    let start_observation = observations
+        .iter()
+        .rev()
+        .find(|observation| observation.timestamp <= start)
+        .ok_or(ExecutorError::NotEnoughPriceHistory)?;
+
+    // NOTE: The accumulators are allowed to overflow, their difference is still correct with wrapping arithmetic.
+    let price_cumulative_delta =
+        cumulative_at(last_observation, end).wrapping_sub(cumulative_at(start_observation, start));
+
+    Ok(price_cumulative_delta / window as u128)
+}
+
+/// Returns the price accumulator of the given observation carried forward to the given timestamp, which must not be earlier than the observation's.
+fn cumulative_at(observation: &Observation, timestamp: u64) -> u128 {
+    let elapsed = (timestamp - observation.timestamp) as u128;
+
+    observation
+        .price_cumulative
+        .wrapping_add(observation.price.wrapping_mul(elapsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::U256;
+
+    fn pool(base_token_amount: u64, quote_token_amount: u64) -> Pool {
+        Pool {
+            base_token_id: U256::from(1u64),
+            quote_token_id: U256::from(0u64),
+            base_token_amount,
+            quote_token_amount,
+            total_liqudity_points: U256::from(1_000_000u64),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
+        }
+    }
+
+    #[test]
+    fn weights_prices_by_the_time_they_last() {
+        let observation_0 = observe_pool(&pool(1_000, 2_000), None, 100).unwrap();
+        let observation_1 = observe_pool(&pool(1_000, 4_000), Some(&observation_0), 130).unwrap();
+        let observations = [observation_0, observation_1];
+
+        // The price is 2 for 30 seconds, then 4 for 10 seconds.
+        let price = time_weighted_average_price(&observations, 40, 140).unwrap();
+        assert_eq!(price, 5 << 63);
+
+        // The price is 2 for the last 10 seconds of the first observation, then 4 for 20 seconds.
+        let price = time_weighted_average_price(&observations, 30, 150).unwrap();
+        assert_eq!(price, (100 << 64) / 30);
+
+        let price = time_weighted_average_price(&observations, 0, 150).unwrap();
+        assert_eq!(price, 4 << 64);
+    }
+
+    #[test]
+    fn doesnt_compute_prices_without_enough_history() {
+        let observation = observe_pool(&pool(1_000, 2_000), None, 100).unwrap();
+
+        let err = time_weighted_average_price(&[], 10, 150).unwrap_err();
+        assert!(matches!(err, ExecutorError::NotEnoughPriceHistory));
+
+        let err = time_weighted_average_price(&[observation.clone()], 51, 150).unwrap_err();
+        assert!(matches!(err, ExecutorError::NotEnoughPriceHistory));
+
+        let price = time_weighted_average_price(&[observation], 50, 150).unwrap();
+        assert_eq!(price, 2 << 64);
+    }
+
+    #[test]
+    fn doesnt_go_back_in_time() {
+        let observation_0 = observe_pool(&pool(1_000, 2_000), None, 100).unwrap();
+        let observation_1 = observe_pool(&pool(1_000, 4_000), Some(&observation_0), 90).unwrap();
+
+        assert_eq!(observation_1.timestamp, 100);
+        assert_eq!(observation_1.price_cumulative, 0);
+    }
+}
@@ -0,0 +1,26 @@
+/// The data structure that represents the result of checking a state database's Merkle tree against its list.
+///
+/// - The `mismatched_leaves` property holds the indexes of the leaves that aren't the hashes of their records.
+/// - The `invalid_nodes` property holds the heights and indexes of the internal nodes that aren't the hashes of their children.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DbReport {
+    pub record_count: u64,
+    pub leaf_count: u64,
+    pub mismatched_leaves: Vec<u64>,
+    pub invalid_nodes: Vec<(usize, u64)>,
+}
+
+impl DbReport {
+    /// Returns whether the Merkle tree matches the list.
+    ///
+    /// The lists are ahead of the trees until the pending transactions are proved,
+    /// so if `is_proved` is false only the internal nodes and the leaves without records are checked.
+    pub fn is_consistent(&self, is_proved: bool) -> bool {
+        if !self.invalid_nodes.is_empty() || self.leaf_count > self.record_count {
+            return false;
+        }
+
+        !is_proved || (self.mismatched_leaves.is_empty() && self.leaf_count == self.record_count)
+    }
+}
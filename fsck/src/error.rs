@@ -0,0 +1,15 @@
+use nacho_dynamic_list::DynamicListError;
+use nacho_dynamic_merkle_tree::DynamicMerkleTreeError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FsckError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    DynamicList(#[from] DynamicListError),
+    #[error(transparent)]
+    MerkleTree(#[from] DynamicMerkleTreeError),
+    #[error("Database doesn't exist.")]
+    DbDoesntExist,
+}
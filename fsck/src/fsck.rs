@@ -0,0 +1,206 @@
+use crate::{DbReport, FsckError};
+use nacho_data_structures::{ByteConversion, Field, FieldConversion};
+use nacho_dynamic_list::DynamicList;
+use nacho_dynamic_merkle_tree::DynamicMerkleTree;
+use nacho_poseidon_hash::{create_poseidon_hasher, poseidon_hash};
+use std::{collections::HashMap, path::Path};
+use tokio::fs::try_exists;
+
+type Result<T> = std::result::Result<T, FsckError>;
+
+/// Checks the Merkle tree of the state database at the given path against its list.
+///
+/// - The type parameter `T` is the type of the records, which are hashed the same way the database hashes its leaves.
+/// - The constant generic parameters `C` and `F` are the byte and field lengths of the records.
+/// - The constant generic parameters `H` and `L` are the height and the sibling count of the Merkle tree.
+///
+/// # Examples
+///
+/// Check Balances DB:
+///
+/// ```rs
+/// let report = check_db::<Balance, BALANCE_SIZE_IN_BYTES, 4, BALANCES_TREE_HEIGHT, BALANCES_TREE_SIBLING_COUNT>(path).await?;
+/// ```
+///
+pub async fn check_db<T, const C: usize, const F: usize, const H: usize, const L: usize>(
+    path: impl AsRef<Path>,
+) -> Result<DbReport>
+where
+    T: ByteConversion<C> + FieldConversion<F>,
+{
+    let (mut list, mut tree) = open_db::<C, H, L>(path.as_ref()).await?;
+
+    let record_hashes = hash_records::<T, C, F>(&mut list).await?;
+    let leaf_count = tree.get_leaf_count().await?;
+
+    let mut mismatched_leaves = Vec::new();
+
+    for index in 0..leaf_count {
+        let leaf = tree.get_leaf(index).await?;
+
+        if record_hashes.get(index as usize) != Some(&leaf) {
+            mismatched_leaves.push(index);
+        }
+    }
+
+    let invalid_nodes = tree.find_invalid_nodes().await?;
+
+    Ok(DbReport {
+        record_count: record_hashes.len() as u64,
+        leaf_count,
+        mismatched_leaves,
+        invalid_nodes,
+    })
+}
+
+/// Rebuilds the Merkle tree of the state database at the given path from the records in its list.
+///
+/// The tree must not be behind the list, which is the case when all executed transactions are proved.
+pub async fn repair_db<T, const C: usize, const F: usize, const H: usize, const L: usize>(
+    path: impl AsRef<Path>,
+) -> Result<()>
+where
+    T: ByteConversion<C> + FieldConversion<F>,
+{
+    let (mut list, mut tree) = open_db::<C, H, L>(path.as_ref()).await?;
+
+    let record_hashes = hash_records::<T, C, F>(&mut list).await?;
+
+    tree.rebuild(&record_hashes).await?;
+
+    Ok(())
+}
+
+async fn open_db<const C: usize, const H: usize, const L: usize>(
+    path: &Path,
+) -> Result<(DynamicList<C>, DynamicMerkleTree<H, L>)> {
+    if !try_exists(path).await? {
+        return Err(FsckError::DbDoesntExist);
+    }
+
+    let list = DynamicList::new(path.join("dynamic_list")).await?;
+    let tree = DynamicMerkleTree::new(path.join("dynamic_merkle_tree")).await?;
+
+    Ok((list, tree))
+}
+
+/// Returns the leaf hashes of the records in the list in their order.
+async fn hash_records<T, const C: usize, const F: usize>(
+    list: &mut DynamicList<C>,
+) -> Result<Vec<Field>>
+where
+    T: ByteConversion<C> + FieldConversion<F>,
+{
+    let mut record_hashes = HashMap::<u64, Field>::new();
+
+    list.for_each(&mut record_hashes, |buf, index, record_hashes| {
+        let fields = T::from_bytes(&buf).to_fields();
+
+        // The closure can't borrow a hasher mutably, creating one is cheap as its parameters are static.
+        let hash = poseidon_hash(&mut create_poseidon_hasher(), &fields);
+
+        record_hashes.insert(index, hash);
+
+        Ok::<(), FsckError>(())
+    })
+    .await?;
+
+    Ok((0..record_hashes.len() as u64)
+        .map(|index| record_hashes[&index])
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_balances_db::{
+        BalancesDb, BALANCES_TREE_HEIGHT, BALANCES_TREE_SIBLING_COUNT, BALANCE_SIZE_IN_BYTES,
+    };
+    use nacho_data_structures::Balance;
+    use tokio::fs::remove_dir_all;
+
+    async fn check_balances_db(dir: &str) -> DbReport {
+        check_db::<
+            Balance,
+            BALANCE_SIZE_IN_BYTES,
+            4,
+            BALANCES_TREE_HEIGHT,
+            BALANCES_TREE_SIBLING_COUNT,
+        >(dir)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn finds_and_repairs_mismatches() {
+        let dir = "/tmp/nacho/tests/fsck/finds_and_repairs_mismatches";
+
+        let balances = [1, 2, 3].map(|n| Balance::from_bytes(&[n; 95]));
+
+        let mut balances_db = BalancesDb::new(dir).await.unwrap();
+
+        for balance in &balances {
+            balances_db.push(balance).await.unwrap();
+            balances_db.push_leaf(balance).await.unwrap();
+        }
+
+        let root = balances_db.get_root().await.unwrap();
+
+        let report = check_balances_db(dir).await;
+
+        assert_eq!(report.record_count, 3);
+        assert_eq!(report.leaf_count, 3);
+        assert!(report.is_consistent(true));
+
+        let mut updated_balance = balances[1].clone();
+        updated_balance.token_amount += 1;
+
+        balances_db.update(&updated_balance).await.unwrap();
+
+        let pushed_balance = Balance::from_bytes(&[4; 95]);
+
+        balances_db.push(&pushed_balance).await.unwrap();
+
+        let report = check_balances_db(dir).await;
+
+        assert_eq!(report.record_count, 4);
+        assert_eq!(report.leaf_count, 3);
+        assert_eq!(report.mismatched_leaves, vec![1]);
+        assert_eq!(report.invalid_nodes, vec![]);
+        assert!(report.is_consistent(false));
+        assert!(!report.is_consistent(true));
+
+        drop(balances_db);
+
+        repair_db::<
+            Balance,
+            BALANCE_SIZE_IN_BYTES,
+            4,
+            BALANCES_TREE_HEIGHT,
+            BALANCES_TREE_SIBLING_COUNT,
+        >(dir)
+        .await
+        .unwrap();
+
+        let report = check_balances_db(dir).await;
+
+        assert_eq!(report.leaf_count, 4);
+        assert!(report.is_consistent(true));
+
+        let mut balances_db = BalancesDb::new(dir).await.unwrap();
+
+        assert_ne!(balances_db.get_root().await.unwrap(), root);
+
+        balances_db.update_leaf(&balances[1]).await.unwrap();
+        balances_db.pop().await.unwrap();
+
+        let report = check_balances_db(dir).await;
+
+        assert_eq!(report.record_count, 3);
+        assert_eq!(report.leaf_count, 4);
+        assert_eq!(report.mismatched_leaves, vec![1, 3]);
+        assert!(!report.is_consistent(false));
+
+        remove_dir_all(dir).await.unwrap();
+    }
+}
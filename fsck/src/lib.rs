@@ -0,0 +1,7 @@
+mod db_report;
+mod error;
+mod fsck;
+
+pub use db_report::DbReport;
+pub use error::FsckError;
+pub use fsck::{check_db, repair_db};
@@ -0,0 +1,179 @@
+use nacho_balances_db::{BALANCES_TREE_HEIGHT, BALANCES_TREE_SIBLING_COUNT, BALANCE_SIZE_IN_BYTES};
+use nacho_burns_db::{BURNS_TREE_HEIGHT, BURNS_TREE_SIBLING_COUNT, BURN_SIZE_IN_BYTES};
+use nacho_data_structures::{
+    Balance, Burn, ByteConversion, FieldConversion, Liquidity, Order, Pool,
+};
+use nacho_fsck::{check_db, repair_db, DbReport, FsckError};
+use nacho_liquidities_db::{
+    LIQUIDITIES_TREE_HEIGHT, LIQUIDITIES_TREE_SIBLING_COUNT, LIQUIDITY_SIZE_IN_BYTES,
+};
+use nacho_orders_db::{ORDERS_TREE_HEIGHT, ORDERS_TREE_SIBLING_COUNT, ORDER_SIZE_IN_BYTES};
+use nacho_pools_db::{POOLS_TREE_HEIGHT, POOLS_TREE_SIBLING_COUNT, POOL_SIZE_IN_BYTES};
+use nacho_transactions_db::TransactionsDb;
+use std::process::ExitCode;
+
+/// Checks the Merkle trees of the state databases against their lists, the node must be stopped meanwhile.
+///
+/// If `--repair` is given, the trees that don't match their lists are rebuilt from the lists.
+/// Trees are only repaired when all executed transactions are proved, otherwise the lists are ahead of the trees.
+///
+/// ```sh
+/// nacho-fsck [--repair]
+/// ```
+#[tokio::main]
+async fn main() -> ExitCode {
+    let should_repair = std::env::args().any(|arg| arg == "--repair");
+
+    let mut transactions_db = TransactionsDb::new(env("NACHO_TRANSACTIONS_DB_PATH"))
+        .await
+        .unwrap();
+
+    let executed_until = transactions_db.get_executed_until().await.unwrap();
+    let proved_until = transactions_db.get_proved_until().await.unwrap();
+
+    let is_proved = executed_until == proved_until;
+
+    if !is_proved {
+        println!(
+            "{} executed transactions aren't proved yet, only the nodes and the leaf counts are checked.",
+            executed_until - proved_until
+        );
+    }
+
+    let results =
+        [
+            fsck::<
+                Balance,
+                BALANCE_SIZE_IN_BYTES,
+                4,
+                BALANCES_TREE_HEIGHT,
+                BALANCES_TREE_SIBLING_COUNT,
+            >(
+                "Balances DB",
+                env("NACHO_BALANCES_DB_PATH"),
+                is_proved,
+                should_repair,
+            )
+            .await,
+            fsck::<
+                Liquidity,
+                LIQUIDITY_SIZE_IN_BYTES,
+                5,
+                LIQUIDITIES_TREE_HEIGHT,
+                LIQUIDITIES_TREE_SIBLING_COUNT,
+            >(
+                "Liquidities DB",
+                env("NACHO_LIQUIDITIES_DB_PATH"),
+                is_proved,
+                should_repair,
+            )
+            .await,
+            fsck::<Pool, POOL_SIZE_IN_BYTES, 9, POOLS_TREE_HEIGHT, POOLS_TREE_SIBLING_COUNT>(
+                "Pools DB",
+                env("NACHO_POOLS_DB_PATH"),
+                is_proved,
+                should_repair,
+            )
+            .await,
+            fsck::<Burn, BURN_SIZE_IN_BYTES, 4, BURNS_TREE_HEIGHT, BURNS_TREE_SIBLING_COUNT>(
+                "Burns DB",
+                env("NACHO_BURNS_DB_PATH"),
+                is_proved,
+                should_repair,
+            )
+            .await,
+            fsck::<Order, ORDER_SIZE_IN_BYTES, 8, ORDERS_TREE_HEIGHT, ORDERS_TREE_SIBLING_COUNT>(
+                "Orders DB",
+                env("NACHO_ORDERS_DB_PATH"),
+                is_proved,
+                should_repair,
+            )
+            .await,
+        ];
+
+    let mut is_consistent = true;
+
+    for result in results {
+        match result {
+            Ok(is_db_consistent) => is_consistent &= is_db_consistent,
+            Err(error) => {
+                eprintln!("{error}");
+                is_consistent = false;
+            }
+        }
+    }
+
+    if is_consistent {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Checks the given state database, prints its report and repairs it if it is asked and possible.
+///
+/// It returns whether the database is consistent afterwards.
+async fn fsck<T, const C: usize, const F: usize, const H: usize, const L: usize>(
+    name: &str,
+    path: String,
+    is_proved: bool,
+    should_repair: bool,
+) -> Result<bool, FsckError>
+where
+    T: ByteConversion<C> + FieldConversion<F>,
+{
+    let report = check_db::<T, C, F, H, L>(&path).await?;
+
+    print_report(name, &report, is_proved);
+
+    if report.is_consistent(is_proved) {
+        return Ok(true);
+    }
+
+    if !should_repair {
+        return Ok(false);
+    }
+
+    if !is_proved {
+        println!(
+            "{name} isn't repaired, start the node until all executed transactions are proved."
+        );
+        return Ok(false);
+    }
+
+    repair_db::<T, C, F, H, L>(&path).await?;
+
+    let report = check_db::<T, C, F, H, L>(&path).await?;
+
+    println!("{name} is repaired.");
+    print_report(name, &report, is_proved);
+
+    Ok(report.is_consistent(is_proved))
+}
+
+fn print_report(name: &str, report: &DbReport, is_proved: bool) {
+    println!(
+        "{name}: {} records, {} leaves, {}.",
+        report.record_count,
+        report.leaf_count,
+        if report.is_consistent(is_proved) {
+            "consistent"
+        } else {
+            "inconsistent"
+        }
+    );
+
+    if is_proved {
+        for index in &report.mismatched_leaves {
+            println!("{name}: leaf {index} isn't the hash of its record.");
+        }
+    }
+
+    for (height, index) in &report.invalid_nodes {
+        println!("{name}: node {index} at height {height} isn't the hash of its children.");
+    }
+}
+
+fn env(key: &str) -> String {
+    std::env::var(key).unwrap()
+}
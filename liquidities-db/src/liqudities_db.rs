@@ -102,6 +102,28 @@ impl LiquiditiesDb {
         Ok(())
     }
 
+    /// Removes the last pushed liquidity and returns it, the liquidity's leaf must not be pushed yet.
+    ///
+    /// It is used to undo the push of a transaction that couldn't be committed.
+    pub async fn pop(&mut self) -> Result<Liquidity> {
+        let buf = self.list.pop().await?;
+
+        let liquidity = Liquidity::from_bytes(&buf);
+
+        if let Some(indexes) = self.indexes.get_mut(&liquidity.provider) {
+            indexes.retain(|(_, base_token_id, quote_token_id)| {
+                base_token_id != &liquidity.base_token_id
+                    || quote_token_id != &liquidity.quote_token_id
+            });
+
+            if indexes.is_empty() {
+                self.indexes.remove(&liquidity.provider);
+            }
+        }
+
+        Ok(liquidity)
+    }
+
     pub async fn push_leaf(&mut self, liquidity: &Liquidity) -> Result<()> {
         let fields = liquidity.to_fields();
 
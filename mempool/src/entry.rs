@@ -0,0 +1,29 @@
+use nacho_data_structures::{ByteConversion, Transaction};
+
+/// The data structure that represents a transaction waiting in the mempool.
+///
+/// - The `tx_id` property represents the ID the transaction is stored with in Transactions DB.
+/// - The `transaction` property represents the transaction itself.
+///
+pub(crate) struct MempoolEntry {
+    pub tx_id: u64,
+    pub transaction: Transaction,
+}
+
+impl ByteConversion<248> for MempoolEntry {
+    fn to_bytes(&self) -> [u8; 248] {
+        let mut bytes = [0u8; 248];
+
+        bytes[0..8].copy_from_slice(&self.tx_id.to_bytes());
+        bytes[8..248].copy_from_slice(&self.transaction.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 248]) -> Self {
+        Self {
+            tx_id: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            transaction: Transaction::from_bytes(bytes[8..248].try_into().unwrap()),
+        }
+    }
+}
@@ -3,10 +3,10 @@ use std::path::Path;
 use nacho_data_structures::Transaction;
 use nacho_dynamic_queue::DynamicQueue;
 
-use crate::MempoolError;
+use crate::{entry::MempoolEntry, MempoolError};
 
 pub struct Mempool {
-    queue: DynamicQueue<232, Transaction>,
+    queue: DynamicQueue<248, MempoolEntry>,
 }
 
 type Result<T> = std::result::Result<T, MempoolError>;
@@ -20,15 +20,29 @@ impl Mempool {
         Ok(Self { queue })
     }
 
-    pub async fn push(&mut self, transaction: &Transaction) -> Result<()> {
-        self.queue.push(transaction).await?;
+    /// Pushes the given transaction with the ID it is stored with in Transactions DB.
+    pub async fn push(&mut self, tx_id: u64, transaction: &Transaction) -> Result<()> {
+        let entry = MempoolEntry {
+            tx_id,
+            transaction: transaction.clone(),
+        };
+
+        self.queue.push(&entry).await?;
 
         Ok(())
     }
 
-    pub async fn pop(&mut self) -> Result<Option<Transaction>> {
-        let maybe_transaction = self.queue.pop().await?;
+    /// Returns the oldest transaction with its ID without popping it.
+    pub async fn peek(&mut self) -> Result<Option<(u64, Transaction)>> {
+        let maybe_entry = self.queue.peek().await?;
+
+        Ok(maybe_entry.map(|entry| (entry.tx_id, entry.transaction)))
+    }
+
+    /// Pops the oldest transaction and returns it with its ID.
+    pub async fn pop(&mut self) -> Result<Option<(u64, Transaction)>> {
+        let maybe_entry = self.queue.pop().await?;
 
-        Ok(maybe_transaction)
+        Ok(maybe_entry.map(|entry| (entry.tx_id, entry.transaction)))
     }
 }
@@ -0,0 +1,4 @@
+pub const OBSERVATIONS_PER_PAIR: u64 = 1024;
+pub const OBSERVATION_INTERVAL_IN_SECONDS: u64 = 60;
+pub const OBSERVATION_SIZE_IN_BYTES: usize = 56;
+pub const PAIR_SIZE_IN_BYTES: usize = 80;
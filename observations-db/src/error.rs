@@ -0,0 +1,10 @@
+use nacho_dynamic_list::DynamicListError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ObservationsDbError {
+    #[error(transparent)]
+    DynamicList(#[from] DynamicListError),
+    #[error("Pair doesn't exist.")]
+    PairDoesntExist,
+}
@@ -0,0 +1,11 @@
+mod constants;
+
+mod error;
+mod observations_db;
+
+pub use constants::{
+    OBSERVATIONS_PER_PAIR, OBSERVATION_INTERVAL_IN_SECONDS, OBSERVATION_SIZE_IN_BYTES,
+    PAIR_SIZE_IN_BYTES,
+};
+pub use error::ObservationsDbError;
+pub use observations_db::ObservationsDb;
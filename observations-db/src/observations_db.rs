@@ -0,0 +1,335 @@
+use crate::{
+    ObservationsDbError, OBSERVATIONS_PER_PAIR, OBSERVATION_INTERVAL_IN_SECONDS,
+    OBSERVATION_SIZE_IN_BYTES, PAIR_SIZE_IN_BYTES,
+};
+use nacho_data_structures::{ByteConversion, Observation, U256};
+use nacho_dynamic_list::DynamicList;
+use std::{collections::HashMap, path::Path};
+
+type Result<T> = std::result::Result<T, ObservationsDbError>;
+
+/// The ring buffer of the observations of a single pair.
+///
+/// - The `index` property represents the index of the pair in the pairs list.
+/// - The `first_slot` property represents the index of the pair's first observation slot in the observations list.
+/// - The `count` property represents the total count of the observations ever pushed for the pair.
+///
+#[derive(Clone)]
+struct Ring {
+    index: u64,
+    first_slot: u64,
+    count: u64,
+}
+
+/// The database that stores the last `OBSERVATIONS_PER_PAIR` price observations of each AMM pool.
+///
+/// Each pair owns a fixed range of slots in the observations list and the oldest observation is overwritten once its range is full.
+///
+/// A pair keeps at most one observation per `OBSERVATION_INTERVAL_IN_SECONDS`, so a burst of trades can't push its history out.
+pub struct ObservationsDb {
+    pairs: DynamicList<PAIR_SIZE_IN_BYTES>,
+    list: DynamicList<OBSERVATION_SIZE_IN_BYTES>,
+    rings: HashMap<(U256, U256), Ring>,
+}
+
+impl ObservationsDb {
+    pub async fn new(path: impl AsRef<Path>) -> Result<ObservationsDb> {
+        let path = path.as_ref();
+
+        let mut pairs = DynamicList::new(path.join("pairs")).await?;
+        let list = DynamicList::new(path.join("dynamic_list")).await?;
+        let mut rings = HashMap::<(U256, U256), Ring>::new();
+
+        pairs
+            .for_each(&mut rings, |buf, index, rings| {
+                let (base_token_id, quote_token_id, first_slot, count) = pair_from_bytes(&buf);
+
+                rings.insert(
+                    (base_token_id, quote_token_id),
+                    Ring {
+                        index,
+                        first_slot,
+                        count,
+                    },
+                );
+
+                Ok::<(), ObservationsDbError>(())
+            })
+            .await?;
+
+        Ok(ObservationsDb { pairs, list, rings })
+    }
+
+    /// Pushes the given observation of the pair, or replaces the latest one if both are made in the same interval.
+    ///
+    /// The replacing observation accumulates the price of the latest one, so only the price changes inside the interval are lost.
+    pub async fn push(
+        &mut self,
+        base_token_id: &U256,
+        quote_token_id: &U256,
+        observation: &Observation,
+    ) -> Result<()> {
+        let key = (base_token_id.clone(), quote_token_id.clone());
+
+        let mut ring = match self.rings.get(&key) {
+            Some(ring) => ring.clone(),
+            None => {
+                let first_slot = self.list.push([0; OBSERVATION_SIZE_IN_BYTES]).await?;
+
+                for _ in 1..OBSERVATIONS_PER_PAIR {
+                    self.list.push([0; OBSERVATION_SIZE_IN_BYTES]).await?;
+                }
+
+                let index = self
+                    .pairs
+                    .push(pair_to_bytes(base_token_id, quote_token_id, first_slot, 0))
+                    .await?;
+
+                Ring {
+                    index,
+                    first_slot,
+                    count: 0,
+                }
+            }
+        };
+
+        let is_same_interval = match ring.count {
+            0 => false,
+            count => {
+                let buf = self.list.get(slot_of(&ring, count - 1)).await?;
+
+                Observation::from_bytes(&buf).timestamp / OBSERVATION_INTERVAL_IN_SECONDS
+                    == observation.timestamp / OBSERVATION_INTERVAL_IN_SECONDS
+            }
+        };
+
+        if is_same_interval {
+            self.list
+                .set(slot_of(&ring, ring.count - 1), observation.to_bytes())
+                .await?;
+        } else {
+            self.list
+                .set(slot_of(&ring, ring.count), observation.to_bytes())
+                .await?;
+
+            ring.count += 1;
+
+            self.pairs
+                .set(
+                    ring.index,
+                    pair_to_bytes(base_token_id, quote_token_id, ring.first_slot, ring.count),
+                )
+                .await?;
+        }
+
+        self.rings.insert(key, ring);
+
+        Ok(())
+    }
+
+    pub async fn get_latest(
+        &mut self,
+        base_token_id: &U256,
+        quote_token_id: &U256,
+    ) -> Result<Observation> {
+        let ring = self
+            .rings
+            .get(&(base_token_id.clone(), quote_token_id.clone()))
+            .ok_or(ObservationsDbError::PairDoesntExist)?;
+
+        if ring.count == 0 {
+            return Err(ObservationsDbError::PairDoesntExist);
+        }
+
+        let buf = self.list.get(slot_of(ring, ring.count - 1)).await?;
+
+        let observation = Observation::from_bytes(&buf);
+
+        Ok(observation)
+    }
+
+    /// Returns the stored observations of the pair from the oldest to the latest.
+    pub async fn get_many(
+        &mut self,
+        base_token_id: &U256,
+        quote_token_id: &U256,
+    ) -> Result<Vec<Observation>> {
+        let ring = self
+            .rings
+            .get(&(base_token_id.clone(), quote_token_id.clone()))
+            .ok_or(ObservationsDbError::PairDoesntExist)?
+            .clone();
+
+        let start = ring.count.saturating_sub(OBSERVATIONS_PER_PAIR);
+
+        let mut observations = Vec::with_capacity((ring.count - start) as usize);
+
+        for i in start..ring.count {
+            let buf = self.list.get(slot_of(&ring, i)).await?;
+
+            let observation = Observation::from_bytes(&buf);
+
+            observations.push(observation)
+        }
+
+        Ok(observations)
+    }
+}
+
+/// Returns the index of the slot in the observations list that stores the pair's observation with the given sequence number.
+fn slot_of(ring: &Ring, sequence: u64) -> u64 {
+    ring.first_slot + sequence % OBSERVATIONS_PER_PAIR
+}
+
+fn pair_to_bytes(
+    base_token_id: &U256,
+    quote_token_id: &U256,
+    first_slot: u64,
+    count: u64,
+) -> [u8; PAIR_SIZE_IN_BYTES] {
+    let mut bytes = [0u8; PAIR_SIZE_IN_BYTES];
+
+    bytes[0..32].copy_from_slice(&base_token_id.to_bytes());
+    bytes[32..64].copy_from_slice(&quote_token_id.to_bytes());
+    bytes[64..72].copy_from_slice(&first_slot.to_bytes());
+    bytes[72..80].copy_from_slice(&count.to_bytes());
+
+    bytes
+}
+
+fn pair_from_bytes(bytes: &[u8; PAIR_SIZE_IN_BYTES]) -> (U256, U256, u64, u64) {
+    (
+        U256::from_bytes(bytes[0..32].try_into().unwrap()),
+        U256::from_bytes(bytes[32..64].try_into().unwrap()),
+        u64::from_bytes(bytes[64..72].try_into().unwrap()),
+        u64::from_bytes(bytes[72..80].try_into().unwrap()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::fs::remove_dir_all;
+
+    use super::*;
+
+    fn observation(timestamp: u64) -> Observation {
+        Observation {
+            timestamp,
+            price_cumulative: timestamp as u128 * 3,
+            price: 3,
+            points_per_liquidity: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn creates_observations_db() {
+        let dir = "/tmp/nacho/tests/observations_db/creates_observations_db";
+
+        let mut observations_db = ObservationsDb::new(dir).await.unwrap();
+
+        let err = observations_db
+            .get_many(&U256([0; 32]), &U256([1; 32]))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ObservationsDbError::PairDoesntExist));
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn pushes_and_gets_observations_correctly() {
+        let dir = "/tmp/nacho/tests/observations_db/pushes_and_gets_observations_correctly";
+
+        let mut observations_db = ObservationsDb::new(dir).await.unwrap();
+
+        let (base_token_id, quote_token_id) = (U256([0; 32]), U256([1; 32]));
+
+        observations_db
+            .push(&base_token_id, &quote_token_id, &observation(1))
+            .await
+            .unwrap();
+        observations_db
+            .push(&base_token_id, &quote_token_id, &observation(60))
+            .await
+            .unwrap();
+
+        // It is made in the same interval as the previous observation, so it replaces it.
+        let mut replacement = observation(119);
+        replacement.price = 4;
+
+        observations_db
+            .push(&base_token_id, &quote_token_id, &replacement)
+            .await
+            .unwrap();
+
+        observations_db
+            .push(&quote_token_id, &base_token_id, &observation(5))
+            .await
+            .unwrap();
+
+        let observations = observations_db
+            .get_many(&base_token_id, &quote_token_id)
+            .await
+            .unwrap();
+
+        assert_eq!(observations, vec![observation(1), replacement.clone()]);
+
+        drop(observations_db);
+
+        let mut observations_db = ObservationsDb::new(dir).await.unwrap();
+
+        let latest = observations_db
+            .get_latest(&base_token_id, &quote_token_id)
+            .await
+            .unwrap();
+
+        assert_eq!(latest, replacement);
+
+        let latest = observations_db
+            .get_latest(&quote_token_id, &base_token_id)
+            .await
+            .unwrap();
+
+        assert_eq!(latest, observation(5));
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn overwrites_the_oldest_observations() {
+        let dir = "/tmp/nacho/tests/observations_db/overwrites_the_oldest_observations";
+
+        let mut observations_db = ObservationsDb::new(dir).await.unwrap();
+
+        let (base_token_id, quote_token_id) = (U256([0; 32]), U256([1; 32]));
+
+        for interval in 0..OBSERVATIONS_PER_PAIR + 2 {
+            let timestamp = interval * OBSERVATION_INTERVAL_IN_SECONDS;
+
+            observations_db
+                .push(&base_token_id, &quote_token_id, &observation(timestamp))
+                .await
+                .unwrap();
+        }
+
+        let observations = observations_db
+            .get_many(&base_token_id, &quote_token_id)
+            .await
+            .unwrap();
+
+        assert_eq!(observations.len() as u64, OBSERVATIONS_PER_PAIR);
+        assert_eq!(
+            observations.first(),
+            Some(&observation(2 * OBSERVATION_INTERVAL_IN_SECONDS))
+        );
+        assert_eq!(
+            observations.last(),
+            Some(&observation(
+                (OBSERVATIONS_PER_PAIR + 1) * OBSERVATION_INTERVAL_IN_SECONDS
+            ))
+        );
+
+        remove_dir_all(dir).await.unwrap();
+    }
+}
@@ -0,0 +1,3 @@
+pub const ORDERS_TREE_HEIGHT: usize = 22;
+pub const ORDERS_TREE_SIBLING_COUNT: usize = ORDERS_TREE_HEIGHT - 1;
+pub const ORDER_SIZE_IN_BYTES: usize = 144;
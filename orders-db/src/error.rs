@@ -0,0 +1,15 @@
+use nacho_dynamic_list::DynamicListError;
+use nacho_dynamic_merkle_tree::DynamicMerkleTreeError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum OrdersDbError {
+    #[error(transparent)]
+    DynamicList(#[from] DynamicListError),
+    #[error(transparent)]
+    MerkleTree(#[from] DynamicMerkleTreeError),
+    #[error("Order doesn't exist.")]
+    OrderDoesntExist,
+    #[error("Order ID isn't the next order ID.")]
+    InvalidOrderId,
+}
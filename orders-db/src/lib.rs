@@ -0,0 +1,10 @@
+mod constants;
+
+mod error;
+mod orders_db;
+mod single_order_witness;
+
+pub use constants::{ORDERS_TREE_HEIGHT, ORDERS_TREE_SIBLING_COUNT, ORDER_SIZE_IN_BYTES};
+pub use error::OrdersDbError;
+pub use orders_db::OrdersDb;
+pub use single_order_witness::SingleOrderWitness;
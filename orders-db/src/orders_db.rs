@@ -0,0 +1,467 @@
+use crate::{
+    OrdersDbError, SingleOrderWitness, ORDERS_TREE_HEIGHT, ORDERS_TREE_SIBLING_COUNT,
+    ORDER_SIZE_IN_BYTES,
+};
+use nacho_data_structures::{
+    Address, ByteConversion, Field, FieldConversion, Order, OrderStatus, U256,
+};
+use nacho_dynamic_list::DynamicList;
+use nacho_dynamic_merkle_tree::DynamicMerkleTree;
+use nacho_poseidon_hash::{create_poseidon_hasher, poseidon_hash, PoseidonHasher};
+use std::{collections::HashMap, path::Path};
+
+type Result<T> = std::result::Result<T, OrdersDbError>;
+
+/// The key of the indexes that are built while reading the orders from the disk.
+#[derive(PartialEq, Eq, Hash)]
+enum IndexKey {
+    Owner(Address),
+    Open(U256, U256),
+}
+
+pub struct OrdersDb {
+    list: DynamicList<ORDER_SIZE_IN_BYTES>,
+    tree: DynamicMerkleTree<ORDERS_TREE_HEIGHT, ORDERS_TREE_SIBLING_COUNT>,
+    owner_indexes: HashMap<Address, Vec<u64>>,
+    open_indexes: HashMap<(U256, U256), Vec<u64>>,
+    next_id: u64,
+    hasher: PoseidonHasher,
+}
+
+impl OrdersDb {
+    pub async fn new(path: impl AsRef<Path>) -> Result<OrdersDb> {
+        let path = path.as_ref();
+
+        let mut list = DynamicList::new(path.join("dynamic_list")).await?;
+        let tree = DynamicMerkleTree::new(path.join("dynamic_merkle_tree")).await?;
+        let mut indexes = HashMap::<IndexKey, Vec<u64>>::new();
+        let hasher = create_poseidon_hasher();
+
+        list.for_each(&mut indexes, |buf, index, indexes| {
+            let order = Order::from_bytes(&buf);
+
+            if order.id != index {
+                return Err(OrdersDbError::InvalidOrderId);
+            }
+
+            if order.status == OrderStatus::Open {
+                indexes
+                    .entry(IndexKey::Open(order.token_in_id, order.token_out_id))
+                    .or_default()
+                    .push(index);
+            }
+
+            indexes
+                .entry(IndexKey::Owner(order.owner))
+                .or_default()
+                .push(index);
+
+            Ok(())
+        })
+        .await?;
+
+        let mut owner_indexes = HashMap::new();
+        let mut open_indexes = HashMap::new();
+        let mut next_id = 0;
+
+        for (key, ids) in indexes {
+            match key {
+                IndexKey::Owner(owner) => {
+                    next_id += ids.len() as u64;
+                    owner_indexes.insert(owner, ids);
+                }
+                IndexKey::Open(token_in_id, token_out_id) => {
+                    open_indexes.insert((token_in_id, token_out_id), ids);
+                }
+            }
+        }
+
+        Ok(OrdersDb {
+            list,
+            tree,
+            owner_indexes,
+            open_indexes,
+            next_id,
+            hasher,
+        })
+    }
+
+    /// Returns the ID the next pushed order must have.
+    pub fn next_id(&self) -> u64 {
+        self.next_id
+    }
+
+    pub async fn push(&mut self, order: &Order) -> Result<()> {
+        if order.id != self.next_id {
+            return Err(OrdersDbError::InvalidOrderId);
+        }
+
+        let buf = order.to_bytes();
+
+        let index = self.list.push(buf).await?;
+
+        self.owner_indexes
+            .entry(order.owner.clone())
+            .or_default()
+            .push(index);
+
+        if order.status == OrderStatus::Open {
+            self.open_indexes
+                .entry((order.token_in_id.clone(), order.token_out_id.clone()))
+                .or_default()
+                .push(index);
+        }
+
+        self.next_id += 1;
+
+        Ok(())
+    }
+
+    /// Removes the last pushed order and returns it, the order's leaf must not be pushed yet.
+    ///
+    /// It is used to undo the push of a transaction that couldn't be committed.
+    pub async fn pop(&mut self) -> Result<Order> {
+        let buf = self.list.pop().await?;
+
+        let order = Order::from_bytes(&buf);
+
+        if let Some(ids) = self.owner_indexes.get_mut(&order.owner) {
+            ids.retain(|&id| id != order.id);
+        }
+
+        if let Some(ids) = self
+            .open_indexes
+            .get_mut(&(order.token_in_id.clone(), order.token_out_id.clone()))
+        {
+            ids.retain(|&id| id != order.id);
+        }
+
+        self.next_id -= 1;
+
+        Ok(order)
+    }
+
+    pub async fn push_leaf(&mut self, order: &Order) -> Result<()> {
+        let fields = order.to_fields();
+
+        let hash = poseidon_hash(&mut self.hasher, &fields);
+
+        self.tree.push_leaf(hash).await?;
+
+        Ok(())
+    }
+
+    pub async fn get(&mut self, id: u64) -> Result<Order> {
+        if id >= self.next_id {
+            return Err(OrdersDbError::OrderDoesntExist);
+        }
+
+        let buf = self.list.get(id).await?;
+
+        let order = Order::from_bytes(&buf);
+
+        Ok(order)
+    }
+
+    pub async fn get_many_by_owner(&mut self, owner: &Address) -> Result<Vec<Order>> {
+        let ids = match self.owner_indexes.get(owner) {
+            Some(ids) => ids.clone(),
+            None => return Ok(Vec::new()),
+        };
+
+        let mut orders = Vec::with_capacity(ids.len());
+
+        for id in ids {
+            let buf = self.list.get(id).await?;
+
+            let order = Order::from_bytes(&buf);
+
+            orders.push(order)
+        }
+
+        Ok(orders)
+    }
+
+    /// Returns the open orders that sell the token with `token_in_id` for the token with `token_out_id`, oldest first.
+    pub async fn get_many_open(
+        &mut self,
+        token_in_id: &U256,
+        token_out_id: &U256,
+    ) -> Result<Vec<Order>> {
+        let ids = match self
+            .open_indexes
+            .get(&(token_in_id.clone(), token_out_id.clone()))
+        {
+            Some(ids) => ids.clone(),
+            None => return Ok(Vec::new()),
+        };
+
+        let mut orders = Vec::with_capacity(ids.len());
+
+        for id in ids {
+            let buf = self.list.get(id).await?;
+
+            let order = Order::from_bytes(&buf);
+
+            orders.push(order)
+        }
+
+        Ok(orders)
+    }
+
+    pub async fn get_single_witness(&mut self, id: u64) -> Result<SingleOrderWitness> {
+        if id >= self.next_id {
+            return Err(OrdersDbError::OrderDoesntExist);
+        }
+
+        let single_witness = self.tree.get_single_witness(id).await?;
+
+        Ok(single_witness)
+    }
+
+    pub async fn get_new_single_witness(&mut self) -> Result<SingleOrderWitness> {
+        let single_witness = self.tree.get_unused_single_witness().await?;
+
+        Ok(single_witness)
+    }
+
+    pub async fn update(&mut self, order: &Order) -> Result<()> {
+        if order.id >= self.next_id {
+            return Err(OrdersDbError::OrderDoesntExist);
+        }
+
+        let buf = order.to_bytes();
+
+        self.list.set(order.id, buf).await?;
+
+        let open_ids = self
+            .open_indexes
+            .entry((order.token_in_id.clone(), order.token_out_id.clone()))
+            .or_default();
+
+        if order.status != OrderStatus::Open {
+            open_ids.retain(|&id| id != order.id);
+        } else if !open_ids.contains(&order.id) {
+            // NOTE: Undoing a fill or a cancellation opens the order again.
+            open_ids.push(order.id);
+            open_ids.sort_unstable();
+        }
+
+        Ok(())
+    }
+
+    pub async fn update_leaf(&mut self, order: &Order) -> Result<()> {
+        if order.id >= self.next_id {
+            return Err(OrdersDbError::OrderDoesntExist);
+        }
+
+        let fields = order.to_fields();
+
+        let hash = poseidon_hash(&mut self.hasher, &fields);
+
+        self.tree.set_leaf(order.id, hash).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_root(&mut self) -> Result<Field> {
+        let root = self.tree.get_root().await?;
+
+        Ok(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::fs::remove_dir_all;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn creates_orders_db() {
+        let dir = "/tmp/nacho/tests/orders_db/creates_orders_db";
+
+        let orders_db = OrdersDb::new(dir).await.unwrap();
+
+        assert_eq!(orders_db.next_id(), 0);
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn pushes_gets_and_updates_orders_correctly() {
+        let dir = "/tmp/nacho/tests/orders_db/pushes_gets_and_updates_orders_correctly";
+
+        let mut orders_db = OrdersDb::new(dir).await.unwrap();
+
+        let owner_1 = Address::from_bytes(
+            "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let owner_2 = Address::from_bytes(
+            "B62qiiGxLsqNemiKFKiD19JdTHmqbE5YKAkMuXGachSdYkTi8xR2dfY"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let mut order_1 = Order {
+            id: 0,
+            owner: owner_1.clone(),
+            token_in_id: U256([0; 32]),
+            token_out_id: U256([1; 32]),
+            token_amount_in: 100,
+            token_amount_out_limit: 200,
+            status: OrderStatus::Open,
+        };
+
+        let order_2 = Order {
+            id: 1,
+            owner: owner_2.clone(),
+            token_in_id: U256([0; 32]),
+            token_out_id: U256([1; 32]),
+            token_amount_in: 300,
+            token_amount_out_limit: 500,
+            status: OrderStatus::Open,
+        };
+
+        let order_3 = Order {
+            id: 2,
+            owner: owner_1.clone(),
+            token_in_id: U256([1; 32]),
+            token_out_id: U256([0; 32]),
+            token_amount_in: 50,
+            token_amount_out_limit: 20,
+            status: OrderStatus::Open,
+        };
+
+        let err = orders_db.get(0).await.unwrap_err();
+
+        assert!(matches!(err, OrdersDbError::OrderDoesntExist));
+
+        let err = orders_db.push(&order_2).await.unwrap_err();
+
+        assert!(matches!(err, OrdersDbError::InvalidOrderId));
+
+        orders_db.push(&order_1).await.unwrap();
+        orders_db.push(&order_2).await.unwrap();
+        orders_db.push(&order_3).await.unwrap();
+
+        assert_eq!(orders_db.next_id(), 3);
+
+        let order = orders_db.get(1).await.unwrap();
+
+        assert_eq!(order, order_2);
+
+        let orders = orders_db.get_many_by_owner(&owner_1).await.unwrap();
+
+        assert_eq!(orders, vec![order_1.clone(), order_3.clone()]);
+
+        let orders = orders_db
+            .get_many_open(&U256([0; 32]), &U256([1; 32]))
+            .await
+            .unwrap();
+
+        assert_eq!(orders, vec![order_1.clone(), order_2.clone()]);
+
+        order_1.status = OrderStatus::Filled;
+
+        orders_db.update(&order_1).await.unwrap();
+
+        let order = orders_db.get(0).await.unwrap();
+
+        assert_eq!(order, order_1);
+
+        let orders = orders_db
+            .get_many_open(&U256([0; 32]), &U256([1; 32]))
+            .await
+            .unwrap();
+
+        assert_eq!(orders, vec![order_2.clone()]);
+
+        drop(orders_db);
+
+        let mut orders_db = OrdersDb::new(dir).await.unwrap();
+
+        assert_eq!(orders_db.next_id(), 3);
+
+        let orders = orders_db
+            .get_many_open(&U256([0; 32]), &U256([1; 32]))
+            .await
+            .unwrap();
+
+        assert_eq!(orders, vec![order_2]);
+
+        let orders = orders_db.get_many_by_owner(&owner_1).await.unwrap();
+
+        assert_eq!(orders, vec![order_1, order_3]);
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn pops_and_reopens_orders() {
+        let dir = "/tmp/nacho/tests/orders_db/pops_and_reopens_orders";
+
+        let mut orders_db = OrdersDb::new(dir).await.unwrap();
+
+        let owner = Address::from_bytes(
+            "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let mut order_1 = Order {
+            id: 0,
+            owner: owner.clone(),
+            token_in_id: U256([0; 32]),
+            token_out_id: U256([1; 32]),
+            token_amount_in: 100,
+            token_amount_out_limit: 200,
+            status: OrderStatus::Open,
+        };
+
+        let order_2 = Order {
+            id: 1,
+            ..order_1.clone()
+        };
+
+        orders_db.push(&order_1).await.unwrap();
+        orders_db.push(&order_2).await.unwrap();
+
+        order_1.status = OrderStatus::Cancelled;
+        orders_db.update(&order_1).await.unwrap();
+
+        order_1.status = OrderStatus::Open;
+        orders_db.update(&order_1).await.unwrap();
+
+        let orders = orders_db
+            .get_many_open(&U256([0; 32]), &U256([1; 32]))
+            .await
+            .unwrap();
+
+        assert_eq!(orders, vec![order_1.clone(), order_2.clone()]);
+
+        assert_eq!(orders_db.pop().await.unwrap(), order_2);
+        assert_eq!(orders_db.next_id(), 1);
+
+        let orders = orders_db
+            .get_many_open(&U256([0; 32]), &U256([1; 32]))
+            .await
+            .unwrap();
+
+        assert_eq!(orders, vec![order_1.clone()]);
+
+        let orders = orders_db.get_many_by_owner(&owner).await.unwrap();
+
+        assert_eq!(orders, vec![order_1]);
+
+        orders_db.push(&order_2).await.unwrap();
+
+        remove_dir_all(dir).await.unwrap();
+    }
+}
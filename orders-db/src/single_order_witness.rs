@@ -0,0 +1,4 @@
+use crate::ORDERS_TREE_SIBLING_COUNT;
+use nacho_data_structures::SingleMerkleWitness;
+
+pub type SingleOrderWitness = SingleMerkleWitness<ORDERS_TREE_SIBLING_COUNT>;
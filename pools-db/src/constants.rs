@@ -1,3 +1,3 @@
 pub const POOLS_TREE_HEIGHT: usize = 21;
 pub const POOLS_TREE_SIBLING_COUNT: usize = POOLS_TREE_HEIGHT - 1;
-pub const POOL_SIZE_IN_BYTES: usize = 112;
+pub const POOL_SIZE_IN_BYTES: usize = 144;
@@ -0,0 +1,5 @@
+use nacho_data_structures::DoubleMerkleWitness;
+
+use crate::POOLS_TREE_SIBLING_COUNT;
+
+pub type DoublePoolWitness = DoubleMerkleWitness<POOLS_TREE_SIBLING_COUNT>;
@@ -1,10 +1,12 @@
 mod constants;
 
+mod double_pool_witness;
 mod error;
 mod pools_db;
 mod single_pool_witness;
 
 pub use constants::{POOLS_TREE_HEIGHT, POOLS_TREE_SIBLING_COUNT, POOL_SIZE_IN_BYTES};
+pub use double_pool_witness::DoublePoolWitness;
 pub use error::PoolsDbError;
 pub use pools_db::PoolsDb;
 pub use single_pool_witness::SinglePoolWitness;
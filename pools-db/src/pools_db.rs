@@ -67,6 +67,20 @@ impl PoolsDb {
         Ok(())
     }
 
+    /// Removes the last pushed pool and returns it, the pool's leaf must not be pushed yet.
+    ///
+    /// It is used to undo the push of a transaction that couldn't be committed.
+    pub async fn pop(&mut self) -> Result<Pool> {
+        let buf = self.list.pop().await?;
+
+        let pool = Pool::from_bytes(&buf);
+
+        self.indexes
+            .remove(&(pool.base_token_id.clone(), pool.quote_token_id.clone()));
+
+        Ok(pool)
+    }
+
     pub async fn push_leaf(&mut self, pool: &Pool) -> Result<()> {
         let fields = pool.to_fields();
 
@@ -192,6 +206,10 @@ mod tests {
             base_token_amount: 4000,
             quote_token_amount: 8000,
             total_liqudity_points: U256([88; 32]),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
         };
 
         let mut pool_2 = Pool {
@@ -200,6 +218,10 @@ mod tests {
             base_token_amount: 2500,
             quote_token_amount: 3000,
             total_liqudity_points: U256([66; 32]),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
         };
 
         let pool_3 = Pool {
@@ -208,6 +230,10 @@ mod tests {
             base_token_amount: 5000,
             quote_token_amount: 9000,
             total_liqudity_points: U256([55; 32]),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
         };
 
         let pool_4 = Pool {
@@ -216,6 +242,10 @@ mod tests {
             base_token_amount: 3000,
             quote_token_amount: 6000,
             total_liqudity_points: U256([33; 32]),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
         };
 
         let err = pools_db
@@ -294,6 +324,10 @@ mod tests {
             base_token_amount: 3000,
             quote_token_amount: 6000,
             total_liqudity_points: U256([33; 32]),
+            fee_rate: 30,
+            protocol_fee_base_token_amount: 0,
+            protocol_fee_quote_token_amount: 0,
+            amplification: 0,
         };
 
         pools_db.push(&pool).await.unwrap();
@@ -0,0 +1,5 @@
+mod process;
+mod processor;
+
+pub use process::process;
+pub use processor::Processor;
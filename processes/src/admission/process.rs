@@ -0,0 +1,279 @@
+use super::Processor;
+use crate::{balances, verifier};
+use nacho_data_structures::{
+    BurnTokensTransaction, BuyTokensTransaction, ClaimProtocolFeesTransaction,
+    CreatePoolTransaction, PlaceOrderTransaction, Pool, ProvideLiquidityTransaction,
+    RejectionReason, RemoveLiquidityTransaction, SellTokensTransaction, SwapRouteTransaction,
+    Transaction, TransferTokensTransaction, U256,
+};
+use nacho_rpc_server::SignerRateLimiter;
+
+pub fn process(balances: balances::Processor, verifier: verifier::Processor) -> Processor {
+    let signer_rate_limiter = SignerRateLimiter::from_env().unwrap();
+
+    Processor {
+        balances,
+        verifier,
+        signer_rate_limiter: Box::leak(Box::new(signer_rate_limiter)),
+    }
+}
+
+/// Checks whether the given transaction can be admitted to the mempool.
+///
+/// The checks are cheap and don't account for the transactions waiting in the mempool,
+/// so the executor still performs the complete checks when the transaction is executed.
+pub async fn admit_tx(
+    tx: Transaction,
+    verifier: verifier::Processor,
+    balances: balances::Processor,
+    signer_rate_limiter: &SignerRateLimiter,
+) -> Result<(), RejectionReason> {
+    check_tx_statelessly(&tx)?;
+
+    let is_valid = verifier
+        .check_signature(tx.clone())
+        .await
+        .ok_or(RejectionReason::Internal)?;
+
+    if !is_valid {
+        return Err(RejectionReason::InvalidSignature);
+    }
+
+    // The signer's quota is only charged once the signature is verified, so it can't be used up by others.
+    if let Some(address) = tx.address() {
+        if !signer_rate_limiter.check(address) {
+            return Err(RejectionReason::RateLimited);
+        }
+    }
+
+    if let (Some(address), Some(nonce)) = (tx.address(), tx.nonce()) {
+        let expected_nonce = balances
+            .get_nonce(address.clone())
+            .await
+            .ok_or(RejectionReason::Internal)?;
+
+        // Only the signer's next nonce is admitted, so a transaction can't take a tx id it can never be executed with.
+        if nonce != expected_nonce {
+            return Err(RejectionReason::InvalidNonce);
+        }
+    }
+
+    let required_balances = match tx {
+        Transaction::BurnTokens(BurnTokensTransaction {
+            address,
+            token_id,
+            token_amount,
+            ..
+        }) => vec![(address, token_id, token_amount)],
+        Transaction::CreatePool(CreatePoolTransaction {
+            address,
+            base_token_id,
+            quote_token_id,
+            base_token_amount,
+            quote_token_amount,
+            ..
+        }) => vec![
+            (address.clone(), base_token_id, base_token_amount),
+            (address, quote_token_id, quote_token_amount),
+        ],
+        Transaction::ProvideLiquidity(ProvideLiquidityTransaction {
+            address,
+            base_token_id,
+            quote_token_id,
+            base_token_amount,
+            ..
+        }) => vec![
+            (address.clone(), base_token_id, base_token_amount),
+            (address, quote_token_id, 1),
+        ],
+        Transaction::BuyTokens(BuyTokensTransaction {
+            address,
+            quote_token_id,
+            ..
+        }) => vec![(address, quote_token_id, 1)],
+        Transaction::SellTokens(SellTokensTransaction {
+            address,
+            base_token_id,
+            ..
+        }) => vec![(address, base_token_id, 1)],
+        Transaction::SwapRoute(SwapRouteTransaction {
+            address,
+            path,
+            token_amount_in,
+            ..
+        }) => vec![(address, path[0].clone(), token_amount_in)],
+        Transaction::TransferTokens(TransferTokensTransaction {
+            address,
+            token_id,
+            token_amount,
+            ..
+        }) => vec![(address, token_id, token_amount)],
+        Transaction::PlaceOrder(PlaceOrderTransaction {
+            address,
+            token_in_id,
+            token_amount_in,
+            ..
+        }) => vec![(address, token_in_id, token_amount_in)],
+        // The claimed fees are added to the treasury's balances, which must already exist.
+        Transaction::ClaimProtocolFees(ClaimProtocolFeesTransaction {
+            address,
+            base_token_id,
+            quote_token_id,
+            ..
+        }) => vec![
+            (address.clone(), base_token_id, 0),
+            (address, quote_token_id, 0),
+        ],
+        _ => vec![],
+    };
+
+    for (address, token_id, token_amount) in required_balances {
+        let balance = balances
+            .get_balance(address, token_id)
+            .await
+            .ok_or(RejectionReason::BalanceDoesntExist)?;
+
+        if balance.token_amount < token_amount {
+            return Err(RejectionReason::NotEnoughBalance);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the parts of the given transaction that don't depend on the state.
+fn check_tx_statelessly(tx: &Transaction) -> Result<(), RejectionReason> {
+    if let Some(address) = tx.address() {
+        address
+            .to_pubkey()
+            .map_err(|_| RejectionReason::InvalidAddress)?;
+    }
+
+    let (maybe_token_ids, amounts, points) = match tx {
+        Transaction::CreateGenesis {}
+        | Transaction::DepositTokens(_)
+        | Transaction::CancelOrder(_)
+        | Transaction::FillOrder(_) => return Ok(()),
+        Transaction::BurnTokens(BurnTokensTransaction { token_amount, .. }) => {
+            (None, vec![*token_amount], None)
+        }
+        Transaction::CreatePool(CreatePoolTransaction {
+            base_token_id,
+            quote_token_id,
+            base_token_amount,
+            quote_token_amount,
+            fee_rate,
+            pool_kind,
+            amplification,
+            ..
+        }) => {
+            if !Pool::FEE_RATES.contains(fee_rate) {
+                return Err(RejectionReason::InvalidFeeRate);
+            }
+
+            if !pool_kind.accepts_amplification(*amplification) {
+                return Err(RejectionReason::InvalidAmplification);
+            }
+
+            (
+                Some((base_token_id, quote_token_id)),
+                vec![*base_token_amount, *quote_token_amount],
+                None,
+            )
+        }
+        Transaction::ProvideLiquidity(ProvideLiquidityTransaction {
+            base_token_id,
+            quote_token_id,
+            base_token_amount,
+            quote_token_amount_limit,
+            ..
+        }) => (
+            Some((base_token_id, quote_token_id)),
+            vec![*base_token_amount, *quote_token_amount_limit],
+            None,
+        ),
+        Transaction::RemoveLiquidity(RemoveLiquidityTransaction {
+            base_token_id,
+            quote_token_id,
+            points,
+            ..
+        }) => (Some((base_token_id, quote_token_id)), vec![], Some(points)),
+        Transaction::BuyTokens(BuyTokensTransaction {
+            base_token_id,
+            quote_token_id,
+            base_token_amount,
+            quote_token_amount_limit,
+            ..
+        }) => (
+            Some((base_token_id, quote_token_id)),
+            vec![*base_token_amount, *quote_token_amount_limit],
+            None,
+        ),
+        Transaction::SellTokens(SellTokensTransaction {
+            base_token_id,
+            quote_token_id,
+            base_token_amount_limit,
+            quote_token_amount,
+            ..
+        }) => (
+            Some((base_token_id, quote_token_id)),
+            vec![*base_token_amount_limit, *quote_token_amount],
+            None,
+        ),
+        Transaction::SwapRoute(SwapRouteTransaction {
+            path,
+            token_amount_in,
+            ..
+        }) => {
+            if path[0] == path[2] || path[1] == path[2] {
+                return Err(RejectionReason::IdenticalTokenIds);
+            }
+
+            (Some((&path[0], &path[1])), vec![*token_amount_in], None)
+        }
+        Transaction::TransferTokens(TransferTokensTransaction {
+            address,
+            receiver_address,
+            token_amount,
+            ..
+        }) => {
+            receiver_address
+                .to_pubkey()
+                .map_err(|_| RejectionReason::InvalidAddress)?;
+
+            if address == receiver_address {
+                return Err(RejectionReason::IdenticalAddresses);
+            }
+
+            (None, vec![*token_amount], None)
+        }
+        Transaction::PlaceOrder(PlaceOrderTransaction {
+            token_in_id,
+            token_out_id,
+            token_amount_in,
+            token_amount_out_limit,
+            ..
+        }) => (
+            Some((token_in_id, token_out_id)),
+            vec![*token_amount_in, *token_amount_out_limit],
+            None,
+        ),
+        Transaction::ClaimProtocolFees(ClaimProtocolFeesTransaction {
+            base_token_id,
+            quote_token_id,
+            ..
+        }) => (Some((base_token_id, quote_token_id)), vec![], None),
+    };
+
+    if let Some((base_token_id, quote_token_id)) = maybe_token_ids {
+        if base_token_id == quote_token_id {
+            return Err(RejectionReason::IdenticalTokenIds);
+        }
+    }
+
+    if amounts.contains(&0) || points.is_some_and(|points| *points == U256::from(0u64)) {
+        return Err(RejectionReason::ZeroAmount);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use super::process::admit_tx;
+use crate::{balances, verifier};
+use nacho_data_structures::{RejectionReason, Transaction};
+use nacho_rpc_server::SignerRateLimiter;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Processor {
+    pub(crate) balances: balances::Processor,
+    pub(crate) verifier: verifier::Processor,
+    pub(crate) signer_rate_limiter: &'static SignerRateLimiter,
+}
+
+impl Processor {
+    pub async fn admit(&self, tx: Transaction) -> Result<(), RejectionReason> {
+        admit_tx(tx, self.verifier, self.balances, self.signer_rate_limiter).await
+    }
+}
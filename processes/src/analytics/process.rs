@@ -0,0 +1,55 @@
+use nacho_analytics_db::AnalyticsDb;
+use tokio::sync::mpsc;
+
+use super::{Processor, Request};
+use crate::abort;
+
+pub fn process() -> Processor {
+    let analytics_db_path = std::env::var("NACHO_ANALYTICS_DB_PATH").unwrap();
+
+    let (sender, mut receiver) = mpsc::channel::<Request>(1000);
+
+    tokio::spawn(async move {
+        let mut analytics_db = AnalyticsDb::new(analytics_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Analytics DB: {err}")));
+
+        while let Some(request) = receiver.recv().await {
+            match request {
+                Request::GetTradeStats {
+                    sender,
+                    base_token_id,
+                    quote_token_id,
+                    since_hour,
+                } => {
+                    let trade_stats = analytics_db
+                        .get_sum(&base_token_id, &quote_token_id, since_hour)
+                        .await;
+
+                    sender.send(trade_stats.ok()).unwrap();
+                }
+                Request::GetPairs { sender } => {
+                    let pairs = analytics_db.get_pairs();
+
+                    sender.send(pairs).unwrap();
+                }
+                Request::PushTradeStats {
+                    sender,
+                    base_token_id,
+                    quote_token_id,
+                    trade_stats,
+                } => {
+                    let result = analytics_db
+                        .push(&base_token_id, &quote_token_id, &trade_stats)
+                        .await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+            }
+        }
+    });
+
+    Processor {
+        sender: Box::leak(Box::new(sender)),
+    }
+}
@@ -0,0 +1,144 @@
+use crate::observations::unix_timestamp;
+use nacho_data_structures::{Pool, SwapSide, TradeStats, U256};
+use std::cmp::Reverse;
+use tokio::sync::{mpsc, oneshot};
+
+use super::Request;
+
+/// The count of hours in a day, which is the period of the daily pool stats.
+const HOURS_PER_DAY: u64 = 24;
+
+/// The count of hours in a week, which is the period of the weekly pool stats.
+const HOURS_PER_WEEK: u64 = 168;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Processor {
+    pub(crate) sender: &'static mpsc::Sender<Request>,
+}
+
+impl Processor {
+    pub async fn get_trade_stats(
+        &self,
+        base_token_id: U256,
+        quote_token_id: U256,
+        since_hour: u64,
+    ) -> Option<TradeStats> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetTradeStats {
+                sender: oneshot_sender,
+                base_token_id,
+                quote_token_id,
+                since_hour,
+            })
+            .await
+            .ok()?;
+
+        let trade_stats = oneshot_receiver.await.ok()?;
+
+        trade_stats
+    }
+
+    pub async fn get_pairs(&self) -> Option<Vec<(U256, U256)>> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetPairs {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let pairs = oneshot_receiver.await.ok()?;
+
+        Some(pairs)
+    }
+
+    pub async fn push_trade_stats(
+        &self,
+        base_token_id: U256,
+        quote_token_id: U256,
+        trade_stats: TradeStats,
+    ) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PushTradeStats {
+                sender: oneshot_sender,
+                base_token_id,
+                quote_token_id,
+                trade_stats,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    /// Records a swap that is about to be made in the given pool, the pool must be the one before the swap.
+    pub async fn observe_trade(&self, pool: Pool, side: SwapSide, amount_out: u64) -> Option<()> {
+        let trade_stats =
+            nacho_executor::observe_trade(&pool, side, amount_out, unix_timestamp()).ok()?;
+
+        self.push_trade_stats(pool.base_token_id, pool.quote_token_id, trade_stats)
+            .await
+    }
+
+    /// Returns the stats of the pool over the last 24 hours and the last 7 days, including the current hour.
+    pub async fn get_pool_stats(
+        &self,
+        base_token_id: U256,
+        quote_token_id: U256,
+    ) -> Option<(TradeStats, TradeStats)> {
+        let hour = unix_timestamp() / TradeStats::PERIOD;
+
+        let daily_stats = self
+            .get_trade_stats(
+                base_token_id.clone(),
+                quote_token_id.clone(),
+                (hour + 1).saturating_sub(HOURS_PER_DAY),
+            )
+            .await?;
+
+        let weekly_stats = self
+            .get_trade_stats(
+                base_token_id,
+                quote_token_id,
+                (hour + 1).saturating_sub(HOURS_PER_WEEK),
+            )
+            .await?;
+
+        Some((daily_stats, weekly_stats))
+    }
+
+    /// Returns the pools with the most swaps over the last 24 hours with their stats, at most `count` of them.
+    ///
+    /// Pools are ranked by their trade counts, as the volumes of different pools are in different tokens.
+    pub async fn get_top_pools(&self, count: usize) -> Option<Vec<(U256, U256, TradeStats)>> {
+        let since_hour = (unix_timestamp() / TradeStats::PERIOD + 1).saturating_sub(HOURS_PER_DAY);
+
+        let pairs = self.get_pairs().await?;
+
+        let mut top_pools = Vec::with_capacity(pairs.len());
+
+        for (base_token_id, quote_token_id) in pairs {
+            let daily_stats = self
+                .get_trade_stats(base_token_id.clone(), quote_token_id.clone(), since_hour)
+                .await?;
+
+            if daily_stats.trade_count != 0 {
+                top_pools.push((base_token_id, quote_token_id, daily_stats));
+            }
+        }
+
+        top_pools.sort_by_key(|(_, _, daily_stats)| {
+            Reverse((daily_stats.trade_count, daily_stats.quote_token_volume))
+        });
+        top_pools.truncate(count);
+
+        Some(top_pools)
+    }
+}
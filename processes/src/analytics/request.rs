@@ -0,0 +1,20 @@
+use nacho_data_structures::{TradeStats, U256};
+use tokio::sync::oneshot;
+
+pub enum Request {
+    GetTradeStats {
+        sender: oneshot::Sender<Option<TradeStats>>,
+        base_token_id: U256,
+        quote_token_id: U256,
+        since_hour: u64,
+    },
+    GetPairs {
+        sender: oneshot::Sender<Vec<(U256, U256)>>,
+    },
+    PushTradeStats {
+        sender: oneshot::Sender<Option<()>>,
+        base_token_id: U256,
+        quote_token_id: U256,
+        trade_stats: TradeStats,
+    },
+}
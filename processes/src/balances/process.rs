@@ -2,6 +2,7 @@ use nacho_balances_db::BalancesDb;
 use tokio::sync::mpsc;
 
 use super::{Processor, Request};
+use crate::abort;
 
 pub fn process() -> Processor {
     let balances_db_path = std::env::var("NACHO_BALANCES_DB_PATH").unwrap();
@@ -9,7 +10,9 @@ pub fn process() -> Processor {
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
     tokio::spawn(async move {
-        let mut balances_db = BalancesDb::new(balances_db_path).await.unwrap();
+        let mut balances_db = BalancesDb::new(balances_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Balances DB: {err}")));
 
         while let Some(request) = receiver.recv().await {
             match request {
@@ -48,6 +51,18 @@ pub fn process() -> Processor {
 
                     sender.send(double_witness.ok()).unwrap();
                 }
+                Request::GetTransferWitness {
+                    sender,
+                    owner,
+                    receiver,
+                    token_id,
+                } => {
+                    let double_witness = balances_db
+                        .get_double_witness(&owner, &token_id, &receiver, &token_id)
+                        .await;
+
+                    sender.send(double_witness.ok()).unwrap();
+                }
                 Request::GetNewWitness { sender } => {
                     let new_witness = balances_db.get_new_single_witness().await;
 
@@ -63,6 +78,11 @@ pub fn process() -> Processor {
 
                     sender.send(result.ok()).unwrap();
                 }
+                Request::PopBalance { sender } => {
+                    let result = balances_db.pop().await;
+
+                    sender.send(result.ok()).unwrap();
+                }
                 Request::PushLeaf { sender, balance } => {
                     let result = balances_db.push_leaf(&balance).await;
 
@@ -76,6 +96,36 @@ pub fn process() -> Processor {
                 Request::GetRoot { sender } => {
                     let result = balances_db.get_root().await;
 
+                    sender.send(result.ok().map(|root| root.into())).unwrap();
+                }
+                Request::GetNonce { sender, owner } => {
+                    let result = balances_db.get_nonce(&owner).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::IncrementNonce { sender, owner } => {
+                    let result = balances_db.increment_nonce(&owner).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::DecrementNonce { sender, owner } => {
+                    let result = balances_db.decrement_nonce(&owner).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::GetNonceWitness { sender, owner } => {
+                    let single_witness = balances_db.get_nonce_witness(&owner).await;
+
+                    sender.send(single_witness.ok()).unwrap();
+                }
+                Request::SetNonceLeaf { sender, nonce } => {
+                    let result = balances_db.set_nonce_leaf(&nonce).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::GetNoncesRoot { sender } => {
+                    let result = balances_db.get_nonces_root().await;
+
                     sender.send(result.ok().map(|root| root.into())).unwrap();
                 }
             }
@@ -1,5 +1,5 @@
-use nacho_balances_db::{DoubleBalanceWitness, SingleBalanceWitness};
-use nacho_data_structures::{Address, Balance, U256};
+use nacho_balances_db::{DoubleBalanceWitness, SingleBalanceWitness, SingleNonceWitness};
+use nacho_data_structures::{Address, Balance, Nonce, U256};
 use tokio::sync::{mpsc, oneshot};
 
 use super::Request;
@@ -87,6 +87,29 @@ impl Processor {
         double_witness
     }
 
+    pub async fn get_transfer_witness(
+        &self,
+        address: Address,
+        receiver_address: Address,
+        token_id: U256,
+    ) -> Option<DoubleBalanceWitness> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetTransferWitness {
+                sender: oneshot_sender,
+                owner: address,
+                receiver: receiver_address,
+                token_id,
+            })
+            .await
+            .ok()?;
+
+        let double_witness = oneshot_receiver.await.ok()?;
+
+        double_witness
+    }
+
     pub async fn get_new_witness(&self) -> Option<SingleBalanceWitness> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
 
@@ -134,6 +157,22 @@ impl Processor {
         result
     }
 
+    /// Removes the last pushed balance, which undoes a push whose transaction couldn't be committed.
+    pub async fn pop_balance(&self) -> Option<Balance> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PopBalance {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let maybe_balance = oneshot_receiver.await.ok()?;
+
+        maybe_balance
+    }
+
     pub async fn push_leaf(&self, balance: Balance) -> Option<()> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
 
@@ -180,4 +219,100 @@ impl Processor {
 
         result
     }
+
+    pub async fn get_nonce(&self, address: Address) -> Option<u64> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetNonce {
+                sender: oneshot_sender,
+                owner: address,
+            })
+            .await
+            .ok()?;
+
+        let nonce = oneshot_receiver.await.ok()?;
+
+        nonce
+    }
+
+    pub async fn increment_nonce(&self, address: Address) -> Option<u64> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::IncrementNonce {
+                sender: oneshot_sender,
+                owner: address,
+            })
+            .await
+            .ok()?;
+
+        let nonce = oneshot_receiver.await.ok()?;
+
+        nonce
+    }
+
+    /// Decrements the nonce of the given address, which undoes an increment whose transaction couldn't be committed.
+    pub async fn decrement_nonce(&self, address: Address) -> Option<u64> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::DecrementNonce {
+                sender: oneshot_sender,
+                owner: address,
+            })
+            .await
+            .ok()?;
+
+        let nonce = oneshot_receiver.await.ok()?;
+
+        nonce
+    }
+
+    pub async fn get_nonce_witness(&self, address: Address) -> Option<SingleNonceWitness> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetNonceWitness {
+                sender: oneshot_sender,
+                owner: address,
+            })
+            .await
+            .ok()?;
+
+        let single_witness = oneshot_receiver.await.ok()?;
+
+        single_witness
+    }
+
+    pub async fn set_nonce_leaf(&self, nonce: Nonce) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::SetNonceLeaf {
+                sender: oneshot_sender,
+                nonce,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    pub async fn get_nonces_root(&self) -> Option<U256> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetNoncesRoot {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
 }
@@ -1,5 +1,5 @@
-use nacho_balances_db::{DoubleBalanceWitness, SingleBalanceWitness};
-use nacho_data_structures::{Address, Balance, U256};
+use nacho_balances_db::{DoubleBalanceWitness, SingleBalanceWitness, SingleNonceWitness};
+use nacho_data_structures::{Address, Balance, Nonce, U256};
 use tokio::sync::oneshot;
 
 pub enum Request {
@@ -23,6 +23,12 @@ pub enum Request {
         base_token_id: U256,
         quote_token_id: U256,
     },
+    GetTransferWitness {
+        sender: oneshot::Sender<Option<DoubleBalanceWitness>>,
+        owner: Address,
+        receiver: Address,
+        token_id: U256,
+    },
     GetNewWitness {
         sender: oneshot::Sender<Option<SingleBalanceWitness>>,
     },
@@ -34,6 +40,9 @@ pub enum Request {
         sender: oneshot::Sender<Option<()>>,
         balance: Balance,
     },
+    PopBalance {
+        sender: oneshot::Sender<Option<Balance>>,
+    },
     PushLeaf {
         sender: oneshot::Sender<Option<()>>,
         balance: Balance,
@@ -45,4 +54,27 @@ pub enum Request {
     GetRoot {
         sender: oneshot::Sender<Option<U256>>,
     },
+    GetNonce {
+        sender: oneshot::Sender<Option<u64>>,
+        owner: Address,
+    },
+    IncrementNonce {
+        sender: oneshot::Sender<Option<u64>>,
+        owner: Address,
+    },
+    DecrementNonce {
+        sender: oneshot::Sender<Option<u64>>,
+        owner: Address,
+    },
+    GetNonceWitness {
+        sender: oneshot::Sender<Option<SingleNonceWitness>>,
+        owner: Address,
+    },
+    SetNonceLeaf {
+        sender: oneshot::Sender<Option<()>>,
+        nonce: Nonce,
+    },
+    GetNoncesRoot {
+        sender: oneshot::Sender<Option<U256>>,
+    },
 }
@@ -2,6 +2,7 @@ use nacho_burns_db::BurnsDb;
 use tokio::sync::mpsc;
 
 use super::{Processor, Request};
+use crate::abort;
 
 pub fn process() -> Processor {
     let burns_db_path = std::env::var("NACHO_BURNS_DB_PATH").unwrap();
@@ -9,7 +10,9 @@ pub fn process() -> Processor {
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
     tokio::spawn(async move {
-        let mut burns_db = BurnsDb::new(burns_db_path).await.unwrap();
+        let mut burns_db = BurnsDb::new(burns_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Burns DB: {err}")));
 
         while let Some(request) = receiver.recv().await {
             match request {
@@ -53,6 +56,11 @@ pub fn process() -> Processor {
 
                     sender.send(result.ok()).unwrap()
                 }
+                Request::PopBurn { sender } => {
+                    let result = burns_db.pop().await;
+
+                    sender.send(result.ok()).unwrap()
+                }
                 Request::PushLeaf { sender, burn } => {
                     let result = burns_db.push_leaf(&burn).await;
 
@@ -111,6 +111,22 @@ impl Processor {
         result
     }
 
+    /// Removes the last pushed burn, which undoes a push whose transaction couldn't be committed.
+    pub async fn pop_burn(&self) -> Option<Burn> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PopBurn {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let maybe_burn = oneshot_receiver.await.ok()?;
+
+        maybe_burn
+    }
+
     pub async fn push_leaf(&self, burn: Burn) -> Option<()> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
 
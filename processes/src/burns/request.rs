@@ -28,6 +28,9 @@ pub enum Request {
         sender: oneshot::Sender<Option<()>>,
         burn: Burn,
     },
+    PopBurn {
+        sender: oneshot::Sender<Option<Burn>>,
+    },
     PushLeaf {
         sender: oneshot::Sender<Option<()>>,
         burn: Burn,
@@ -0,0 +1,512 @@
+use crate::{balances, burns, liquidities, orders, pools, proofpool, transactions};
+use nacho_data_structures::{
+    Address, Balance, Burn, Liquidity, Order, Pool, RejectionReason, StatefulTransaction, U256,
+};
+use nacho_write_ahead_log::{WalEntry, WriteAheadLog};
+use std::collections::HashMap;
+
+/// The writes of a transaction to the state databases that are staged until the transaction is executed.
+///
+/// It has the same methods as the processors it wraps. Reads see the staged writes, and writes only change the batch.
+///
+/// A rejected transaction's batch is dropped, so none of its writes are applied.
+/// An executed transaction's batch is committed through the write-ahead log, so either all of its writes are applied or none of them.
+///
+/// The write-ahead log covers crashes. Failed writes while committing are undone from an undo journal of the values the records had before.
+pub struct StateBatch {
+    balances: balances::Processor,
+    burns: burns::Processor,
+    liquidities: liquidities::Processor,
+    orders: orders::Processor,
+    pools: pools::Processor,
+    entries: Vec<WalEntry>,
+    staged_balances: HashMap<(Address, U256), Balance>,
+    staged_nonces: HashMap<Address, u64>,
+    staged_pools: HashMap<(U256, U256), Pool>,
+    staged_liquidities: HashMap<(Address, U256, U256), Liquidity>,
+    staged_burns: HashMap<(Address, U256), Burn>,
+    staged_orders: HashMap<u64, Order>,
+    pushed_order_count: u64,
+}
+
+impl StateBatch {
+    pub fn new(
+        balances: balances::Processor,
+        burns: burns::Processor,
+        liquidities: liquidities::Processor,
+        orders: orders::Processor,
+        pools: pools::Processor,
+    ) -> StateBatch {
+        StateBatch {
+            balances,
+            burns,
+            liquidities,
+            orders,
+            pools,
+            entries: Vec::new(),
+            staged_balances: HashMap::new(),
+            staged_nonces: HashMap::new(),
+            staged_pools: HashMap::new(),
+            staged_liquidities: HashMap::new(),
+            staged_burns: HashMap::new(),
+            staged_orders: HashMap::new(),
+            pushed_order_count: 0,
+        }
+    }
+
+    pub async fn get_balance(&self, address: Address, token_id: U256) -> Option<Balance> {
+        match self
+            .staged_balances
+            .get(&(address.clone(), token_id.clone()))
+        {
+            Some(balance) => Some(balance.clone()),
+            None => self.balances.get_balance(address, token_id).await,
+        }
+    }
+
+    pub async fn push_balance(&mut self, balance: Balance) -> Option<()> {
+        self.stage_balance(balance);
+
+        Some(())
+    }
+
+    pub async fn update_balance(&mut self, balance: Balance) -> Option<()> {
+        self.stage_balance(balance);
+
+        Some(())
+    }
+
+    pub async fn get_nonce(&self, address: Address) -> Option<u64> {
+        match self.staged_nonces.get(&address) {
+            Some(nonce) => Some(*nonce),
+            None => self.balances.get_nonce(address).await,
+        }
+    }
+
+    pub async fn increment_nonce(&mut self, address: Address) -> Option<u64> {
+        let nonce = self.get_nonce(address.clone()).await? + 1;
+
+        self.staged_nonces.insert(address.clone(), nonce);
+        self.entries.push(WalEntry::Nonce(address, nonce));
+
+        Some(nonce)
+    }
+
+    pub async fn get_pool(&self, base_token_id: U256, quote_token_id: U256) -> Option<Pool> {
+        match self
+            .staged_pools
+            .get(&(base_token_id.clone(), quote_token_id.clone()))
+        {
+            Some(pool) => Some(pool.clone()),
+            None => self.pools.get_pool(base_token_id, quote_token_id).await,
+        }
+    }
+
+    pub async fn get_hop_pool(&self, token_in_id: U256, token_out_id: U256) -> Option<Pool> {
+        match self
+            .get_pool(token_in_id.clone(), token_out_id.clone())
+            .await
+        {
+            Some(pool) => Some(pool),
+            None => self.get_pool(token_out_id, token_in_id).await,
+        }
+    }
+
+    pub async fn push_pool(&mut self, pool: Pool) -> Option<()> {
+        self.stage_pool(pool);
+
+        Some(())
+    }
+
+    pub async fn update_pool(&mut self, pool: Pool) -> Option<()> {
+        self.stage_pool(pool);
+
+        Some(())
+    }
+
+    pub async fn get_liquidity(
+        &self,
+        provider: Address,
+        base_token_id: U256,
+        quote_token_id: U256,
+    ) -> Option<Liquidity> {
+        match self.staged_liquidities.get(&(
+            provider.clone(),
+            base_token_id.clone(),
+            quote_token_id.clone(),
+        )) {
+            Some(liquidity) => Some(liquidity.clone()),
+            None => {
+                self.liquidities
+                    .get_liquidity(provider, base_token_id, quote_token_id)
+                    .await
+            }
+        }
+    }
+
+    pub async fn push_liquidity(&mut self, liquidity: Liquidity) -> Option<()> {
+        self.stage_liquidity(liquidity);
+
+        Some(())
+    }
+
+    pub async fn update_liquidity(&mut self, liquidity: Liquidity) -> Option<()> {
+        self.stage_liquidity(liquidity);
+
+        Some(())
+    }
+
+    pub async fn get_burn(&self, burner: Address, token_id: U256) -> Option<Burn> {
+        match self.staged_burns.get(&(burner.clone(), token_id.clone())) {
+            Some(burn) => Some(burn.clone()),
+            None => self.burns.get_burn(burner, token_id).await,
+        }
+    }
+
+    pub async fn push_burn(&mut self, burn: Burn) -> Option<()> {
+        self.stage_burn(burn);
+
+        Some(())
+    }
+
+    pub async fn update_burn(&mut self, burn: Burn) -> Option<()> {
+        self.stage_burn(burn);
+
+        Some(())
+    }
+
+    pub async fn get_order(&self, order_id: u64) -> Option<Order> {
+        match self.staged_orders.get(&order_id) {
+            Some(order) => Some(order.clone()),
+            None => self.orders.get_order(order_id).await,
+        }
+    }
+
+    pub async fn get_next_order_id(&self) -> Option<u64> {
+        let next_order_id = self.orders.get_next_order_id().await?;
+
+        Some(next_order_id + self.pushed_order_count)
+    }
+
+    pub async fn push_order(&mut self, order: Order) -> Option<()> {
+        if order.id != self.get_next_order_id().await? {
+            return None;
+        }
+
+        self.pushed_order_count += 1;
+        self.stage_order(order);
+
+        Some(())
+    }
+
+    pub async fn update_order(&mut self, order: Order) -> Option<()> {
+        self.stage_order(order);
+
+        Some(())
+    }
+
+    /// Returns the staged writes in the order they are made.
+    pub fn staged_entries(&self) -> &[WalEntry] {
+        &self.entries
+    }
+
+    /// Writes the staged writes to the write-ahead log and applies them.
+    ///
+    /// The log also holds the increments of `executed_until` and `processed_until` and the push of the stateful transaction to the proofpool,
+    /// so the transaction is either executed with all of its writes or not at all.
+    ///
+    /// If a write fails, the applied writes are undone in reverse order and `Internal` is returned.
+    /// It returns `None` if they can't be undone, or if a write fails after the push to the proofpool,
+    /// in which case the writes are applied on restart.
+    pub async fn commit(
+        self,
+        stateful_tx: StatefulTransaction,
+        tx_id: u64,
+        executed_until: u64,
+        wal: &mut WriteAheadLog,
+        proofpool: proofpool::Processor,
+        transactions: transactions::Processor,
+    ) -> Option<Result<(), RejectionReason>> {
+        let push_count = match proofpool.get_push_count().await {
+            Some(push_count) => push_count,
+            None => return Some(Err(RejectionReason::Internal)),
+        };
+
+        let mut entries = self.entries.clone();
+
+        entries.push(WalEntry::StatefulTransaction(
+            Box::new(stateful_tx.clone()),
+            push_count,
+        ));
+        entries.push(WalEntry::ExecutedUntil(executed_until + 1));
+        entries.push(WalEntry::ProcessedUntil(tx_id + 1));
+
+        if wal.write(&entries).await.is_err() {
+            return Some(Err(RejectionReason::Internal));
+        }
+
+        // The writes that undo the applied entries, in the order the entries are applied.
+        let mut undo_journal = Vec::with_capacity(self.entries.len());
+
+        for entry in &self.entries {
+            match self.apply(entry.clone(), transactions).await {
+                Some(undo) => undo_journal.push(undo),
+                None => return self.roll_back(undo_journal, wal, transactions).await,
+            }
+        }
+
+        if proofpool.push(stateful_tx).await.is_none() {
+            return self.roll_back(undo_journal, wal, transactions).await;
+        }
+
+        transactions.set_executed_until(executed_until + 1).await?;
+        transactions.set_processed_until(tx_id + 1).await?;
+
+        wal.clear().await.ok()?;
+
+        Some(Ok(()))
+    }
+
+    /// Applies the batch left in the write-ahead log, if the node stopped while committing it.
+    ///
+    /// The entries are applied again, which is safe as each entry holds the value a record has after the transaction.
+    /// The stateful transaction is only pushed if the proofpool's push count hasn't increased since the batch was written.
+    pub async fn recover(
+        self,
+        wal: &mut WriteAheadLog,
+        proofpool: proofpool::Processor,
+        transactions: transactions::Processor,
+    ) -> Option<()> {
+        let entries = match wal.read().await.ok()? {
+            Some(entries) => entries,
+            None => return Some(()),
+        };
+
+        for entry in entries {
+            match entry {
+                WalEntry::StatefulTransaction(stateful_tx, push_count) => {
+                    let is_pushed = proofpool.get_push_count().await? > push_count;
+
+                    if !is_pushed {
+                        proofpool.push(*stateful_tx).await?;
+                    }
+                }
+                entry => {
+                    self.apply(entry, transactions).await?;
+                }
+            }
+        }
+
+        wal.clear().await.ok()
+    }
+
+    /// Undoes the applied entries of a batch that couldn't be committed and clears the write-ahead log.
+    ///
+    /// The log is only cleared after all the entries are undone, otherwise they are applied again on restart.
+    async fn roll_back(
+        &self,
+        undo_journal: Vec<Undo>,
+        wal: &mut WriteAheadLog,
+        transactions: transactions::Processor,
+    ) -> Option<Result<(), RejectionReason>> {
+        for undo in undo_journal.into_iter().rev() {
+            match undo {
+                Undo::Restore(entry) => {
+                    self.apply(entry, transactions).await?;
+                }
+                Undo::PopBalance => {
+                    self.balances.pop_balance().await?;
+                }
+                Undo::DecrementNonce(address) => {
+                    self.balances.decrement_nonce(address).await?;
+                }
+                Undo::PopPool => {
+                    self.pools.pop_pool().await?;
+                }
+                Undo::PopLiquidity => {
+                    self.liquidities.pop_liquidity().await?;
+                }
+                Undo::PopBurn => {
+                    self.burns.pop_burn().await?;
+                }
+                Undo::PopOrder => {
+                    self.orders.pop_order().await?;
+                }
+                Undo::Nothing => (),
+            }
+        }
+
+        wal.clear().await.ok()?;
+
+        Some(Err(RejectionReason::Internal))
+    }
+
+    /// Applies the given entry and returns the write that undoes it.
+    ///
+    /// Records are pushed if they don't exist and updated otherwise.
+    async fn apply(&self, entry: WalEntry, transactions: transactions::Processor) -> Option<Undo> {
+        match entry {
+            WalEntry::Balance(balance) => {
+                match self
+                    .balances
+                    .get_balance(balance.owner.clone(), balance.token_id.clone())
+                    .await
+                {
+                    Some(old_balance) => {
+                        self.balances.update_balance(balance).await?;
+
+                        Some(Undo::Restore(WalEntry::Balance(old_balance)))
+                    }
+                    None => {
+                        self.balances.push_balance(balance).await?;
+
+                        Some(Undo::PopBalance)
+                    }
+                }
+            }
+            WalEntry::Nonce(address, nonce) => {
+                if self.balances.get_nonce(address.clone()).await? < nonce {
+                    self.balances.increment_nonce(address.clone()).await?;
+
+                    Some(Undo::DecrementNonce(address))
+                } else {
+                    Some(Undo::Nothing)
+                }
+            }
+            WalEntry::Pool(pool) => {
+                match self
+                    .pools
+                    .get_pool(pool.base_token_id.clone(), pool.quote_token_id.clone())
+                    .await
+                {
+                    Some(old_pool) => {
+                        self.pools.update_pool(pool).await?;
+
+                        Some(Undo::Restore(WalEntry::Pool(old_pool)))
+                    }
+                    None => {
+                        self.pools.push_pool(pool).await?;
+
+                        Some(Undo::PopPool)
+                    }
+                }
+            }
+            WalEntry::Liquidity(liquidity) => {
+                match self
+                    .liquidities
+                    .get_liquidity(
+                        liquidity.provider.clone(),
+                        liquidity.base_token_id.clone(),
+                        liquidity.quote_token_id.clone(),
+                    )
+                    .await
+                {
+                    Some(old_liquidity) => {
+                        self.liquidities.update_liquidity(liquidity).await?;
+
+                        Some(Undo::Restore(WalEntry::Liquidity(old_liquidity)))
+                    }
+                    None => {
+                        self.liquidities.push_liquidity(liquidity).await?;
+
+                        Some(Undo::PopLiquidity)
+                    }
+                }
+            }
+            WalEntry::Burn(burn) => {
+                match self
+                    .burns
+                    .get_burn(burn.burner.clone(), burn.token_id.clone())
+                    .await
+                {
+                    Some(old_burn) => {
+                        self.burns.update_burn(burn).await?;
+
+                        Some(Undo::Restore(WalEntry::Burn(old_burn)))
+                    }
+                    None => {
+                        self.burns.push_burn(burn).await?;
+
+                        Some(Undo::PopBurn)
+                    }
+                }
+            }
+            WalEntry::Order(order) => match self.orders.get_order(order.id).await {
+                Some(old_order) => {
+                    self.orders.update_order(order).await?;
+
+                    Some(Undo::Restore(WalEntry::Order(old_order)))
+                }
+                None => {
+                    self.orders.push_order(order).await?;
+
+                    Some(Undo::PopOrder)
+                }
+            },
+            WalEntry::ExecutedUntil(executed_until) => {
+                transactions.set_executed_until(executed_until).await?;
+
+                Some(Undo::Nothing)
+            }
+            WalEntry::ProcessedUntil(processed_until) => {
+                transactions.set_processed_until(processed_until).await?;
+
+                Some(Undo::Nothing)
+            }
+            WalEntry::StatefulTransaction(_, _) => None,
+        }
+    }
+
+    fn stage_balance(&mut self, balance: Balance) {
+        self.staged_balances.insert(
+            (balance.owner.clone(), balance.token_id.clone()),
+            balance.clone(),
+        );
+        self.entries.push(WalEntry::Balance(balance));
+    }
+
+    fn stage_pool(&mut self, pool: Pool) {
+        self.staged_pools.insert(
+            (pool.base_token_id.clone(), pool.quote_token_id.clone()),
+            pool.clone(),
+        );
+        self.entries.push(WalEntry::Pool(pool));
+    }
+
+    fn stage_liquidity(&mut self, liquidity: Liquidity) {
+        self.staged_liquidities.insert(
+            (
+                liquidity.provider.clone(),
+                liquidity.base_token_id.clone(),
+                liquidity.quote_token_id.clone(),
+            ),
+            liquidity.clone(),
+        );
+        self.entries.push(WalEntry::Liquidity(liquidity));
+    }
+
+    fn stage_burn(&mut self, burn: Burn) {
+        self.staged_burns
+            .insert((burn.burner.clone(), burn.token_id.clone()), burn.clone());
+        self.entries.push(WalEntry::Burn(burn));
+    }
+
+    fn stage_order(&mut self, order: Order) {
+        self.staged_orders.insert(order.id, order.clone());
+        self.entries.push(WalEntry::Order(order));
+    }
+}
+
+/// The write that undoes an applied entry of a batch.
+enum Undo {
+    /// Writes the value the record had before the entry back.
+    Restore(WalEntry),
+    PopBalance,
+    DecrementNonce(Address),
+    PopPool,
+    PopLiquidity,
+    PopBurn,
+    PopOrder,
+    /// The entry didn't change anything, as it was already applied.
+    Nothing,
+}
@@ -1,5 +1,7 @@
+mod batch;
 mod process;
 mod processor;
 
-pub use process::process;
+pub use batch::StateBatch;
+pub use process::{apply_tx, execute_tx, process, protocol_fee_share, treasury_address};
 pub use processor::Processor;
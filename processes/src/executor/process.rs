@@ -1,42 +1,183 @@
-use super::Processor;
-use crate::{balances, burns, liquidities, mempool, pools, proofpool, transactions, verifier};
+use super::{Processor, StateBatch};
+use crate::{
+    abort, analytics, balances, burns, liquidities, mempool, observations, orders, pools,
+    proofpool, snapshots, transactions, verifier,
+};
 use nacho_data_structures::{
-    BurnTokensTransaction, BurnTokensTransactionState, BuyTokensTransaction,
-    BuyTokensTransactionState, CreatePoolTransaction, CreatePoolTransactionState,
-    DepositTokensTransaction, DepositTokensTransactionState, ProvideLiquidityTransaction,
-    ProvideLiquidityTransactionState, RemoveLiquidityTransaction, RemoveLiquidityTransactionState,
-    SellTokensTransaction, SellTokensTransactionState, StatefulTransaction, Transaction,
+    Address, BurnTokensTransaction, BurnTokensTransactionState, BuyTokensTransaction,
+    BuyTokensTransactionState, ByteConversion, CancelOrderTransaction, CancelOrderTransactionState,
+    ClaimProtocolFeesTransaction, ClaimProtocolFeesTransactionState, CreatePoolTransaction,
+    CreatePoolTransactionState, DepositTokensTransaction, DepositTokensTransactionState,
+    FillOrderTransaction, FillOrderTransactionState, PlaceOrderTransaction,
+    PlaceOrderTransactionState, Pool, ProvideLiquidityTransaction,
+    ProvideLiquidityTransactionState, RejectionReason, RemoveLiquidityTransaction,
+    RemoveLiquidityTransactionState, SellTokensTransaction, SellTokensTransactionState,
+    StatefulTransaction, SwapRouteTransaction, SwapRouteTransactionState, SwapSide, Transaction,
+    TransferTokensTransaction, TransferTokensTransactionState, U256,
 };
+use nacho_write_ahead_log::WriteAheadLog;
+use std::collections::HashSet;
 use tokio::sync::Notify;
 
 pub fn process(
+    analytics: analytics::Processor,
     balances: balances::Processor,
     burns: burns::Processor,
     liquidities: liquidities::Processor,
     mempool: mempool::Processor,
+    observations: observations::Processor,
+    orders: orders::Processor,
     pools: pools::Processor,
     proofpool: proofpool::Processor,
+    snapshots: snapshots::Processor,
     transactions: transactions::Processor,
     verifier: verifier::Processor,
 ) -> Processor {
     let notify: &Notify = Box::leak(Box::new(Notify::new()));
+    let protocol_fee_share = protocol_fee_share();
+    let treasury_address = treasury_address();
+
+    assert!(
+        protocol_fee_share == 0 || treasury_address.is_some(),
+        "NACHO_TREASURY_ADDRESS must be set when NACHO_PROTOCOL_FEE_SHARE isn't zero"
+    );
+
+    let write_ahead_log_path = std::env::var("NACHO_WRITE_AHEAD_LOG_PATH").unwrap();
 
     tokio::spawn(async move {
+        let mut wal = WriteAheadLog::new(write_ahead_log_path).await.unwrap();
+
+        let state_guard = snapshots.hold_state().await;
+
+        if StateBatch::new(balances, burns, liquidities, orders, pools)
+            .recover(&mut wal, proofpool, transactions)
+            .await
+            .is_none()
+        {
+            abort("Couldn't apply the batch in the write-ahead log.");
+        }
+
+        drop(state_guard);
+
+        // The IDs of the orders whose fill transactions are in the mempool.
+        let mut queued_order_ids = queued_order_ids(transactions)
+            .await
+            .expect("Couldn't read the fill transactions in the mempool.");
+
         loop {
-            if let Some(tx) = mempool.pop().await {
-                let tx_result =
-                    execute_tx(tx, verifier, proofpool, balances, pools, liquidities, burns).await;
-
-                if tx_result.is_err() {
-                    if let Some(executed_until) = transactions.get_executed_until().await {
-                        transactions.set_rejected(executed_until).await;
-                    };
-                } else {
-                    if let Some(executed_until) = transactions.get_executed_until().await {
-                        transactions.set_executed_until(executed_until + 1).await;
-                    };
+            let state_guard = snapshots.hold_state().await;
+
+            let executed_until = transactions
+                .get_executed_until()
+                .await
+                .expect("Couldn't read the count of the executed transactions.");
+
+            let processed_until = transactions
+                .get_processed_until()
+                .await
+                .expect("Couldn't read the ID of the next transaction to execute.");
+
+            // NOTE: A transaction is only popped after it is executed or rejected, so it isn't lost if the node stops in between.
+            if let Some((tx_id, tx)) = mempool.peek().await {
+                // A transaction whose batch is committed is left in the mempool if the node stops before popping it.
+                if tx_id < processed_until {
+                    mempool.pop().await;
+
+                    continue;
+                }
+
+                if let Transaction::FillOrder(FillOrderTransaction { order_id }) = &tx {
+                    queued_order_ids.remove(order_id);
+                }
+
+                let pool_token_pairs = pool_token_pairs(&tx, orders).await;
+                let order_token_pairs = order_token_pairs(&tx, &pool_token_pairs);
+                let swapped_pools = swapped_pools_of(&tx, &pool_token_pairs, pools).await;
+
+                let mut batch = StateBatch::new(balances, burns, liquidities, orders, pools);
+
+                let tx_result = execute_tx(
+                    tx,
+                    protocol_fee_share,
+                    treasury_address,
+                    verifier,
+                    &mut batch,
+                )
+                .await;
+
+                let tx_result = match tx_result {
+                    Ok(stateful_tx) => {
+                        // NOTE: The node stops if a batch can be neither applied nor undone, as the next batch would replace it in the log.
+                        match batch
+                            .commit(
+                                stateful_tx,
+                                tx_id,
+                                executed_until,
+                                &mut wal,
+                                proofpool,
+                                transactions,
+                            )
+                            .await
+                        {
+                            Some(tx_result) => tx_result,
+                            None => {
+                                abort("Couldn't apply or undo the batch, it is applied on restart.")
+                            }
+                        }
+                    }
+                    Err(reason) => Err(reason),
+                };
+
+                let is_executed = match tx_result {
+                    Ok(()) => true,
+                    Err(reason) => {
+                        transactions.set_rejected(tx_id, reason).await;
+                        transactions.set_processed_until(tx_id + 1).await;
+
+                        false
+                    }
+                };
+
+                mempool.pop().await;
+
+                if is_executed {
+                    for pool_before in swapped_pools {
+                        let pool_after = pools
+                            .get_pool(
+                                pool_before.base_token_id.clone(),
+                                pool_before.quote_token_id.clone(),
+                            )
+                            .await;
+
+                        if let Some((side, amount_out)) =
+                            pool_after.and_then(|pool_after| trade_of(&pool_before, &pool_after))
+                        {
+                            analytics.observe_trade(pool_before, side, amount_out).await;
+                        }
+                    }
+
+                    for (token_in_id, token_out_id) in pool_token_pairs {
+                        if let Some(pool) = pools.get_hop_pool(token_in_id, token_out_id).await {
+                            observations.observe_pool(pool).await;
+                        }
+                    }
+
+                    for token_pair in order_token_pairs {
+                        queue_fillable_orders(
+                            token_pair,
+                            protocol_fee_share,
+                            &mut queued_order_ids,
+                            mempool,
+                            orders,
+                            pools,
+                            transactions,
+                        )
+                        .await;
+                    }
                 }
             } else {
+                drop(state_guard);
+
                 notify.notified().await;
             }
         }
@@ -45,21 +186,290 @@ pub fn process(
     Processor { notify }
 }
 
+/// Returns the share of each swap fee in basis points that is accrued as the protocol fee.
+///
+/// It is set with `NACHO_PROTOCOL_FEE_SHARE` and defaults to zero, which disables protocol fees.
+pub fn protocol_fee_share() -> u64 {
+    let protocol_fee_share = std::env::var("NACHO_PROTOCOL_FEE_SHARE")
+        .map(|share| share.parse().unwrap())
+        .unwrap_or(0);
+
+    assert!(
+        protocol_fee_share <= Pool::MAX_PROTOCOL_FEE_SHARE,
+        "NACHO_PROTOCOL_FEE_SHARE can't be greater than {}",
+        Pool::MAX_PROTOCOL_FEE_SHARE
+    );
+
+    protocol_fee_share
+}
+
+/// Returns the address of the treasury, which is the only user that can claim the protocol fees accrued in the pools.
+///
+/// It is set with `NACHO_TREASURY_ADDRESS`, protocol fees can't be claimed when it isn't set.
+pub fn treasury_address() -> Option<&'static Address> {
+    let treasury_address = std::env::var("NACHO_TREASURY_ADDRESS").ok()?;
+
+    assert!(
+        Address::is_valid(&treasury_address),
+        "NACHO_TREASURY_ADDRESS must be a valid address"
+    );
+
+    let bytes = treasury_address.as_bytes().try_into().unwrap();
+
+    Some(Box::leak(Box::new(Address::from_bytes(bytes))))
+}
+
+/// Returns the token pairs of the pools whose reserves the given transaction changes.
+async fn pool_token_pairs(tx: &Transaction, orders: orders::Processor) -> Vec<(U256, U256)> {
+    match tx {
+        Transaction::CreatePool(CreatePoolTransaction {
+            base_token_id,
+            quote_token_id,
+            ..
+        })
+        | Transaction::ProvideLiquidity(ProvideLiquidityTransaction {
+            base_token_id,
+            quote_token_id,
+            ..
+        })
+        | Transaction::RemoveLiquidity(RemoveLiquidityTransaction {
+            base_token_id,
+            quote_token_id,
+            ..
+        })
+        | Transaction::BuyTokens(BuyTokensTransaction {
+            base_token_id,
+            quote_token_id,
+            ..
+        })
+        | Transaction::SellTokens(SellTokensTransaction {
+            base_token_id,
+            quote_token_id,
+            ..
+        }) => vec![(base_token_id.clone(), quote_token_id.clone())],
+        Transaction::SwapRoute(SwapRouteTransaction { path, .. }) => vec![
+            (path[0].clone(), path[1].clone()),
+            (path[1].clone(), path[2].clone()),
+        ],
+        Transaction::FillOrder(FillOrderTransaction { order_id }) => {
+            match orders.get_order(*order_id).await {
+                Some(order) => vec![(order.token_in_id, order.token_out_id)],
+                None => vec![],
+            }
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the pools the given transaction swaps in, as they are before the swap, if it is a swap recorded in pool analytics.
+///
+/// Each hop of a swap route and the pool an order is filled against are recorded as separate trades.
+async fn swapped_pools_of(
+    tx: &Transaction,
+    pool_token_pairs: &[(U256, U256)],
+    pools: pools::Processor,
+) -> Vec<Pool> {
+    match tx {
+        Transaction::BuyTokens(_)
+        | Transaction::SellTokens(_)
+        | Transaction::SwapRoute(_)
+        | Transaction::FillOrder(_) => (),
+        _ => return vec![],
+    }
+
+    let mut swapped_pools = Vec::with_capacity(pool_token_pairs.len());
+
+    for (token_in_id, token_out_id) in pool_token_pairs {
+        if let Some(pool) = pools
+            .get_hop_pool(token_in_id.clone(), token_out_id.clone())
+            .await
+        {
+            swapped_pools.push(pool);
+        }
+    }
+
+    swapped_pools
+}
+
+/// Returns the side and the amount bought of the swap that changed the given pool's reserves, if it did.
+///
+/// Swap fees are paid in the token sold, so the reserve of the token bought decreases by exactly the amount bought.
+fn trade_of(pool_before: &Pool, pool_after: &Pool) -> Option<(SwapSide, u64)> {
+    if pool_after.base_token_amount < pool_before.base_token_amount {
+        Some((
+            SwapSide::Buy,
+            pool_before.base_token_amount - pool_after.base_token_amount,
+        ))
+    } else if pool_after.quote_token_amount < pool_before.quote_token_amount {
+        Some((
+            SwapSide::Sell,
+            pool_before.quote_token_amount - pool_after.quote_token_amount,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Returns the token pairs whose open orders might become fillable after the given transaction.
+///
+/// These are the pairs of the pools whose reserves the transaction changes, and the pair of a placed order.
+fn order_token_pairs(tx: &Transaction, pool_token_pairs: &[(U256, U256)]) -> Vec<(U256, U256)> {
+    match tx {
+        Transaction::PlaceOrder(PlaceOrderTransaction {
+            token_in_id,
+            token_out_id,
+            ..
+        }) => vec![(token_in_id.clone(), token_out_id.clone())],
+        _ => pool_token_pairs.to_vec(),
+    }
+}
+
+/// Returns the IDs of the orders whose fill transactions are queued but not executed yet.
+///
+/// The mempool is persisted, so they are read from the transactions that are stored but not executed or rejected yet on startup.
+async fn queued_order_ids(transactions: transactions::Processor) -> Option<HashSet<u64>> {
+    let processed_until = transactions.get_processed_until().await?;
+    let total_tx_count = transactions.get_total_tx_count().await?;
+
+    let mut queued_order_ids = HashSet::new();
+
+    for tx_id in processed_until..total_tx_count {
+        if let Transaction::FillOrder(FillOrderTransaction { order_id }) =
+            transactions.get_tx(tx_id).await?
+        {
+            queued_order_ids.insert(order_id);
+        }
+    }
+
+    Some(queued_order_ids)
+}
+
+/// Queues fill transactions for the open orders in both directions of the given pool that its current price satisfies.
+///
+/// Orders are checked oldest first against the pool as it would be after the earlier fills, so a fill doesn't use up the price another one relies on.
+///
+/// Fills are sequencer-originated transactions, so they are added to the mempool like deposits.
+///
+/// Queueing isn't atomic with the transaction that made the orders fillable, as the transactions before a fill in the mempool can still move the price.
+/// A fill is checked again when it is executed, and if it is rejected, the order stays open and is queued again after the next transaction that changes the pool.
+async fn queue_fillable_orders(
+    (token_in_id, token_out_id): (U256, U256),
+    protocol_fee_share: u64,
+    queued_order_ids: &mut HashSet<u64>,
+    mempool: mempool::Processor,
+    orders: orders::Processor,
+    pools: pools::Processor,
+    transactions: transactions::Processor,
+) {
+    let mut pool = match pools
+        .get_hop_pool(token_in_id.clone(), token_out_id.clone())
+        .await
+    {
+        Some(pool) => pool,
+        None => return,
+    };
+
+    let mut open_orders = Vec::new();
+
+    for (order_token_in_id, order_token_out_id) in [
+        (token_in_id.clone(), token_out_id.clone()),
+        (token_out_id, token_in_id),
+    ] {
+        if let Some(orders) = orders
+            .get_open_orders(order_token_in_id, order_token_out_id)
+            .await
+        {
+            open_orders.extend(orders);
+        }
+    }
+
+    open_orders.sort_by_key(|order| order.id);
+
+    for mut order in open_orders {
+        if queued_order_ids.contains(&order.id) || !nacho_executor::is_order_fillable(&order, &pool)
+        {
+            continue;
+        }
+
+        if nacho_executor::fill_order(&mut order, None, &mut pool, protocol_fee_share).is_err() {
+            continue;
+        }
+
+        let transaction = Transaction::FillOrder(FillOrderTransaction { order_id: order.id });
+
+        let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+            Some(tx_id) => tx_id,
+            None => continue,
+        };
+
+        // The fill transactions aren't signed, so they are added to the histories of the owners of the orders they fill.
+        transactions
+            .add_to_history(tx_id, order.owner.clone())
+            .await;
+
+        if mempool.push(tx_id, transaction).await.is_some() {
+            queued_order_ids.insert(order.id);
+        }
+    }
+}
+
+/// Executes the given transaction against the state seen through the given batch and stages its writes in the batch.
+///
+/// It returns the stateful transaction to push to the proofpool, the writes are only applied when the batch is committed.
 pub async fn execute_tx(
     tx: Transaction,
+    protocol_fee_share: u64,
+    treasury_address: Option<&Address>,
     verifier: verifier::Processor,
-    proofpool: proofpool::Processor,
-    balances: balances::Processor,
-    pools: pools::Processor,
-    liquidities: liquidities::Processor,
-    burns: burns::Processor,
-) -> Result<(), ()> {
-    let is_valid = verifier.check_signature(tx.clone()).await.ok_or(())?;
+    batch: &mut StateBatch,
+) -> Result<StatefulTransaction, RejectionReason> {
+    let is_valid = verifier
+        .check_signature(tx.clone())
+        .await
+        .ok_or(RejectionReason::Internal)?;
 
     if !is_valid {
-        return Err(());
+        return Err(RejectionReason::InvalidSignature);
+    }
+
+    if let (Some(address), Some(nonce)) = (tx.address(), tx.nonce()) {
+        let expected_nonce = batch
+            .get_nonce(address.clone())
+            .await
+            .ok_or(RejectionReason::Internal)?;
+
+        if nonce != expected_nonce {
+            return Err(RejectionReason::InvalidNonce);
+        }
     }
 
+    apply_tx(tx, protocol_fee_share, treasury_address, batch).await
+}
+
+/// Applies the given transaction to the state seen through the given batch and stages its writes in the batch.
+///
+/// Its signature and nonce aren't checked, so that the simulator can run unsigned transactions against a batch it never commits.
+pub async fn apply_tx(
+    tx: Transaction,
+    protocol_fee_share: u64,
+    treasury_address: Option<&Address>,
+    batch: &mut StateBatch,
+) -> Result<StatefulTransaction, RejectionReason> {
+    // The state of each transaction is read before it is executed, as its proof starts from the state before it.
+    let mut maybe_deposit_tokens_state = None;
+    let mut maybe_burn_tokens_state = None;
+    let mut maybe_create_pool_state = None;
+    let mut maybe_provide_liquidity_state = None;
+    let mut maybe_remove_liquidity_state = None;
+    let mut maybe_buy_tokens_state = None;
+    let mut maybe_sell_tokens_state = None;
+    let mut maybe_swap_route_state = None;
+    let mut maybe_transfer_tokens_state = None;
+    let mut maybe_place_order_state = None;
+    let mut maybe_cancel_order_state = None;
+    let mut maybe_fill_order_state = None;
+    let mut maybe_claim_protocol_fees_state = None;
+
     match tx.clone() {
         Transaction::CreateGenesis {} => {}
         Transaction::DepositTokens(DepositTokensTransaction {
@@ -67,10 +477,17 @@ pub async fn execute_tx(
             token_id,
             token_amount,
         }) => {
-            let mut maybe_balance = balances
+            let mut maybe_balance = batch
                 .get_balance(user_address.clone(), token_id.clone())
                 .await;
 
+            let deposit_tokens_state = DepositTokensTransactionState {
+                user_token_balance: maybe_balance
+                    .as_ref()
+                    .map(|balance| balance.token_amount)
+                    .unwrap_or(0),
+            };
+
             let amount_to_deposit = token_amount;
 
             let result = nacho_executor::deposit_tokens(
@@ -79,48 +496,76 @@ pub async fn execute_tx(
                 token_id,
                 user_address,
             )
-            .map_err(|_| ())?;
+            .map_err(RejectionReason::from)?;
 
             match (result, maybe_balance) {
                 (Some(balance), None) => {
-                    balances.push_balance(balance).await.ok_or(())?;
+                    batch
+                        .push_balance(balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
                 (None, Some(balance)) => {
-                    balances.update_balance(balance).await.ok_or(())?;
+                    batch
+                        .update_balance(balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
-                _ => return Err(()),
+                _ => return Err(RejectionReason::Internal),
             }
+
+            maybe_deposit_tokens_state = Some(deposit_tokens_state);
         }
         Transaction::BurnTokens(BurnTokensTransaction {
             address,
             signature: _,
             token_id,
             token_amount,
+            nonce: _,
         }) => {
-            let mut balance = balances
+            let mut balance = batch
                 .get_balance(address.clone(), token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-            let mut maybe_burn = burns.get_burn(address.clone(), token_id.clone()).await;
+            let mut maybe_burn = batch.get_burn(address.clone(), token_id.clone()).await;
+
+            let burn_tokens_state = BurnTokensTransactionState {
+                user_balance_token_amount: balance.token_amount,
+                user_burn_token_amount: maybe_burn
+                    .as_ref()
+                    .map(|burn| burn.token_amount)
+                    .unwrap_or(0),
+            };
 
             let amount_to_burn = token_amount;
 
             let result =
                 nacho_executor::burn_tokens(&mut balance, maybe_burn.as_mut(), amount_to_burn)
-                    .map_err(|_| ())?;
+                    .map_err(RejectionReason::from)?;
 
-            balances.update_balance(balance).await.ok_or(())?;
+            batch
+                .update_balance(balance)
+                .await
+                .ok_or(RejectionReason::Internal)?;
 
             match (result, maybe_burn) {
                 (Some(burn), None) => {
-                    burns.push_burn(burn).await.ok_or(())?;
+                    batch
+                        .push_burn(burn)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
                 (None, Some(burn)) => {
-                    burns.update_burn(burn).await.ok_or(())?;
+                    batch
+                        .update_burn(burn)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
-                _ => return Err(()),
+                _ => return Err(RejectionReason::Internal),
             }
+
+            maybe_burn_tokens_state = Some(burn_tokens_state);
         }
         Transaction::CreatePool(CreatePoolTransaction {
             address,
@@ -129,46 +574,66 @@ pub async fn execute_tx(
             quote_token_id,
             base_token_amount,
             quote_token_amount,
+            fee_rate,
+            pool_kind,
+            amplification,
+            nonce: _,
         }) => {
-            let is_pool_exists = pools
+            let is_pool_exists = batch
                 .get_pool(base_token_id.clone(), quote_token_id.clone())
                 .await
                 .is_some();
 
             if is_pool_exists {
-                return Err(());
+                return Err(RejectionReason::PoolAlreadyExists);
             }
 
-            let mut base_token_balance = balances
+            let mut base_token_balance = batch
                 .get_balance(address.clone(), base_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-            let mut quote_token_balance = balances
+            let mut quote_token_balance = batch
                 .get_balance(address.clone(), quote_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
+
+            let create_pool_state = CreatePoolTransactionState {
+                user_balance_base_token_amount: base_token_balance.token_amount,
+                user_balance_quote_token_amount: quote_token_balance.token_amount,
+            };
 
             let (pool, liquidity) = nacho_executor::create_pool(
                 &mut base_token_balance,
                 &mut quote_token_balance,
                 base_token_amount,
                 quote_token_amount,
+                fee_rate,
+                pool_kind,
+                amplification,
             )
-            .map_err(|_| ())?;
+            .map_err(RejectionReason::from)?;
 
-            balances
+            batch
                 .update_balance(base_token_balance)
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::Internal)?;
 
-            balances
+            batch
                 .update_balance(quote_token_balance)
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::Internal)?;
 
-            liquidities.push_liquidity(liquidity).await.ok_or(())?;
-            pools.push_pool(pool).await.ok_or(())?;
+            batch
+                .push_liquidity(liquidity)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+            batch
+                .push_pool(pool)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            maybe_create_pool_state = Some(create_pool_state);
         }
         Transaction::ProvideLiquidity(ProvideLiquidityTransaction {
             address,
@@ -177,23 +642,24 @@ pub async fn execute_tx(
             quote_token_id,
             base_token_amount,
             quote_token_amount_limit,
+            nonce: _,
         }) => {
-            let mut base_token_balance = balances
+            let mut base_token_balance = batch
                 .get_balance(address.clone(), base_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-            let mut quote_token_balance = balances
+            let mut quote_token_balance = batch
                 .get_balance(address.clone(), quote_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-            let mut pool = pools
+            let mut pool = batch
                 .get_pool(base_token_id.clone(), quote_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::PoolDoesntExist)?;
 
-            let mut maybe_liquidity = liquidities
+            let mut maybe_liquidity = batch
                 .get_liquidity(
                     address.clone(),
                     base_token_id.clone(),
@@ -201,6 +667,22 @@ pub async fn execute_tx(
                 )
                 .await;
 
+            let provide_liquidity_state = ProvideLiquidityTransactionState {
+                user_liquidity_points: maybe_liquidity
+                    .as_ref()
+                    .map(|liquidity| liquidity.points.clone())
+                    .unwrap_or(0u64.into()),
+                user_balance_base_token_amount: base_token_balance.token_amount,
+                user_balance_quote_token_amount: quote_token_balance.token_amount,
+                pool_base_token_amount: pool.base_token_amount,
+                pool_quote_token_amount: pool.quote_token_amount,
+                pool_total_liquidity_points: pool.total_liqudity_points.clone(),
+                pool_fee_rate: pool.fee_rate,
+                pool_protocol_fee_base_token_amount: pool.protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount: pool.protocol_fee_quote_token_amount,
+                pool_amplification: pool.amplification,
+            };
+
             let result = nacho_executor::provide_liquidity(
                 &mut base_token_balance,
                 &mut quote_token_balance,
@@ -209,29 +691,40 @@ pub async fn execute_tx(
                 base_token_amount,
                 quote_token_amount_limit,
             )
-            .map_err(|_| ())?;
+            .map_err(RejectionReason::from)?;
 
-            balances
+            batch
                 .update_balance(base_token_balance)
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::Internal)?;
 
-            balances
+            batch
                 .update_balance(quote_token_balance)
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::Internal)?;
 
-            pools.update_pool(pool).await.ok_or(())?;
+            batch
+                .update_pool(pool)
+                .await
+                .ok_or(RejectionReason::Internal)?;
 
             match (result, maybe_liquidity) {
                 (Some(liquidity), None) => {
-                    liquidities.push_liquidity(liquidity).await.ok_or(())?;
+                    batch
+                        .push_liquidity(liquidity)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
                 (None, Some(liquidity)) => {
-                    liquidities.update_liquidity(liquidity).await.ok_or(())?;
+                    batch
+                        .update_liquidity(liquidity)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
-                _ => return Err(()),
+                _ => return Err(RejectionReason::Internal),
             }
+
+            maybe_provide_liquidity_state = Some(provide_liquidity_state);
         }
         Transaction::RemoveLiquidity(RemoveLiquidityTransaction {
             address,
@@ -241,30 +734,44 @@ pub async fn execute_tx(
             base_token_amount_limit,
             quote_token_amount_limit,
             points,
+            nonce: _,
         }) => {
-            let mut base_token_balance = balances
+            let mut base_token_balance = batch
                 .get_balance(address.clone(), base_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-            let mut quote_token_balance = balances
+            let mut quote_token_balance = batch
                 .get_balance(address.clone(), quote_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-            let mut pool = pools
+            let mut pool = batch
                 .get_pool(base_token_id.clone(), quote_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::PoolDoesntExist)?;
 
-            let mut liquidity = liquidities
+            let mut liquidity = batch
                 .get_liquidity(
                     address.clone(),
                     base_token_id.clone(),
                     quote_token_id.clone(),
                 )
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::LiquidityDoesntExist)?;
+
+            let remove_liquidity_state = RemoveLiquidityTransactionState {
+                user_liquidity_points: liquidity.points.clone(),
+                user_balance_base_token_amount: base_token_balance.token_amount,
+                user_balance_quote_token_amount: quote_token_balance.token_amount,
+                pool_base_token_amount: pool.base_token_amount,
+                pool_quote_token_amount: pool.quote_token_amount,
+                pool_total_liquidity_points: pool.total_liqudity_points.clone(),
+                pool_fee_rate: pool.fee_rate,
+                pool_protocol_fee_base_token_amount: pool.protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount: pool.protocol_fee_quote_token_amount,
+                pool_amplification: pool.amplification,
+            };
 
             nacho_executor::remove_liquidity(
                 &mut base_token_balance,
@@ -275,21 +782,29 @@ pub async fn execute_tx(
                 base_token_amount_limit,
                 quote_token_amount_limit,
             )
-            .map_err(|_| ())?;
+            .map_err(RejectionReason::from)?;
 
-            balances
+            batch
                 .update_balance(base_token_balance)
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::Internal)?;
 
-            balances
+            batch
                 .update_balance(quote_token_balance)
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::Internal)?;
 
-            pools.update_pool(pool).await.ok_or(())?;
+            batch
+                .update_pool(pool)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            batch
+                .update_liquidity(liquidity)
+                .await
+                .ok_or(RejectionReason::Internal)?;
 
-            liquidities.update_liquidity(liquidity).await.ok_or(())?;
+            maybe_remove_liquidity_state = Some(remove_liquidity_state);
         }
         Transaction::BuyTokens(BuyTokensTransaction {
             address,
@@ -298,20 +813,37 @@ pub async fn execute_tx(
             quote_token_id,
             base_token_amount,
             quote_token_amount_limit,
+            nonce: _,
         }) => {
-            let mut maybe_base_token_balance = balances
+            let mut maybe_base_token_balance = batch
                 .get_balance(address.clone(), base_token_id.clone())
                 .await;
 
-            let mut quote_token_balance = balances
+            let mut quote_token_balance = batch
                 .get_balance(address.clone(), quote_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-            let mut pool = pools
+            let mut pool = batch
                 .get_pool(base_token_id.clone(), quote_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::PoolDoesntExist)?;
+
+            let buy_tokens_state = BuyTokensTransactionState {
+                user_balance_base_token_amount: maybe_base_token_balance
+                    .as_ref()
+                    .map(|balance| balance.token_amount)
+                    .unwrap_or(0),
+                user_balance_quote_token_amount: quote_token_balance.token_amount,
+                pool_base_token_amount: pool.base_token_amount,
+                pool_quote_token_amount: pool.quote_token_amount,
+                pool_total_liquidity_points: pool.total_liqudity_points.clone(),
+                pool_fee_rate: pool.fee_rate,
+                pool_protocol_fee_base_token_amount: pool.protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount: pool.protocol_fee_quote_token_amount,
+                pool_amplification: pool.amplification,
+                protocol_fee_share,
+            };
 
             let result = nacho_executor::buy_tokens(
                 base_token_id,
@@ -320,28 +852,37 @@ pub async fn execute_tx(
                 &mut pool,
                 base_token_amount,
                 quote_token_amount_limit,
+                protocol_fee_share,
             )
-            .map_err(|_| ())?;
+            .map_err(RejectionReason::from)?;
 
-            balances
+            batch
                 .update_balance(quote_token_balance)
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::Internal)?;
 
-            pools.update_pool(pool).await.ok_or(())?;
+            batch
+                .update_pool(pool)
+                .await
+                .ok_or(RejectionReason::Internal)?;
 
             match (result, maybe_base_token_balance) {
                 (Some(base_token_balance), None) => {
-                    balances.push_balance(base_token_balance).await.ok_or(())?;
+                    batch
+                        .push_balance(base_token_balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
                 (None, Some(base_token_balance)) => {
-                    balances
+                    batch
                         .update_balance(base_token_balance)
                         .await
-                        .ok_or(())?;
+                        .ok_or(RejectionReason::Internal)?;
                 }
-                _ => return Err(()),
+                _ => return Err(RejectionReason::Internal),
             }
+
+            maybe_buy_tokens_state = Some(buy_tokens_state);
         }
         Transaction::SellTokens(SellTokensTransaction {
             address,
@@ -350,20 +891,37 @@ pub async fn execute_tx(
             quote_token_id,
             base_token_amount_limit,
             quote_token_amount,
+            nonce: _,
         }) => {
-            let mut base_token_balance = balances
+            let mut base_token_balance = batch
                 .get_balance(address.clone(), base_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-            let mut maybe_quote_token_balance = balances
+            let mut maybe_quote_token_balance = batch
                 .get_balance(address.clone(), quote_token_id.clone())
                 .await;
 
-            let mut pool = pools
+            let mut pool = batch
                 .get_pool(base_token_id.clone(), quote_token_id.clone())
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::PoolDoesntExist)?;
+
+            let sell_tokens_state = SellTokensTransactionState {
+                user_balance_base_token_amount: base_token_balance.token_amount,
+                user_balance_quote_token_amount: maybe_quote_token_balance
+                    .as_ref()
+                    .map(|balance| balance.token_amount)
+                    .unwrap_or(0),
+                pool_base_token_amount: pool.base_token_amount,
+                pool_quote_token_amount: pool.quote_token_amount,
+                pool_total_liquidity_points: pool.total_liqudity_points.clone(),
+                pool_fee_rate: pool.fee_rate,
+                pool_protocol_fee_base_token_amount: pool.protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount: pool.protocol_fee_quote_token_amount,
+                pool_amplification: pool.amplification,
+                protocol_fee_share,
+            };
 
             let result = nacho_executor::sell_tokens(
                 base_token_id,
@@ -372,273 +930,451 @@ pub async fn execute_tx(
                 &mut pool,
                 base_token_amount_limit,
                 quote_token_amount,
+                protocol_fee_share,
             )
-            .map_err(|_| ())?;
+            .map_err(RejectionReason::from)?;
 
-            balances
+            batch
                 .update_balance(base_token_balance)
                 .await
-                .ok_or(())?;
+                .ok_or(RejectionReason::Internal)?;
 
-            pools.update_pool(pool).await.ok_or(())?;
+            batch
+                .update_pool(pool)
+                .await
+                .ok_or(RejectionReason::Internal)?;
 
             match (result, maybe_quote_token_balance) {
                 (Some(quote_token_balance), None) => {
-                    balances.push_balance(quote_token_balance).await.ok_or(())?;
+                    batch
+                        .push_balance(quote_token_balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
                 (None, Some(quote_token_balance)) => {
-                    balances
+                    batch
                         .update_balance(quote_token_balance)
                         .await
-                        .ok_or(())?;
+                        .ok_or(RejectionReason::Internal)?;
                 }
-                _ => return Err(()),
+                _ => return Err(RejectionReason::Internal),
             }
+
+            maybe_sell_tokens_state = Some(sell_tokens_state);
         }
-    }
+        Transaction::SwapRoute(SwapRouteTransaction {
+            address,
+            signature: _,
+            path,
+            token_amount_in,
+            token_amount_out_limit,
+            nonce: _,
+        }) => {
+            let mut token_in_balance = batch
+                .get_balance(address.clone(), path[0].clone())
+                .await
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-    proofpool
-        .push(match tx {
-            Transaction::CreateGenesis() => StatefulTransaction::CreateGenesis {
-                transaction: (),
-                state: (),
-            },
-            Transaction::DepositTokens(deposit_token_tx) => {
-                let maybe_balance = balances
-                    .get_balance(
-                        deposit_token_tx.user_address.clone(),
-                        deposit_token_tx.token_id.clone(),
-                    )
-                    .await;
-
-                StatefulTransaction::DepositTokens {
-                    transaction: deposit_token_tx,
-                    state: DepositTokensTransactionState {
-                        user_token_balance: maybe_balance
-                            .map(|balance| balance.token_amount)
-                            .unwrap_or(0),
-                    },
-                }
-            }
-            Transaction::BurnTokens(burn_tokens_tx) => {
-                let balance = balances
-                    .get_balance(
-                        burn_tokens_tx.address.clone(),
-                        burn_tokens_tx.token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
-
-                let maybe_burn = burns
-                    .get_burn(
-                        burn_tokens_tx.address.clone(),
-                        burn_tokens_tx.token_id.clone(),
-                    )
-                    .await;
-
-                StatefulTransaction::BurnTokens {
-                    transaction: burn_tokens_tx,
-                    state: BurnTokensTransactionState {
-                        user_balance_token_amount: balance.token_amount,
-                        user_burn_token_amount: maybe_burn
-                            .map(|burn| burn.token_amount)
-                            .unwrap_or(0),
-                    },
-                }
-            }
-            Transaction::CreatePool(create_pool_tx) => {
-                let base_token_balance = balances
-                    .get_balance(
-                        create_pool_tx.address.clone(),
-                        create_pool_tx.base_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
+            let mut maybe_token_out_balance =
+                batch.get_balance(address.clone(), path[2].clone()).await;
 
-                let quote_token_balance = balances
-                    .get_balance(
-                        create_pool_tx.address.clone(),
-                        create_pool_tx.quote_token_id.clone(),
-                    )
+            let mut route_pools = vec![
+                batch
+                    .get_hop_pool(path[0].clone(), path[1].clone())
                     .await
-                    .ok_or(())?;
-
-                StatefulTransaction::CreatePool {
-                    transaction: create_pool_tx,
-                    state: CreatePoolTransactionState {
-                        user_balance_base_token_amount: base_token_balance.token_amount,
-                        user_balance_quote_token_amount: quote_token_balance.token_amount,
-                    },
-                }
-            }
-            Transaction::ProvideLiquidity(provide_liquidity_tx) => {
-                let maybe_liquidity = liquidities
-                    .get_liquidity(
-                        provide_liquidity_tx.address.clone(),
-                        provide_liquidity_tx.base_token_id.clone(),
-                        provide_liquidity_tx.quote_token_id.clone(),
-                    )
-                    .await;
-
-                let base_token_balance = balances
-                    .get_balance(
-                        provide_liquidity_tx.address.clone(),
-                        provide_liquidity_tx.base_token_id.clone(),
-                    )
+                    .ok_or(RejectionReason::PoolDoesntExist)?,
+                batch
+                    .get_hop_pool(path[1].clone(), path[2].clone())
                     .await
-                    .ok_or(())?;
+                    .ok_or(RejectionReason::PoolDoesntExist)?,
+            ];
+
+            let swap_route_state = SwapRouteTransactionState {
+                user_balance_token_in_amount: token_in_balance.token_amount,
+                user_balance_token_out_amount: maybe_token_out_balance
+                    .as_ref()
+                    .map(|balance| balance.token_amount)
+                    .unwrap_or(0),
+                first_pool: route_pools[0].clone(),
+                second_pool: route_pools[1].clone(),
+                protocol_fee_share,
+            };
+
+            let result = nacho_executor::swap_route(
+                &path,
+                &mut token_in_balance,
+                maybe_token_out_balance.as_mut(),
+                &mut route_pools,
+                token_amount_in,
+                token_amount_out_limit,
+                protocol_fee_share,
+            )
+            .map_err(RejectionReason::from)?;
 
-                let quote_token_balance = balances
-                    .get_balance(
-                        provide_liquidity_tx.address.clone(),
-                        provide_liquidity_tx.base_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
+            batch
+                .update_balance(token_in_balance)
+                .await
+                .ok_or(RejectionReason::Internal)?;
 
-                let pool = pools
-                    .get_pool(
-                        provide_liquidity_tx.base_token_id.clone(),
-                        provide_liquidity_tx.quote_token_id.clone(),
-                    )
+            for pool in route_pools {
+                batch
+                    .update_pool(pool)
                     .await
-                    .ok_or(())?;
-
-                StatefulTransaction::ProvideLiquidity {
-                    transaction: provide_liquidity_tx,
-                    state: ProvideLiquidityTransactionState {
-                        user_liquidity_points: maybe_liquidity
-                            .map(|liquidity| liquidity.points)
-                            .unwrap_or(0u64.into()),
-                        user_balance_base_token_amount: base_token_balance.token_amount,
-                        user_balance_quote_token_amount: quote_token_balance.token_amount,
-                        pool_base_token_amount: pool.base_token_amount,
-                        pool_quote_token_amount: pool.quote_token_amount,
-                        pool_total_liquidity_points: pool.total_liqudity_points,
-                    },
+                    .ok_or(RejectionReason::Internal)?;
+            }
+
+            match (result, maybe_token_out_balance) {
+                (Some(token_out_balance), None) => {
+                    batch
+                        .push_balance(token_out_balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
+                }
+                (None, Some(token_out_balance)) => {
+                    batch
+                        .update_balance(token_out_balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
+                _ => return Err(RejectionReason::Internal),
             }
-            Transaction::RemoveLiquidity(remove_liquidity_tx) => {
-                let liquidity = liquidities
-                    .get_liquidity(
-                        remove_liquidity_tx.address.clone(),
-                        remove_liquidity_tx.base_token_id.clone(),
-                        remove_liquidity_tx.quote_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
 
-                let base_token_balance = balances
-                    .get_balance(
-                        remove_liquidity_tx.address.clone(),
-                        remove_liquidity_tx.base_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
+            maybe_swap_route_state = Some(swap_route_state);
+        }
+        Transaction::TransferTokens(TransferTokensTransaction {
+            address,
+            signature: _,
+            receiver_address,
+            token_id,
+            token_amount,
+            nonce: _,
+        }) => {
+            let mut balance = batch
+                .get_balance(address.clone(), token_id.clone())
+                .await
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-                let quote_token_balance = balances
-                    .get_balance(
-                        remove_liquidity_tx.address.clone(),
-                        remove_liquidity_tx.base_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
+            let mut maybe_receiver_balance = batch
+                .get_balance(receiver_address.clone(), token_id.clone())
+                .await;
 
-                let pool = pools
-                    .get_pool(
-                        remove_liquidity_tx.base_token_id.clone(),
-                        remove_liquidity_tx.quote_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
-
-                StatefulTransaction::RemoveLiquidity {
-                    transaction: remove_liquidity_tx,
-                    state: RemoveLiquidityTransactionState {
-                        user_liquidity_points: liquidity.points,
-                        user_balance_base_token_amount: base_token_balance.token_amount,
-                        user_balance_quote_token_amount: quote_token_balance.token_amount,
-                        pool_base_token_amount: pool.base_token_amount,
-                        pool_quote_token_amount: pool.quote_token_amount,
-                        pool_total_liquidity_points: pool.total_liqudity_points,
-                    },
+            let transfer_tokens_state = TransferTokensTransactionState {
+                user_balance_token_amount: balance.token_amount,
+                receiver_balance_token_amount: maybe_receiver_balance
+                    .as_ref()
+                    .map(|balance| balance.token_amount)
+                    .unwrap_or(0),
+            };
+
+            let amount_to_transfer = token_amount;
+
+            let result = nacho_executor::transfer_tokens(
+                &mut balance,
+                maybe_receiver_balance.as_mut(),
+                &receiver_address,
+                amount_to_transfer,
+            )
+            .map_err(RejectionReason::from)?;
+
+            batch
+                .update_balance(balance)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            match (result, maybe_receiver_balance) {
+                (Some(receiver_balance), None) => {
+                    batch
+                        .push_balance(receiver_balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
+                }
+                (None, Some(receiver_balance)) => {
+                    batch
+                        .update_balance(receiver_balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
+                _ => return Err(RejectionReason::Internal),
             }
-            Transaction::BuyTokens(buy_tokens_tx) => {
-                let base_token_balance = balances
-                    .get_balance(
-                        buy_tokens_tx.address.clone(),
-                        buy_tokens_tx.base_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
 
-                let quote_token_balance = balances
-                    .get_balance(
-                        buy_tokens_tx.address.clone(),
-                        buy_tokens_tx.base_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
+            maybe_transfer_tokens_state = Some(transfer_tokens_state);
+        }
+        Transaction::PlaceOrder(PlaceOrderTransaction {
+            address,
+            signature: _,
+            token_in_id,
+            token_out_id,
+            token_amount_in,
+            token_amount_out_limit,
+            nonce: _,
+        }) => {
+            let mut token_in_balance = batch
+                .get_balance(address.clone(), token_in_id.clone())
+                .await
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
 
-                let pool = pools
-                    .get_pool(
-                        buy_tokens_tx.base_token_id.clone(),
-                        buy_tokens_tx.quote_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
-
-                StatefulTransaction::BuyTokens {
-                    transaction: buy_tokens_tx,
-                    state: BuyTokensTransactionState {
-                        user_balance_base_token_amount: base_token_balance.token_amount,
-                        user_balance_quote_token_amount: quote_token_balance.token_amount,
-                        pool_base_token_amount: pool.base_token_amount,
-                        pool_quote_token_amount: pool.quote_token_amount,
-                        pool_total_liquidity_points: pool.total_liqudity_points,
-                    },
-                }
+            batch
+                .get_hop_pool(token_in_id, token_out_id.clone())
+                .await
+                .ok_or(RejectionReason::PoolDoesntExist)?;
+
+            let order_id = batch
+                .get_next_order_id()
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            let place_order_state = PlaceOrderTransactionState {
+                user_balance_token_in_amount: token_in_balance.token_amount,
+                order_id,
+            };
+
+            let order = nacho_executor::place_order(
+                &mut token_in_balance,
+                order_id,
+                &token_out_id,
+                token_amount_in,
+                token_amount_out_limit,
+            )
+            .map_err(RejectionReason::from)?;
+
+            batch
+                .update_balance(token_in_balance)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            batch
+                .push_order(order)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            maybe_place_order_state = Some(place_order_state);
+        }
+        Transaction::CancelOrder(CancelOrderTransaction {
+            address,
+            signature: _,
+            order_id,
+            nonce: _,
+        }) => {
+            let mut order = batch
+                .get_order(order_id)
+                .await
+                .ok_or(RejectionReason::OrderDoesntExist)?;
+
+            if order.owner != address {
+                return Err(RejectionReason::NotOrderOwner);
             }
-            Transaction::SellTokens(sell_tokens_tx) => {
-                let base_token_balance = balances
-                    .get_balance(
-                        sell_tokens_tx.address.clone(),
-                        sell_tokens_tx.base_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
 
-                let quote_token_balance = balances
-                    .get_balance(
-                        sell_tokens_tx.address.clone(),
-                        sell_tokens_tx.base_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
+            let mut token_in_balance = batch
+                .get_balance(address.clone(), order.token_in_id.clone())
+                .await
+                .ok_or(RejectionReason::Internal)?;
 
-                let pool = pools
-                    .get_pool(
-                        sell_tokens_tx.base_token_id.clone(),
-                        sell_tokens_tx.quote_token_id.clone(),
-                    )
-                    .await
-                    .ok_or(())?;
-
-                StatefulTransaction::SellTokens {
-                    transaction: sell_tokens_tx,
-                    state: SellTokensTransactionState {
-                        user_balance_base_token_amount: base_token_balance.token_amount,
-                        user_balance_quote_token_amount: quote_token_balance.token_amount,
-                        pool_base_token_amount: pool.base_token_amount,
-                        pool_quote_token_amount: pool.quote_token_amount,
-                        pool_total_liquidity_points: pool.total_liqudity_points,
-                    },
+            let cancel_order_state = CancelOrderTransactionState {
+                user_balance_token_in_amount: token_in_balance.token_amount,
+                order: order.clone(),
+            };
+
+            nacho_executor::cancel_order(&mut order, &mut token_in_balance)
+                .map_err(RejectionReason::from)?;
+
+            batch
+                .update_balance(token_in_balance)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            batch
+                .update_order(order)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            maybe_cancel_order_state = Some(cancel_order_state);
+        }
+        Transaction::FillOrder(FillOrderTransaction { order_id }) => {
+            let mut order = batch
+                .get_order(order_id)
+                .await
+                .ok_or(RejectionReason::OrderDoesntExist)?;
+
+            let mut maybe_token_out_balance = batch
+                .get_balance(order.owner.clone(), order.token_out_id.clone())
+                .await;
+
+            let mut pool = batch
+                .get_hop_pool(order.token_in_id.clone(), order.token_out_id.clone())
+                .await
+                .ok_or(RejectionReason::PoolDoesntExist)?;
+
+            let fill_order_state = FillOrderTransactionState {
+                user_balance_token_out_amount: maybe_token_out_balance
+                    .as_ref()
+                    .map(|balance| balance.token_amount)
+                    .unwrap_or(0),
+                order: order.clone(),
+                pool: pool.clone(),
+                protocol_fee_share,
+            };
+
+            let result = nacho_executor::fill_order(
+                &mut order,
+                maybe_token_out_balance.as_mut(),
+                &mut pool,
+                protocol_fee_share,
+            )
+            .map_err(RejectionReason::from)?;
+
+            batch
+                .update_pool(pool)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            batch
+                .update_order(order)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            match (result, maybe_token_out_balance) {
+                (Some(token_out_balance), None) => {
+                    batch
+                        .push_balance(token_out_balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
+                }
+                (None, Some(token_out_balance)) => {
+                    batch
+                        .update_balance(token_out_balance)
+                        .await
+                        .ok_or(RejectionReason::Internal)?;
                 }
+                _ => return Err(RejectionReason::Internal),
+            }
+
+            maybe_fill_order_state = Some(fill_order_state);
+        }
+        Transaction::ClaimProtocolFees(ClaimProtocolFeesTransaction {
+            address,
+            signature: _,
+            base_token_id,
+            quote_token_id,
+            nonce: _,
+        }) => {
+            if treasury_address != Some(&address) {
+                return Err(RejectionReason::NotTreasury);
             }
-        })
-        .await
-        .ok_or(())?;
 
-    Ok(())
+            let mut pool = batch
+                .get_pool(base_token_id.clone(), quote_token_id.clone())
+                .await
+                .ok_or(RejectionReason::PoolDoesntExist)?;
+
+            let mut base_token_balance = batch
+                .get_balance(address.clone(), base_token_id)
+                .await
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
+
+            let mut quote_token_balance = batch
+                .get_balance(address, quote_token_id)
+                .await
+                .ok_or(RejectionReason::BalanceDoesntExist)?;
+
+            let claim_protocol_fees_state = ClaimProtocolFeesTransactionState {
+                user_balance_base_token_amount: base_token_balance.token_amount,
+                user_balance_quote_token_amount: quote_token_balance.token_amount,
+                pool: pool.clone(),
+            };
+
+            nacho_executor::claim_protocol_fees(
+                &mut base_token_balance,
+                &mut quote_token_balance,
+                &mut pool,
+            )
+            .map_err(RejectionReason::from)?;
+
+            batch
+                .update_balance(base_token_balance)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            batch
+                .update_balance(quote_token_balance)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            batch
+                .update_pool(pool)
+                .await
+                .ok_or(RejectionReason::Internal)?;
+
+            maybe_claim_protocol_fees_state = Some(claim_protocol_fees_state);
+        }
+    }
+
+    if let (Some(address), Some(_)) = (tx.address(), tx.nonce()) {
+        batch
+            .increment_nonce(address.clone())
+            .await
+            .ok_or(RejectionReason::Internal)?;
+    }
+
+    Ok(match tx {
+        Transaction::CreateGenesis() => StatefulTransaction::CreateGenesis {
+            transaction: (),
+            state: (),
+        },
+        Transaction::DepositTokens(deposit_tokens_tx) => StatefulTransaction::DepositTokens {
+            transaction: deposit_tokens_tx,
+            state: maybe_deposit_tokens_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::BurnTokens(burn_tokens_tx) => StatefulTransaction::BurnTokens {
+            transaction: burn_tokens_tx,
+            state: maybe_burn_tokens_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::CreatePool(create_pool_tx) => StatefulTransaction::CreatePool {
+            transaction: create_pool_tx,
+            state: maybe_create_pool_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::ProvideLiquidity(provide_liquidity_tx) => {
+            StatefulTransaction::ProvideLiquidity {
+                transaction: provide_liquidity_tx,
+                state: maybe_provide_liquidity_state.ok_or(RejectionReason::Internal)?,
+            }
+        }
+        Transaction::RemoveLiquidity(remove_liquidity_tx) => StatefulTransaction::RemoveLiquidity {
+            transaction: remove_liquidity_tx,
+            state: maybe_remove_liquidity_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::BuyTokens(buy_tokens_tx) => StatefulTransaction::BuyTokens {
+            transaction: buy_tokens_tx,
+            state: maybe_buy_tokens_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::SellTokens(sell_tokens_tx) => StatefulTransaction::SellTokens {
+            transaction: sell_tokens_tx,
+            state: maybe_sell_tokens_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::SwapRoute(swap_route_tx) => StatefulTransaction::SwapRoute {
+            transaction: swap_route_tx,
+            state: maybe_swap_route_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::TransferTokens(transfer_tokens_tx) => StatefulTransaction::TransferTokens {
+            transaction: transfer_tokens_tx,
+            state: maybe_transfer_tokens_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::PlaceOrder(place_order_tx) => StatefulTransaction::PlaceOrder {
+            transaction: place_order_tx,
+            state: maybe_place_order_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::CancelOrder(cancel_order_tx) => StatefulTransaction::CancelOrder {
+            transaction: cancel_order_tx,
+            state: maybe_cancel_order_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::FillOrder(fill_order_tx) => StatefulTransaction::FillOrder {
+            transaction: fill_order_tx,
+            state: maybe_fill_order_state.ok_or(RejectionReason::Internal)?,
+        },
+        Transaction::ClaimProtocolFees(claim_protocol_fees_tx) => {
+            StatefulTransaction::ClaimProtocolFees {
+                transaction: claim_protocol_fees_tx,
+                state: maybe_claim_protocol_fees_state.ok_or(RejectionReason::Internal)?,
+            }
+        }
+    })
 }
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use super::Processor;
-use crate::{burns, executor, generator, mempool, transactions, withdrawals};
+use crate::{burns, executor, generator, mempool, snapshots, transactions, withdrawals};
 use nacho_data_structures::{
     ByteConversion, Deposit, DepositTokensTransaction, Transaction, Withdrawal,
 };
@@ -16,6 +16,7 @@ pub fn process(
     executor: executor::Processor,
     generator: generator::Processor,
     mempool: mempool::Processor,
+    snapshots: snapshots::Processor,
     transactions: transactions::Processor,
     withdrawals: withdrawals::Processor,
 ) -> Processor {
@@ -37,7 +38,15 @@ pub fn process(
                     Err(_) => continue,
                 };
 
-            match fetch_deposited_events(stdin, stdout, from_block_deposited).await {
+            let maybe_deposited_events =
+                fetch_deposited_events(stdin, stdout, from_block_deposited).await;
+            let maybe_withdrawn_events =
+                fetch_withdrawn_events(stdin, stdout, from_block_withdrawn).await;
+
+            // The events are fetched before the state is held, so snapshots don't wait for the network.
+            let _state_guard = snapshots.hold_state().await;
+
+            match maybe_deposited_events {
                 Some((last_fetched_block, deposited_events)) => {
                     from_block_deposited = last_fetched_block;
 
@@ -50,8 +59,8 @@ pub fn process(
                     });
 
                     for transaction in deposit_transactions {
-                        if let Some(_) = transactions.add_new_tx().await {
-                            mempool.push(transaction).await;
+                        if let Some(tx_id) = transactions.add_new_tx(transaction.clone()).await {
+                            mempool.push(tx_id, transaction).await;
                         }
                     }
 
@@ -61,7 +70,7 @@ pub fn process(
                 None => (),
             };
 
-            match fetch_withdrawn_events(stdin, stdout, from_block_withdrawn).await {
+            match maybe_withdrawn_events {
                 Some((last_fetched_block, withdrawn_events)) => {
                     from_block_withdrawn = last_fetched_block;
 
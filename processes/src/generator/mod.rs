@@ -1,5 +1,5 @@
 mod process;
 mod processor;
 
-pub use process::process;
+pub use process::{process, update_trees};
 pub use processor::Processor;
@@ -1,7 +1,7 @@
 use super::Processor;
-use crate::{balances, burns, liquidities, pools, proofpool, transactions};
+use crate::{balances, burns, liquidities, orders, pools, proofpool, snapshots, transactions};
 use nacho_data_structures::{
-    Balance, Burn, ByteConversion, Deposit, FieldConversion, Liquidity, Pool, ProverMethod,
+    Balance, Burn, ByteConversion, Deposit, FieldConversion, Liquidity, Nonce, Pool, ProverMethod,
     StateRoots, StatefulTransaction,
 };
 use nacho_poseidon_hash::{create_poseidon_hasher, poseidon_hash, PoseidonHasher};
@@ -14,8 +14,10 @@ pub fn process(
     balances: balances::Processor,
     burns: burns::Processor,
     liquidities: liquidities::Processor,
+    orders: orders::Processor,
     pools: pools::Processor,
     proofpool: proofpool::Processor,
+    snapshots: snapshots::Processor,
     transactions: transactions::Processor,
 ) -> Processor {
     let proof_generator_process_script_path =
@@ -30,6 +32,8 @@ pub fn process(
         let mut hasher = create_poseidon_hasher();
 
         loop {
+            let state_guard = snapshots.hold_state().await;
+
             if let Some(stateful_tx) = proofpool.pop().await {
                 generate_proof(
                     &stateful_tx,
@@ -39,13 +43,14 @@ pub fn process(
                     pools,
                     liquidities,
                     burns,
+                    orders,
                     transactions,
                     &mut hasher,
                 )
                 .await
                 .ok();
 
-                update_trees(stateful_tx, balances, pools, liquidities, burns)
+                update_trees(stateful_tx, balances, pools, liquidities, burns, orders)
                     .await
                     .ok();
 
@@ -53,6 +58,8 @@ pub fn process(
                     transactions.set_proved_until(proved_until + 1).await;
                 }
             } else {
+                drop(state_guard);
+
                 notify.notified().await;
             }
         }
@@ -69,6 +76,7 @@ pub async fn generate_proof(
     pools: pools::Processor,
     liquidities: liquidities::Processor,
     burns: burns::Processor,
+    orders: orders::Processor,
     transactions: transactions::Processor,
     hasher: &mut PoseidonHasher,
 ) -> Result<(), ()> {
@@ -78,6 +86,7 @@ pub async fn generate_proof(
         pools,
         liquidities,
         burns,
+        orders,
         transactions,
         hasher,
     )
@@ -106,6 +115,7 @@ pub async fn create_prover_method(
     pools: pools::Processor,
     liquidities: liquidities::Processor,
     burns: burns::Processor,
+    orders: orders::Processor,
     transactions: transactions::Processor,
     hasher: &mut PoseidonHasher,
 ) -> Option<ProverMethod> {
@@ -119,6 +129,8 @@ pub async fn create_prover_method(
                 liquidities: liquidities.get_root().await?,
                 pools: pools.get_root().await?,
                 burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
             },
         },
 
@@ -128,6 +140,8 @@ pub async fn create_prover_method(
                 liquidities: liquidities.get_root().await?,
                 pools: pools.get_root().await?,
                 burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
             },
             earlier_proof_index: transactions.get_proved_until().await? - 1,
             single_balance_witness: balances
@@ -163,6 +177,8 @@ pub async fn create_prover_method(
                 liquidities: liquidities.get_root().await?,
                 pools: pools.get_root().await?,
                 burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
             },
             earlier_proof_index: transactions.get_proved_until().await? - 1,
             single_balance_witness: balances
@@ -173,12 +189,16 @@ pub async fn create_prover_method(
                 .await
                 .map(|(witness, _)| witness)
                 .unwrap_or(burns.get_new_witness().await?),
+            single_nonce_witness: balances
+                .get_nonce_witness(transaction.address.clone())
+                .await?,
             user_address: transaction.address.clone(),
             token_id: transaction.token_id.clone(),
             user_burn_token_amount: state.user_burn_token_amount,
             user_balance_token_amount: state.user_balance_token_amount,
             amount_to_burn: transaction.token_amount,
             user_signature: transaction.signature.clone(),
+            user_nonce: transaction.nonce,
         },
 
         StatefulTransaction::CreatePool { transaction, state } => ProverMethod::CreatePool {
@@ -187,6 +207,8 @@ pub async fn create_prover_method(
                 liquidities: liquidities.get_root().await?,
                 pools: pools.get_root().await?,
                 burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
             },
             earlier_proof_index: transactions.get_proved_until().await? - 1,
             single_pool_witness: pools.get_new_witness().await?,
@@ -198,6 +220,9 @@ pub async fn create_prover_method(
                     transaction.quote_token_id.clone(),
                 )
                 .await?,
+            single_nonce_witness: balances
+                .get_nonce_witness(transaction.address.clone())
+                .await?,
             base_token_id: transaction.base_token_id.clone(),
             quote_token_id: transaction.quote_token_id.clone(),
             user_address: transaction.address.clone(),
@@ -206,6 +231,9 @@ pub async fn create_prover_method(
             user_balance_base_token_amount: state.user_balance_base_token_amount,
             user_balance_quote_token_amount: state.user_balance_quote_token_amount,
             user_signature: transaction.signature.clone(),
+            user_nonce: transaction.nonce,
+            pool_fee_rate: transaction.fee_rate,
+            pool_amplification: transaction.amplification,
         },
 
         StatefulTransaction::ProvideLiquidity { transaction, state } => {
@@ -215,6 +243,8 @@ pub async fn create_prover_method(
                     liquidities: liquidities.get_root().await?,
                     pools: pools.get_root().await?,
                     burns: burns.get_root().await?,
+                    orders: orders.get_root().await?,
+                    nonces: balances.get_nonces_root().await?,
                 },
                 earlier_proof_index: transactions.get_proved_until().await? - 1,
                 single_pool_witness: pools
@@ -238,6 +268,9 @@ pub async fn create_prover_method(
                         transaction.quote_token_id.clone(),
                     )
                     .await?,
+                single_nonce_witness: balances
+                    .get_nonce_witness(transaction.address.clone())
+                    .await?,
                 base_token_id: transaction.base_token_id.clone(),
                 quote_token_id: transaction.quote_token_id.clone(),
                 user_address: transaction.address.clone(),
@@ -250,6 +283,11 @@ pub async fn create_prover_method(
                 user_base_token_amount_to_provide: transaction.base_token_amount,
                 user_quote_token_amount_limit_to_provide: transaction.quote_token_amount_limit,
                 user_signature: transaction.signature.clone(),
+                user_nonce: transaction.nonce,
+                pool_fee_rate: state.pool_fee_rate,
+                pool_protocol_fee_base_token_amount: state.pool_protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount: state.pool_protocol_fee_quote_token_amount,
+                pool_amplification: state.pool_amplification,
             }
         }
 
@@ -260,6 +298,8 @@ pub async fn create_prover_method(
                     liquidities: liquidities.get_root().await?,
                     pools: pools.get_root().await?,
                     burns: burns.get_root().await?,
+                    orders: orders.get_root().await?,
+                    nonces: balances.get_nonces_root().await?,
                 },
                 earlier_proof_index: transactions.get_proved_until().await? - 1,
                 single_pool_witness: pools
@@ -282,6 +322,9 @@ pub async fn create_prover_method(
                         transaction.quote_token_id.clone(),
                     )
                     .await?,
+                single_nonce_witness: balances
+                    .get_nonce_witness(transaction.address.clone())
+                    .await?,
                 base_token_id: transaction.base_token_id.clone(),
                 quote_token_id: transaction.quote_token_id.clone(),
                 user_address: transaction.address.clone(),
@@ -295,6 +338,11 @@ pub async fn create_prover_method(
                 user_base_token_amount_limit_to_remove: transaction.base_token_amount_limit,
                 user_quote_token_amount_limit_to_remove: transaction.quote_token_amount_limit,
                 user_signature: transaction.signature.clone(),
+                user_nonce: transaction.nonce,
+                pool_fee_rate: state.pool_fee_rate,
+                pool_protocol_fee_base_token_amount: state.pool_protocol_fee_base_token_amount,
+                pool_protocol_fee_quote_token_amount: state.pool_protocol_fee_quote_token_amount,
+                pool_amplification: state.pool_amplification,
             }
         }
 
@@ -304,6 +352,8 @@ pub async fn create_prover_method(
                 liquidities: liquidities.get_root().await?,
                 pools: pools.get_root().await?,
                 burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
             },
             earlier_proof_index: transactions.get_proved_until().await? - 1,
             single_pool_witness: pools
@@ -328,6 +378,9 @@ pub async fn create_prover_method(
                     .await?,
             )
                 .into(),
+            single_nonce_witness: balances
+                .get_nonce_witness(transaction.address.clone())
+                .await?,
             user_address: transaction.address.clone(),
             base_token_id: transaction.base_token_id.clone(),
             quote_token_id: transaction.quote_token_id.clone(),
@@ -339,6 +392,12 @@ pub async fn create_prover_method(
             user_base_token_amount_to_swap: transaction.base_token_amount,
             user_quote_token_amount_limit_to_swap: transaction.quote_token_amount_limit,
             user_signature: transaction.signature.clone(),
+            user_nonce: transaction.nonce,
+            pool_fee_rate: state.pool_fee_rate,
+            pool_protocol_fee_base_token_amount: state.pool_protocol_fee_base_token_amount,
+            pool_protocol_fee_quote_token_amount: state.pool_protocol_fee_quote_token_amount,
+            protocol_fee_share: state.protocol_fee_share,
+            pool_amplification: state.pool_amplification,
         },
 
         StatefulTransaction::SellTokens { transaction, state } => ProverMethod::SellTokens {
@@ -347,6 +406,8 @@ pub async fn create_prover_method(
                 liquidities: liquidities.get_root().await?,
                 pools: pools.get_root().await?,
                 burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
             },
             earlier_proof_index: transactions.get_proved_until().await? - 1,
             single_pool_witness: pools
@@ -371,6 +432,9 @@ pub async fn create_prover_method(
                     .unwrap_or(balances.get_new_witness().await?),
             )
                 .into(),
+            single_nonce_witness: balances
+                .get_nonce_witness(transaction.address.clone())
+                .await?,
             user_address: transaction.address.clone(),
             base_token_id: transaction.base_token_id.clone(),
             quote_token_id: transaction.quote_token_id.clone(),
@@ -382,7 +446,219 @@ pub async fn create_prover_method(
             user_base_token_amount_limit_to_swap: transaction.base_token_amount_limit,
             user_quote_token_amount_to_swap: transaction.quote_token_amount,
             user_signature: transaction.signature.clone(),
+            user_nonce: transaction.nonce,
+            pool_fee_rate: state.pool_fee_rate,
+            pool_protocol_fee_base_token_amount: state.pool_protocol_fee_base_token_amount,
+            pool_protocol_fee_quote_token_amount: state.pool_protocol_fee_quote_token_amount,
+            protocol_fee_share: state.protocol_fee_share,
+            pool_amplification: state.pool_amplification,
+        },
+        StatefulTransaction::SwapRoute { transaction, state } => ProverMethod::SwapRoute {
+            state_roots: StateRoots {
+                balances: balances.get_root().await?,
+                liquidities: liquidities.get_root().await?,
+                pools: pools.get_root().await?,
+                burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
+            },
+            earlier_proof_index: transactions.get_proved_until().await? - 1,
+            double_pool_witness: (
+                pools
+                    .get_witness(
+                        state.first_pool.base_token_id.clone(),
+                        state.first_pool.quote_token_id.clone(),
+                    )
+                    .await?,
+                pools
+                    .get_witness(
+                        state.second_pool.base_token_id.clone(),
+                        state.second_pool.quote_token_id.clone(),
+                    )
+                    .await?,
+            )
+                .into(),
+            double_balance_witness: (
+                balances
+                    .get_single_witness(transaction.address.clone(), transaction.path[0].clone())
+                    .await?,
+                balances
+                    .get_single_witness(transaction.address.clone(), transaction.path[2].clone())
+                    .await
+                    .unwrap_or(balances.get_new_witness().await?),
+            )
+                .into(),
+            single_nonce_witness: balances
+                .get_nonce_witness(transaction.address.clone())
+                .await?,
+            user_address: transaction.address.clone(),
+            path: transaction.path.clone(),
+            user_balance_token_in_amount: state.user_balance_token_in_amount,
+            user_balance_token_out_amount: state.user_balance_token_out_amount,
+            first_pool: state.first_pool.clone(),
+            second_pool: state.second_pool.clone(),
+            user_token_amount_in: transaction.token_amount_in,
+            user_token_amount_out_limit: transaction.token_amount_out_limit,
+            user_signature: transaction.signature.clone(),
+            user_nonce: transaction.nonce,
+            protocol_fee_share: state.protocol_fee_share,
         },
+
+        StatefulTransaction::TransferTokens { transaction, state } => {
+            ProverMethod::TransferTokens {
+                state_roots: StateRoots {
+                    balances: balances.get_root().await?,
+                    liquidities: liquidities.get_root().await?,
+                    pools: pools.get_root().await?,
+                    burns: burns.get_root().await?,
+                    orders: orders.get_root().await?,
+                    nonces: balances.get_nonces_root().await?,
+                },
+                earlier_proof_index: transactions.get_proved_until().await? - 1,
+                double_balance_witness: match balances
+                    .get_transfer_witness(
+                        transaction.address.clone(),
+                        transaction.receiver_address.clone(),
+                        transaction.token_id.clone(),
+                    )
+                    .await
+                {
+                    Some(double_witness) => double_witness,
+                    None => (
+                        balances
+                            .get_single_witness(
+                                transaction.address.clone(),
+                                transaction.token_id.clone(),
+                            )
+                            .await?,
+                        balances.get_new_witness().await?,
+                    )
+                        .into(),
+                },
+                single_nonce_witness: balances
+                    .get_nonce_witness(transaction.address.clone())
+                    .await?,
+                user_address: transaction.address.clone(),
+                receiver_address: transaction.receiver_address.clone(),
+                token_id: transaction.token_id.clone(),
+                user_balance_token_amount: state.user_balance_token_amount,
+                receiver_balance_token_amount: state.receiver_balance_token_amount,
+                amount_to_transfer: transaction.token_amount,
+                user_signature: transaction.signature.clone(),
+                user_nonce: transaction.nonce,
+            }
+        }
+
+        StatefulTransaction::PlaceOrder { transaction, state } => ProverMethod::PlaceOrder {
+            state_roots: StateRoots {
+                balances: balances.get_root().await?,
+                liquidities: liquidities.get_root().await?,
+                pools: pools.get_root().await?,
+                burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
+            },
+            earlier_proof_index: transactions.get_proved_until().await? - 1,
+            single_order_witness: orders.get_new_witness().await?,
+            single_balance_witness: balances
+                .get_single_witness(transaction.address.clone(), transaction.token_in_id.clone())
+                .await?,
+            single_nonce_witness: balances
+                .get_nonce_witness(transaction.address.clone())
+                .await?,
+            user_address: transaction.address.clone(),
+            token_in_id: transaction.token_in_id.clone(),
+            token_out_id: transaction.token_out_id.clone(),
+            user_balance_token_in_amount: state.user_balance_token_in_amount,
+            user_token_amount_in: transaction.token_amount_in,
+            user_token_amount_out_limit: transaction.token_amount_out_limit,
+            order_id: state.order_id,
+            user_signature: transaction.signature.clone(),
+            user_nonce: transaction.nonce,
+        },
+        StatefulTransaction::CancelOrder { transaction, state } => ProverMethod::CancelOrder {
+            state_roots: StateRoots {
+                balances: balances.get_root().await?,
+                liquidities: liquidities.get_root().await?,
+                pools: pools.get_root().await?,
+                burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
+            },
+            earlier_proof_index: transactions.get_proved_until().await? - 1,
+            single_order_witness: orders.get_witness(transaction.order_id).await?,
+            single_balance_witness: balances
+                .get_single_witness(transaction.address.clone(), state.order.token_in_id.clone())
+                .await?,
+            single_nonce_witness: balances
+                .get_nonce_witness(transaction.address.clone())
+                .await?,
+            order: state.order.clone(),
+            user_balance_token_in_amount: state.user_balance_token_in_amount,
+            user_signature: transaction.signature.clone(),
+            user_nonce: transaction.nonce,
+        },
+        StatefulTransaction::FillOrder { transaction, state } => ProverMethod::FillOrder {
+            state_roots: StateRoots {
+                balances: balances.get_root().await?,
+                liquidities: liquidities.get_root().await?,
+                pools: pools.get_root().await?,
+                burns: burns.get_root().await?,
+                orders: orders.get_root().await?,
+                nonces: balances.get_nonces_root().await?,
+            },
+            earlier_proof_index: transactions.get_proved_until().await? - 1,
+            single_order_witness: orders.get_witness(transaction.order_id).await?,
+            single_pool_witness: pools
+                .get_witness(
+                    state.pool.base_token_id.clone(),
+                    state.pool.quote_token_id.clone(),
+                )
+                .await?,
+            single_balance_witness: balances
+                .get_single_witness(state.order.owner.clone(), state.order.token_out_id.clone())
+                .await
+                .unwrap_or(balances.get_new_witness().await?),
+            order: state.order.clone(),
+            pool: state.pool.clone(),
+            user_balance_token_out_amount: state.user_balance_token_out_amount,
+            protocol_fee_share: state.protocol_fee_share,
+        },
+        StatefulTransaction::ClaimProtocolFees { transaction, state } => {
+            ProverMethod::ClaimProtocolFees {
+                state_roots: StateRoots {
+                    balances: balances.get_root().await?,
+                    liquidities: liquidities.get_root().await?,
+                    pools: pools.get_root().await?,
+                    burns: burns.get_root().await?,
+                    orders: orders.get_root().await?,
+                    nonces: balances.get_nonces_root().await?,
+                },
+                earlier_proof_index: transactions.get_proved_until().await? - 1,
+                single_pool_witness: pools
+                    .get_witness(
+                        transaction.base_token_id.clone(),
+                        transaction.quote_token_id.clone(),
+                    )
+                    .await?,
+                double_balance_witness: balances
+                    .get_double_witness(
+                        transaction.address.clone(),
+                        transaction.base_token_id.clone(),
+                        transaction.quote_token_id.clone(),
+                    )
+                    .await?,
+                single_nonce_witness: balances
+                    .get_nonce_witness(transaction.address.clone())
+                    .await?,
+                user_address: transaction.address.clone(),
+                pool: state.pool.clone(),
+                user_balance_base_token_amount: state.user_balance_base_token_amount,
+                user_balance_quote_token_amount: state.user_balance_quote_token_amount,
+                user_signature: transaction.signature.clone(),
+                user_nonce: transaction.nonce,
+            }
+        }
     })
 }
 
@@ -392,6 +668,7 @@ pub async fn update_trees(
     pools: pools::Processor,
     liquidities: liquidities::Processor,
     burns: burns::Processor,
+    orders: orders::Processor,
 ) -> Result<(), ()> {
     match stateful_tx {
         StatefulTransaction::CreateGenesis { transaction, state } => {
@@ -473,6 +750,14 @@ pub async fn update_trees(
                 }
                 _ => return Err(()),
             }
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
         }
         StatefulTransaction::CreatePool { transaction, state } => {
             let mut base_token_balance = Balance {
@@ -492,6 +777,9 @@ pub async fn update_trees(
                 &mut quote_token_balance,
                 transaction.base_token_amount,
                 transaction.quote_token_amount,
+                transaction.fee_rate,
+                transaction.pool_kind,
+                transaction.amplification,
             )
             .map_err(|_| ())?;
 
@@ -501,6 +789,14 @@ pub async fn update_trees(
 
             liquidities.push_leaf(liquidity).await.ok_or(())?;
             pools.push_leaf(pool).await.ok_or(())?;
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
         }
         StatefulTransaction::ProvideLiquidity { transaction, state } => {
             let mut base_token_balance = Balance {
@@ -521,6 +817,10 @@ pub async fn update_trees(
                 base_token_amount: state.pool_base_token_amount,
                 quote_token_amount: state.pool_quote_token_amount,
                 total_liqudity_points: state.pool_total_liquidity_points.clone(),
+                fee_rate: state.pool_fee_rate,
+                protocol_fee_base_token_amount: state.pool_protocol_fee_base_token_amount,
+                protocol_fee_quote_token_amount: state.pool_protocol_fee_quote_token_amount,
+                amplification: state.pool_amplification,
             };
 
             let mut maybe_liquidity = if liquidities
@@ -567,6 +867,14 @@ pub async fn update_trees(
                 }
                 _ => return Err(()),
             }
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
         }
         StatefulTransaction::RemoveLiquidity { transaction, state } => {
             let mut base_token_balance = Balance {
@@ -587,6 +895,10 @@ pub async fn update_trees(
                 base_token_amount: state.pool_base_token_amount,
                 quote_token_amount: state.pool_quote_token_amount,
                 total_liqudity_points: state.pool_total_liquidity_points.clone(),
+                fee_rate: state.pool_fee_rate,
+                protocol_fee_base_token_amount: state.pool_protocol_fee_base_token_amount,
+                protocol_fee_quote_token_amount: state.pool_protocol_fee_quote_token_amount,
+                amplification: state.pool_amplification,
             };
 
             let mut liquidity = Liquidity {
@@ -614,6 +926,14 @@ pub async fn update_trees(
             pools.update_leaf(pool).await.ok_or(())?;
 
             liquidities.update_leaf(liquidity).await.ok_or(())?;
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
         }
         StatefulTransaction::BuyTokens { transaction, state } => {
             let mut maybe_base_token_balance = if balances
@@ -645,6 +965,10 @@ pub async fn update_trees(
                 base_token_amount: state.pool_base_token_amount,
                 quote_token_amount: state.pool_quote_token_amount,
                 total_liqudity_points: state.pool_total_liquidity_points.clone(),
+                fee_rate: state.pool_fee_rate,
+                protocol_fee_base_token_amount: state.pool_protocol_fee_base_token_amount,
+                protocol_fee_quote_token_amount: state.pool_protocol_fee_quote_token_amount,
+                amplification: state.pool_amplification,
             };
 
             let result = nacho_executor::buy_tokens(
@@ -654,6 +978,7 @@ pub async fn update_trees(
                 &mut pool,
                 transaction.base_token_amount,
                 transaction.quote_token_amount_limit,
+                state.protocol_fee_share,
             )
             .map_err(|_| ())?;
 
@@ -670,6 +995,14 @@ pub async fn update_trees(
                 }
                 _ => return Err(()),
             }
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
         }
         StatefulTransaction::SellTokens { transaction, state } => {
             let mut base_token_balance = Balance {
@@ -701,6 +1034,10 @@ pub async fn update_trees(
                 base_token_amount: state.pool_base_token_amount,
                 quote_token_amount: state.pool_quote_token_amount,
                 total_liqudity_points: state.pool_total_liquidity_points.clone(),
+                fee_rate: state.pool_fee_rate,
+                protocol_fee_base_token_amount: state.pool_protocol_fee_base_token_amount,
+                protocol_fee_quote_token_amount: state.pool_protocol_fee_quote_token_amount,
+                amplification: state.pool_amplification,
             };
 
             let result = nacho_executor::sell_tokens(
@@ -710,6 +1047,7 @@ pub async fn update_trees(
                 &mut pool,
                 transaction.base_token_amount_limit,
                 transaction.quote_token_amount,
+                state.protocol_fee_share,
             )
             .map_err(|_| ())?;
 
@@ -726,6 +1064,252 @@ pub async fn update_trees(
                 }
                 _ => return Err(()),
             }
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
+        }
+        StatefulTransaction::SwapRoute { transaction, state } => {
+            let mut token_in_balance = Balance {
+                owner: transaction.address.clone(),
+                token_id: transaction.path[0].clone(),
+                token_amount: state.user_balance_token_in_amount,
+            };
+
+            let mut maybe_token_out_balance = if balances
+                .get_single_witness(transaction.address.clone(), transaction.path[2].clone())
+                .await
+                .is_some()
+            {
+                Some(Balance {
+                    owner: transaction.address.clone(),
+                    token_amount: state.user_balance_token_out_amount,
+                    token_id: transaction.path[2].clone(),
+                })
+            } else {
+                None
+            };
+
+            let mut route_pools = vec![state.first_pool, state.second_pool];
+
+            let result = nacho_executor::swap_route(
+                &transaction.path,
+                &mut token_in_balance,
+                maybe_token_out_balance.as_mut(),
+                &mut route_pools,
+                transaction.token_amount_in,
+                transaction.token_amount_out_limit,
+                state.protocol_fee_share,
+            )
+            .map_err(|_| ())?;
+
+            balances.update_leaf(token_in_balance).await.ok_or(())?;
+
+            for pool in route_pools {
+                pools.update_leaf(pool).await.ok_or(())?;
+            }
+
+            match (result, maybe_token_out_balance) {
+                (Some(token_out_balance), None) => {
+                    balances.push_leaf(token_out_balance).await.ok_or(())?;
+                }
+                (None, Some(token_out_balance)) => {
+                    balances.update_leaf(token_out_balance).await.ok_or(())?;
+                }
+                _ => return Err(()),
+            }
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
+        }
+        StatefulTransaction::TransferTokens { transaction, state } => {
+            let mut balance = Balance {
+                owner: transaction.address.clone(),
+                token_id: transaction.token_id.clone(),
+                token_amount: state.user_balance_token_amount,
+            };
+
+            let mut maybe_receiver_balance = if balances
+                .get_single_witness(
+                    transaction.receiver_address.clone(),
+                    transaction.token_id.clone(),
+                )
+                .await
+                .is_some()
+            {
+                Some(Balance {
+                    owner: transaction.receiver_address.clone(),
+                    token_id: transaction.token_id.clone(),
+                    token_amount: state.receiver_balance_token_amount,
+                })
+            } else {
+                None
+            };
+
+            let amount_to_transfer = transaction.token_amount;
+
+            let result = nacho_executor::transfer_tokens(
+                &mut balance,
+                maybe_receiver_balance.as_mut(),
+                &transaction.receiver_address,
+                amount_to_transfer,
+            )
+            .map_err(|_| ())?;
+
+            balances.update_leaf(balance).await.ok_or(())?;
+
+            match (result, maybe_receiver_balance) {
+                (Some(receiver_balance), None) => {
+                    balances.push_leaf(receiver_balance).await.ok_or(())?;
+                }
+                (None, Some(receiver_balance)) => {
+                    balances.update_leaf(receiver_balance).await.ok_or(())?;
+                }
+                _ => return Err(()),
+            }
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
+        }
+        StatefulTransaction::PlaceOrder { transaction, state } => {
+            let mut token_in_balance = Balance {
+                owner: transaction.address.clone(),
+                token_id: transaction.token_in_id.clone(),
+                token_amount: state.user_balance_token_in_amount,
+            };
+
+            let order = nacho_executor::place_order(
+                &mut token_in_balance,
+                state.order_id,
+                &transaction.token_out_id,
+                transaction.token_amount_in,
+                transaction.token_amount_out_limit,
+            )
+            .map_err(|_| ())?;
+
+            balances.update_leaf(token_in_balance).await.ok_or(())?;
+            orders.push_leaf(order).await.ok_or(())?;
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
+        }
+        StatefulTransaction::CancelOrder { transaction, state } => {
+            let mut token_in_balance = Balance {
+                owner: transaction.address.clone(),
+                token_id: state.order.token_in_id.clone(),
+                token_amount: state.user_balance_token_in_amount,
+            };
+
+            let mut order = state.order;
+
+            nacho_executor::cancel_order(&mut order, &mut token_in_balance).map_err(|_| ())?;
+
+            balances.update_leaf(token_in_balance).await.ok_or(())?;
+            orders.update_leaf(order).await.ok_or(())?;
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
+        }
+        StatefulTransaction::FillOrder {
+            transaction: _,
+            state,
+        } => {
+            let mut order = state.order;
+            let mut pool = state.pool;
+
+            let mut maybe_token_out_balance = if balances
+                .get_single_witness(order.owner.clone(), order.token_out_id.clone())
+                .await
+                .is_some()
+            {
+                Some(Balance {
+                    owner: order.owner.clone(),
+                    token_id: order.token_out_id.clone(),
+                    token_amount: state.user_balance_token_out_amount,
+                })
+            } else {
+                None
+            };
+
+            let result = nacho_executor::fill_order(
+                &mut order,
+                maybe_token_out_balance.as_mut(),
+                &mut pool,
+                state.protocol_fee_share,
+            )
+            .map_err(|_| ())?;
+
+            pools.update_leaf(pool).await.ok_or(())?;
+            orders.update_leaf(order).await.ok_or(())?;
+
+            match (result, maybe_token_out_balance) {
+                (Some(token_out_balance), None) => {
+                    balances.push_leaf(token_out_balance).await.ok_or(())?;
+                }
+                (None, Some(token_out_balance)) => {
+                    balances.update_leaf(token_out_balance).await.ok_or(())?;
+                }
+                _ => return Err(()),
+            }
+        }
+        StatefulTransaction::ClaimProtocolFees { transaction, state } => {
+            let mut base_token_balance = Balance {
+                owner: transaction.address.clone(),
+                token_id: transaction.base_token_id.clone(),
+                token_amount: state.user_balance_base_token_amount,
+            };
+
+            let mut quote_token_balance = Balance {
+                owner: transaction.address.clone(),
+                token_id: transaction.quote_token_id.clone(),
+                token_amount: state.user_balance_quote_token_amount,
+            };
+
+            let mut pool = state.pool;
+
+            nacho_executor::claim_protocol_fees(
+                &mut base_token_balance,
+                &mut quote_token_balance,
+                &mut pool,
+            )
+            .map_err(|_| ())?;
+
+            balances.update_leaf(base_token_balance).await.ok_or(())?;
+            balances.update_leaf(quote_token_balance).await.ok_or(())?;
+
+            pools.update_leaf(pool).await.ok_or(())?;
+
+            balances
+                .set_nonce_leaf(Nonce {
+                    owner: transaction.address.clone(),
+                    value: transaction.nonce + 1,
+                })
+                .await
+                .ok_or(())?;
         }
     }
 
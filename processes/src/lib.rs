@@ -1,3 +1,5 @@
+pub mod admission;
+pub mod analytics;
 pub mod balances;
 pub mod burns;
 pub mod executor;
@@ -6,9 +8,22 @@ pub mod generator;
 pub mod liquidities;
 pub mod mempool;
 pub mod merger;
+pub mod observations;
+pub mod orders;
 pub mod pools;
 pub mod proofpool;
+pub mod simulator;
+pub mod snapshots;
 pub mod submitter;
 pub mod transactions;
 pub mod verifier;
 pub mod withdrawals;
+
+/// Stops the node after printing the given message.
+///
+/// A panic would only stop the process it happens in, so the node would keep accepting transactions that it can't store, execute or prove.
+pub(crate) fn abort(message: &str) -> ! {
+    eprintln!("{message}");
+
+    std::process::abort()
+}
@@ -2,6 +2,7 @@ use nacho_liquidities_db::LiquiditiesDb;
 use tokio::sync::mpsc;
 
 use super::{Processor, Request};
+use crate::abort;
 
 pub fn process() -> Processor {
     let liquidities_db_path = std::env::var("NACHO_LIQUIDITIES_DB_PATH").unwrap();
@@ -9,7 +10,9 @@ pub fn process() -> Processor {
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
     tokio::spawn(async move {
-        let mut liquidities_db = LiquiditiesDb::new(liquidities_db_path).await.unwrap();
+        let mut liquidities_db = LiquiditiesDb::new(liquidities_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Liquidities DB: {err}")));
 
         while let Some(request) = receiver.recv().await {
             match request {
@@ -57,6 +60,11 @@ pub fn process() -> Processor {
 
                     sender.send(result.ok()).unwrap();
                 }
+                Request::PopLiquidity { sender } => {
+                    let result = liquidities_db.pop().await;
+
+                    sender.send(result.ok()).unwrap();
+                }
                 Request::PushLeaf { sender, liquidity } => {
                     let result = liquidities_db.push_leaf(&liquidity).await;
 
@@ -119,6 +119,22 @@ impl Processor {
         result
     }
 
+    /// Removes the last pushed liquidity, which undoes a push whose transaction couldn't be committed.
+    pub async fn pop_liquidity(&self) -> Option<Liquidity> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PopLiquidity {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let maybe_liquidity = oneshot_receiver.await.ok()?;
+
+        maybe_liquidity
+    }
+
     pub async fn push_leaf(&self, liquidity: Liquidity) -> Option<()> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
 
@@ -30,6 +30,9 @@ pub enum Request {
         sender: oneshot::Sender<Option<()>>,
         liquidity: Liquidity,
     },
+    PopLiquidity {
+        sender: oneshot::Sender<Option<Liquidity>>,
+    },
     PushLeaf {
         sender: oneshot::Sender<Option<()>>,
         liquidity: Liquidity,
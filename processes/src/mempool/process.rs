@@ -2,6 +2,7 @@ use nacho_mempool::Mempool;
 use tokio::sync::mpsc;
 
 use super::{Processor, Request};
+use crate::abort;
 
 pub fn process() -> Processor {
     let mempool_path = std::env::var("NACHO_MEMPOOL_PATH").unwrap();
@@ -9,22 +10,30 @@ pub fn process() -> Processor {
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
     tokio::spawn(async move {
-        let mut mempool = Mempool::new(mempool_path).await.unwrap();
+        let mut mempool = Mempool::new(mempool_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the mempool: {err}")));
 
         while let Some(request) = receiver.recv().await {
             match request {
                 Request::Push {
                     sender,
+                    tx_id,
                     transaction,
                 } => {
-                    let result = mempool.push(&transaction).await;
+                    let result = mempool.push(tx_id, &transaction).await;
 
                     sender.send(result.ok()).unwrap();
                 }
+                Request::Peek { sender } => {
+                    let maybe_entry = mempool.peek().await;
+
+                    sender.send(maybe_entry.ok().flatten()).unwrap();
+                }
                 Request::Pop { sender } => {
-                    let maybe_transaction = mempool.pop().await;
+                    let maybe_entry = mempool.pop().await;
 
-                    sender.send(maybe_transaction.ok().flatten()).unwrap();
+                    sender.send(maybe_entry.ok().flatten()).unwrap();
                 }
             }
         }
@@ -9,12 +9,13 @@ pub struct Processor {
 }
 
 impl Processor {
-    pub async fn push(&self, transaction: Transaction) -> Option<()> {
+    pub async fn push(&self, tx_id: u64, transaction: Transaction) -> Option<()> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
 
         self.sender
             .send(Request::Push {
                 sender: oneshot_sender,
+                tx_id,
                 transaction,
             })
             .await
@@ -25,7 +26,22 @@ impl Processor {
         result
     }
 
-    pub async fn pop(&self) -> Option<Transaction> {
+    pub async fn peek(&self) -> Option<(u64, Transaction)> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::Peek {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let maybe_entry = oneshot_receiver.await.ok()?;
+
+        maybe_entry
+    }
+
+    pub async fn pop(&self) -> Option<(u64, Transaction)> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
 
         self.sender
@@ -35,8 +51,8 @@ impl Processor {
             .await
             .ok()?;
 
-        let maybe_transaction = oneshot_receiver.await.ok()?;
+        let maybe_entry = oneshot_receiver.await.ok()?;
 
-        maybe_transaction
+        maybe_entry
     }
 }
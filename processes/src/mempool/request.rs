@@ -4,9 +4,13 @@ use tokio::sync::oneshot;
 pub enum Request {
     Push {
         sender: oneshot::Sender<Option<()>>,
+        tx_id: u64,
         transaction: Transaction,
     },
+    Peek {
+        sender: oneshot::Sender<Option<(u64, Transaction)>>,
+    },
     Pop {
-        sender: oneshot::Sender<Option<Transaction>>,
+        sender: oneshot::Sender<Option<(u64, Transaction)>>,
     },
 }
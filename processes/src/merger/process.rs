@@ -1,14 +1,17 @@
 use std::time::Duration;
 
 use super::Processor;
-use crate::transactions;
+use crate::{snapshots, transactions};
 use tokio::{
     process::{ChildStdin, ChildStdout},
     sync::mpsc,
     time::sleep,
 };
 
-pub fn process(transactions: transactions::Processor) -> Processor {
+pub fn process(
+    snapshots: snapshots::Processor,
+    transactions: transactions::Processor,
+) -> Processor {
     let proof_merger_process_script_path =
         std::env::var("NACHO_PROOF_MERGER_PROCESS_SCRIPT_PATH").unwrap();
 
@@ -26,6 +29,8 @@ pub fn process(transactions: transactions::Processor) -> Processor {
         loop {
             tokio::select! {
                 _ = &mut sleep => {
+                    let _state_guard = snapshots.hold_state().await;
+
                     let maybe_merged_until = transactions.get_merged_until().await;
                     let maybe_proved_until = transactions.get_proved_until().await;
 
@@ -52,6 +57,8 @@ pub fn process(transactions: transactions::Processor) -> Processor {
                 }
                 msg = receiver.recv() => {
                     if let Some(at) = msg {
+                        let _state_guard = snapshots.hold_state().await;
+
                         if start_merge(&mut stdin, &mut stdout, at).await.is_ok() {
                             transactions.set_merged_until(at as u64 + 2).await;
                         }
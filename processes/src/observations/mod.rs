@@ -0,0 +1,8 @@
+mod process;
+mod processor;
+mod request;
+
+pub use process::process;
+pub(crate) use processor::unix_timestamp;
+pub use processor::Processor;
+pub use request::Request;
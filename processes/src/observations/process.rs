@@ -0,0 +1,60 @@
+use nacho_observations_db::ObservationsDb;
+use tokio::sync::mpsc;
+
+use super::{Processor, Request};
+use crate::abort;
+
+pub fn process() -> Processor {
+    let observations_db_path = std::env::var("NACHO_OBSERVATIONS_DB_PATH").unwrap();
+
+    let (sender, mut receiver) = mpsc::channel::<Request>(1000);
+
+    tokio::spawn(async move {
+        let mut observations_db = ObservationsDb::new(observations_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Observations DB: {err}")));
+
+        while let Some(request) = receiver.recv().await {
+            match request {
+                Request::GetLatestObservation {
+                    sender,
+                    base_token_id,
+                    quote_token_id,
+                } => {
+                    let observation = observations_db
+                        .get_latest(&base_token_id, &quote_token_id)
+                        .await;
+
+                    sender.send(observation.ok()).unwrap();
+                }
+                Request::GetObservations {
+                    sender,
+                    base_token_id,
+                    quote_token_id,
+                } => {
+                    let observations = observations_db
+                        .get_many(&base_token_id, &quote_token_id)
+                        .await;
+
+                    sender.send(observations.ok()).unwrap();
+                }
+                Request::PushObservation {
+                    sender,
+                    base_token_id,
+                    quote_token_id,
+                    observation,
+                } => {
+                    let result = observations_db
+                        .push(&base_token_id, &quote_token_id, &observation)
+                        .await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+            }
+        }
+    });
+
+    Processor {
+        sender: Box::leak(Box::new(sender)),
+    }
+}
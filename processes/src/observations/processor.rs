@@ -0,0 +1,115 @@
+use nacho_data_structures::{Observation, Pool, RejectionReason, U256};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::{mpsc, oneshot};
+
+use super::Request;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Processor {
+    pub(crate) sender: &'static mpsc::Sender<Request>,
+}
+
+impl Processor {
+    pub async fn get_latest_observation(
+        &self,
+        base_token_id: U256,
+        quote_token_id: U256,
+    ) -> Option<Observation> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetLatestObservation {
+                sender: oneshot_sender,
+                base_token_id,
+                quote_token_id,
+            })
+            .await
+            .ok()?;
+
+        let observation = oneshot_receiver.await.ok()?;
+
+        observation
+    }
+
+    pub async fn get_observations(
+        &self,
+        base_token_id: U256,
+        quote_token_id: U256,
+    ) -> Option<Vec<Observation>> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetObservations {
+                sender: oneshot_sender,
+                base_token_id,
+                quote_token_id,
+            })
+            .await
+            .ok()?;
+
+        let observations = oneshot_receiver.await.ok()?;
+
+        observations
+    }
+
+    pub async fn push_observation(
+        &self,
+        base_token_id: U256,
+        quote_token_id: U256,
+        observation: Observation,
+    ) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PushObservation {
+                sender: oneshot_sender,
+                base_token_id,
+                quote_token_id,
+                observation,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    /// Records the current price of the given pool, accumulating its previous price until now.
+    pub async fn observe_pool(&self, pool: Pool) -> Option<()> {
+        let maybe_last_observation = self
+            .get_latest_observation(pool.base_token_id.clone(), pool.quote_token_id.clone())
+            .await;
+
+        let observation =
+            nacho_executor::observe_pool(&pool, maybe_last_observation.as_ref(), unix_timestamp())
+                .ok()?;
+
+        self.push_observation(pool.base_token_id, pool.quote_token_id, observation)
+            .await
+    }
+
+    /// Returns the time-weighted average price of the pool over the last `window` seconds, in quote tokens per base token as a 64.64 fixed point number.
+    pub async fn get_twap(
+        &self,
+        base_token_id: U256,
+        quote_token_id: U256,
+        window: u64,
+    ) -> Result<u128, RejectionReason> {
+        let observations = self
+            .get_observations(base_token_id, quote_token_id)
+            .await
+            .ok_or(RejectionReason::PoolDoesntExist)?;
+
+        nacho_executor::time_weighted_average_price(&observations, window, unix_timestamp())
+            .map_err(RejectionReason::from)
+    }
+}
+
+/// Returns the current Unix timestamp in seconds, which observations and trade stats are made at.
+pub(crate) fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,21 @@
+use nacho_data_structures::{Observation, U256};
+use tokio::sync::oneshot;
+
+pub enum Request {
+    GetLatestObservation {
+        sender: oneshot::Sender<Option<Observation>>,
+        base_token_id: U256,
+        quote_token_id: U256,
+    },
+    GetObservations {
+        sender: oneshot::Sender<Option<Vec<Observation>>>,
+        base_token_id: U256,
+        quote_token_id: U256,
+    },
+    PushObservation {
+        sender: oneshot::Sender<Option<()>>,
+        base_token_id: U256,
+        quote_token_id: U256,
+        observation: Observation,
+    },
+}
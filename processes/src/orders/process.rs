@@ -0,0 +1,88 @@
+use nacho_orders_db::OrdersDb;
+use tokio::sync::mpsc;
+
+use super::{Processor, Request};
+use crate::abort;
+
+pub fn process() -> Processor {
+    let orders_db_path = std::env::var("NACHO_ORDERS_DB_PATH").unwrap();
+
+    let (sender, mut receiver) = mpsc::channel::<Request>(1000);
+
+    tokio::spawn(async move {
+        let mut orders_db = OrdersDb::new(orders_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Orders DB: {err}")));
+
+        while let Some(request) = receiver.recv().await {
+            match request {
+                Request::GetOrder { sender, order_id } => {
+                    let order = orders_db.get(order_id).await;
+
+                    sender.send(order.ok()).unwrap();
+                }
+                Request::GetOrders { sender, owner } => {
+                    let orders = orders_db.get_many_by_owner(&owner).await;
+
+                    sender.send(orders.ok()).unwrap();
+                }
+                Request::GetOpenOrders {
+                    sender,
+                    token_in_id,
+                    token_out_id,
+                } => {
+                    let orders = orders_db.get_many_open(&token_in_id, &token_out_id).await;
+
+                    sender.send(orders.ok()).unwrap();
+                }
+                Request::GetNextOrderId { sender } => {
+                    sender.send(orders_db.next_id()).unwrap();
+                }
+                Request::GetWitness { sender, order_id } => {
+                    let single_witness = orders_db.get_single_witness(order_id).await;
+
+                    sender.send(single_witness.ok()).unwrap();
+                }
+                Request::GetNewWitness { sender } => {
+                    let new_witness = orders_db.get_new_single_witness().await;
+
+                    sender.send(new_witness.ok()).unwrap();
+                }
+                Request::PushOrder { sender, order } => {
+                    let result = orders_db.push(&order).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::UpdateOrder { sender, order } => {
+                    let result = orders_db.update(&order).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::PopOrder { sender } => {
+                    let result = orders_db.pop().await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::PushLeaf { sender, order } => {
+                    let result = orders_db.push_leaf(&order).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::UpdateLeaf { sender, order } => {
+                    let result = orders_db.update_leaf(&order).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+                Request::GetRoot { sender } => {
+                    let result = orders_db.get_root().await;
+
+                    sender.send(result.ok().map(|root| root.into())).unwrap();
+                }
+            }
+        }
+    });
+
+    Processor {
+        sender: Box::leak(Box::new(sender)),
+    }
+}
@@ -0,0 +1,206 @@
+use nacho_data_structures::{Address, Order, U256};
+use nacho_orders_db::SingleOrderWitness;
+use tokio::sync::{mpsc, oneshot};
+
+use super::Request;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Processor {
+    pub(crate) sender: &'static mpsc::Sender<Request>,
+}
+
+impl Processor {
+    pub async fn get_order(&self, order_id: u64) -> Option<Order> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetOrder {
+                sender: oneshot_sender,
+                order_id,
+            })
+            .await
+            .ok()?;
+
+        let order = oneshot_receiver.await.ok()?;
+
+        order
+    }
+
+    pub async fn get_orders(&self, owner: Address) -> Option<Vec<Order>> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetOrders {
+                sender: oneshot_sender,
+                owner,
+            })
+            .await
+            .ok()?;
+
+        let orders = oneshot_receiver.await.ok()?;
+
+        orders
+    }
+
+    pub async fn get_open_orders(
+        &self,
+        token_in_id: U256,
+        token_out_id: U256,
+    ) -> Option<Vec<Order>> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetOpenOrders {
+                sender: oneshot_sender,
+                token_in_id,
+                token_out_id,
+            })
+            .await
+            .ok()?;
+
+        let orders = oneshot_receiver.await.ok()?;
+
+        orders
+    }
+
+    pub async fn get_next_order_id(&self) -> Option<u64> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetNextOrderId {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let next_order_id = oneshot_receiver.await.ok()?;
+
+        Some(next_order_id)
+    }
+
+    pub async fn get_witness(&self, order_id: u64) -> Option<SingleOrderWitness> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetWitness {
+                sender: oneshot_sender,
+                order_id,
+            })
+            .await
+            .ok()?;
+
+        let single_witness = oneshot_receiver.await.ok()?;
+
+        single_witness
+    }
+
+    pub async fn get_new_witness(&self) -> Option<SingleOrderWitness> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetNewWitness {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let new_witness = oneshot_receiver.await.ok()?;
+
+        new_witness
+    }
+
+    pub async fn push_order(&self, order: Order) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PushOrder {
+                sender: oneshot_sender,
+                order,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    pub async fn update_order(&self, order: Order) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::UpdateOrder {
+                sender: oneshot_sender,
+                order,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    /// Removes the last pushed order, which undoes a push whose transaction couldn't be committed.
+    pub async fn pop_order(&self) -> Option<Order> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PopOrder {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let maybe_order = oneshot_receiver.await.ok()?;
+
+        maybe_order
+    }
+
+    pub async fn push_leaf(&self, order: Order) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PushLeaf {
+                sender: oneshot_sender,
+                order,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    pub async fn update_leaf(&self, order: Order) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::UpdateLeaf {
+                sender: oneshot_sender,
+                order,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    pub async fn get_root(&self) -> Option<U256> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetRoot {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+}
@@ -0,0 +1,51 @@
+use nacho_data_structures::{Address, Order, U256};
+use nacho_orders_db::SingleOrderWitness;
+use tokio::sync::oneshot;
+
+pub enum Request {
+    GetOrder {
+        sender: oneshot::Sender<Option<Order>>,
+        order_id: u64,
+    },
+    GetOrders {
+        sender: oneshot::Sender<Option<Vec<Order>>>,
+        owner: Address,
+    },
+    GetOpenOrders {
+        sender: oneshot::Sender<Option<Vec<Order>>>,
+        token_in_id: U256,
+        token_out_id: U256,
+    },
+    GetNextOrderId {
+        sender: oneshot::Sender<u64>,
+    },
+    GetWitness {
+        sender: oneshot::Sender<Option<SingleOrderWitness>>,
+        order_id: u64,
+    },
+    GetNewWitness {
+        sender: oneshot::Sender<Option<SingleOrderWitness>>,
+    },
+    PushOrder {
+        sender: oneshot::Sender<Option<()>>,
+        order: Order,
+    },
+    UpdateOrder {
+        sender: oneshot::Sender<Option<()>>,
+        order: Order,
+    },
+    PopOrder {
+        sender: oneshot::Sender<Option<Order>>,
+    },
+    PushLeaf {
+        sender: oneshot::Sender<Option<()>>,
+        order: Order,
+    },
+    UpdateLeaf {
+        sender: oneshot::Sender<Option<()>>,
+        order: Order,
+    },
+    GetRoot {
+        sender: oneshot::Sender<Option<U256>>,
+    },
+}
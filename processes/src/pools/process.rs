@@ -2,6 +2,7 @@ use nacho_pools_db::PoolsDb;
 use tokio::sync::mpsc;
 
 use super::{Processor, Request};
+use crate::abort;
 
 pub fn process() -> Processor {
     let pools_db_path = std::env::var("NACHO_POOLS_DB_PATH").unwrap();
@@ -9,7 +10,9 @@ pub fn process() -> Processor {
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
     tokio::spawn(async move {
-        let mut pools_db = PoolsDb::new(pools_db_path).await.unwrap();
+        let mut pools_db = PoolsDb::new(pools_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Pools DB: {err}")));
 
         while let Some(request) = receiver.recv().await {
             match request {
@@ -53,6 +56,11 @@ pub fn process() -> Processor {
 
                     sender.send(result.ok()).unwrap();
                 }
+                Request::PopPool { sender } => {
+                    let result = pools_db.pop().await;
+
+                    sender.send(result.ok()).unwrap();
+                }
                 Request::PushLeaf { sender, pool } => {
                     let result = pools_db.push_leaf(&pool).await;
 
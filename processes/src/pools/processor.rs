@@ -27,6 +27,16 @@ impl Processor {
         pool
     }
 
+    pub async fn get_hop_pool(&self, token_in_id: U256, token_out_id: U256) -> Option<Pool> {
+        match self
+            .get_pool(token_in_id.clone(), token_out_id.clone())
+            .await
+        {
+            Some(pool) => Some(pool),
+            None => self.get_pool(token_out_id, token_in_id).await,
+        }
+    }
+
     pub async fn get_pools(&self) -> Option<Vec<Pool>> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
 
@@ -110,6 +120,22 @@ impl Processor {
         result
     }
 
+    /// Removes the last pushed pool, which undoes a push whose transaction couldn't be committed.
+    pub async fn pop_pool(&self) -> Option<Pool> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::PopPool {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let maybe_pool = oneshot_receiver.await.ok()?;
+
+        maybe_pool
+    }
+
     pub async fn push_leaf(&self, pool: Pool) -> Option<()> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
 
@@ -27,6 +27,9 @@ pub enum Request {
         sender: oneshot::Sender<Option<()>>,
         pool: Pool,
     },
+    PopPool {
+        sender: oneshot::Sender<Option<Pool>>,
+    },
     PushLeaf {
         sender: oneshot::Sender<Option<()>>,
         pool: Pool,
@@ -2,6 +2,7 @@ use nacho_proofpool::Proofpool;
 use tokio::sync::mpsc;
 
 use super::{Processor, Request};
+use crate::abort;
 
 pub fn process() -> Processor {
     let proofpool_path = std::env::var("NACHO_PROOFPOOL_PATH").unwrap();
@@ -9,7 +10,9 @@ pub fn process() -> Processor {
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
     tokio::spawn(async move {
-        let mut proofpool = Proofpool::new(proofpool_path).await.unwrap();
+        let mut proofpool = Proofpool::new(proofpool_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the proofpool: {err}")));
 
         while let Some(request) = receiver.recv().await {
             match request {
@@ -26,6 +29,11 @@ pub fn process() -> Processor {
 
                     sender.send(maybe_transaction.ok().flatten()).unwrap();
                 }
+                Request::GetPushCount { sender } => {
+                    let push_count = proofpool.get_push_count();
+
+                    sender.send(Some(push_count)).unwrap();
+                }
             }
         }
     });
@@ -39,4 +39,20 @@ impl Processor {
 
         maybe_stateful_tx
     }
+
+    /// Returns the count of all the stateful transactions pushed to the proofpool, including the popped ones.
+    pub async fn get_push_count(&self) -> Option<u64> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::GetPushCount {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let push_count = oneshot_receiver.await.ok()?;
+
+        push_count
+    }
 }
@@ -9,4 +9,7 @@ pub enum Request {
     Pop {
         sender: oneshot::Sender<Option<StatefulTransaction>>,
     },
+    GetPushCount {
+        sender: oneshot::Sender<Option<u64>>,
+    },
 }
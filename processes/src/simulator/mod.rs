@@ -0,0 +1,7 @@
+mod process;
+mod processor;
+mod simulation;
+
+pub use process::process;
+pub use processor::Processor;
+pub use simulation::Simulation;
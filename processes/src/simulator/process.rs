@@ -0,0 +1,117 @@
+use super::{Processor, Simulation};
+use crate::{
+    balances, burns,
+    executor::{self, StateBatch},
+    liquidities, observations, orders, pools,
+};
+use nacho_data_structures::{Balance, Liquidity, Pool, RejectionReason, Transaction, U256};
+use nacho_write_ahead_log::WalEntry;
+
+pub fn process(
+    balances: balances::Processor,
+    burns: burns::Processor,
+    liquidities: liquidities::Processor,
+    observations: observations::Processor,
+    orders: orders::Processor,
+    pools: pools::Processor,
+) -> Processor {
+    Processor {
+        balances,
+        burns,
+        liquidities,
+        observations,
+        orders,
+        pools,
+        protocol_fee_share: executor::protocol_fee_share(),
+        treasury_address: executor::treasury_address(),
+    }
+}
+
+/// Applies the given transaction with the executor against a batch over the current state that is never committed, and returns the outcome.
+///
+/// Nothing is persisted, and the signature and the nonce of the transaction aren't checked.
+pub async fn simulate_tx(
+    tx: Transaction,
+    simulator: Processor,
+) -> Result<Simulation, RejectionReason> {
+    let Processor {
+        balances,
+        burns,
+        liquidities,
+        orders,
+        pools,
+        protocol_fee_share,
+        treasury_address,
+        ..
+    } = simulator;
+
+    let address = tx.address().cloned();
+
+    let mut batch = StateBatch::new(balances, burns, liquidities, orders, pools);
+
+    executor::apply_tx(tx, protocol_fee_share, treasury_address, &mut batch).await?;
+
+    let mut user_balances = Vec::<Balance>::new();
+    let mut changed_pools = Vec::<Pool>::new();
+    let mut maybe_user_liquidity = None::<Liquidity>;
+
+    for entry in batch.staged_entries() {
+        match entry {
+            WalEntry::Balance(balance) if Some(&balance.owner) == address.as_ref() => {
+                match user_balances
+                    .iter_mut()
+                    .find(|user_balance| user_balance.token_id == balance.token_id)
+                {
+                    Some(user_balance) => *user_balance = balance.clone(),
+                    None => user_balances.push(balance.clone()),
+                }
+            }
+            WalEntry::Pool(pool) => match changed_pools.iter_mut().find(|changed_pool| {
+                changed_pool.base_token_id == pool.base_token_id
+                    && changed_pool.quote_token_id == pool.quote_token_id
+            }) {
+                Some(changed_pool) => *changed_pool = pool.clone(),
+                None => changed_pools.push(pool.clone()),
+            },
+            WalEntry::Liquidity(liquidity) if Some(&liquidity.provider) == address.as_ref() => {
+                maybe_user_liquidity = Some(liquidity.clone());
+            }
+            _ => {}
+        }
+    }
+
+    let mut balance_changes = Vec::with_capacity(user_balances.len());
+
+    for balance in user_balances {
+        let before = balances
+            .get_balance(balance.owner, balance.token_id.clone())
+            .await
+            .map(|balance| balance.token_amount)
+            .unwrap_or(0);
+
+        balance_changes.push((balance.token_id, before, balance.token_amount));
+    }
+
+    let liquidity_points_change = match maybe_user_liquidity {
+        Some(liquidity) => {
+            let points_before = liquidities
+                .get_liquidity(
+                    liquidity.provider,
+                    liquidity.base_token_id,
+                    liquidity.quote_token_id,
+                )
+                .await
+                .map(|liquidity| liquidity.points)
+                .unwrap_or(U256::from(0u64));
+
+            Some((points_before, liquidity.points))
+        }
+        None => None,
+    };
+
+    Ok(Simulation {
+        balance_changes,
+        pools: changed_pools,
+        liquidity_points_change,
+    })
+}
@@ -0,0 +1,108 @@
+use super::{process::simulate_tx, Simulation};
+use crate::{balances, burns, liquidities, observations, orders, pools};
+use nacho_data_structures::{Address, Liquidity, RejectionReason, SwapSide, Transaction, U256};
+use nacho_executor::{Position, Quote, Route};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Processor {
+    pub(crate) balances: balances::Processor,
+    pub(crate) burns: burns::Processor,
+    pub(crate) liquidities: liquidities::Processor,
+    pub(crate) observations: observations::Processor,
+    pub(crate) orders: orders::Processor,
+    pub(crate) pools: pools::Processor,
+    pub(crate) protocol_fee_share: u64,
+    pub(crate) treasury_address: Option<&'static Address>,
+}
+
+impl Processor {
+    pub async fn simulate(&self, tx: Transaction) -> Result<Simulation, RejectionReason> {
+        simulate_tx(tx, *self).await
+    }
+
+    pub async fn quote(
+        &self,
+        base_token_id: U256,
+        quote_token_id: U256,
+        side: SwapSide,
+        amount: u64,
+    ) -> Result<Quote, RejectionReason> {
+        let pool = self
+            .pools
+            .get_pool(base_token_id, quote_token_id)
+            .await
+            .ok_or(RejectionReason::PoolDoesntExist)?;
+
+        nacho_executor::quote_swap(&pool, side, amount).map_err(RejectionReason::from)
+    }
+
+    pub async fn route(
+        &self,
+        token_in_id: U256,
+        token_out_id: U256,
+        token_amount_in: u64,
+        max_hops: usize,
+    ) -> Result<Route, RejectionReason> {
+        let pools = self
+            .pools
+            .get_pools()
+            .await
+            .ok_or(RejectionReason::Internal)?;
+
+        nacho_executor::find_best_route(
+            &pools,
+            &token_in_id,
+            &token_out_id,
+            token_amount_in,
+            max_hops,
+        )
+        .ok_or(RejectionReason::InvalidRoute)
+    }
+
+    /// Returns the liquidities of the given provider with their value in the pools' current reserves.
+    ///
+    /// The fees are estimated since the oldest observation kept for each pool.
+    pub async fn positions(
+        &self,
+        provider: Address,
+    ) -> Result<Vec<(Liquidity, Position)>, RejectionReason> {
+        let liquidities = self
+            .liquidities
+            .get_liquidities(provider)
+            .await
+            .ok_or(RejectionReason::Internal)?;
+
+        let mut positions = Vec::with_capacity(liquidities.len());
+
+        for liquidity in liquidities {
+            let pool = self
+                .pools
+                .get_pool(
+                    liquidity.base_token_id.clone(),
+                    liquidity.quote_token_id.clone(),
+                )
+                .await
+                .ok_or(RejectionReason::PoolDoesntExist)?;
+
+            let maybe_oldest_observation = self
+                .observations
+                .get_observations(
+                    liquidity.base_token_id.clone(),
+                    liquidity.quote_token_id.clone(),
+                )
+                .await
+                .and_then(|observations| observations.into_iter().next());
+
+            let position = nacho_executor::value_position(
+                &liquidity,
+                &pool,
+                maybe_oldest_observation.as_ref(),
+            )
+            .map_err(RejectionReason::from)?;
+
+            positions.push((liquidity, position));
+        }
+
+        Ok(positions)
+    }
+}
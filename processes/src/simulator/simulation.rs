@@ -0,0 +1,12 @@
+use nacho_data_structures::{Pool, U256};
+
+/// The struct that represents the outcome of a transaction executed against the current state without persisting anything.
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    /// The token amounts of the user's balances before and after the transaction as `(token_id, before, after)`.
+    pub balance_changes: Vec<(U256, u64, u64)>,
+    /// The AMM pools the transaction changes after the transaction, in the order they are changed.
+    pub pools: Vec<Pool>,
+    /// The user's liquidity points before and after the transaction, if the transaction involves a pool's liquidity.
+    pub liquidity_points_change: Option<(U256, U256)>,
+}
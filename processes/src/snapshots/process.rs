@@ -0,0 +1,110 @@
+use super::{Processor, Request};
+use crate::{balances, burns, liquidities, orders, pools, transactions};
+use nacho_data_structures::StateRoots;
+use nacho_snapshots::{SnapshotManifest, Snapshots, SNAPSHOTS_TO_KEEP, STATE_DIRS};
+use std::{path::PathBuf, time::Duration};
+use tokio::sync::{mpsc, RwLock};
+
+/// Spawns the process that takes snapshots of the state directories.
+///
+/// A snapshot is taken every `NACHO_SNAPSHOT_INTERVAL` seconds, which defaults to an hour, `0` disables them.
+///
+/// NOTE: A snapshot waits for the proof or the merge in progress, no transaction is executed meanwhile.
+pub fn process(
+    balances: balances::Processor,
+    burns: burns::Processor,
+    liquidities: liquidities::Processor,
+    orders: orders::Processor,
+    pools: pools::Processor,
+    transactions: transactions::Processor,
+) -> Processor {
+    let snapshots_path = std::env::var("NACHO_SNAPSHOTS_PATH").unwrap();
+    let snapshot_interval = std::env::var("NACHO_SNAPSHOT_INTERVAL")
+        .map(|interval| interval.parse().unwrap())
+        .unwrap_or(60 * 60);
+
+    let state_dirs =
+        STATE_DIRS.map(|(key, name)| (name, PathBuf::from(std::env::var(key).unwrap())));
+
+    let state_lock: &RwLock<()> = Box::leak(Box::new(RwLock::new(())));
+
+    let (sender, mut receiver) = mpsc::channel::<Request>(1000);
+
+    tokio::spawn(async move {
+        let mut snapshots = Snapshots::new(snapshots_path).await.unwrap();
+
+        while let Some(request) = receiver.recv().await {
+            match request {
+                Request::TakeSnapshot { sender } => {
+                    let _state_guard = state_lock.write().await;
+
+                    let maybe_manifest =
+                        get_manifest(balances, burns, liquidities, orders, pools, transactions)
+                            .await;
+
+                    let maybe_version = match maybe_manifest {
+                        Some(manifest) => {
+                            take_snapshot(&mut snapshots, &manifest, &state_dirs).await
+                        }
+                        None => None,
+                    };
+
+                    sender.send(maybe_version).unwrap();
+                }
+            }
+        }
+    });
+
+    let processor = Processor {
+        sender: Box::leak(Box::new(sender)),
+        state_lock,
+    };
+
+    if snapshot_interval != 0 {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_secs(snapshot_interval)).await;
+
+                processor.take_snapshot().await;
+            }
+        });
+    }
+
+    processor
+}
+
+/// Returns the manifest of the current state, the state must not be written until the snapshot is taken.
+async fn get_manifest(
+    balances: balances::Processor,
+    burns: burns::Processor,
+    liquidities: liquidities::Processor,
+    orders: orders::Processor,
+    pools: pools::Processor,
+    transactions: transactions::Processor,
+) -> Option<SnapshotManifest> {
+    Some(SnapshotManifest {
+        executed_until: transactions.get_executed_until().await?,
+        proved_until: transactions.get_proved_until().await?,
+        state_roots: StateRoots {
+            balances: balances.get_root().await?,
+            liquidities: liquidities.get_root().await?,
+            pools: pools.get_root().await?,
+            burns: burns.get_root().await?,
+            orders: orders.get_root().await?,
+            nonces: balances.get_nonces_root().await?,
+        },
+    })
+}
+
+/// Copies the state directories into a new snapshot and removes the old snapshots.
+async fn take_snapshot(
+    snapshots: &mut Snapshots,
+    manifest: &SnapshotManifest,
+    state_dirs: &[(&str, PathBuf)],
+) -> Option<u64> {
+    let version = snapshots.take(manifest, state_dirs).await.ok()?;
+
+    snapshots.keep_latest(SNAPSHOTS_TO_KEEP).await.ok()?;
+
+    Some(version)
+}
@@ -0,0 +1,35 @@
+use tokio::sync::{mpsc, oneshot, RwLock, RwLockReadGuard};
+
+use super::Request;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Processor {
+    pub(crate) sender: &'static mpsc::Sender<Request>,
+    pub(crate) state_lock: &'static RwLock<()>,
+}
+
+impl Processor {
+    /// Returns a guard that keeps snapshots from being taken until it is dropped.
+    ///
+    /// Processes hold it while they write a transaction's state, so snapshots are only taken between transactions.
+    /// It must not be held while waiting for new work, as writers wait for a pending snapshot.
+    pub async fn hold_state(&self) -> RwLockReadGuard<'static, ()> {
+        self.state_lock.read().await
+    }
+
+    /// Takes a snapshot of the state after the current writes are done and returns its version.
+    pub async fn take_snapshot(&self) -> Option<u64> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+
+        self.sender
+            .send(Request::TakeSnapshot {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let maybe_version = oneshot_receiver.await.ok()?;
+
+        maybe_version
+    }
+}
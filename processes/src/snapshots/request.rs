@@ -0,0 +1,7 @@
+use tokio::sync::oneshot;
+
+pub enum Request {
+    TakeSnapshot {
+        sender: oneshot::Sender<Option<u64>>,
+    },
+}
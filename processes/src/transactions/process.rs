@@ -2,6 +2,7 @@ use nacho_transactions_db::TransactionsDb;
 use tokio::sync::mpsc;
 
 use super::{Processor, Request};
+use crate::abort;
 
 pub fn process() -> Processor {
     let transaction_db_path = std::env::var("NACHO_TRANSACTIONS_DB_PATH").unwrap();
@@ -9,7 +10,9 @@ pub fn process() -> Processor {
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
     tokio::spawn(async move {
-        let mut transactions_db = TransactionsDb::new(transaction_db_path).await.unwrap();
+        let mut transactions_db = TransactionsDb::new(transaction_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Transactions DB: {err}")));
 
         while let Some(request) = receiver.recv().await {
             match request {
@@ -25,14 +28,53 @@ pub fn process() -> Processor {
                     sender.send(tx_status.ok()).unwrap();
                 }
 
-                Request::AddNewTx { sender } => {
-                    let tx_status = transactions_db.add_new_tx().await;
+                Request::GetRejectionReason { sender, tx_id } => {
+                    let reason = transactions_db.get_rejection_reason(tx_id).await;
 
-                    sender.send(tx_status.ok()).unwrap();
+                    sender.send(reason.ok()).unwrap();
+                }
+
+                Request::GetTx { sender, tx_id } => {
+                    let tx = transactions_db.get_tx(tx_id).await;
+
+                    sender.send(tx.ok()).unwrap();
+                }
+
+                Request::GetTxHistory {
+                    sender,
+                    address,
+                    from_tx_id,
+                    limit,
+                } => {
+                    let history = transactions_db
+                        .get_history(&address, from_tx_id, limit)
+                        .await;
+
+                    sender.send(history.ok()).unwrap();
+                }
+
+                Request::AddNewTx { sender, tx } => {
+                    let tx_id = transactions_db.add_new_tx(&tx).await;
+
+                    sender.send(tx_id.ok()).unwrap();
                 }
 
-                Request::SetRejected { sender, tx_id } => {
-                    let result = transactions_db.set_rejected(tx_id).await;
+                Request::AddToHistory {
+                    sender,
+                    tx_id,
+                    address,
+                } => {
+                    let result = transactions_db.add_to_history(tx_id, &address).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+
+                Request::SetRejected {
+                    sender,
+                    tx_id,
+                    reason,
+                } => {
+                    let result = transactions_db.set_rejected(tx_id, reason).await;
 
                     sender.send(result.ok()).unwrap();
                 }
@@ -52,6 +94,21 @@ pub fn process() -> Processor {
                     sender.send(result.ok()).unwrap();
                 }
 
+                Request::SetProcessedUntil {
+                    sender,
+                    until_tx_id,
+                } => {
+                    let result = transactions_db.set_processed_until(until_tx_id).await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+
+                Request::GetProcessedUntil { sender } => {
+                    let result = transactions_db.get_processed_until().await;
+
+                    sender.send(result.ok()).unwrap();
+                }
+
                 Request::SetProvedUntil {
                     sender,
                     until_tx_id,
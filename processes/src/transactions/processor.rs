@@ -1,4 +1,4 @@
-use nacho_data_structures::TxStatus;
+use nacho_data_structures::{Address, RejectionReason, Transaction, TxStatus};
 use tokio::sync::{mpsc, oneshot};
 
 use super::Request;
@@ -38,11 +38,80 @@ impl Processor {
         tx_status
     }
 
-    pub async fn add_new_tx(&self) -> Option<u64> {
+    pub async fn get_rejection_reason(&self, tx_id: u64) -> Option<Option<RejectionReason>> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+        self.sender
+            .send(Request::GetRejectionReason {
+                sender: oneshot_sender,
+                tx_id,
+            })
+            .await
+            .ok()?;
+
+        let reason = oneshot_receiver.await.ok()?;
+
+        reason
+    }
+
+    pub async fn get_tx(&self, tx_id: u64) -> Option<Transaction> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+        self.sender
+            .send(Request::GetTx {
+                sender: oneshot_sender,
+                tx_id,
+            })
+            .await
+            .ok()?;
+
+        let tx = oneshot_receiver.await.ok()?;
+
+        tx
+    }
+
+    pub async fn get_tx_history(
+        &self,
+        address: Address,
+        from_tx_id: u64,
+        limit: u64,
+    ) -> Option<Vec<(u64, Transaction, TxStatus)>> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+        self.sender
+            .send(Request::GetTxHistory {
+                sender: oneshot_sender,
+                address,
+                from_tx_id,
+                limit,
+            })
+            .await
+            .ok()?;
+
+        let history = oneshot_receiver.await.ok()?;
+
+        history
+    }
+
+    pub async fn add_new_tx(&self, tx: Transaction) -> Option<u64> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
         self.sender
             .send(Request::AddNewTx {
                 sender: oneshot_sender,
+                tx,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    pub async fn add_to_history(&self, tx_id: u64, address: Address) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+        self.sender
+            .send(Request::AddToHistory {
+                sender: oneshot_sender,
+                tx_id,
+                address,
             })
             .await
             .ok()?;
@@ -52,12 +121,13 @@ impl Processor {
         result
     }
 
-    pub async fn set_rejected(&self, tx_id: u64) -> Option<()> {
+    pub async fn set_rejected(&self, tx_id: u64, reason: RejectionReason) -> Option<()> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
         self.sender
             .send(Request::SetRejected {
                 sender: oneshot_sender,
                 tx_id,
+                reason,
             })
             .await
             .ok()?;
@@ -96,6 +166,35 @@ impl Processor {
         result
     }
 
+    pub async fn set_processed_until(&self, until_tx_id: u64) -> Option<()> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+        self.sender
+            .send(Request::SetProcessedUntil {
+                sender: oneshot_sender,
+                until_tx_id,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
+    pub async fn get_processed_until(&self) -> Option<u64> {
+        let (oneshot_sender, oneshot_receiver) = oneshot::channel();
+        self.sender
+            .send(Request::GetProcessedUntil {
+                sender: oneshot_sender,
+            })
+            .await
+            .ok()?;
+
+        let result = oneshot_receiver.await.ok()?;
+
+        result
+    }
+
     pub async fn set_proved_until(&self, until_tx_id: u64) -> Option<()> {
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
         self.sender
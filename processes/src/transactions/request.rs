@@ -1,4 +1,4 @@
-use nacho_data_structures::TxStatus;
+use nacho_data_structures::{Address, RejectionReason, Transaction, TxStatus};
 use tokio::sync::oneshot;
 
 pub enum Request {
@@ -9,12 +9,33 @@ pub enum Request {
         sender: oneshot::Sender<Option<TxStatus>>,
         tx_id: u64,
     },
+    GetRejectionReason {
+        sender: oneshot::Sender<Option<Option<RejectionReason>>>,
+        tx_id: u64,
+    },
+    GetTx {
+        sender: oneshot::Sender<Option<Transaction>>,
+        tx_id: u64,
+    },
+    GetTxHistory {
+        sender: oneshot::Sender<Option<Vec<(u64, Transaction, TxStatus)>>>,
+        address: Address,
+        from_tx_id: u64,
+        limit: u64,
+    },
     AddNewTx {
         sender: oneshot::Sender<Option<u64>>,
+        tx: Transaction,
+    },
+    AddToHistory {
+        sender: oneshot::Sender<Option<()>>,
+        tx_id: u64,
+        address: Address,
     },
     SetRejected {
         sender: oneshot::Sender<Option<()>>,
         tx_id: u64,
+        reason: RejectionReason,
     },
     SetExecutedUntil {
         sender: oneshot::Sender<Option<()>>,
@@ -23,6 +44,13 @@ pub enum Request {
     GetExecutedUntil {
         sender: oneshot::Sender<Option<u64>>,
     },
+    SetProcessedUntil {
+        sender: oneshot::Sender<Option<()>>,
+        until_tx_id: u64,
+    },
+    GetProcessedUntil {
+        sender: oneshot::Sender<Option<u64>>,
+    },
     SetProvedUntil {
         sender: oneshot::Sender<Option<()>>,
         until_tx_id: u64,
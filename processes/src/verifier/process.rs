@@ -1,29 +1,69 @@
+use std::{cell::RefCell, thread::available_parallelism};
+
 use super::{Processor, Request};
-use nacho_data_structures::ByteConversion;
-use tokio::sync::mpsc;
+use nacho_data_structures::{ByteConversion, Transaction};
+use nacho_schnorr_signature::Signer;
+use tokio::{
+    process::{ChildStdin, ChildStdout},
+    sync::{mpsc, Mutex, Semaphore},
+    task::spawn_blocking,
+};
+
+thread_local! {
+    /// The signer of each blocking thread, as creating one is costly.
+    static SIGNER: RefCell<Signer> = RefCell::new(Signer::new());
+}
 
 pub fn process() -> Processor {
-    let signature_verifier_process_script_path =
-        std::env::var("NACHO_SIGNATURE_VERIFIER_PROCESS_SCRIPT_PATH").unwrap();
+    let maybe_signature_verifier_process_script_path =
+        std::env::var("NACHO_SIGNATURE_VERIFIER_PROCESS_SCRIPT_PATH").ok();
 
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
-    let (stdin, stdout) = nacho_js_process::spawn(&signature_verifier_process_script_path).unwrap();
+    let maybe_js_process: &'static Option<Mutex<(&mut ChildStdin, &mut ChildStdout)>> =
+        Box::leak(Box::new(
+            maybe_signature_verifier_process_script_path
+                .map(|path| Mutex::new(nacho_js_process::spawn(&path).unwrap())),
+        ));
+
+    let max_concurrent_checks = available_parallelism().map_or(1, |n| n.get());
+    let semaphore: &'static Semaphore = Box::leak(Box::new(Semaphore::new(max_concurrent_checks)));
 
     tokio::spawn(async move {
         while let Some(request) = receiver.recv().await {
             match request {
                 Request::CheckSignature { sender, tx } => {
-                    let input = tx.to_bytes();
-                    let mut output = [0u8; 1];
+                    let permit = semaphore.acquire().await.unwrap();
+
+                    tokio::spawn(async move {
+                        let maybe_is_valid = match check_signature_natively(tx.clone()).await {
+                            Some(is_valid) => Some(is_valid),
+                            None => match (maybe_js_process, js_verifier_input(&tx)) {
+                                (Some(js_process), Some(input)) => {
+                                    let (stdin, stdout) = &mut *js_process.lock().await;
+
+                                    let mut output = [0u8; 1];
 
-                    nacho_js_process::interact(stdin, stdout, &input, &mut output)
-                        .await
-                        .ok();
+                                    match nacho_js_process::interact(
+                                        stdin,
+                                        stdout,
+                                        &input,
+                                        &mut output,
+                                    )
+                                    .await
+                                    {
+                                        Ok(()) => Some(output[0] != 0),
+                                        Err(_) => None,
+                                    }
+                                }
+                                _ => None,
+                            },
+                        };
 
-                    let is_valid = output[0] != 0;
+                        drop(permit);
 
-                    sender.send(Some(is_valid)).unwrap();
+                        sender.send(maybe_is_valid).ok();
+                    });
                 }
             }
         }
@@ -33,3 +73,205 @@ pub fn process() -> Processor {
         sender: Box::leak(Box::new(sender)),
     }
 }
+
+/// Checks the signature of the given transaction using the Rust signer on a blocking thread.
+///
+/// Returns `None` if the check couldn't be performed, so that the JS verifier can be used as a fallback.
+async fn check_signature_natively(tx: Transaction) -> Option<bool> {
+    spawn_blocking(move || {
+        let (signature, msg) = match tx.signed_message() {
+            Some(signed_message) => signed_message,
+            None => return Some(true),
+        };
+
+        let pubkey = tx.address()?.to_pubkey().ok()?;
+
+        SIGNER.with(|signer| Some(signer.borrow_mut().check_signature(signature, &pubkey, msg)))
+    })
+    .await
+    .ok()?
+}
+
+/// Returns the input the JS verifier parses for the given transaction, if it is signed.
+///
+/// It has the layout of the transaction's RPC request, but the first byte is the JS verifier's own tag of the transaction kind.
+fn js_verifier_input(tx: &Transaction) -> Option<[u8; 240]> {
+    let mut input = [0u8; 240];
+
+    match tx {
+        Transaction::BurnTokens(tx) => {
+            input[0] = 0;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.token_id.to_bytes());
+            input[152..160].copy_from_slice(&tx.token_amount.to_bytes());
+            input[160..168].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::CreatePool(tx) => {
+            input[0] = 1;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.base_token_id.to_bytes());
+            input[152..184].copy_from_slice(&tx.quote_token_id.to_bytes());
+            input[184..192].copy_from_slice(&tx.base_token_amount.to_bytes());
+            input[192..200].copy_from_slice(&tx.quote_token_amount.to_bytes());
+            input[200..208].copy_from_slice(&tx.fee_rate.to_bytes());
+            input[208..209].copy_from_slice(&tx.pool_kind.to_bytes());
+            input[209..217].copy_from_slice(&tx.amplification.to_bytes());
+            input[217..225].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::ProvideLiquidity(tx) => {
+            input[0] = 2;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.base_token_id.to_bytes());
+            input[152..184].copy_from_slice(&tx.quote_token_id.to_bytes());
+            input[184..192].copy_from_slice(&tx.base_token_amount.to_bytes());
+            input[192..200].copy_from_slice(&tx.quote_token_amount_limit.to_bytes());
+            input[200..208].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::RemoveLiquidity(tx) => {
+            input[0] = 3;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.base_token_id.to_bytes());
+            input[152..184].copy_from_slice(&tx.quote_token_id.to_bytes());
+            input[184..192].copy_from_slice(&tx.base_token_amount_limit.to_bytes());
+            input[192..200].copy_from_slice(&tx.quote_token_amount_limit.to_bytes());
+            input[200..232].copy_from_slice(&tx.points.to_bytes());
+            input[232..240].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::BuyTokens(tx) => {
+            input[0] = 4;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.base_token_id.to_bytes());
+            input[152..184].copy_from_slice(&tx.quote_token_id.to_bytes());
+            input[184..192].copy_from_slice(&tx.base_token_amount.to_bytes());
+            input[192..200].copy_from_slice(&tx.quote_token_amount_limit.to_bytes());
+            input[200..208].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::SellTokens(tx) => {
+            input[0] = 5;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.base_token_id.to_bytes());
+            input[152..184].copy_from_slice(&tx.quote_token_id.to_bytes());
+            input[184..192].copy_from_slice(&tx.base_token_amount_limit.to_bytes());
+            input[192..200].copy_from_slice(&tx.quote_token_amount.to_bytes());
+            input[200..208].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::SwapRoute(tx) => {
+            input[0] = 6;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.path[0].to_bytes());
+            input[152..184].copy_from_slice(&tx.path[1].to_bytes());
+            input[184..216].copy_from_slice(&tx.path[2].to_bytes());
+            input[216..224].copy_from_slice(&tx.token_amount_in.to_bytes());
+            input[224..232].copy_from_slice(&tx.token_amount_out_limit.to_bytes());
+            input[232..240].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::TransferTokens(tx) => {
+            input[0] = 7;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..175].copy_from_slice(&tx.receiver_address.to_bytes());
+            input[175..207].copy_from_slice(&tx.token_id.to_bytes());
+            input[207..215].copy_from_slice(&tx.token_amount.to_bytes());
+            input[215..223].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::PlaceOrder(tx) => {
+            input[0] = 8;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.token_in_id.to_bytes());
+            input[152..184].copy_from_slice(&tx.token_out_id.to_bytes());
+            input[184..192].copy_from_slice(&tx.token_amount_in.to_bytes());
+            input[192..200].copy_from_slice(&tx.token_amount_out_limit.to_bytes());
+            input[200..208].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::CancelOrder(tx) => {
+            input[0] = 9;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..128].copy_from_slice(&tx.order_id.to_bytes());
+            input[128..136].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        Transaction::ClaimProtocolFees(tx) => {
+            input[0] = 10;
+            input[1..56].copy_from_slice(&tx.address.to_bytes());
+            input[56..120].copy_from_slice(&tx.signature.to_bytes());
+            input[120..152].copy_from_slice(&tx.base_token_id.to_bytes());
+            input[152..184].copy_from_slice(&tx.quote_token_id.to_bytes());
+            input[184..192].copy_from_slice(&tx.nonce.to_bytes());
+        }
+        _ => return None,
+    }
+
+    Some(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use nacho_data_structures::{ByteConversion, Field};
+    use nacho_rpc_server::RpcMethod;
+
+    use super::js_verifier_input;
+
+    /// The requests encoded by the SDK, their signatures and the messages the JS verifier checks them against.
+    ///
+    /// They are generated by `signature-verifier-process/scripts/generate-message-vectors.mjs`.
+    const MESSAGE_VECTORS: &str = include_str!("message_vectors.txt");
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn signs_same_messages_as_sdk() {
+        let mut kinds = HashSet::new();
+
+        for line in MESSAGE_VECTORS.lines().filter(|line| !line.is_empty()) {
+            let [kind, hex, r, s, fields] = line.split(' ').collect::<Vec<_>>().try_into().unwrap();
+
+            let bytes: [u8; RpcMethod::SIZE_IN_BYTES] = decode_hex(hex).try_into().unwrap();
+            let tx = RpcMethod::from_bytes(&bytes).into_transaction().unwrap();
+
+            let (signature, msg) = tx.signed_message().unwrap();
+            let signature_bytes = signature.to_bytes();
+
+            assert_eq!(
+                Field::from_bytes(signature_bytes[0..32].try_into().unwrap()),
+                r.parse::<Field>().unwrap(),
+                "{kind}"
+            );
+            assert_eq!(
+                Field::from_bytes(signature_bytes[32..64].try_into().unwrap()),
+                s.parse::<Field>().unwrap(),
+                "{kind}"
+            );
+            assert_eq!(
+                msg,
+                fields
+                    .split(',')
+                    .map(|field| field.parse::<Field>().unwrap())
+                    .collect::<Vec<_>>(),
+                "{kind}"
+            );
+
+            // The JS verifier parses the same layouts as the RPC server, except for the method tags.
+            let input = js_verifier_input(&tx).unwrap();
+            assert_eq!(input[1..], bytes[1..], "{kind}");
+
+            kinds.insert(kind.to_owned());
+        }
+
+        assert_eq!(kinds.len(), 11);
+    }
+}
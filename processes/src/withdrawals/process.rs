@@ -2,6 +2,7 @@ use nacho_withdrawals_db::WithdrawalsDb;
 use tokio::sync::mpsc;
 
 use super::{Processor, Request};
+use crate::abort;
 
 pub fn process() -> Processor {
     let withdrawals_db_path = std::env::var("NACHO_WITHRAWALS_DB_PATH").unwrap();
@@ -9,7 +10,9 @@ pub fn process() -> Processor {
     let (sender, mut receiver) = mpsc::channel::<Request>(1000);
 
     tokio::spawn(async move {
-        let mut withdrawals_db = WithdrawalsDb::new(withdrawals_db_path).await.unwrap();
+        let mut withdrawals_db = WithdrawalsDb::new(withdrawals_db_path)
+            .await
+            .unwrap_or_else(|err| abort(&format!("Couldn't open the Withdrawals DB: {err}")));
 
         while let Some(request) = receiver.recv().await {
             match request {
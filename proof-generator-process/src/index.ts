@@ -9,6 +9,12 @@ import {
     makeRemoveLiquidity,
     makeBuyTokens,
     makeSellTokens,
+    makeSwapRoute,
+    makeTransferTokens,
+    makePlaceOrder,
+    makeCancelOrder,
+    makeFillOrder,
+    makeClaimProtocolFees,
 } from "./prove"
 import { saveProofToDisk } from "./utils"
 import { proofGenerator } from "nacho-proof-generator"
@@ -43,6 +49,18 @@ stdin.on("data", async (chunk) => {
                 ? await makeBuyTokens(input, proofsPath)
                 : input.kind === "SellTokens"
                 ? await makeSellTokens(input, proofsPath)
+                : input.kind === "SwapRoute"
+                ? await makeSwapRoute(input, proofsPath)
+                : input.kind === "TransferTokens"
+                ? await makeTransferTokens(input, proofsPath)
+                : input.kind === "PlaceOrder"
+                ? await makePlaceOrder(input, proofsPath)
+                : input.kind === "CancelOrder"
+                ? await makeCancelOrder(input, proofsPath)
+                : input.kind === "FillOrder"
+                ? await makeFillOrder(input, proofsPath)
+                : input.kind === "ClaimProtocolFees"
+                ? await makeClaimProtocolFees(input, proofsPath)
                 : null
 
         if (input.kind === "CreateGenesis") {
@@ -54,7 +72,13 @@ stdin.on("data", async (chunk) => {
             input.kind === "ProvideLiquidity" ||
             input.kind === "RemoveLiquidity" ||
             input.kind === "BuyTokens" ||
-            input.kind === "SellTokens"
+            input.kind === "SellTokens" ||
+            input.kind === "SwapRoute" ||
+            input.kind === "TransferTokens" ||
+            input.kind === "PlaceOrder" ||
+            input.kind === "CancelOrder" ||
+            input.kind === "FillOrder" ||
+            input.kind === "ClaimProtocolFees"
         ) {
             await saveProofToDisk(proofsPath, input.earlier_proof_index + 1n, proof!)
         }
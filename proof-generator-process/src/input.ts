@@ -1,8 +1,13 @@
 import {
     DoubleBalanceWitness,
+    DoublePoolWitness,
+    Order,
+    Pool,
     SingleBalanceWitness,
     SingleBurnWitness,
     SingleLiquidityWitness,
+    SingleNonceWitness,
+    SingleOrderWitness,
     SinglePoolWitness,
     StateRoots,
 } from "./types"
@@ -16,6 +21,12 @@ export type Input =
     | RemoveLiquidity
     | BuyTokens
     | SellTokens
+    | SwapRoute
+    | TransferTokens
+    | PlaceOrder
+    | CancelOrder
+    | FillOrder
+    | ClaimProtocolFees
     | MistakenInput
 
 export type CreateGenesis = {
@@ -42,12 +53,14 @@ export type BurnTokens = {
     earlier_proof_index: bigint
     single_balance_witness: SingleBalanceWitness
     single_burn_witness: SingleBurnWitness
+    single_nonce_witness: SingleNonceWitness
     user_address: string
     token_id: bigint
     user_burn_token_amount: bigint
     user_balance_token_amount: bigint
     amount_to_burn: bigint
     user_signature: [bigint, bigint]
+    user_nonce: bigint
 }
 
 export type CreatePool = {
@@ -57,6 +70,7 @@ export type CreatePool = {
     single_pool_witness: SinglePoolWitness
     single_liquidity_witness: SingleLiquidityWitness
     double_balance_witness: DoubleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
     base_token_id: bigint
     quote_token_id: bigint
     user_address: string
@@ -65,6 +79,9 @@ export type CreatePool = {
     user_balance_base_token_amount: bigint
     user_balance_quote_token_amount: bigint
     user_signature: [bigint, bigint]
+    user_nonce: bigint
+    pool_fee_rate: bigint
+    pool_amplification: bigint
 }
 
 export type ProvideLiquidity = {
@@ -74,6 +91,7 @@ export type ProvideLiquidity = {
     single_pool_witness: SinglePoolWitness
     single_liquidity_witness: SingleLiquidityWitness
     double_balance_witness: DoubleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
     base_token_id: bigint
     quote_token_id: bigint
     user_address: string
@@ -86,6 +104,11 @@ export type ProvideLiquidity = {
     user_base_token_amount_to_provide: bigint
     user_quote_token_amount_limit_to_provide: bigint
     user_signature: [bigint, bigint]
+    user_nonce: bigint
+    pool_fee_rate: bigint
+    pool_protocol_fee_base_token_amount: bigint
+    pool_protocol_fee_quote_token_amount: bigint
+    pool_amplification: bigint
 }
 
 export type RemoveLiquidity = {
@@ -95,6 +118,7 @@ export type RemoveLiquidity = {
     single_pool_witness: SinglePoolWitness
     single_liquidity_witness: SingleLiquidityWitness
     double_balance_witness: DoubleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
     base_token_id: bigint
     quote_token_id: bigint
     user_address: string
@@ -108,6 +132,11 @@ export type RemoveLiquidity = {
     user_base_token_amount_limit_to_remove: bigint
     user_quote_token_amount_limit_to_remove: bigint
     user_signature: [bigint, bigint]
+    user_nonce: bigint
+    pool_fee_rate: bigint
+    pool_protocol_fee_base_token_amount: bigint
+    pool_protocol_fee_quote_token_amount: bigint
+    pool_amplification: bigint
 }
 
 export type BuyTokens = {
@@ -116,6 +145,7 @@ export type BuyTokens = {
     earlier_proof_index: bigint
     single_pool_witness: SinglePoolWitness
     double_balance_witness: DoubleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
     user_address: string
     base_token_id: bigint
     quote_token_id: bigint
@@ -127,6 +157,12 @@ export type BuyTokens = {
     user_base_token_amount_to_swap: bigint
     user_quote_token_amount_limit_to_swap: bigint
     user_signature: [bigint, bigint]
+    user_nonce: bigint
+    pool_fee_rate: bigint
+    pool_protocol_fee_base_token_amount: bigint
+    pool_protocol_fee_quote_token_amount: bigint
+    protocol_fee_share: bigint
+    pool_amplification: bigint
 }
 
 export type SellTokens = {
@@ -135,6 +171,7 @@ export type SellTokens = {
     earlier_proof_index: bigint
     single_pool_witness: SinglePoolWitness
     double_balance_witness: DoubleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
     user_address: string
     base_token_id: bigint
     quote_token_id: bigint
@@ -146,6 +183,107 @@ export type SellTokens = {
     user_base_token_amount_limit_to_swap: bigint
     user_quote_token_amount_to_swap: bigint
     user_signature: [bigint, bigint]
+    user_nonce: bigint
+    pool_fee_rate: bigint
+    pool_protocol_fee_base_token_amount: bigint
+    pool_protocol_fee_quote_token_amount: bigint
+    protocol_fee_share: bigint
+    pool_amplification: bigint
+}
+
+export type SwapRoute = {
+    kind: "SwapRoute"
+    state_roots: StateRoots
+    earlier_proof_index: bigint
+    double_pool_witness: DoublePoolWitness
+    double_balance_witness: DoubleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
+    user_address: string
+    path: [bigint, bigint, bigint]
+    user_balance_token_in_amount: bigint
+    user_balance_token_out_amount: bigint
+    first_pool: Pool
+    second_pool: Pool
+    user_token_amount_in: bigint
+    user_token_amount_out_limit: bigint
+    user_signature: [bigint, bigint]
+    user_nonce: bigint
+    protocol_fee_share: bigint
+}
+
+export type TransferTokens = {
+    kind: "TransferTokens"
+    state_roots: StateRoots
+    earlier_proof_index: bigint
+    double_balance_witness: DoubleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
+    user_address: string
+    receiver_address: string
+    token_id: bigint
+    user_balance_token_amount: bigint
+    receiver_balance_token_amount: bigint
+    amount_to_transfer: bigint
+    user_signature: [bigint, bigint]
+    user_nonce: bigint
+}
+
+export type PlaceOrder = {
+    kind: "PlaceOrder"
+    state_roots: StateRoots
+    earlier_proof_index: bigint
+    single_order_witness: SingleOrderWitness
+    single_balance_witness: SingleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
+    user_address: string
+    token_in_id: bigint
+    token_out_id: bigint
+    user_balance_token_in_amount: bigint
+    user_token_amount_in: bigint
+    user_token_amount_out_limit: bigint
+    order_id: bigint
+    user_signature: [bigint, bigint]
+    user_nonce: bigint
+}
+
+export type CancelOrder = {
+    kind: "CancelOrder"
+    state_roots: StateRoots
+    earlier_proof_index: bigint
+    single_order_witness: SingleOrderWitness
+    single_balance_witness: SingleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
+    order: Order
+    user_balance_token_in_amount: bigint
+    user_signature: [bigint, bigint]
+    user_nonce: bigint
+}
+
+export type FillOrder = {
+    kind: "FillOrder"
+    state_roots: StateRoots
+    earlier_proof_index: bigint
+    single_order_witness: SingleOrderWitness
+    single_pool_witness: SinglePoolWitness
+    single_balance_witness: SingleBalanceWitness
+    order: Order
+    pool: Pool
+    user_balance_token_out_amount: bigint
+    protocol_fee_share: bigint
+}
+
+export type ClaimProtocolFees = {
+    kind: "ClaimProtocolFees"
+    state_roots: StateRoots
+    earlier_proof_index: bigint
+    single_pool_witness: SinglePoolWitness
+    double_balance_witness: DoubleBalanceWitness
+    single_nonce_witness: SingleNonceWitness
+    user_address: string
+    pool: Pool
+    user_balance_base_token_amount: bigint
+    user_balance_quote_token_amount: bigint
+    user_signature: [bigint, bigint]
+    user_nonce: bigint
 }
 
 export type MistakenInput = {
@@ -1,21 +1,27 @@
 import {
     BurnTokens,
     BuyTokens,
+    CancelOrder,
+    ClaimProtocolFees,
     CreateGenesis,
     CreatePool,
     DepositTokens,
+    FillOrder,
     Input,
     MistakenInput,
+    PlaceOrder,
     ProvideLiquidity,
     RemoveLiquidity,
     SellTokens,
+    SwapRoute,
+    TransferTokens,
 } from "./input"
-import { Sibling, StateRoots } from "./types"
+import { Order, Pool, Sibling, StateRoots } from "./types"
 
 export const parseInput = (buffer: Buffer): Input => {
     const array = new Uint8Array(buffer)
 
-    if (array.length !== 3291) {
+    if (array.length !== 4259) {
         return {
             kind: "MistakenInput",
         }
@@ -25,141 +31,278 @@ export const parseInput = (buffer: Buffer): Input => {
         case 0: {
             return {
                 kind: "CreateGenesis",
-                state_roots: parseStateRoots(buffer.subarray(1, 129)),
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
             } satisfies CreateGenesis
         }
         case 1: {
             return {
                 kind: "DepositTokens",
-                state_roots: parseStateRoots(buffer.subarray(1, 129)),
-                earlier_proof_index: parseUint64(buffer.subarray(129, 137)),
-                single_balance_witness: parseSingleWitness(buffer.subarray(137, 863)),
-                current_deposits_merkle_list_hash: parseUint256(buffer.subarray(863, 895)),
-                expected_deposits_merkle_list_hash: parseUint256(buffer.subarray(895, 927)),
-                user_address: parseAddress(buffer.subarray(927, 982)),
-                token_id: parseUint256(buffer.subarray(982, 1014)),
-                user_deposit_token_amount: parseUint64(buffer.subarray(1014, 1022)),
-                user_balance_token_amount: parseUint64(buffer.subarray(1022, 1030)),
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_balance_witness: parseSingleWitness(buffer.subarray(201, 927)),
+                current_deposits_merkle_list_hash: parseUint256(buffer.subarray(927, 959)),
+                expected_deposits_merkle_list_hash: parseUint256(buffer.subarray(959, 991)),
+                user_address: parseAddress(buffer.subarray(991, 1046)),
+                token_id: parseUint256(buffer.subarray(1046, 1078)),
+                user_deposit_token_amount: parseUint64(buffer.subarray(1078, 1086)),
+                user_balance_token_amount: parseUint64(buffer.subarray(1086, 1094)),
             } satisfies DepositTokens
         }
         case 2: {
             return {
                 kind: "BurnTokens",
-                state_roots: parseStateRoots(buffer.subarray(1, 129)),
-                earlier_proof_index: parseUint64(buffer.subarray(129, 137)),
-                single_balance_witness: parseSingleWitness(buffer.subarray(137, 863)),
-                single_burn_witness: parseSingleWitness(buffer.subarray(863, 1490)),
-                user_address: parseAddress(buffer.subarray(1490, 1545)),
-                token_id: parseUint256(buffer.subarray(1545, 1577)),
-                user_burn_token_amount: parseUint64(buffer.subarray(1577, 1585)),
-                user_balance_token_amount: parseUint64(buffer.subarray(1585, 1593)),
-                amount_to_burn: parseUint64(buffer.subarray(1593, 1601)),
-                user_signature: parseSignature(buffer.subarray(1601, 1665)),
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_balance_witness: parseSingleWitness(buffer.subarray(201, 927)),
+                single_burn_witness: parseSingleWitness(buffer.subarray(927, 1554)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(1554, 2247)),
+                user_address: parseAddress(buffer.subarray(2247, 2302)),
+                token_id: parseUint256(buffer.subarray(2302, 2334)),
+                user_burn_token_amount: parseUint64(buffer.subarray(2334, 2342)),
+                user_balance_token_amount: parseUint64(buffer.subarray(2342, 2350)),
+                amount_to_burn: parseUint64(buffer.subarray(2350, 2358)),
+                user_signature: parseSignature(buffer.subarray(2358, 2422)),
+                user_nonce: parseUint64(buffer.subarray(2422, 2430)),
             } satisfies BurnTokens
         }
         case 3: {
             return {
                 kind: "CreatePool",
-                state_roots: parseStateRoots(buffer.subarray(1, 129)),
-                earlier_proof_index: parseUint64(buffer.subarray(129, 137)),
-                single_pool_witness: parseSingleWitness(buffer.subarray(137, 797)),
-                single_liquidity_witness: parseSingleWitness(buffer.subarray(797, 1490)),
-                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(1490, 2964)),
-                base_token_id: parseUint256(buffer.subarray(2964, 2996)),
-                quote_token_id: parseUint256(buffer.subarray(2996, 3028)),
-                user_address: parseAddress(buffer.subarray(3028, 3083)),
-                user_liquidity_base_token_amount: parseUint64(buffer.subarray(3083, 3091)),
-                user_liquidity_quote_token_amount: parseUint64(buffer.subarray(3091, 3099)),
-                user_balance_base_token_amount: parseUint64(buffer.subarray(3099, 3107)),
-                user_balance_quote_token_amount: parseUint64(buffer.subarray(3107, 3115)),
-                user_signature: parseSignature(buffer.subarray(3115, 3179)),
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_pool_witness: parseSingleWitness(buffer.subarray(201, 861)),
+                single_liquidity_witness: parseSingleWitness(buffer.subarray(861, 1554)),
+                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(1554, 3028)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(3028, 3721)),
+                base_token_id: parseUint256(buffer.subarray(3721, 3753)),
+                quote_token_id: parseUint256(buffer.subarray(3753, 3785)),
+                user_address: parseAddress(buffer.subarray(3785, 3840)),
+                user_liquidity_base_token_amount: parseUint64(buffer.subarray(3840, 3848)),
+                user_liquidity_quote_token_amount: parseUint64(buffer.subarray(3848, 3856)),
+                user_balance_base_token_amount: parseUint64(buffer.subarray(3856, 3864)),
+                user_balance_quote_token_amount: parseUint64(buffer.subarray(3864, 3872)),
+                user_signature: parseSignature(buffer.subarray(3872, 3936)),
+                user_nonce: parseUint64(buffer.subarray(3936, 3944)),
+                pool_fee_rate: parseUint64(buffer.subarray(3944, 3952)),
+                pool_amplification: parseUint64(buffer.subarray(3952, 3960)),
             } satisfies CreatePool
         }
         case 4: {
             return {
                 kind: "ProvideLiquidity",
-                state_roots: parseStateRoots(buffer.subarray(1, 129)),
-                earlier_proof_index: parseUint64(buffer.subarray(129, 137)),
-                single_pool_witness: parseSingleWitness(buffer.subarray(137, 797)),
-                single_liquidity_witness: parseSingleWitness(buffer.subarray(797, 1490)),
-                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(1490, 2964)),
-                base_token_id: parseUint256(buffer.subarray(2964, 2996)),
-                quote_token_id: parseUint256(buffer.subarray(2996, 3028)),
-                user_address: parseAddress(buffer.subarray(3028, 3083)),
-                user_liquidity_points: parseUint256(buffer.subarray(3083, 3115)),
-                user_balance_base_token_amount: parseUint64(buffer.subarray(3115, 3123)),
-                user_balance_quote_token_amount: parseUint64(buffer.subarray(3123, 3131)),
-                pool_base_token_amount: parseUint64(buffer.subarray(3131, 3139)),
-                pool_quote_token_amount: parseUint64(buffer.subarray(3139, 3147)),
-                pool_total_liquidity_points: parseUint256(buffer.subarray(3147, 3179)),
-                user_base_token_amount_to_provide: parseUint64(buffer.subarray(3179, 3187)),
-                user_quote_token_amount_limit_to_provide: parseUint64(buffer.subarray(3187, 3195)),
-                user_signature: parseSignature(buffer.subarray(3195, 3259)),
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_pool_witness: parseSingleWitness(buffer.subarray(201, 861)),
+                single_liquidity_witness: parseSingleWitness(buffer.subarray(861, 1554)),
+                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(1554, 3028)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(3028, 3721)),
+                base_token_id: parseUint256(buffer.subarray(3721, 3753)),
+                quote_token_id: parseUint256(buffer.subarray(3753, 3785)),
+                user_address: parseAddress(buffer.subarray(3785, 3840)),
+                user_liquidity_points: parseUint256(buffer.subarray(3840, 3872)),
+                user_balance_base_token_amount: parseUint64(buffer.subarray(3872, 3880)),
+                user_balance_quote_token_amount: parseUint64(buffer.subarray(3880, 3888)),
+                pool_base_token_amount: parseUint64(buffer.subarray(3888, 3896)),
+                pool_quote_token_amount: parseUint64(buffer.subarray(3896, 3904)),
+                pool_total_liquidity_points: parseUint256(buffer.subarray(3904, 3936)),
+                user_base_token_amount_to_provide: parseUint64(buffer.subarray(3936, 3944)),
+                user_quote_token_amount_limit_to_provide: parseUint64(buffer.subarray(3944, 3952)),
+                user_signature: parseSignature(buffer.subarray(3952, 4016)),
+                user_nonce: parseUint64(buffer.subarray(4016, 4024)),
+                pool_fee_rate: parseUint64(buffer.subarray(4024, 4032)),
+                pool_protocol_fee_base_token_amount: parseUint64(buffer.subarray(4032, 4040)),
+                pool_protocol_fee_quote_token_amount: parseUint64(buffer.subarray(4040, 4048)),
+                pool_amplification: parseUint64(buffer.subarray(4048, 4056)),
             } satisfies ProvideLiquidity
         }
         case 5: {
             return {
                 kind: "RemoveLiquidity",
-                state_roots: parseStateRoots(buffer.subarray(1, 129)),
-                earlier_proof_index: parseUint64(buffer.subarray(129, 137)),
-                single_pool_witness: parseSingleWitness(buffer.subarray(137, 797)),
-                single_liquidity_witness: parseSingleWitness(buffer.subarray(797, 1490)),
-                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(1490, 2964)),
-                base_token_id: parseUint256(buffer.subarray(2964, 2996)),
-                quote_token_id: parseUint256(buffer.subarray(2996, 3028)),
-                user_address: parseAddress(buffer.subarray(3028, 3083)),
-                user_liquidity_points: parseUint256(buffer.subarray(3083, 3115)),
-                user_balance_base_token_amount: parseUint64(buffer.subarray(3115, 3123)),
-                user_balance_quote_token_amount: parseUint64(buffer.subarray(3123, 3131)),
-                pool_base_token_amount: parseUint64(buffer.subarray(3131, 3139)),
-                pool_quote_token_amount: parseUint64(buffer.subarray(3139, 3147)),
-                pool_total_liquidity_points: parseUint256(buffer.subarray(3147, 3179)),
-                user_liquidity_points_to_remove: parseUint256(buffer.subarray(3179, 3211)),
-                user_base_token_amount_limit_to_remove: parseUint64(buffer.subarray(3211, 3219)),
-                user_quote_token_amount_limit_to_remove: parseUint64(buffer.subarray(3219, 3227)),
-                user_signature: parseSignature(buffer.subarray(3227, 3291)),
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_pool_witness: parseSingleWitness(buffer.subarray(201, 861)),
+                single_liquidity_witness: parseSingleWitness(buffer.subarray(861, 1554)),
+                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(1554, 3028)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(3028, 3721)),
+                base_token_id: parseUint256(buffer.subarray(3721, 3753)),
+                quote_token_id: parseUint256(buffer.subarray(3753, 3785)),
+                user_address: parseAddress(buffer.subarray(3785, 3840)),
+                user_liquidity_points: parseUint256(buffer.subarray(3840, 3872)),
+                user_balance_base_token_amount: parseUint64(buffer.subarray(3872, 3880)),
+                user_balance_quote_token_amount: parseUint64(buffer.subarray(3880, 3888)),
+                pool_base_token_amount: parseUint64(buffer.subarray(3888, 3896)),
+                pool_quote_token_amount: parseUint64(buffer.subarray(3896, 3904)),
+                pool_total_liquidity_points: parseUint256(buffer.subarray(3904, 3936)),
+                user_liquidity_points_to_remove: parseUint256(buffer.subarray(3936, 3968)),
+                user_base_token_amount_limit_to_remove: parseUint64(buffer.subarray(3968, 3976)),
+                user_quote_token_amount_limit_to_remove: parseUint64(buffer.subarray(3976, 3984)),
+                user_signature: parseSignature(buffer.subarray(3984, 4048)),
+                user_nonce: parseUint64(buffer.subarray(4048, 4056)),
+                pool_fee_rate: parseUint64(buffer.subarray(4056, 4064)),
+                pool_protocol_fee_base_token_amount: parseUint64(buffer.subarray(4064, 4072)),
+                pool_protocol_fee_quote_token_amount: parseUint64(buffer.subarray(4072, 4080)),
+                pool_amplification: parseUint64(buffer.subarray(4080, 4088)),
             } satisfies RemoveLiquidity
         }
         case 6: {
             return {
                 kind: "BuyTokens",
-                state_roots: parseStateRoots(buffer.subarray(1, 129)),
-                earlier_proof_index: parseUint64(buffer.subarray(129, 137)),
-                single_pool_witness: parseSingleWitness(buffer.subarray(137, 797)),
-                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(797, 2271)),
-                user_address: parseAddress(buffer.subarray(2271, 2326)),
-                base_token_id: parseUint256(buffer.subarray(2326, 2358)),
-                quote_token_id: parseUint256(buffer.subarray(2358, 2390)),
-                user_balance_base_token_amount: parseUint64(buffer.subarray(2390, 2398)),
-                user_balance_quote_token_amount: parseUint64(buffer.subarray(2398, 2406)),
-                pool_base_token_amount: parseUint64(buffer.subarray(2406, 2414)),
-                pool_quote_token_amount: parseUint64(buffer.subarray(2414, 2422)),
-                pool_total_liquidity_points: parseUint256(buffer.subarray(2422, 2454)),
-                user_base_token_amount_to_swap: parseUint64(buffer.subarray(2454, 2462)),
-                user_quote_token_amount_limit_to_swap: parseUint64(buffer.subarray(2462, 2470)),
-                user_signature: parseSignature(buffer.subarray(2470, 2534)),
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_pool_witness: parseSingleWitness(buffer.subarray(201, 861)),
+                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(861, 2335)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(2335, 3028)),
+                user_address: parseAddress(buffer.subarray(3028, 3083)),
+                base_token_id: parseUint256(buffer.subarray(3083, 3115)),
+                quote_token_id: parseUint256(buffer.subarray(3115, 3147)),
+                user_balance_base_token_amount: parseUint64(buffer.subarray(3147, 3155)),
+                user_balance_quote_token_amount: parseUint64(buffer.subarray(3155, 3163)),
+                pool_base_token_amount: parseUint64(buffer.subarray(3163, 3171)),
+                pool_quote_token_amount: parseUint64(buffer.subarray(3171, 3179)),
+                pool_total_liquidity_points: parseUint256(buffer.subarray(3179, 3211)),
+                user_base_token_amount_to_swap: parseUint64(buffer.subarray(3211, 3219)),
+                user_quote_token_amount_limit_to_swap: parseUint64(buffer.subarray(3219, 3227)),
+                user_signature: parseSignature(buffer.subarray(3227, 3291)),
+                user_nonce: parseUint64(buffer.subarray(3291, 3299)),
+                pool_fee_rate: parseUint64(buffer.subarray(3299, 3307)),
+                pool_protocol_fee_base_token_amount: parseUint64(buffer.subarray(3307, 3315)),
+                pool_protocol_fee_quote_token_amount: parseUint64(buffer.subarray(3315, 3323)),
+                protocol_fee_share: parseUint64(buffer.subarray(3323, 3331)),
+                pool_amplification: parseUint64(buffer.subarray(3331, 3339)),
             } satisfies BuyTokens
         }
         case 7: {
             return {
                 kind: "SellTokens",
-                state_roots: parseStateRoots(buffer.subarray(1, 129)),
-                earlier_proof_index: parseUint64(buffer.subarray(129, 137)),
-                single_pool_witness: parseSingleWitness(buffer.subarray(137, 797)),
-                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(797, 2271)),
-                user_address: parseAddress(buffer.subarray(2271, 2326)),
-                base_token_id: parseUint256(buffer.subarray(2326, 2358)),
-                quote_token_id: parseUint256(buffer.subarray(2358, 2390)),
-                user_balance_base_token_amount: parseUint64(buffer.subarray(2390, 2398)),
-                user_balance_quote_token_amount: parseUint64(buffer.subarray(2398, 2406)),
-                pool_base_token_amount: parseUint64(buffer.subarray(2406, 2414)),
-                pool_quote_token_amount: parseUint64(buffer.subarray(2414, 2422)),
-                pool_total_liquidity_points: parseUint256(buffer.subarray(2422, 2454)),
-                user_base_token_amount_limit_to_swap: parseUint64(buffer.subarray(2454, 2462)),
-                user_quote_token_amount_to_swap: parseUint64(buffer.subarray(2462, 2470)),
-                user_signature: parseSignature(buffer.subarray(2470, 2534)),
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_pool_witness: parseSingleWitness(buffer.subarray(201, 861)),
+                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(861, 2335)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(2335, 3028)),
+                user_address: parseAddress(buffer.subarray(3028, 3083)),
+                base_token_id: parseUint256(buffer.subarray(3083, 3115)),
+                quote_token_id: parseUint256(buffer.subarray(3115, 3147)),
+                user_balance_base_token_amount: parseUint64(buffer.subarray(3147, 3155)),
+                user_balance_quote_token_amount: parseUint64(buffer.subarray(3155, 3163)),
+                pool_base_token_amount: parseUint64(buffer.subarray(3163, 3171)),
+                pool_quote_token_amount: parseUint64(buffer.subarray(3171, 3179)),
+                pool_total_liquidity_points: parseUint256(buffer.subarray(3179, 3211)),
+                user_base_token_amount_limit_to_swap: parseUint64(buffer.subarray(3211, 3219)),
+                user_quote_token_amount_to_swap: parseUint64(buffer.subarray(3219, 3227)),
+                user_signature: parseSignature(buffer.subarray(3227, 3291)),
+                user_nonce: parseUint64(buffer.subarray(3291, 3299)),
+                pool_fee_rate: parseUint64(buffer.subarray(3299, 3307)),
+                pool_protocol_fee_base_token_amount: parseUint64(buffer.subarray(3307, 3315)),
+                pool_protocol_fee_quote_token_amount: parseUint64(buffer.subarray(3315, 3323)),
+                protocol_fee_share: parseUint64(buffer.subarray(3323, 3331)),
+                pool_amplification: parseUint64(buffer.subarray(3331, 3339)),
             } satisfies SellTokens
         }
+        case 8: {
+            return {
+                kind: "SwapRoute",
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                double_pool_witness: parseDoublePoolWitness(buffer.subarray(201, 1541)),
+                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(1541, 3015)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(3015, 3708)),
+                user_address: parseAddress(buffer.subarray(3708, 3763)),
+                path: [
+                    parseUint256(buffer.subarray(3763, 3795)),
+                    parseUint256(buffer.subarray(3795, 3827)),
+                    parseUint256(buffer.subarray(3827, 3859)),
+                ],
+                user_balance_token_in_amount: parseUint64(buffer.subarray(3859, 3867)),
+                user_balance_token_out_amount: parseUint64(buffer.subarray(3867, 3875)),
+                first_pool: parsePool(buffer.subarray(3875, 4019)),
+                second_pool: parsePool(buffer.subarray(4019, 4163)),
+                user_token_amount_in: parseUint64(buffer.subarray(4163, 4171)),
+                user_token_amount_out_limit: parseUint64(buffer.subarray(4171, 4179)),
+                user_signature: parseSignature(buffer.subarray(4179, 4243)),
+                user_nonce: parseUint64(buffer.subarray(4243, 4251)),
+                protocol_fee_share: parseUint64(buffer.subarray(4251, 4259)),
+            } satisfies SwapRoute
+        }
+        case 9: {
+            return {
+                kind: "TransferTokens",
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(201, 1675)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(1675, 2368)),
+                user_address: parseAddress(buffer.subarray(2368, 2423)),
+                receiver_address: parseAddress(buffer.subarray(2423, 2478)),
+                token_id: parseUint256(buffer.subarray(2478, 2510)),
+                user_balance_token_amount: parseUint64(buffer.subarray(2510, 2518)),
+                receiver_balance_token_amount: parseUint64(buffer.subarray(2518, 2526)),
+                amount_to_transfer: parseUint64(buffer.subarray(2526, 2534)),
+                user_signature: parseSignature(buffer.subarray(2534, 2598)),
+                user_nonce: parseUint64(buffer.subarray(2598, 2606)),
+            } satisfies TransferTokens
+        }
+        case 10: {
+            return {
+                kind: "PlaceOrder",
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_order_witness: parseSingleWitness(buffer.subarray(201, 894)),
+                single_balance_witness: parseSingleWitness(buffer.subarray(894, 1620)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(1620, 2313)),
+                user_address: parseAddress(buffer.subarray(2313, 2368)),
+                token_in_id: parseUint256(buffer.subarray(2368, 2400)),
+                token_out_id: parseUint256(buffer.subarray(2400, 2432)),
+                user_balance_token_in_amount: parseUint64(buffer.subarray(2432, 2440)),
+                user_token_amount_in: parseUint64(buffer.subarray(2440, 2448)),
+                user_token_amount_out_limit: parseUint64(buffer.subarray(2448, 2456)),
+                order_id: parseUint64(buffer.subarray(2456, 2464)),
+                user_signature: parseSignature(buffer.subarray(2464, 2528)),
+                user_nonce: parseUint64(buffer.subarray(2528, 2536)),
+            } satisfies PlaceOrder
+        }
+        case 11: {
+            return {
+                kind: "CancelOrder",
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_order_witness: parseSingleWitness(buffer.subarray(201, 894)),
+                single_balance_witness: parseSingleWitness(buffer.subarray(894, 1620)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(1620, 2313)),
+                order: parseOrder(buffer.subarray(2313, 2457)),
+                user_balance_token_in_amount: parseUint64(buffer.subarray(2457, 2465)),
+                user_signature: parseSignature(buffer.subarray(2465, 2529)),
+                user_nonce: parseUint64(buffer.subarray(2529, 2537)),
+            } satisfies CancelOrder
+        }
+        case 12: {
+            return {
+                kind: "FillOrder",
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_order_witness: parseSingleWitness(buffer.subarray(201, 894)),
+                single_pool_witness: parseSingleWitness(buffer.subarray(894, 1554)),
+                single_balance_witness: parseSingleWitness(buffer.subarray(1554, 2280)),
+                order: parseOrder(buffer.subarray(2280, 2424)),
+                pool: parsePool(buffer.subarray(2424, 2568)),
+                user_balance_token_out_amount: parseUint64(buffer.subarray(2568, 2576)),
+                protocol_fee_share: parseUint64(buffer.subarray(2576, 2584)),
+            } satisfies FillOrder
+        }
+        case 13: {
+            return {
+                kind: "ClaimProtocolFees",
+                state_roots: parseStateRoots(buffer.subarray(1, 193)),
+                earlier_proof_index: parseUint64(buffer.subarray(193, 201)),
+                single_pool_witness: parseSingleWitness(buffer.subarray(201, 861)),
+                double_balance_witness: parseDoubleBalanceWitness(buffer.subarray(861, 2335)),
+                single_nonce_witness: parseSingleWitness(buffer.subarray(2335, 3028)),
+                user_address: parseAddress(buffer.subarray(3028, 3083)),
+                pool: parsePool(buffer.subarray(3083, 3227)),
+                user_balance_base_token_amount: parseUint64(buffer.subarray(3227, 3235)),
+                user_balance_quote_token_amount: parseUint64(buffer.subarray(3235, 3243)),
+                user_signature: parseSignature(buffer.subarray(3243, 3307)),
+                user_nonce: parseUint64(buffer.subarray(3307, 3315)),
+            } satisfies ClaimProtocolFees
+        }
         default: {
             return {
                 kind: "MistakenInput",
@@ -209,6 +352,8 @@ const parseStateRoots = (array: Uint8Array) => {
         liquidities: parseUint256(array.subarray(32, 64)),
         pools: parseUint256(array.subarray(64, 96)),
         burns: parseUint256(array.subarray(96, 128)),
+        orders: parseUint256(array.subarray(128, 160)),
+        nonces: parseUint256(array.subarray(160, 192)),
     } satisfies StateRoots
 
     return stateRoots
@@ -260,3 +405,59 @@ const parseDoubleBalanceWitness = (array: Uint8Array) => {
 
     return doubleWitness
 }
+
+const parseDoublePoolWitness = (array: Uint8Array) => {
+    const siblingsX1: Array<Sibling> = []
+    const siblingsX2: Array<Sibling> = []
+    const siblingsAt: Array<boolean> = []
+
+    for (let i = 0; i < 660; i += 33) {
+        siblingsX1.push(parseSibling(array.subarray(i, i + 33)))
+    }
+
+    for (let i = 660; i < 1320; i += 33) {
+        siblingsX2.push(parseSibling(array.subarray(i, i + 33)))
+    }
+
+    for (let i = 1320; i < 1340; i += 1) {
+        siblingsAt.push(parseBoolean(array.subarray(i, i + 1)))
+    }
+
+    const doubleWitness = {
+        siblingsX1,
+        siblingsX2,
+        siblingsAt,
+    }
+
+    return doubleWitness
+}
+
+const parsePool = (array: Uint8Array) => {
+    const pool = {
+        base_token_id: parseUint256(array.subarray(0, 32)),
+        quote_token_id: parseUint256(array.subarray(32, 64)),
+        base_token_amount: parseUint64(array.subarray(64, 72)),
+        quote_token_amount: parseUint64(array.subarray(72, 80)),
+        total_liquidity_points: parseUint256(array.subarray(80, 112)),
+        fee_rate: parseUint64(array.subarray(112, 120)),
+        protocol_fee_base_token_amount: parseUint64(array.subarray(120, 128)),
+        protocol_fee_quote_token_amount: parseUint64(array.subarray(128, 136)),
+        amplification: parseUint64(array.subarray(136, 144)),
+    } satisfies Pool
+
+    return pool
+}
+
+const parseOrder = (array: Uint8Array) => {
+    const order = {
+        id: parseUint64(array.subarray(0, 8)),
+        owner: parseAddress(array.subarray(8, 63)),
+        token_in_id: parseUint256(array.subarray(63, 95)),
+        token_out_id: parseUint256(array.subarray(95, 127)),
+        token_amount_in: parseUint64(array.subarray(127, 135)),
+        token_amount_out_limit: parseUint64(array.subarray(135, 143)),
+        status: BigInt(array[143]),
+    } satisfies Order
+
+    return order
+}
@@ -1,8 +1,13 @@
 import {
     DoubleBalanceWitness,
+    DoublePoolWitness,
+    Order,
+    Pool,
     SingleBalanceWitness,
     SingleBurnWitness,
     SingleLiquidityWitness,
+    SingleNonceWitness,
+    SingleOrderWitness,
     SinglePoolWitness,
     StateRoots,
 } from "nacho-common-o1js"
@@ -11,13 +16,20 @@ import { Bool, Field, PublicKey, Scalar, Signature, UInt64 } from "o1js"
 import {
     BurnTokens,
     BuyTokens,
+    CancelOrder,
+    ClaimProtocolFees,
     CreateGenesis,
     CreatePool,
     DepositTokens,
+    FillOrder,
+    PlaceOrder,
     ProvideLiquidity,
     RemoveLiquidity,
     SellTokens,
+    SwapRoute,
+    TransferTokens,
 } from "./input"
+import { Order as OrderParams, Pool as PoolParams } from "./types"
 import { readProofFromDisk } from "./utils"
 
 export const createGenesis = async (params: CreateGenesis) => {
@@ -27,6 +39,8 @@ export const createGenesis = async (params: CreateGenesis) => {
             liquidities: Field(params.state_roots.liquidities),
             pools: Field(params.state_roots.pools),
             burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
         }),
     )
 
@@ -40,6 +54,8 @@ export const depositTokens = async (params: DepositTokens, proofsPath: string) =
             liquidities: Field(params.state_roots.liquidities),
             pools: Field(params.state_roots.pools),
             burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
         }),
         await readProofFromDisk(proofsPath, params.earlier_proof_index),
         new SingleBalanceWitness(params.single_balance_witness.siblings),
@@ -61,10 +77,13 @@ export const makeBurnTokens = async (params: BurnTokens, proofsPath: string) =>
             liquidities: Field(params.state_roots.liquidities),
             pools: Field(params.state_roots.pools),
             burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
         }),
         await readProofFromDisk(proofsPath, params.earlier_proof_index),
         new SingleBalanceWitness(params.single_balance_witness.siblings),
         new SingleBurnWitness(params.single_burn_witness.siblings),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
         PublicKey.fromBase58(params.user_address),
         Field(params.token_id),
         UInt64.from(params.user_burn_token_amount),
@@ -74,6 +93,7 @@ export const makeBurnTokens = async (params: BurnTokens, proofsPath: string) =>
             r: Field.from(params.user_signature[0]),
             s: Scalar.from(params.user_signature[1]),
         }),
+        UInt64.from(params.user_nonce),
     )
 
     return proof
@@ -86,6 +106,8 @@ export const makeCreatePool = async (params: CreatePool, proofsPath: string) =>
             liquidities: Field(params.state_roots.liquidities),
             pools: Field(params.state_roots.pools),
             burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
         }),
         await readProofFromDisk(proofsPath, params.earlier_proof_index),
         new SinglePoolWitness(params.single_pool_witness.siblings),
@@ -95,6 +117,7 @@ export const makeCreatePool = async (params: CreatePool, proofsPath: string) =>
             params.double_balance_witness.siblingsX2,
             params.double_balance_witness.siblingsAt,
         ),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
         Field(params.base_token_id),
         Field(params.quote_token_id),
         PublicKey.fromBase58(params.user_address),
@@ -106,6 +129,9 @@ export const makeCreatePool = async (params: CreatePool, proofsPath: string) =>
             r: Field.from(params.user_signature[0]),
             s: Scalar.from(params.user_signature[1]),
         }),
+        UInt64.from(params.user_nonce),
+        UInt64.from(params.pool_fee_rate),
+        UInt64.from(params.pool_amplification),
     )
 
     return proof
@@ -118,6 +144,8 @@ export const makeProvideLiquidity = async (params: ProvideLiquidity, proofsPath:
             liquidities: Field(params.state_roots.liquidities),
             pools: Field(params.state_roots.pools),
             burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
         }),
         await readProofFromDisk(proofsPath, params.earlier_proof_index),
         new SinglePoolWitness(params.single_pool_witness.siblings),
@@ -127,6 +155,7 @@ export const makeProvideLiquidity = async (params: ProvideLiquidity, proofsPath:
             params.double_balance_witness.siblingsX2,
             params.double_balance_witness.siblingsAt,
         ),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
         Field(params.base_token_id),
         Field(params.quote_token_id),
         PublicKey.fromBase58(params.user_address),
@@ -142,6 +171,11 @@ export const makeProvideLiquidity = async (params: ProvideLiquidity, proofsPath:
             r: Field.from(params.user_signature[0]),
             s: Scalar.from(params.user_signature[1]),
         }),
+        UInt64.from(params.user_nonce),
+        UInt64.from(params.pool_fee_rate),
+        UInt64.from(params.pool_protocol_fee_base_token_amount),
+        UInt64.from(params.pool_protocol_fee_quote_token_amount),
+        UInt64.from(params.pool_amplification),
     )
 
     return proof
@@ -154,6 +188,8 @@ export const makeRemoveLiquidity = async (params: RemoveLiquidity, proofsPath: s
             liquidities: Field(params.state_roots.liquidities),
             pools: Field(params.state_roots.pools),
             burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
         }),
         await readProofFromDisk(proofsPath, params.earlier_proof_index),
         new SinglePoolWitness(params.single_pool_witness.siblings),
@@ -163,6 +199,7 @@ export const makeRemoveLiquidity = async (params: RemoveLiquidity, proofsPath: s
             params.double_balance_witness.siblingsX2,
             params.double_balance_witness.siblingsAt,
         ),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
         Field(params.base_token_id),
         Field(params.quote_token_id),
         PublicKey.fromBase58(params.user_address),
@@ -179,6 +216,11 @@ export const makeRemoveLiquidity = async (params: RemoveLiquidity, proofsPath: s
             r: Field.from(params.user_signature[0]),
             s: Scalar.from(params.user_signature[1]),
         }),
+        UInt64.from(params.user_nonce),
+        UInt64.from(params.pool_fee_rate),
+        UInt64.from(params.pool_protocol_fee_base_token_amount),
+        UInt64.from(params.pool_protocol_fee_quote_token_amount),
+        UInt64.from(params.pool_amplification),
     )
 
     return proof
@@ -191,6 +233,8 @@ export const makeBuyTokens = async (params: BuyTokens, proofsPath: string) => {
             liquidities: Field(params.state_roots.liquidities),
             pools: Field(params.state_roots.pools),
             burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
         }),
         await readProofFromDisk(proofsPath, params.earlier_proof_index),
         new SinglePoolWitness(params.single_pool_witness.siblings),
@@ -199,6 +243,7 @@ export const makeBuyTokens = async (params: BuyTokens, proofsPath: string) => {
             params.double_balance_witness.siblingsX2,
             params.double_balance_witness.siblingsAt,
         ),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
         PublicKey.fromBase58(params.user_address),
         Field(params.base_token_id),
         Field(params.quote_token_id),
@@ -213,6 +258,12 @@ export const makeBuyTokens = async (params: BuyTokens, proofsPath: string) => {
             r: Field.from(params.user_signature[0]),
             s: Scalar.from(params.user_signature[1]),
         }),
+        UInt64.from(params.user_nonce),
+        UInt64.from(params.pool_fee_rate),
+        UInt64.from(params.pool_protocol_fee_base_token_amount),
+        UInt64.from(params.pool_protocol_fee_quote_token_amount),
+        UInt64.from(params.protocol_fee_share),
+        UInt64.from(params.pool_amplification),
     )
 
     return proof
@@ -225,6 +276,8 @@ export const makeSellTokens = async (params: SellTokens, proofsPath: string) =>
             liquidities: Field(params.state_roots.liquidities),
             pools: Field(params.state_roots.pools),
             burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
         }),
         await readProofFromDisk(proofsPath, params.earlier_proof_index),
         new SinglePoolWitness(params.single_pool_witness.siblings),
@@ -233,6 +286,7 @@ export const makeSellTokens = async (params: SellTokens, proofsPath: string) =>
             params.double_balance_witness.siblingsX2,
             params.double_balance_witness.siblingsAt,
         ),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
         PublicKey.fromBase58(params.user_address),
         Field(params.base_token_id),
         Field(params.quote_token_id),
@@ -247,7 +301,227 @@ export const makeSellTokens = async (params: SellTokens, proofsPath: string) =>
             r: Field.from(params.user_signature[0]),
             s: Scalar.from(params.user_signature[1]),
         }),
+        UInt64.from(params.user_nonce),
+        UInt64.from(params.pool_fee_rate),
+        UInt64.from(params.pool_protocol_fee_base_token_amount),
+        UInt64.from(params.pool_protocol_fee_quote_token_amount),
+        UInt64.from(params.protocol_fee_share),
+        UInt64.from(params.pool_amplification),
     )
 
     return proof
 }
+
+export const makeSwapRoute = async (params: SwapRoute, proofsPath: string) => {
+    const proof = await proofGenerator.makeSwapRoute(
+        new StateRoots({
+            balances: Field(params.state_roots.balances),
+            liquidities: Field(params.state_roots.liquidities),
+            pools: Field(params.state_roots.pools),
+            burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
+        }),
+        await readProofFromDisk(proofsPath, params.earlier_proof_index),
+        new DoublePoolWitness(
+            params.double_pool_witness.siblingsX1,
+            params.double_pool_witness.siblingsX2,
+            params.double_pool_witness.siblingsAt,
+        ),
+        new DoubleBalanceWitness(
+            params.double_balance_witness.siblingsX1,
+            params.double_balance_witness.siblingsX2,
+            params.double_balance_witness.siblingsAt,
+        ),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
+        PublicKey.fromBase58(params.user_address),
+        Field(params.path[0]),
+        Field(params.path[1]),
+        Field(params.path[2]),
+        UInt64.from(params.user_balance_token_in_amount),
+        UInt64.from(params.user_balance_token_out_amount),
+        toPool(params.first_pool),
+        toPool(params.second_pool),
+        UInt64.from(params.user_token_amount_in),
+        UInt64.from(params.user_token_amount_out_limit),
+        Signature.fromObject({
+            r: Field.from(params.user_signature[0]),
+            s: Scalar.from(params.user_signature[1]),
+        }),
+        UInt64.from(params.user_nonce),
+        UInt64.from(params.protocol_fee_share),
+    )
+
+    return proof
+}
+
+export const makeTransferTokens = async (params: TransferTokens, proofsPath: string) => {
+    const proof = await proofGenerator.makeTransferTokens(
+        new StateRoots({
+            balances: Field(params.state_roots.balances),
+            liquidities: Field(params.state_roots.liquidities),
+            pools: Field(params.state_roots.pools),
+            burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
+        }),
+        await readProofFromDisk(proofsPath, params.earlier_proof_index),
+        new DoubleBalanceWitness(
+            params.double_balance_witness.siblingsX1,
+            params.double_balance_witness.siblingsX2,
+            params.double_balance_witness.siblingsAt,
+        ),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
+        PublicKey.fromBase58(params.user_address),
+        PublicKey.fromBase58(params.receiver_address),
+        Field(params.token_id),
+        UInt64.from(params.user_balance_token_amount),
+        UInt64.from(params.receiver_balance_token_amount),
+        UInt64.from(params.amount_to_transfer),
+        Signature.fromObject({
+            r: Field.from(params.user_signature[0]),
+            s: Scalar.from(params.user_signature[1]),
+        }),
+        UInt64.from(params.user_nonce),
+    )
+
+    return proof
+}
+
+export const makePlaceOrder = async (params: PlaceOrder, proofsPath: string) => {
+    const proof = await proofGenerator.makePlaceOrder(
+        new StateRoots({
+            balances: Field(params.state_roots.balances),
+            liquidities: Field(params.state_roots.liquidities),
+            pools: Field(params.state_roots.pools),
+            burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
+        }),
+        await readProofFromDisk(proofsPath, params.earlier_proof_index),
+        new SingleOrderWitness(params.single_order_witness.siblings),
+        new SingleBalanceWitness(params.single_balance_witness.siblings),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
+        PublicKey.fromBase58(params.user_address),
+        Field(params.token_in_id),
+        Field(params.token_out_id),
+        UInt64.from(params.user_balance_token_in_amount),
+        UInt64.from(params.user_token_amount_in),
+        UInt64.from(params.user_token_amount_out_limit),
+        UInt64.from(params.order_id),
+        Signature.fromObject({
+            r: Field.from(params.user_signature[0]),
+            s: Scalar.from(params.user_signature[1]),
+        }),
+        UInt64.from(params.user_nonce),
+    )
+
+    return proof
+}
+
+export const makeCancelOrder = async (params: CancelOrder, proofsPath: string) => {
+    const proof = await proofGenerator.makeCancelOrder(
+        new StateRoots({
+            balances: Field(params.state_roots.balances),
+            liquidities: Field(params.state_roots.liquidities),
+            pools: Field(params.state_roots.pools),
+            burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
+        }),
+        await readProofFromDisk(proofsPath, params.earlier_proof_index),
+        new SingleOrderWitness(params.single_order_witness.siblings),
+        new SingleBalanceWitness(params.single_balance_witness.siblings),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
+        toOrder(params.order),
+        UInt64.from(params.user_balance_token_in_amount),
+        Signature.fromObject({
+            r: Field.from(params.user_signature[0]),
+            s: Scalar.from(params.user_signature[1]),
+        }),
+        UInt64.from(params.user_nonce),
+    )
+
+    return proof
+}
+
+export const makeFillOrder = async (params: FillOrder, proofsPath: string) => {
+    const proof = await proofGenerator.makeFillOrder(
+        new StateRoots({
+            balances: Field(params.state_roots.balances),
+            liquidities: Field(params.state_roots.liquidities),
+            pools: Field(params.state_roots.pools),
+            burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
+        }),
+        await readProofFromDisk(proofsPath, params.earlier_proof_index),
+        new SingleOrderWitness(params.single_order_witness.siblings),
+        new SinglePoolWitness(params.single_pool_witness.siblings),
+        new SingleBalanceWitness(params.single_balance_witness.siblings),
+        toOrder(params.order),
+        toPool(params.pool),
+        UInt64.from(params.user_balance_token_out_amount),
+        UInt64.from(params.protocol_fee_share),
+    )
+
+    return proof
+}
+
+export const makeClaimProtocolFees = async (params: ClaimProtocolFees, proofsPath: string) => {
+    const proof = await proofGenerator.makeClaimProtocolFees(
+        new StateRoots({
+            balances: Field(params.state_roots.balances),
+            liquidities: Field(params.state_roots.liquidities),
+            pools: Field(params.state_roots.pools),
+            burns: Field(params.state_roots.burns),
+            orders: Field(params.state_roots.orders),
+            nonces: Field(params.state_roots.nonces),
+        }),
+        await readProofFromDisk(proofsPath, params.earlier_proof_index),
+        new SinglePoolWitness(params.single_pool_witness.siblings),
+        new DoubleBalanceWitness(
+            params.double_balance_witness.siblingsX1,
+            params.double_balance_witness.siblingsX2,
+            params.double_balance_witness.siblingsAt,
+        ),
+        new SingleNonceWitness(params.single_nonce_witness.siblings),
+        PublicKey.fromBase58(params.user_address),
+        toPool(params.pool),
+        UInt64.from(params.user_balance_base_token_amount),
+        UInt64.from(params.user_balance_quote_token_amount),
+        Signature.fromObject({
+            r: Field.from(params.user_signature[0]),
+            s: Scalar.from(params.user_signature[1]),
+        }),
+        UInt64.from(params.user_nonce),
+    )
+
+    return proof
+}
+
+const toPool = (params: PoolParams) => {
+    return new Pool({
+        baseTokenId: Field(params.base_token_id),
+        quoteTokenId: Field(params.quote_token_id),
+        baseTokenAmount: UInt64.from(params.base_token_amount),
+        quoteTokenAmount: UInt64.from(params.quote_token_amount),
+        totalLiquidityPoints: Field(params.total_liquidity_points),
+        feeRate: UInt64.from(params.fee_rate),
+        protocolFeeBaseTokenAmount: UInt64.from(params.protocol_fee_base_token_amount),
+        protocolFeeQuoteTokenAmount: UInt64.from(params.protocol_fee_quote_token_amount),
+        amplification: UInt64.from(params.amplification),
+    })
+}
+
+const toOrder = (params: OrderParams) => {
+    return new Order({
+        id: UInt64.from(params.id),
+        owner: PublicKey.fromBase58(params.owner),
+        tokenInId: Field(params.token_in_id),
+        tokenOutId: Field(params.token_out_id),
+        tokenAmountIn: UInt64.from(params.token_amount_in),
+        tokenAmountOutLimit: UInt64.from(params.token_amount_out_limit),
+        status: UInt64.from(params.status),
+    })
+}
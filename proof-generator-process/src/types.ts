@@ -3,6 +3,8 @@ export type StateRoots = {
     liquidities: bigint
     pools: bigint
     burns: bigint
+    orders: bigint
+    nonces: bigint
 }
 
 export type Sibling = {
@@ -20,6 +22,12 @@ export type DoubleBalanceWitness = {
     siblingsAt: Array<boolean>
 }
 
+export type DoublePoolWitness = {
+    siblingsX1: Array<Sibling>
+    siblingsX2: Array<Sibling>
+    siblingsAt: Array<boolean>
+}
+
 export type SingleBurnWitness = {
     siblings: Array<Sibling>
 }
@@ -28,6 +36,36 @@ export type SinglePoolWitness = {
     siblings: Array<Sibling>
 }
 
+export type SingleOrderWitness = {
+    siblings: Array<Sibling>
+}
+
 export type SingleLiquidityWitness = {
     siblings: Array<Sibling>
 }
+
+export type SingleNonceWitness = {
+    siblings: Array<Sibling>
+}
+
+export type Pool = {
+    base_token_id: bigint
+    quote_token_id: bigint
+    base_token_amount: bigint
+    quote_token_amount: bigint
+    total_liquidity_points: bigint
+    fee_rate: bigint
+    protocol_fee_base_token_amount: bigint
+    protocol_fee_quote_token_amount: bigint
+    amplification: bigint
+}
+
+export type Order = {
+    id: bigint
+    owner: string
+    token_in_id: bigint
+    token_out_id: bigint
+    token_amount_in: bigint
+    token_amount_out_limit: bigint
+    status: bigint
+}
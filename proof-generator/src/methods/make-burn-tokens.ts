@@ -4,8 +4,10 @@ import {
     Burn,
     SingleBalanceWitness,
     SingleBurnWitness,
+    SingleNonceWitness,
     StateRoots,
     choose,
+    incrementNonce,
 } from "nacho-common-o1js"
 
 export const makeBurnTokens = async (
@@ -13,19 +15,29 @@ export const makeBurnTokens = async (
     earlierProof: SelfProof<StateRoots, StateRoots>,
     singleBalanceWitness: SingleBalanceWitness,
     singleBurnWitness: SingleBurnWitness,
+    singleNonceWitness: SingleNonceWitness,
     userAddress: PublicKey,
     tokenId: Field,
     userBurnTokenAmount: UInt64,
     userBalanceTokenAmount: UInt64,
     amountToBurn: UInt64,
     userSignature: Signature,
+    userNonce: UInt64,
 ): Promise<StateRoots> => {
     stateRoots.assertEquals(earlierProof.publicOutput)
     earlierProof.verify()
 
-    // The signature message is a token ID and burn amount.
-    // NOTE: A compromised signature might be used to make multiple unwanted transactions.
-    userSignature.verify(userAddress, [tokenId, amountToBurn.value]).assertTrue()
+    // The signature message is a token ID, burn amount and nonce.
+    userSignature
+        .verify(userAddress, [tokenId, amountToBurn.value, userNonce.value])
+        .assertTrue()
+
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
 
     const userBalance = new Balance({
         owner: userAddress,
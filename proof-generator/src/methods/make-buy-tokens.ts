@@ -2,11 +2,17 @@ import { Field, Poseidon, PublicKey, SelfProof, Signature, UInt64 } from "o1js"
 import {
     Balance,
     DoubleBalanceWitness,
+    PROTOCOL_FEE_SHARE,
     Pool,
+    SingleNonceWitness,
     SinglePoolWitness,
     StateRoots,
-    addOnePerMilFee,
+    addFee,
+    choose,
+    incrementNonce,
     normalDiv,
+    protocolFeeOf,
+    stableReserveOf,
 } from "nacho-common-o1js"
 
 export const makeBuyTokens = async (
@@ -14,6 +20,7 @@ export const makeBuyTokens = async (
     earlierProof: SelfProof<StateRoots, StateRoots>,
     singlePoolWitness: SinglePoolWitness,
     doubleBalanceWitness: DoubleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
     userAddress: PublicKey,
     baseTokenId: Field,
     quoteTokenId: Field,
@@ -25,21 +32,34 @@ export const makeBuyTokens = async (
     userBaseTokenAmountToSwap: UInt64,
     userQuoteTokenAmountLimitToSwap: UInt64,
     userSignature: Signature,
+    userNonce: UInt64,
+    poolFeeRate: UInt64,
+    poolProtocolFeeBaseTokenAmount: UInt64,
+    poolProtocolFeeQuoteTokenAmount: UInt64,
+    protocolFeeShare: UInt64,
+    poolAmplification: UInt64,
 ): Promise<StateRoots> => {
     stateRoots.assertEquals(earlierProof.publicOutput)
     earlierProof.verify()
 
-    // The signature message is base and quote tokens IDs, base token amount and quote token amount limit.
-    // NOTE: A compromised signature might be used to make multiple unwanted transactions.
+    // The signature message is base and quote tokens IDs, base token amount, quote token amount limit and nonce.
     userSignature
         .verify(userAddress, [
             baseTokenId,
             quoteTokenId,
             userBaseTokenAmountToSwap.value,
             userQuoteTokenAmountLimitToSwap.value,
+            userNonce.value,
         ])
         .assertTrue()
 
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
     const userBaseTokenBalance = new Balance({
         owner: userAddress,
         tokenId: baseTokenId,
@@ -58,6 +78,10 @@ export const makeBuyTokens = async (
         baseTokenAmount: poolBaseTokenAmount,
         quoteTokenAmount: poolQuoteTokenAmount,
         totalLiquidityPoints: poolTotalLiquidityPoints,
+        feeRate: poolFeeRate,
+        protocolFeeBaseTokenAmount: poolProtocolFeeBaseTokenAmount,
+        protocolFeeQuoteTokenAmount: poolProtocolFeeQuoteTokenAmount,
+        amplification: poolAmplification,
     })
 
     // NOTE: We make sure that this double Merkle witness is correct to prevent mistaken root calculation.
@@ -80,13 +104,36 @@ export const makeBuyTokens = async (
     newPoolBaseTokenAmount.equals(UInt64.zero).assertFalse()
 
     // NOTE: The result is always expected to be less than 2^64, `UInt64.from` throws an overflow error if it isn't.
-    const newPoolQuoteTokenAmount = UInt64.fromFields([normalDiv(k, newPoolBaseTokenAmount.value)])
+    // NOTE: Constant product pools have no amplification coefficient, StableSwap pools keep their own invariant instead of `k`.
+    const newPoolQuoteTokenAmount = UInt64.fromFields([
+        choose(
+            poolAmplification.equals(UInt64.zero),
+            normalDiv(k, newPoolBaseTokenAmount.value),
+            stableReserveOf(
+                poolBaseTokenAmount.value,
+                poolQuoteTokenAmount.value,
+                newPoolBaseTokenAmount.value,
+                poolAmplification,
+            ),
+        ),
+    ])
 
     // NOTE: The result is always expected to be greater than zero, it throws an underflow error if it isn't.
     const quoteTokenAmountToSwap = newPoolQuoteTokenAmount.sub(poolQuoteTokenAmount)
 
-    const quoteTokenAmountToSwapPlusFee = addOnePerMilFee(quoteTokenAmountToSwap)
-    quoteTokenAmountToSwapPlusFee.lessThanOrEqual(userQuoteTokenAmountLimitToSwap)
+    const quoteTokenAmountToSwapPlusFee = addFee(quoteTokenAmountToSwap, poolFeeRate)
+    quoteTokenAmountToSwapPlusFee.lessThanOrEqual(userQuoteTokenAmountLimitToSwap).assertTrue()
+
+    // NOTE: The protocol fee share is a constant of the circuit, so the sequencer can't pick another one for a transaction.
+    protocolFeeShare.assertEquals(UInt64.from(PROTOCOL_FEE_SHARE))
+
+    // NOTE: The protocol fee is a part of the fee that is accrued in the pool instead of being added to its reserves.
+    const protocolFee = UInt64.fromFields([
+        protocolFeeOf(
+            quoteTokenAmountToSwapPlusFee.sub(quoteTokenAmountToSwap).value,
+            protocolFeeShare,
+        ),
+    ])
 
     userBaseTokenBalance.tokenAmount = userBalanceBaseTokenAmount.add(userBaseTokenAmountToSwap)
     // NOTE: We don't have to check if the user has enough quote token balance, because it throws underflow error if it isn't.
@@ -95,7 +142,8 @@ export const makeBuyTokens = async (
     )
     // NOTE: We don't have to check if the pool has enough base token balance, because it throws underflow error if it isn't.
     pool.baseTokenAmount = poolBaseTokenAmount.sub(userBaseTokenAmountToSwap)
-    pool.quoteTokenAmount = poolQuoteTokenAmount.add(quoteTokenAmountToSwapPlusFee)
+    pool.quoteTokenAmount = poolQuoteTokenAmount.add(quoteTokenAmountToSwapPlusFee.sub(protocolFee))
+    pool.protocolFeeQuoteTokenAmount = poolProtocolFeeQuoteTokenAmount.add(protocolFee)
 
     stateRoots.pools = singlePoolWitness.calculateRoot(Poseidon.hash(pool.toFields()))
     stateRoots.balances = doubleBalanceWitness.calculateRoot(
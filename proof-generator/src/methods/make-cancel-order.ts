@@ -0,0 +1,60 @@
+import { Poseidon, SelfProof, Signature, UInt64 } from "o1js"
+import {
+    Balance,
+    Order,
+    SingleBalanceWitness,
+    SingleNonceWitness,
+    SingleOrderWitness,
+    StateRoots,
+    incrementNonce,
+} from "nacho-common-o1js"
+
+export const makeCancelOrder = async (
+    stateRoots: StateRoots,
+    earlierProof: SelfProof<StateRoots, StateRoots>,
+    singleOrderWitness: SingleOrderWitness,
+    singleBalanceWitness: SingleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
+    order: Order,
+    userBalanceTokenInAmount: UInt64,
+    userSignature: Signature,
+    userNonce: UInt64,
+): Promise<StateRoots> => {
+    stateRoots.assertEquals(earlierProof.publicOutput)
+    earlierProof.verify()
+
+    // The signature message is order ID and nonce.
+    userSignature.verify(order.owner, [order.id.value, userNonce.value]).assertTrue()
+
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        order.owner,
+        userNonce,
+    )
+
+    order.status.assertEquals(UInt64.zero)
+
+    const userTokenInBalance = new Balance({
+        owner: order.owner,
+        tokenId: order.tokenInId,
+        tokenAmount: userBalanceTokenInAmount,
+    })
+
+    stateRoots.orders.assertEquals(singleOrderWitness.calculateRoot(Poseidon.hash(order.toFields())))
+    singleOrderWitness.calculateIndex().assertEquals(order.id.value)
+    stateRoots.balances.assertEquals(
+        singleBalanceWitness.calculateRoot(Poseidon.hash(userTokenInBalance.toFields())),
+    )
+
+    // NOTE: The escrowed tokens are refunded to the owner.
+    userTokenInBalance.tokenAmount = userBalanceTokenInAmount.add(order.tokenAmountIn)
+    order.status = UInt64.from(2)
+
+    stateRoots.balances = singleBalanceWitness.calculateRoot(
+        Poseidon.hash(userTokenInBalance.toFields()),
+    )
+    stateRoots.orders = singleOrderWitness.calculateRoot(Poseidon.hash(order.toFields()))
+
+    return stateRoots
+}
@@ -0,0 +1,84 @@
+import { Poseidon, PublicKey, SelfProof, Signature, UInt64 } from "o1js"
+import {
+    Balance,
+    DoubleBalanceWitness,
+    Pool,
+    SingleNonceWitness,
+    SinglePoolWitness,
+    StateRoots,
+    TREASURY_ADDRESS,
+    incrementNonce,
+} from "nacho-common-o1js"
+
+export const makeClaimProtocolFees = async (
+    stateRoots: StateRoots,
+    earlierProof: SelfProof<StateRoots, StateRoots>,
+    singlePoolWitness: SinglePoolWitness,
+    doubleBalanceWitness: DoubleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
+    userAddress: PublicKey,
+    pool: Pool,
+    userBalanceBaseTokenAmount: UInt64,
+    userBalanceQuoteTokenAmount: UInt64,
+    userSignature: Signature,
+    userNonce: UInt64,
+): Promise<StateRoots> => {
+    stateRoots.assertEquals(earlierProof.publicOutput)
+    earlierProof.verify()
+
+    // The signature message is base and quote tokens IDs and nonce.
+    userSignature
+        .verify(userAddress, [pool.baseTokenId, pool.quoteTokenId, userNonce.value])
+        .assertTrue()
+
+    // NOTE: Only the treasury can claim the protocol fees.
+    userAddress.assertEquals(TREASURY_ADDRESS)
+
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
+    const userBaseTokenBalance = new Balance({
+        owner: userAddress,
+        tokenId: pool.baseTokenId,
+        tokenAmount: userBalanceBaseTokenAmount,
+    })
+
+    const userQuoteTokenBalance = new Balance({
+        owner: userAddress,
+        tokenId: pool.quoteTokenId,
+        tokenAmount: userBalanceQuoteTokenAmount,
+    })
+
+    // NOTE: We make sure that this double Merkle witness is correct to prevent mistaken root calculation.
+    doubleBalanceWitness.isCorrect().assertTrue()
+
+    stateRoots.pools.assertEquals(singlePoolWitness.calculateRoot(Poseidon.hash(pool.toFields())))
+    stateRoots.balances.assertEquals(
+        doubleBalanceWitness.calculateRoot(
+            Poseidon.hash(userBaseTokenBalance.toFields()),
+            Poseidon.hash(userQuoteTokenBalance.toFields()),
+        ),
+    )
+
+    // NOTE: The protocol fees aren't a part of the pool's reserves, so they are moved without changing its price.
+    userBaseTokenBalance.tokenAmount = userBalanceBaseTokenAmount.add(
+        pool.protocolFeeBaseTokenAmount,
+    )
+    userQuoteTokenBalance.tokenAmount = userBalanceQuoteTokenAmount.add(
+        pool.protocolFeeQuoteTokenAmount,
+    )
+    pool.protocolFeeBaseTokenAmount = UInt64.zero
+    pool.protocolFeeQuoteTokenAmount = UInt64.zero
+
+    stateRoots.pools = singlePoolWitness.calculateRoot(Poseidon.hash(pool.toFields()))
+    stateRoots.balances = doubleBalanceWitness.calculateRoot(
+        Poseidon.hash(userBaseTokenBalance.toFields()),
+        Poseidon.hash(userQuoteTokenBalance.toFields()),
+    )
+
+    return stateRoots
+}
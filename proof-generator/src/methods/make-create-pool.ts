@@ -5,8 +5,10 @@ import {
     Liquidity,
     Pool,
     SingleLiquidityWitness,
+    SingleNonceWitness,
     SinglePoolWitness,
     StateRoots,
+    incrementNonce,
 } from "nacho-common-o1js"
 
 export const makeCreatePool = async (
@@ -15,6 +17,7 @@ export const makeCreatePool = async (
     singlePoolWitness: SinglePoolWitness,
     singleLiquidityWitness: SingleLiquidityWitness,
     doubleBalanceWitness: DoubleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
     baseTokenId: Field,
     quoteTokenId: Field,
     userAddress: PublicKey,
@@ -23,21 +26,47 @@ export const makeCreatePool = async (
     userBalanceBaseTokenAmount: UInt64,
     userBalanceQuoteTokenAmount: UInt64,
     userSignature: Signature,
+    userNonce: UInt64,
+    poolFeeRate: UInt64,
+    poolAmplification: UInt64,
 ): Promise<StateRoots> => {
     stateRoots.assertEquals(earlierProof.publicOutput)
     earlierProof.verify()
 
-    // The signature message is base and quote tokens IDs and liquidity amounts.
-    // NOTE: A compromised signature might be used to make multiple unwanted transactions.
+    // NOTE: Constant product pools have no amplification coefficient, any other pool is a StableSwap pool.
+    const poolKind = poolAmplification.equals(UInt64.zero).not().toField()
+
+    // The signature message is base and quote tokens IDs, liquidity amounts, fee rate, pool kind, amplification and nonce.
     userSignature
         .verify(userAddress, [
             baseTokenId,
             quoteTokenId,
             userLiquidityBaseTokenAmount.value,
             userLiquidityQuoteTokenAmount.value,
+            poolFeeRate.value,
+            poolKind,
+            poolAmplification.value,
+            userNonce.value,
         ])
         .assertTrue()
 
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
+    // NOTE: The fee rate is in basis points and it has to be one of the fee tiers that the sequencer supports.
+    poolFeeRate
+        .equals(UInt64.from(5))
+        .or(poolFeeRate.equals(UInt64.from(30)))
+        .or(poolFeeRate.equals(UInt64.from(100)))
+        .assertTrue()
+
+    // NOTE: The amplification coefficient of StableSwap pools has an upper limit that the sequencer enforces.
+    poolAmplification.assertLessThanOrEqual(UInt64.from(10000))
+
     const userBaseTokenBalance = new Balance({
         owner: userAddress,
         tokenId: baseTokenId,
@@ -68,6 +97,10 @@ export const makeCreatePool = async (
         baseTokenAmount: userLiquidityBaseTokenAmount,
         quoteTokenAmount: userLiquidityQuoteTokenAmount,
         totalLiquidityPoints,
+        feeRate: poolFeeRate,
+        protocolFeeBaseTokenAmount: UInt64.zero,
+        protocolFeeQuoteTokenAmount: UInt64.zero,
+        amplification: poolAmplification,
     })
 
     // NOTE: We make sure that this double Merkle witness is correct to prevent mistaken root calculation.
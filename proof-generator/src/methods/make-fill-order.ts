@@ -0,0 +1,77 @@
+import { Bool, Field, Poseidon, SelfProof, UInt64 } from "o1js"
+import {
+    Balance,
+    Order,
+    PROTOCOL_FEE_SHARE,
+    Pool,
+    SingleBalanceWitness,
+    SingleOrderWitness,
+    SinglePoolWitness,
+    StateRoots,
+    choose,
+} from "nacho-common-o1js"
+import { swapInPool } from "./make-swap-route.js"
+
+export const makeFillOrder = async (
+    stateRoots: StateRoots,
+    earlierProof: SelfProof<StateRoots, StateRoots>,
+    singleOrderWitness: SingleOrderWitness,
+    singlePoolWitness: SinglePoolWitness,
+    singleBalanceWitness: SingleBalanceWitness,
+    order: Order,
+    pool: Pool,
+    userBalanceTokenOutAmount: UInt64,
+    protocolFeeShare: UInt64,
+): Promise<StateRoots> => {
+    stateRoots.assertEquals(earlierProof.publicOutput)
+    earlierProof.verify()
+
+    // NOTE: Fills aren't signed, anyone can fill an open order as long as the pool gives it at least its limit.
+    order.status.assertEquals(UInt64.zero)
+
+    const userTokenOutBalance = new Balance({
+        owner: order.owner,
+        tokenId: order.tokenOutId,
+        tokenAmount: userBalanceTokenOutAmount,
+    })
+
+    stateRoots.orders.assertEquals(singleOrderWitness.calculateRoot(Poseidon.hash(order.toFields())))
+    singleOrderWitness.calculateIndex().assertEquals(order.id.value)
+    stateRoots.pools.assertEquals(singlePoolWitness.calculateRoot(Poseidon.hash(pool.toFields())))
+
+    const balancesRootIfFirstBalance = singleBalanceWitness.calculateRoot(Field(0))
+
+    const isUsersFirstBalance = stateRoots.balances.equals(balancesRootIfFirstBalance)
+
+    Bool.or(
+        isUsersFirstBalance,
+        stateRoots.balances.equals(
+            singleBalanceWitness.calculateRoot(Poseidon.hash(userTokenOutBalance.toFields())),
+        ),
+    ).assertTrue()
+
+    choose(isUsersFirstBalance, userBalanceTokenOutAmount.value, Field(0)).assertEquals(0)
+
+    protocolFeeShare.assertEquals(UInt64.from(PROTOCOL_FEE_SHARE))
+
+    const tokenAmountOut = swapInPool(
+        pool,
+        order.tokenInId,
+        order.tokenOutId,
+        order.tokenAmountIn,
+        protocolFeeShare,
+    )
+
+    tokenAmountOut.assertGreaterThanOrEqual(order.tokenAmountOutLimit)
+
+    userTokenOutBalance.tokenAmount = userBalanceTokenOutAmount.add(tokenAmountOut)
+    order.status = UInt64.one
+
+    stateRoots.pools = singlePoolWitness.calculateRoot(Poseidon.hash(pool.toFields()))
+    stateRoots.balances = singleBalanceWitness.calculateRoot(
+        Poseidon.hash(userTokenOutBalance.toFields()),
+    )
+    stateRoots.orders = singleOrderWitness.calculateRoot(Poseidon.hash(order.toFields()))
+
+    return stateRoots
+}
@@ -0,0 +1,86 @@
+import { Field, Poseidon, PublicKey, SelfProof, Signature, UInt64 } from "o1js"
+import {
+    Balance,
+    Order,
+    SingleBalanceWitness,
+    SingleNonceWitness,
+    SingleOrderWitness,
+    StateRoots,
+    incrementNonce,
+} from "nacho-common-o1js"
+
+export const makePlaceOrder = async (
+    stateRoots: StateRoots,
+    earlierProof: SelfProof<StateRoots, StateRoots>,
+    singleOrderWitness: SingleOrderWitness,
+    singleBalanceWitness: SingleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
+    userAddress: PublicKey,
+    tokenInId: Field,
+    tokenOutId: Field,
+    userBalanceTokenInAmount: UInt64,
+    userTokenAmountIn: UInt64,
+    userTokenAmountOutLimit: UInt64,
+    orderId: UInt64,
+    userSignature: Signature,
+    userNonce: UInt64,
+): Promise<StateRoots> => {
+    stateRoots.assertEquals(earlierProof.publicOutput)
+    earlierProof.verify()
+
+    // The signature message is token in and token out IDs, token amount in, token amount out limit and nonce.
+    userSignature
+        .verify(userAddress, [
+            tokenInId,
+            tokenOutId,
+            userTokenAmountIn.value,
+            userTokenAmountOutLimit.value,
+            userNonce.value,
+        ])
+        .assertTrue()
+
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
+    tokenInId.equals(tokenOutId).assertFalse()
+    userTokenAmountIn.equals(UInt64.zero).assertFalse()
+    userTokenAmountOutLimit.equals(UInt64.zero).assertFalse()
+
+    const userTokenInBalance = new Balance({
+        owner: userAddress,
+        tokenId: tokenInId,
+        tokenAmount: userBalanceTokenInAmount,
+    })
+
+    stateRoots.balances.assertEquals(
+        singleBalanceWitness.calculateRoot(Poseidon.hash(userTokenInBalance.toFields())),
+    )
+
+    // NOTE: The order is placed at an unused leaf whose index is the order's ID.
+    stateRoots.orders.assertEquals(singleOrderWitness.calculateRoot(Field(0)))
+    singleOrderWitness.calculateIndex().assertEquals(orderId.value)
+
+    const order = new Order({
+        id: orderId,
+        owner: userAddress,
+        tokenInId,
+        tokenOutId,
+        tokenAmountIn: userTokenAmountIn,
+        tokenAmountOutLimit: userTokenAmountOutLimit,
+        status: UInt64.zero,
+    })
+
+    // NOTE: We don't have to check if the user has enough balance, because it throws underflow error if it isn't.
+    userTokenInBalance.tokenAmount = userBalanceTokenInAmount.sub(userTokenAmountIn)
+
+    stateRoots.balances = singleBalanceWitness.calculateRoot(
+        Poseidon.hash(userTokenInBalance.toFields()),
+    )
+    stateRoots.orders = singleOrderWitness.calculateRoot(Poseidon.hash(order.toFields()))
+
+    return stateRoots
+}
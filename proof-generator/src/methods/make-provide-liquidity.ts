@@ -5,9 +5,11 @@ import {
     Liquidity,
     Pool,
     SingleLiquidityWitness,
+    SingleNonceWitness,
     SinglePoolWitness,
     StateRoots,
     choose,
+    incrementNonce,
     normalDiv,
 } from "nacho-common-o1js"
 
@@ -17,6 +19,7 @@ export const makeProvideLiquidity = async (
     singlePoolWitness: SinglePoolWitness,
     singleLiquidityWitness: SingleLiquidityWitness,
     doubleBalanceWitness: DoubleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
     baseTokenId: Field,
     quoteTokenId: Field,
     userAddress: PublicKey,
@@ -29,21 +32,33 @@ export const makeProvideLiquidity = async (
     userBaseTokenAmountToProvide: UInt64,
     userQuoteTokenAmountLimitToProvide: UInt64,
     userSignature: Signature,
+    userNonce: UInt64,
+    poolFeeRate: UInt64,
+    poolProtocolFeeBaseTokenAmount: UInt64,
+    poolProtocolFeeQuoteTokenAmount: UInt64,
+    poolAmplification: UInt64,
 ): Promise<StateRoots> => {
     stateRoots.assertEquals(earlierProof.publicOutput)
     earlierProof.verify()
 
-    // The signature message is base and quote tokens IDs, base token amount and quote token amount limit.
-    // NOTE: A compromised signature might be used to make multiple unwanted transactions.
+    // The signature message is base and quote tokens IDs, base token amount, quote token amount limit and nonce.
     userSignature
         .verify(userAddress, [
             baseTokenId,
             quoteTokenId,
             userBaseTokenAmountToProvide.value,
             userQuoteTokenAmountLimitToProvide.value,
+            userNonce.value,
         ])
         .assertTrue()
 
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
     const userBaseTokenBalance = new Balance({
         owner: userAddress,
         tokenId: baseTokenId,
@@ -69,6 +84,10 @@ export const makeProvideLiquidity = async (
         baseTokenAmount: poolBaseTokenAmount,
         quoteTokenAmount: poolQuoteTokenAmount,
         totalLiquidityPoints: poolTotalLiquidityPoints,
+        feeRate: poolFeeRate,
+        protocolFeeBaseTokenAmount: poolProtocolFeeBaseTokenAmount,
+        protocolFeeQuoteTokenAmount: poolProtocolFeeQuoteTokenAmount,
+        amplification: poolAmplification,
     })
 
     // NOTE: We make sure that this double Merkle witness is correct to prevent mistaken root calculation.
@@ -5,8 +5,10 @@ import {
     Liquidity,
     Pool,
     SingleLiquidityWitness,
+    SingleNonceWitness,
     SinglePoolWitness,
     StateRoots,
+    incrementNonce,
     normalDiv,
 } from "nacho-common-o1js"
 
@@ -16,6 +18,7 @@ export const makeRemoveLiquidity = async (
     singlePoolWitness: SinglePoolWitness,
     singleLiquidityWitness: SingleLiquidityWitness,
     doubleBalanceWitness: DoubleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
     baseTokenId: Field,
     quoteTokenId: Field,
     userAddress: PublicKey,
@@ -29,12 +32,16 @@ export const makeRemoveLiquidity = async (
     userBaseTokenAmountLimitToRemove: UInt64,
     userQuoteTokenAmountLimitToRemove: UInt64,
     userSignature: Signature,
+    userNonce: UInt64,
+    poolFeeRate: UInt64,
+    poolProtocolFeeBaseTokenAmount: UInt64,
+    poolProtocolFeeQuoteTokenAmount: UInt64,
+    poolAmplification: UInt64,
 ): Promise<StateRoots> => {
     stateRoots.assertEquals(earlierProof.publicOutput)
     earlierProof.verify()
 
-    // The signature message is base and quote tokens IDs and limits and liquidity points.
-    // NOTE: A compromised signature might be used to make multiple unwanted transactions.
+    // The signature message is base and quote tokens IDs, limits, liquidity points and nonce.
     userSignature
         .verify(userAddress, [
             baseTokenId,
@@ -42,9 +49,17 @@ export const makeRemoveLiquidity = async (
             userBaseTokenAmountLimitToRemove.value,
             userQuoteTokenAmountLimitToRemove.value,
             userLiquidityPointsToRemove,
+            userNonce.value,
         ])
         .assertTrue()
 
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
     const userBaseTokenBalance = new Balance({
         owner: userAddress,
         tokenId: baseTokenId,
@@ -70,6 +85,10 @@ export const makeRemoveLiquidity = async (
         baseTokenAmount: poolBaseTokenAmount,
         quoteTokenAmount: poolQuoteTokenAmount,
         totalLiquidityPoints: poolTotalLiquidityPoints,
+        feeRate: poolFeeRate,
+        protocolFeeBaseTokenAmount: poolProtocolFeeBaseTokenAmount,
+        protocolFeeQuoteTokenAmount: poolProtocolFeeQuoteTokenAmount,
+        amplification: poolAmplification,
     })
 
     // NOTE: We make sure that this double Merkle witness is correct to prevent mistaken root calculation.
@@ -2,11 +2,17 @@ import { Field, Poseidon, PublicKey, SelfProof, Signature, UInt64 } from "o1js"
 import {
     Balance,
     DoubleBalanceWitness,
+    PROTOCOL_FEE_SHARE,
     Pool,
+    SingleNonceWitness,
     SinglePoolWitness,
     StateRoots,
-    addOnePerMilFee,
+    addFee,
+    choose,
+    incrementNonce,
     normalDiv,
+    protocolFeeOf,
+    stableReserveOf,
 } from "nacho-common-o1js"
 
 export const makeSellTokens = async (
@@ -14,6 +20,7 @@ export const makeSellTokens = async (
     earlierProof: SelfProof<StateRoots, StateRoots>,
     singlePoolWitness: SinglePoolWitness,
     doubleBalanceWitness: DoubleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
     userAddress: PublicKey,
     baseTokenId: Field,
     quoteTokenId: Field,
@@ -25,21 +32,34 @@ export const makeSellTokens = async (
     userBaseTokenAmountLimitToSwap: UInt64,
     userQuoteTokenAmountToSwap: UInt64,
     userSignature: Signature,
+    userNonce: UInt64,
+    poolFeeRate: UInt64,
+    poolProtocolFeeBaseTokenAmount: UInt64,
+    poolProtocolFeeQuoteTokenAmount: UInt64,
+    protocolFeeShare: UInt64,
+    poolAmplification: UInt64,
 ): Promise<StateRoots> => {
     stateRoots.assertEquals(earlierProof.publicOutput)
     earlierProof.verify()
 
-    // The signature message is base and quote tokens IDs, base token amount and quote token amount limit.
-    // NOTE: A compromised signature might be used to make multiple unwanted transactions.
+    // The signature message is base and quote tokens IDs, base token amount, quote token amount limit and nonce.
     userSignature
         .verify(userAddress, [
             baseTokenId,
             quoteTokenId,
             userBaseTokenAmountLimitToSwap.value,
             userQuoteTokenAmountToSwap.value,
+            userNonce.value,
         ])
         .assertTrue()
 
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
     const userBaseTokenBalance = new Balance({
         owner: userAddress,
         tokenId: baseTokenId,
@@ -58,6 +78,10 @@ export const makeSellTokens = async (
         baseTokenAmount: poolBaseTokenAmount,
         quoteTokenAmount: poolQuoteTokenAmount,
         totalLiquidityPoints: poolTotalLiquidityPoints,
+        feeRate: poolFeeRate,
+        protocolFeeBaseTokenAmount: poolProtocolFeeBaseTokenAmount,
+        protocolFeeQuoteTokenAmount: poolProtocolFeeQuoteTokenAmount,
+        amplification: poolAmplification,
     })
 
     // NOTE: We make sure that this double Merkle witness is correct to prevent mistaken root calculation.
@@ -80,18 +104,41 @@ export const makeSellTokens = async (
     newPoolQuoteTokenAmount.equals(UInt64.zero).assertFalse()
 
     // NOTE: The result is always expected to be less than 2^64, `UInt64.from` throws an overflow error if it isn't.
-    const newPoolBaseTokenAmount = UInt64.fromFields([normalDiv(k, newPoolQuoteTokenAmount.value)])
+    // NOTE: Constant product pools have no amplification coefficient, StableSwap pools keep their own invariant instead of `k`.
+    const newPoolBaseTokenAmount = UInt64.fromFields([
+        choose(
+            poolAmplification.equals(UInt64.zero),
+            normalDiv(k, newPoolQuoteTokenAmount.value),
+            stableReserveOf(
+                poolQuoteTokenAmount.value,
+                poolBaseTokenAmount.value,
+                newPoolQuoteTokenAmount.value,
+                poolAmplification,
+            ),
+        ),
+    ])
 
     // NOTE: The result is always expected to be greater than zero, it throws an underflow error if it isn't.
     const baseTokenAmountToSwap = newPoolBaseTokenAmount.sub(poolBaseTokenAmount)
 
-    const baseTokenAmountToSwapPlusFee = addOnePerMilFee(baseTokenAmountToSwap)
-    baseTokenAmountToSwapPlusFee.lessThanOrEqual(userBaseTokenAmountLimitToSwap)
+    const baseTokenAmountToSwapPlusFee = addFee(baseTokenAmountToSwap, poolFeeRate)
+    baseTokenAmountToSwapPlusFee.lessThanOrEqual(userBaseTokenAmountLimitToSwap).assertTrue()
+
+    protocolFeeShare.assertEquals(UInt64.from(PROTOCOL_FEE_SHARE))
+
+    // NOTE: The protocol fee is a part of the fee that is accrued in the pool instead of being added to its reserves.
+    const protocolFee = UInt64.fromFields([
+        protocolFeeOf(
+            baseTokenAmountToSwapPlusFee.sub(baseTokenAmountToSwap).value,
+            protocolFeeShare,
+        ),
+    ])
 
     // NOTE: We don't have to check if the user has enough quote token balance, because it throws underflow error if it isn't.
     userBaseTokenBalance.tokenAmount = userBalanceBaseTokenAmount.sub(baseTokenAmountToSwapPlusFee)
     userQuoteTokenBalance.tokenAmount = userBalanceQuoteTokenAmount.add(userQuoteTokenAmountToSwap)
-    pool.baseTokenAmount = poolBaseTokenAmount.add(baseTokenAmountToSwapPlusFee)
+    pool.baseTokenAmount = poolBaseTokenAmount.add(baseTokenAmountToSwapPlusFee.sub(protocolFee))
+    pool.protocolFeeBaseTokenAmount = poolProtocolFeeBaseTokenAmount.add(protocolFee)
     // NOTE: We don't have to check if the pool has enough quote token balance, because it throws underflow error if it isn't.
     pool.quoteTokenAmount = poolQuoteTokenAmount.sub(userQuoteTokenAmountToSwap)
 
@@ -0,0 +1,189 @@
+import { Field, Poseidon, Provable, PublicKey, SelfProof, Signature, UInt64 } from "o1js"
+import {
+    Balance,
+    DoubleBalanceWitness,
+    DoublePoolWitness,
+    PROTOCOL_FEE_SHARE,
+    Pool,
+    SingleNonceWitness,
+    StateRoots,
+    choose,
+    incrementNonce,
+    normalDiv,
+    protocolFeeOf,
+    stableReserveOf,
+    subtractFee,
+} from "nacho-common-o1js"
+
+export const makeSwapRoute = async (
+    stateRoots: StateRoots,
+    earlierProof: SelfProof<StateRoots, StateRoots>,
+    doublePoolWitness: DoublePoolWitness,
+    doubleBalanceWitness: DoubleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
+    userAddress: PublicKey,
+    tokenInId: Field,
+    tokenViaId: Field,
+    tokenOutId: Field,
+    userBalanceTokenInAmount: UInt64,
+    userBalanceTokenOutAmount: UInt64,
+    firstPool: Pool,
+    secondPool: Pool,
+    userTokenAmountIn: UInt64,
+    userTokenAmountOutLimit: UInt64,
+    userSignature: Signature,
+    userNonce: UInt64,
+    protocolFeeShare: UInt64,
+): Promise<StateRoots> => {
+    stateRoots.assertEquals(earlierProof.publicOutput)
+    earlierProof.verify()
+
+    // The signature message is the path, token amount in, token amount out limit and nonce.
+    userSignature
+        .verify(userAddress, [
+            tokenInId,
+            tokenViaId,
+            tokenOutId,
+            userTokenAmountIn.value,
+            userTokenAmountOutLimit.value,
+            userNonce.value,
+        ])
+        .assertTrue()
+
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
+    const userTokenInBalance = new Balance({
+        owner: userAddress,
+        tokenId: tokenInId,
+        tokenAmount: userBalanceTokenInAmount,
+    })
+
+    const userTokenOutBalance = new Balance({
+        owner: userAddress,
+        tokenId: tokenOutId,
+        tokenAmount: userBalanceTokenOutAmount,
+    })
+
+    // NOTE: We make sure that these double Merkle witnesses are correct to prevent mistaken root calculation.
+    doublePoolWitness.isCorrect().assertTrue()
+    doubleBalanceWitness.isCorrect().assertTrue()
+
+    stateRoots.pools.assertEquals(
+        doublePoolWitness.calculateRoot(
+            Poseidon.hash(firstPool.toFields()),
+            Poseidon.hash(secondPool.toFields()),
+        ),
+    )
+    stateRoots.balances.assertEquals(
+        doubleBalanceWitness.calculateRoot(
+            Poseidon.hash(userTokenInBalance.toFields()),
+            Poseidon.hash(userTokenOutBalance.toFields()),
+        ),
+    )
+
+    // NOTE: Both hops take the protocol fee with the circuit's constant share.
+    protocolFeeShare.assertEquals(UInt64.from(PROTOCOL_FEE_SHARE))
+
+    const tokenViaAmount = swapInPool(
+        firstPool,
+        tokenInId,
+        tokenViaId,
+        userTokenAmountIn,
+        protocolFeeShare,
+    )
+    const tokenOutAmount = swapInPool(
+        secondPool,
+        tokenViaId,
+        tokenOutId,
+        tokenViaAmount,
+        protocolFeeShare,
+    )
+
+    tokenOutAmount.assertGreaterThanOrEqual(userTokenAmountOutLimit)
+
+    // NOTE: We don't have to check if the user has enough token in balance, because it throws underflow error if it isn't.
+    userTokenInBalance.tokenAmount = userBalanceTokenInAmount.sub(userTokenAmountIn)
+    userTokenOutBalance.tokenAmount = userBalanceTokenOutAmount.add(tokenOutAmount)
+
+    stateRoots.pools = doublePoolWitness.calculateRoot(
+        Poseidon.hash(firstPool.toFields()),
+        Poseidon.hash(secondPool.toFields()),
+    )
+    stateRoots.balances = doubleBalanceWitness.calculateRoot(
+        Poseidon.hash(userTokenInBalance.toFields()),
+        Poseidon.hash(userTokenOutBalance.toFields()),
+    )
+
+    return stateRoots
+}
+
+/**
+ * Swaps the given token amount through the given pool in either direction.
+ * Updates the reserves and the accrued protocol fees of the pool and returns the token amount out.
+ */
+export const swapInPool = (
+    pool: Pool,
+    tokenInId: Field,
+    tokenOutId: Field,
+    tokenAmountIn: UInt64,
+    protocolFeeShare: UInt64,
+): UInt64 => {
+    const isBaseIn = pool.baseTokenId.equals(tokenInId).and(pool.quoteTokenId.equals(tokenOutId))
+    const isQuoteIn = pool.quoteTokenId.equals(tokenInId).and(pool.baseTokenId.equals(tokenOutId))
+
+    isBaseIn.or(isQuoteIn).assertTrue()
+
+    const reserveIn = Provable.if(isBaseIn, UInt64, pool.baseTokenAmount, pool.quoteTokenAmount)
+    const reserveOut = Provable.if(isBaseIn, UInt64, pool.quoteTokenAmount, pool.baseTokenAmount)
+
+    reserveIn.equals(UInt64.zero).assertFalse()
+    reserveOut.equals(UInt64.zero).assertFalse()
+
+    // NOTE: We don't have to check overflow because both numbers are less than 2^64 and the target can store up to 2^254.
+    const k = reserveIn.value.mul(reserveOut.value)
+
+    const tokenAmountInWithoutFee = subtractFee(tokenAmountIn.value, pool.feeRate)
+    const newReserveIn = reserveIn.value.add(tokenAmountInWithoutFee)
+
+    // NOTE: The new reserve out is rounded up, so the pool never loses value because of rounding.
+    // NOTE: Constant product pools have no amplification coefficient, StableSwap pools keep their own invariant instead of `k`.
+    const newReserveOut = UInt64.fromFields([
+        choose(
+            pool.amplification.equals(UInt64.zero),
+            normalDiv(k.add(newReserveIn).sub(Field(1)), newReserveIn),
+            stableReserveOf(reserveIn.value, reserveOut.value, newReserveIn, pool.amplification),
+        ),
+    ])
+
+    // NOTE: The result is always expected to be greater than or equal to zero, it throws an underflow error if it isn't.
+    const tokenAmountOut = reserveOut.sub(newReserveOut)
+
+    // NOTE: The protocol fee is a part of the fee that is accrued in the pool instead of being added to its reserves.
+    const protocolFee = UInt64.fromFields([
+        protocolFeeOf(tokenAmountIn.value.sub(tokenAmountInWithoutFee), protocolFeeShare),
+    ])
+
+    const updatedReserveIn = reserveIn.add(tokenAmountIn).sub(protocolFee)
+
+    pool.baseTokenAmount = Provable.if(isBaseIn, UInt64, updatedReserveIn, newReserveOut)
+    pool.quoteTokenAmount = Provable.if(isBaseIn, UInt64, newReserveOut, updatedReserveIn)
+    pool.protocolFeeBaseTokenAmount = Provable.if(
+        isBaseIn,
+        UInt64,
+        pool.protocolFeeBaseTokenAmount.add(protocolFee),
+        pool.protocolFeeBaseTokenAmount,
+    )
+    pool.protocolFeeQuoteTokenAmount = Provable.if(
+        isBaseIn,
+        UInt64,
+        pool.protocolFeeQuoteTokenAmount,
+        pool.protocolFeeQuoteTokenAmount.add(protocolFee),
+    )
+
+    return tokenAmountOut
+}
@@ -0,0 +1,93 @@
+import { Bool, Field, Poseidon, PublicKey, SelfProof, Signature, UInt64 } from "o1js"
+import {
+    Balance,
+    DoubleBalanceWitness,
+    SingleNonceWitness,
+    StateRoots,
+    choose,
+    incrementNonce,
+} from "nacho-common-o1js"
+
+export const makeTransferTokens = async (
+    stateRoots: StateRoots,
+    earlierProof: SelfProof<StateRoots, StateRoots>,
+    doubleBalanceWitness: DoubleBalanceWitness,
+    singleNonceWitness: SingleNonceWitness,
+    userAddress: PublicKey,
+    receiverAddress: PublicKey,
+    tokenId: Field,
+    userBalanceTokenAmount: UInt64,
+    receiverBalanceTokenAmount: UInt64,
+    amountToTransfer: UInt64,
+    userSignature: Signature,
+    userNonce: UInt64,
+): Promise<StateRoots> => {
+    stateRoots.assertEquals(earlierProof.publicOutput)
+    earlierProof.verify()
+
+    // The signature message is receiver address, token ID, token amount and nonce.
+    userSignature
+        .verify(userAddress, [
+            ...receiverAddress.toFields(),
+            tokenId,
+            amountToTransfer.value,
+            userNonce.value,
+        ])
+        .assertTrue()
+
+    stateRoots.nonces = incrementNonce(
+        stateRoots.nonces,
+        singleNonceWitness,
+        userAddress,
+        userNonce,
+    )
+
+    userAddress.equals(receiverAddress).assertFalse()
+
+    const userBalance = new Balance({
+        owner: userAddress,
+        tokenId,
+        tokenAmount: userBalanceTokenAmount,
+    })
+
+    const receiverBalance = new Balance({
+        owner: receiverAddress,
+        tokenId,
+        tokenAmount: receiverBalanceTokenAmount,
+    })
+
+    // NOTE: We make sure that this double Merkle witness is correct to prevent mistaken root calculation.
+    doubleBalanceWitness.isCorrect().assertTrue()
+
+    const userBalanceHash = Poseidon.hash(userBalance.toFields())
+
+    const balancesRootIfReceiversFirstBalance = doubleBalanceWitness.calculateRoot(
+        userBalanceHash,
+        Field(0),
+    )
+
+    const isReceiversFirstBalance = stateRoots.balances.equals(balancesRootIfReceiversFirstBalance)
+
+    Bool.or(
+        isReceiversFirstBalance,
+        stateRoots.balances.equals(
+            doubleBalanceWitness.calculateRoot(
+                userBalanceHash,
+                Poseidon.hash(receiverBalance.toFields()),
+            ),
+        ),
+    ).assertTrue()
+
+    choose(isReceiversFirstBalance, receiverBalanceTokenAmount.value, Field(0)).assertEquals(0)
+
+    // NOTE: We don't have to check if the user has enough balance, because it throws underflow error if it isn't.
+    userBalance.tokenAmount = userBalanceTokenAmount.sub(amountToTransfer)
+    receiverBalance.tokenAmount = receiverBalanceTokenAmount.add(amountToTransfer)
+
+    stateRoots.balances = doubleBalanceWitness.calculateRoot(
+        Poseidon.hash(userBalance.toFields()),
+        Poseidon.hash(receiverBalance.toFields()),
+    )
+
+    return stateRoots
+}
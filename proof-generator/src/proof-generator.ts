@@ -1,9 +1,14 @@
 import { Field, PublicKey, SelfProof, Signature, UInt64, ZkProgram } from "o1js"
 import {
     DoubleBalanceWitness,
+    DoublePoolWitness,
+    Order,
+    Pool,
     SingleBalanceWitness,
     SingleBurnWitness,
     SingleLiquidityWitness,
+    SingleNonceWitness,
+    SingleOrderWitness,
     SinglePoolWitness,
     StateRoots,
 } from "nacho-common-o1js"
@@ -13,9 +18,15 @@ import { depositTokens } from "./methods/deposit-tokens.js"
 import { makeBurnTokens } from "./methods/make-burn-tokens.js"
 import { makeBuyTokens } from "./methods/make-buy-tokens.js"
 import { makeSellTokens } from "./methods/make-sell-tokens.js"
+import { makeSwapRoute } from "./methods/make-swap-route.js"
+import { makeTransferTokens } from "./methods/make-transfer-tokens.js"
 import { makeProvideLiquidity } from "./methods/make-provide-liquidity.js"
 import { makeRemoveLiquidity } from "./methods/make-remove-liquidity.js"
 import { makeCreatePool } from "./methods/make-create-pool.js"
+import { makePlaceOrder } from "./methods/make-place-order.js"
+import { makeCancelOrder } from "./methods/make-cancel-order.js"
+import { makeFillOrder } from "./methods/make-fill-order.js"
+import { makeClaimProtocolFees } from "./methods/make-claim-protocol-fees.js"
 
 export const proofGenerator = ZkProgram({
     name: "proofGenerator",
@@ -49,12 +60,14 @@ export const proofGenerator = ZkProgram({
                 SelfProof<StateRoots, StateRoots>,
                 SingleBalanceWitness,
                 SingleBurnWitness,
+                SingleNonceWitness,
                 PublicKey,
                 Field,
                 UInt64,
                 UInt64,
                 UInt64,
                 Signature,
+                UInt64,
             ],
             method: makeBurnTokens,
         },
@@ -64,6 +77,7 @@ export const proofGenerator = ZkProgram({
                 SinglePoolWitness,
                 SingleLiquidityWitness,
                 DoubleBalanceWitness,
+                SingleNonceWitness,
                 Field,
                 Field,
                 PublicKey,
@@ -72,6 +86,9 @@ export const proofGenerator = ZkProgram({
                 UInt64,
                 UInt64,
                 Signature,
+                UInt64,
+                UInt64,
+                UInt64,
             ],
             method: makeCreatePool,
         },
@@ -81,6 +98,7 @@ export const proofGenerator = ZkProgram({
                 SinglePoolWitness,
                 SingleLiquidityWitness,
                 DoubleBalanceWitness,
+                SingleNonceWitness,
                 Field,
                 Field,
                 PublicKey,
@@ -93,6 +111,11 @@ export const proofGenerator = ZkProgram({
                 UInt64,
                 UInt64,
                 Signature,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
             ],
             method: makeProvideLiquidity,
         },
@@ -102,6 +125,7 @@ export const proofGenerator = ZkProgram({
                 SinglePoolWitness,
                 SingleLiquidityWitness,
                 DoubleBalanceWitness,
+                SingleNonceWitness,
                 Field,
                 Field,
                 PublicKey,
@@ -115,6 +139,11 @@ export const proofGenerator = ZkProgram({
                 UInt64,
                 UInt64,
                 Signature,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
             ],
             method: makeRemoveLiquidity,
         },
@@ -123,6 +152,7 @@ export const proofGenerator = ZkProgram({
                 SelfProof<StateRoots, StateRoots>,
                 SinglePoolWitness,
                 DoubleBalanceWitness,
+                SingleNonceWitness,
                 PublicKey,
                 Field,
                 Field,
@@ -134,6 +164,12 @@ export const proofGenerator = ZkProgram({
                 UInt64,
                 UInt64,
                 Signature,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
             ],
             method: makeBuyTokens,
         },
@@ -142,6 +178,7 @@ export const proofGenerator = ZkProgram({
                 SelfProof<StateRoots, StateRoots>,
                 SinglePoolWitness,
                 DoubleBalanceWitness,
+                SingleNonceWitness,
                 PublicKey,
                 Field,
                 Field,
@@ -153,8 +190,111 @@ export const proofGenerator = ZkProgram({
                 UInt64,
                 UInt64,
                 Signature,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
             ],
             method: makeSellTokens,
         },
+        makeSwapRoute: {
+            privateInputs: [
+                SelfProof<StateRoots, StateRoots>,
+                DoublePoolWitness,
+                DoubleBalanceWitness,
+                SingleNonceWitness,
+                PublicKey,
+                Field,
+                Field,
+                Field,
+                UInt64,
+                UInt64,
+                Pool,
+                Pool,
+                UInt64,
+                UInt64,
+                Signature,
+                UInt64,
+                UInt64,
+            ],
+            method: makeSwapRoute,
+        },
+        makeTransferTokens: {
+            privateInputs: [
+                SelfProof<StateRoots, StateRoots>,
+                DoubleBalanceWitness,
+                SingleNonceWitness,
+                PublicKey,
+                PublicKey,
+                Field,
+                UInt64,
+                UInt64,
+                UInt64,
+                Signature,
+                UInt64,
+            ],
+            method: makeTransferTokens,
+        },
+        makePlaceOrder: {
+            privateInputs: [
+                SelfProof<StateRoots, StateRoots>,
+                SingleOrderWitness,
+                SingleBalanceWitness,
+                SingleNonceWitness,
+                PublicKey,
+                Field,
+                Field,
+                UInt64,
+                UInt64,
+                UInt64,
+                UInt64,
+                Signature,
+                UInt64,
+            ],
+            method: makePlaceOrder,
+        },
+        makeCancelOrder: {
+            privateInputs: [
+                SelfProof<StateRoots, StateRoots>,
+                SingleOrderWitness,
+                SingleBalanceWitness,
+                SingleNonceWitness,
+                Order,
+                UInt64,
+                Signature,
+                UInt64,
+            ],
+            method: makeCancelOrder,
+        },
+        makeFillOrder: {
+            privateInputs: [
+                SelfProof<StateRoots, StateRoots>,
+                SingleOrderWitness,
+                SinglePoolWitness,
+                SingleBalanceWitness,
+                Order,
+                Pool,
+                UInt64,
+                UInt64,
+            ],
+            method: makeFillOrder,
+        },
+        makeClaimProtocolFees: {
+            privateInputs: [
+                SelfProof<StateRoots, StateRoots>,
+                SinglePoolWitness,
+                DoubleBalanceWitness,
+                SingleNonceWitness,
+                PublicKey,
+                Pool,
+                UInt64,
+                UInt64,
+                Signature,
+                UInt64,
+            ],
+            method: makeClaimProtocolFees,
+        },
     },
 })
@@ -5,10 +5,15 @@ import {
     BURNS_TREE_HEIGHT,
     DoubleBalanceWitness,
     LIQUIDITIES_TREE_HEIGHT,
+    NONCES_TREE_HEIGHT,
+    ORDERS_TREE_HEIGHT,
+    Order,
     POOLS_TREE_HEIGHT,
     SingleBalanceWitness,
     SingleBurnWitness,
     SingleLiquidityWitness,
+    SingleNonceWitness,
+    SingleOrderWitness,
     SinglePoolWitness,
     StateRoots,
 } from "nacho-common-o1js"
@@ -18,6 +23,8 @@ export const createStateUtil = () => {
     const poolsTree = new MerkleTree(POOLS_TREE_HEIGHT)
     const liquiditiesTree = new MerkleTree(LIQUIDITIES_TREE_HEIGHT)
     const burnsTree = new MerkleTree(BURNS_TREE_HEIGHT)
+    const ordersTree = new MerkleTree(ORDERS_TREE_HEIGHT)
+    const noncesTree = new MerkleTree(NONCES_TREE_HEIGHT)
     let depositsRoot = Field(0)
     const proofs: Array<ZkProof> = []
 
@@ -37,6 +44,8 @@ export const createStateUtil = () => {
             stateRoots.pools = poolsTree.getRoot()
             stateRoots.liquidities = liquiditiesTree.getRoot()
             stateRoots.burns = burnsTree.getRoot()
+            stateRoots.orders = ordersTree.getRoot()
+            stateRoots.nonces = noncesTree.getRoot()
             return stateRoots
         },
         get currentDepositsRoot() {
@@ -119,6 +128,10 @@ export const createStateUtil = () => {
             baseTokenAmount: UInt64,
             quoteTokenAmount: UInt64,
             totalLiquidityPoints: Field,
+            feeRate: UInt64,
+            protocolFeeBaseTokenAmount: UInt64,
+            protocolFeeQuoteTokenAmount: UInt64,
+            amplification: UInt64,
         ) {
             poolsTree.setLeaf(
                 index,
@@ -128,6 +141,10 @@ export const createStateUtil = () => {
                     baseTokenAmount.value,
                     quoteTokenAmount.value,
                     totalLiquidityPoints,
+                    feeRate.value,
+                    protocolFeeBaseTokenAmount.value,
+                    protocolFeeQuoteTokenAmount.value,
+                    amplification.value,
                 ]),
             )
         },
@@ -144,5 +161,25 @@ export const createStateUtil = () => {
                 Poseidon.hash([...burner.toFields(), tokenId, tokenAmount.value]),
             )
         },
+        getSingleOrderWitness(index: bigint): SingleOrderWitness {
+            return new SingleOrderWitness(
+                ordersTree
+                    .getWitness(index)
+                    .map((a) => ({ value: a.sibling.toBigInt(), isLeft: !a.isLeft })),
+            )
+        },
+        setOrder(index: bigint, order: Order) {
+            ordersTree.setLeaf(index, Poseidon.hash(order.toFields()))
+        },
+        getSingleNonceWitness(index: bigint): SingleNonceWitness {
+            return new SingleNonceWitness(
+                noncesTree
+                    .getWitness(index)
+                    .map((a) => ({ value: a.sibling.toBigInt(), isLeft: !a.isLeft })),
+            )
+        },
+        setNonce(index: bigint, owner: PublicKey, nonce: UInt64) {
+            noncesTree.setLeaf(index, Poseidon.hash([...owner.toFields(), nonce.value]))
+        },
     }
 }
@@ -1,6 +1,6 @@
 import { describe, it } from "node:test"
 import { Field, Mina, Signature, UInt64 } from "o1js"
-import { addOnePerMilFee, normalDiv } from "nacho-common-o1js"
+import { addFee, normalDiv } from "nacho-common-o1js"
 import { proofGenerator } from "../src/proof-generator.js"
 import { createStateUtil } from "./create-state-util.js"
 import assert from "node:assert"
@@ -12,6 +12,10 @@ describe("proof generator", async () => {
     const john = Mina.TestPublicKey.random(1)
     const minaTokenId = Field(1)
     const usdcTokenId = Field(2)
+    const poolFeeRate = UInt64.from(30)
+    const poolProtocolFeeAmount = UInt64.zero
+    const protocolFeeShare = UInt64.zero
+    const poolAmplification = UInt64.zero
 
     it("generates create genesis proof", async () => {
         const proof = await proofGenerator.createGenesis(stateUtil.stateRoots)
@@ -50,19 +54,27 @@ describe("proof generator", async () => {
         const currentBurn = UInt64.from(0)
         const currentBalance = UInt64.from(45_000_000)
         const amountToBurn = UInt64.from(3_000_000)
-        const userSignature = Signature.create(john.key, [minaTokenId, amountToBurn.value])
+        const userNonce = UInt64.from(0)
+        const userSignature = Signature.create(john.key, [
+            minaTokenId,
+            amountToBurn.value,
+            userNonce.value,
+        ])
 
         const proof = await proofGenerator.makeBurnTokens(
             stateUtil.stateRoots,
             stateUtil.lastProof,
             stateUtil.getSingleBalanceWitness(0n),
             stateUtil.getSingleBurnWitness(0n),
+            stateUtil.getSingleNonceWitness(0n),
             john,
             minaTokenId,
             currentBurn,
             currentBalance,
             amountToBurn,
             userSignature,
+            userNonce,
+            poolFeeRate,
         )
 
         proof.publicInput.assertEquals(stateUtil.stateRoots)
@@ -70,6 +82,8 @@ describe("proof generator", async () => {
         stateUtil.setBalance(0n, john, minaTokenId, currentBalance.sub(amountToBurn))
         stateUtil.setBurn(0n, john, minaTokenId, amountToBurn)
 
+        stateUtil.setNonce(0n, john, userNonce.add(1))
+
         proof.publicOutput.assertEquals(stateUtil.stateRoots)
         stateUtil.pushProof(proof)
     })
@@ -104,11 +118,16 @@ describe("proof generator", async () => {
         const currentUsdcBalance = UInt64.from(100_000_000)
         const initialMinaLiquidity = UInt64.from(32_000_000)
         const initialUsdcLiquidity = UInt64.from(50_000_000)
+        const userNonce = UInt64.from(1)
         const userSignature = Signature.create(john.key, [
             minaTokenId,
             usdcTokenId,
             initialMinaLiquidity.value,
             initialUsdcLiquidity.value,
+            poolFeeRate.value,
+            Field(0),
+            poolAmplification.value,
+            userNonce.value,
         ])
 
         const proof = await proofGenerator.makeCreatePool(
@@ -117,6 +136,7 @@ describe("proof generator", async () => {
             stateUtil.getSinglePoolWitness(0n),
             stateUtil.getSingleLiqudityWitness(0n),
             stateUtil.getDoubleBalanceWitness(0n, 1n),
+            stateUtil.getSingleNonceWitness(0n),
             minaTokenId,
             usdcTokenId,
             john,
@@ -125,6 +145,9 @@ describe("proof generator", async () => {
             currentMinaBalance,
             currentUsdcBalance,
             userSignature,
+            userNonce,
+            poolFeeRate,
+            poolAmplification,
         )
 
         proof.publicInput.assertEquals(stateUtil.stateRoots)
@@ -136,6 +159,10 @@ describe("proof generator", async () => {
             initialMinaLiquidity,
             initialUsdcLiquidity,
             initialMinaLiquidity.value.mul(initialUsdcLiquidity.value),
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            poolAmplification,
         )
 
         stateUtil.setLiquidity(
@@ -149,6 +176,8 @@ describe("proof generator", async () => {
         stateUtil.setBalance(0n, john, minaTokenId, currentMinaBalance.sub(initialMinaLiquidity))
         stateUtil.setBalance(1n, john, usdcTokenId, currentUsdcBalance.sub(initialUsdcLiquidity))
 
+        stateUtil.setNonce(0n, john, userNonce.add(1))
+
         proof.publicOutput.assertEquals(stateUtil.stateRoots)
         stateUtil.pushProof(proof)
     })
@@ -161,11 +190,13 @@ describe("proof generator", async () => {
         const currentLiquidityPoints = currentMinaLiquidity.value.mul(currentUsdcLiquidity.value)
         const baseTokenAmountToProvide = UInt64.from(5_000_000)
         const quoteTokenAmountLimitToProvide = UInt64.from(10_000_000)
+        const userNonce = UInt64.from(2)
         const userSignature = Signature.create(john.key, [
             minaTokenId,
             usdcTokenId,
             baseTokenAmountToProvide.value,
             quoteTokenAmountLimitToProvide.value,
+            userNonce.value,
         ])
 
         const proof = await proofGenerator.makeProvideLiquidity(
@@ -174,6 +205,7 @@ describe("proof generator", async () => {
             stateUtil.getSinglePoolWitness(0n),
             stateUtil.getSingleLiqudityWitness(0n),
             stateUtil.getDoubleBalanceWitness(0n, 1n),
+            stateUtil.getSingleNonceWitness(0n),
             minaTokenId,
             usdcTokenId,
             john,
@@ -186,6 +218,11 @@ describe("proof generator", async () => {
             baseTokenAmountToProvide,
             quoteTokenAmountLimitToProvide,
             userSignature,
+            userNonce,
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            poolAmplification,
         )
 
         proof.publicInput.assertEquals(stateUtil.stateRoots)
@@ -209,6 +246,10 @@ describe("proof generator", async () => {
             currentMinaLiquidity.add(baseTokenAmountToProvide),
             currentUsdcLiquidity.add(quoteTokenAmountToProvide),
             currentLiquidityPoints.add(liquidityPointsToBeCreated),
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            poolAmplification,
         )
 
         stateUtil.setLiquidity(
@@ -233,6 +274,8 @@ describe("proof generator", async () => {
             currentUsdcBalance.sub(quoteTokenAmountToProvide),
         )
 
+        stateUtil.setNonce(0n, john, userNonce.add(1))
+
         proof.publicOutput.assertEquals(stateUtil.stateRoots)
         stateUtil.pushProof(proof)
     })
@@ -246,12 +289,14 @@ describe("proof generator", async () => {
         const baseTokenAmountLimitToProvide = UInt64.from(5_000_000)
         const quoteTokenAmountLimitToProvide = UInt64.from(7_812_500)
         const liquidityPointsToRemove = Field(250_000_000_000_000n)
+        const userNonce = UInt64.from(3)
         const userSignature = Signature.create(john.key, [
             minaTokenId,
             usdcTokenId,
             baseTokenAmountLimitToProvide.value,
             quoteTokenAmountLimitToProvide.value,
             liquidityPointsToRemove,
+            userNonce.value,
         ])
 
         const proof = await proofGenerator.makeRemoveLiquidity(
@@ -260,6 +305,7 @@ describe("proof generator", async () => {
             stateUtil.getSinglePoolWitness(0n),
             stateUtil.getSingleLiqudityWitness(0n),
             stateUtil.getDoubleBalanceWitness(0n, 1n),
+            stateUtil.getSingleNonceWitness(0n),
             minaTokenId,
             usdcTokenId,
             john,
@@ -273,6 +319,11 @@ describe("proof generator", async () => {
             baseTokenAmountLimitToProvide,
             quoteTokenAmountLimitToProvide,
             userSignature,
+            userNonce,
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            poolAmplification,
         )
 
         proof.publicInput.assertEquals(stateUtil.stateRoots)
@@ -290,6 +341,10 @@ describe("proof generator", async () => {
             newMinaLiquidity,
             newUsdcLiquidity,
             newLiquidityPoints,
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            poolAmplification,
         )
 
         stateUtil.setLiquidity(0n, john, minaTokenId, usdcTokenId, newLiquidityPoints)
@@ -297,6 +352,8 @@ describe("proof generator", async () => {
         stateUtil.setBalance(0n, john, minaTokenId, newMinaBalance)
         stateUtil.setBalance(1n, john, usdcTokenId, newUsdcBalance)
 
+        stateUtil.setNonce(0n, john, userNonce.add(1))
+
         proof.publicOutput.assertEquals(stateUtil.stateRoots)
         stateUtil.pushProof(proof)
     })
@@ -309,11 +366,13 @@ describe("proof generator", async () => {
         const currentLiquidityPoints = currentMinaLiquidity.value.mul(currentUsdcLiquidity.value)
         const baseTokenAmountToSwap = UInt64.from(1_000_000)
         const quoteTokenAmountLimitToSwap = UInt64.from(2_000_000)
+        const userNonce = UInt64.from(4)
         const userSignature = Signature.create(john.key, [
             minaTokenId,
             usdcTokenId,
             baseTokenAmountToSwap.value,
             quoteTokenAmountLimitToSwap.value,
+            userNonce.value,
         ])
 
         const proof = await proofGenerator.makeBuyTokens(
@@ -321,6 +380,7 @@ describe("proof generator", async () => {
             stateUtil.lastProof,
             stateUtil.getSinglePoolWitness(0n),
             stateUtil.getDoubleBalanceWitness(0n, 1n),
+            stateUtil.getSingleNonceWitness(0n),
             john,
             minaTokenId,
             usdcTokenId,
@@ -332,6 +392,12 @@ describe("proof generator", async () => {
             baseTokenAmountToSwap,
             quoteTokenAmountLimitToSwap,
             userSignature,
+            userNonce,
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            protocolFeeShare,
+            poolAmplification,
         )
 
         proof.publicInput.assertEquals(stateUtil.stateRoots)
@@ -346,7 +412,7 @@ describe("proof generator", async () => {
 
         const usdcAmountToSwap = newUsdcLiquidity.sub(currentUsdcLiquidity)
 
-        const quoteTokenAmountToSwapPlusFee = addOnePerMilFee(usdcAmountToSwap)
+        const quoteTokenAmountToSwapPlusFee = addFee(usdcAmountToSwap, poolFeeRate)
 
         stateUtil.setPool(
             0n,
@@ -355,6 +421,10 @@ describe("proof generator", async () => {
             currentMinaLiquidity.sub(baseTokenAmountToSwap),
             currentUsdcLiquidity.add(quoteTokenAmountToSwapPlusFee),
             currentLiquidityPoints,
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            poolAmplification,
         )
 
         stateUtil.setBalance(0n, john, minaTokenId, currentMinaBalance.add(baseTokenAmountToSwap))
@@ -366,23 +436,27 @@ describe("proof generator", async () => {
             currentUsdcBalance.sub(quoteTokenAmountToSwapPlusFee),
         )
 
+        stateUtil.setNonce(0n, john, userNonce.add(1))
+
         proof.publicOutput.assertEquals(stateUtil.stateRoots)
         stateUtil.pushProof(proof)
     })
 
     it("generates make sell tokens proof", async () => {
         const currentMinaBalance = UInt64.from(11_000_000)
-        const currentUsdcBalance = UInt64.from(48_385_485n)
+        const currentUsdcBalance = UInt64.from(48_382_259n)
         const currentMinaLiquidity = UInt64.from(31_000_000)
-        const currentUsdcLiquidity = UInt64.from(51_614_515n)
+        const currentUsdcLiquidity = UInt64.from(51_617_741n)
         const currentLiquidityPoints = Field(1600000000000000n)
         const baseTokenAmountLimitToSwap = UInt64.from(1_000_000)
         const quoteTokenAmountToSwap = UInt64.from(1_614_515)
+        const userNonce = UInt64.from(5)
         const userSignature = Signature.create(john.key, [
             minaTokenId,
             usdcTokenId,
             baseTokenAmountLimitToSwap.value,
             quoteTokenAmountToSwap.value,
+            userNonce.value,
         ])
 
         const proof = await proofGenerator.makeSellTokens(
@@ -390,6 +464,7 @@ describe("proof generator", async () => {
             stateUtil.lastProof,
             stateUtil.getSinglePoolWitness(0n),
             stateUtil.getDoubleBalanceWitness(0n, 1n),
+            stateUtil.getSingleNonceWitness(0n),
             john,
             minaTokenId,
             usdcTokenId,
@@ -401,6 +476,12 @@ describe("proof generator", async () => {
             baseTokenAmountLimitToSwap,
             quoteTokenAmountToSwap,
             userSignature,
+            userNonce,
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            protocolFeeShare,
+            poolAmplification,
         )
 
         proof.publicInput.assertEquals(stateUtil.stateRoots)
@@ -413,7 +494,7 @@ describe("proof generator", async () => {
 
         const baseTokenAmountToSwap = newMinaLiquidity.sub(currentMinaLiquidity)
 
-        const baseTokenAmountToSwapPlusFee = addOnePerMilFee(baseTokenAmountToSwap)
+        const baseTokenAmountToSwapPlusFee = addFee(baseTokenAmountToSwap, poolFeeRate)
 
         stateUtil.setPool(
             0n,
@@ -422,6 +503,10 @@ describe("proof generator", async () => {
             currentMinaLiquidity.add(baseTokenAmountToSwapPlusFee),
             currentUsdcLiquidity.sub(quoteTokenAmountToSwap),
             currentLiquidityPoints,
+            poolFeeRate,
+            poolProtocolFeeAmount,
+            poolProtocolFeeAmount,
+            poolAmplification,
         )
 
         stateUtil.setBalance(
@@ -433,6 +518,8 @@ describe("proof generator", async () => {
 
         stateUtil.setBalance(1n, john, usdcTokenId, currentUsdcBalance.add(quoteTokenAmountToSwap))
 
+        stateUtil.setNonce(0n, john, userNonce.add(1))
+
         proof.publicOutput.assertEquals(stateUtil.stateRoots)
         stateUtil.pushProof(proof)
     })
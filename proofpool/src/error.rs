@@ -3,6 +3,8 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum ProofpoolError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
     #[error(transparent)]
     Dd(#[from] DynamicQueueError),
 }
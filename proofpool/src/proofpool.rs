@@ -1,12 +1,22 @@
-use std::path::Path;
+use std::{io::SeekFrom, path::Path};
 
 use nacho_data_structures::StatefulTransaction;
 use nacho_dynamic_queue::DynamicQueue;
+use tokio::{
+    fs::{File, OpenOptions},
+    io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+};
 
 use crate::ProofpoolError;
 
+/// The queue of the stateful transactions whose proofs are to be generated.
+///
+/// It keeps the count of all the transactions ever pushed, which is stored next to the count of the items in the queue's file.
+/// The queue's file only grows by an item when a transaction is pushed, so a push whose count isn't stored is counted on startup.
 pub struct Proofpool {
-    queue: DynamicQueue<328, StatefulTransaction>,
+    queue: DynamicQueue<552, StatefulTransaction>,
+    file: File,
+    push_count: u64,
 }
 
 type Result<T> = std::result::Result<T, ProofpoolError>;
@@ -15,20 +25,116 @@ impl Proofpool {
     pub async fn new(path: impl AsRef<Path>) -> Result<Proofpool> {
         let path = path.as_ref();
 
-        let queue = DynamicQueue::new(path).await?;
+        let mut queue = DynamicQueue::new(path).await?;
 
-        Ok(Self { queue })
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path.join("push_count"))
+            .await?;
+
+        let (mut push_count, stored_item_count) = if file.metadata().await?.len() == 0 {
+            (0, 0)
+        } else {
+            file.seek(SeekFrom::Start(0)).await?;
+
+            (file.read_u64().await?, file.read_u64().await?)
+        };
+
+        if queue.get_stored_item_count().await? == stored_item_count + 1 {
+            push_count += 1;
+        }
+
+        let mut proofpool = Self {
+            queue,
+            file,
+            push_count,
+        };
+
+        proofpool.store_counts().await?;
+
+        Ok(proofpool)
     }
 
     pub async fn push(&mut self, stateful_tx: &StatefulTransaction) -> Result<()> {
         self.queue.push(stateful_tx).await?;
 
+        self.push_count += 1;
+        self.store_counts().await?;
+
         Ok(())
     }
 
     pub async fn pop(&mut self) -> Result<Option<StatefulTransaction>> {
         let maybe_method = self.queue.pop().await?;
 
+        self.store_counts().await?;
+
         Ok(maybe_method)
     }
+
+    /// Returns the count of all the stateful transactions pushed to the proofpool, including the popped ones.
+    pub fn get_push_count(&self) -> u64 {
+        self.push_count
+    }
+
+    async fn store_counts(&mut self) -> Result<()> {
+        let stored_item_count = self.queue.get_stored_item_count().await?;
+
+        let mut buf = [0u8; 16];
+        buf[0..8].copy_from_slice(&self.push_count.to_be_bytes());
+        buf[8..16].copy_from_slice(&stored_item_count.to_be_bytes());
+
+        self.file.seek(SeekFrom::Start(0)).await?;
+        self.file.write_all(&buf).await?;
+        self.file.flush().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::fs::remove_dir_all;
+
+    #[tokio::test]
+    async fn counts_pushed_txs_correctly() {
+        let dir = "/tmp/nacho/tests/proofpool/counts_pushed_txs_correctly";
+
+        let mut proofpool = Proofpool::new(dir).await.unwrap();
+
+        let stateful_tx = StatefulTransaction::CreateGenesis {
+            transaction: (),
+            state: (),
+        };
+
+        proofpool.push(&stateful_tx).await.unwrap();
+        proofpool.push(&stateful_tx).await.unwrap();
+        proofpool.pop().await.unwrap();
+
+        assert_eq!(proofpool.get_push_count(), 2);
+
+        // Simulates a crash after pushing a transaction but before storing the push count.
+        proofpool.queue.push(&stateful_tx).await.unwrap();
+
+        drop(proofpool);
+
+        let mut proofpool = Proofpool::new(dir).await.unwrap();
+
+        assert_eq!(proofpool.get_push_count(), 3);
+
+        proofpool.pop().await.unwrap();
+        proofpool.pop().await.unwrap();
+
+        drop(proofpool);
+
+        let proofpool = Proofpool::new(dir).await.unwrap();
+
+        assert_eq!(proofpool.get_push_count(), 3);
+
+        remove_dir_all(dir).await.unwrap();
+    }
 }
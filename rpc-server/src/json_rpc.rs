@@ -0,0 +1,1424 @@
+use crate::{RpcMethod, RpcResponse};
+use nacho_data_structures::{
+    Address, ByteConversion, OrderStatus, PoolKind, RejectionReason, Signature,
+    SingleMerkleWitness, SwapSide, Transaction, TxStatus, U256,
+};
+use primitive_types::U256 as PU256;
+use serde_json::{json, Map, Value};
+use std::future::Future;
+
+/// The path JSON-RPC 2.0 requests are served on.
+///
+/// Requests to any other path are handled by the binary protocol.
+pub const JSON_RPC_PATH: &str = "/json-rpc";
+
+/// The struct that represents a JSON-RPC 2.0 error object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct JsonRpcError {
+    code: i64,
+    message: &'static str,
+    data: Option<&'static str>,
+}
+
+impl JsonRpcError {
+    const PARSE_ERROR: JsonRpcError = JsonRpcError {
+        code: -32700,
+        message: "Parse error",
+        data: None,
+    };
+    const INVALID_REQUEST: JsonRpcError = JsonRpcError {
+        code: -32600,
+        message: "Invalid Request",
+        data: None,
+    };
+    const METHOD_NOT_FOUND: JsonRpcError = JsonRpcError {
+        code: -32601,
+        message: "Method not found",
+        data: None,
+    };
+    const INVALID_PARAMS: JsonRpcError = JsonRpcError {
+        code: -32602,
+        message: "Invalid params",
+        data: None,
+    };
+    const SERVER_ERROR: JsonRpcError = JsonRpcError {
+        code: -32000,
+        message: "Server error",
+        data: None,
+    };
+    const RATE_LIMITED: JsonRpcError = JsonRpcError {
+        code: -32005,
+        message: "Rate limited",
+        data: None,
+    };
+
+    fn rejected(reason: RejectionReason) -> JsonRpcError {
+        JsonRpcError {
+            code: -32003,
+            message: "Transaction rejected",
+            data: Some(rejection_reason_to_str(reason)),
+        }
+    }
+
+    fn to_json(self, id: Value) -> Value {
+        let mut error = json!({
+            "code": self.code,
+            "message": self.message,
+        });
+
+        if let Some(data) = self.data {
+            error["data"] = json!(data);
+        }
+
+        json!({
+            "jsonrpc": "2.0",
+            "error": error,
+            "id": id,
+        })
+    }
+}
+
+/// Handles a JSON-RPC 2.0 request body which is either a single request or a batch of requests.
+///
+/// Returns `None` if there is nothing to respond with, which is the case for notifications.
+pub(crate) async fn handle_json_rpc<F, Fut>(body: &[u8], rpc_method_handler: F) -> Option<Value>
+where
+    F: Fn(RpcMethod) -> Fut + Copy,
+    Fut: Future<Output = RpcResponse>,
+{
+    let request: Value = match serde_json::from_slice(body) {
+        Ok(request) => request,
+        Err(_) => return Some(JsonRpcError::PARSE_ERROR.to_json(Value::Null)),
+    };
+
+    match request {
+        Value::Array(requests) => {
+            if requests.is_empty() {
+                return Some(JsonRpcError::INVALID_REQUEST.to_json(Value::Null));
+            }
+
+            let mut responses = Vec::with_capacity(requests.len());
+
+            for request in requests {
+                if let Some(response) = handle_single(request, rpc_method_handler).await {
+                    responses.push(response);
+                }
+            }
+
+            if responses.is_empty() {
+                None
+            } else {
+                Some(Value::Array(responses))
+            }
+        }
+        request => handle_single(request, rpc_method_handler).await,
+    }
+}
+
+async fn handle_single<F, Fut>(request: Value, rpc_method_handler: F) -> Option<Value>
+where
+    F: Fn(RpcMethod) -> Fut,
+    Fut: Future<Output = RpcResponse>,
+{
+    let request = match request {
+        Value::Object(request) => request,
+        _ => return Some(JsonRpcError::INVALID_REQUEST.to_json(Value::Null)),
+    };
+
+    let maybe_id = request.get("id").cloned();
+
+    let id = match maybe_id {
+        Some(Value::Null) | Some(Value::Number(_)) | Some(Value::String(_)) | None => {
+            maybe_id.clone().unwrap_or(Value::Null)
+        }
+        Some(_) => return Some(JsonRpcError::INVALID_REQUEST.to_json(Value::Null)),
+    };
+
+    let is_notification = maybe_id.is_none();
+
+    let method = match (request.get("jsonrpc"), request.get("method")) {
+        (Some(Value::String(version)), Some(Value::String(method))) if version == "2.0" => method,
+        _ => return Some(JsonRpcError::INVALID_REQUEST.to_json(id)),
+    };
+
+    let result = match parse_method(method, request.get("params")) {
+        Ok(rpc_method) => response_to_json(rpc_method_handler(rpc_method).await),
+        Err(err) => Err(err),
+    };
+
+    if is_notification {
+        return None;
+    }
+
+    Some(match result {
+        Ok(result) => json!({
+            "jsonrpc": "2.0",
+            "result": result,
+            "id": id,
+        }),
+        Err(err) => err.to_json(id),
+    })
+}
+
+pub(crate) fn parse_method(
+    method: &str,
+    params: Option<&Value>,
+) -> Result<RpcMethod, JsonRpcError> {
+    let empty = Map::new();
+
+    let params = match params {
+        Some(Value::Object(params)) => params,
+        None => &empty,
+        Some(_) => return Err(JsonRpcError::INVALID_PARAMS),
+    };
+
+    Ok(match method {
+        "getTotalTxCount" => RpcMethod::GetTotalTxCount,
+
+        "getTxStatus" => RpcMethod::GetTxStatus {
+            tx_id: parse_param(params, "tx_id", parse_u64)?,
+        },
+
+        "getTx" => RpcMethod::GetTx {
+            tx_id: parse_param(params, "tx_id", parse_u64)?,
+        },
+
+        "getTxHistory" => RpcMethod::GetTxHistory {
+            address: parse_param(params, "address", parse_address)?,
+            from_tx_id: parse_param(params, "from_tx_id", parse_u64)?,
+            limit: parse_param(params, "limit", parse_u64)?,
+        },
+
+        "getNonce" => RpcMethod::GetNonce {
+            address: parse_param(params, "address", parse_address)?,
+        },
+
+        "getBalances" => RpcMethod::GetBalances {
+            address: parse_param(params, "address", parse_address)?,
+        },
+
+        "getPools" => RpcMethod::GetPools,
+
+        "getProtocolFees" => RpcMethod::GetProtocolFees,
+
+        "getLiquidities" => RpcMethod::GetLiquidities {
+            address: parse_param(params, "address", parse_address)?,
+        },
+
+        "getPositions" => RpcMethod::GetPositions {
+            address: parse_param(params, "address", parse_address)?,
+        },
+
+        "getPoolStats" => RpcMethod::GetPoolStats {
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+        },
+
+        "getTopPools" => RpcMethod::GetTopPools,
+
+        "getBurns" => RpcMethod::GetBurns {
+            address: parse_param(params, "address", parse_address)?,
+        },
+
+        "getBridgeWitnesses" => RpcMethod::GetBridgeWitnesses {
+            address: parse_param(params, "address", parse_address)?,
+            token_id: parse_param(params, "token_id", parse_u256)?,
+        },
+
+        "burnTokens" => RpcMethod::BurnTokens {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            token_id: parse_param(params, "token_id", parse_u256)?,
+            token_amount: parse_param(params, "token_amount", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "createPool" => RpcMethod::CreatePool {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+            base_token_amount: parse_param(params, "base_token_amount", parse_u64)?,
+            quote_token_amount: parse_param(params, "quote_token_amount", parse_u64)?,
+            fee_rate: parse_param(params, "fee_rate", parse_u64)?,
+            pool_kind: parse_param(params, "pool_kind", parse_pool_kind)?,
+            amplification: parse_param(params, "amplification", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "provideLiquidity" => RpcMethod::ProvideLiquidity {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+            base_token_amount: parse_param(params, "base_token_amount", parse_u64)?,
+            quote_token_amount_limit: parse_param(params, "quote_token_amount_limit", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "removeLiquidity" => RpcMethod::RemoveLiquidity {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+            base_token_amount_limit: parse_param(params, "base_token_amount_limit", parse_u64)?,
+            quote_token_amount_limit: parse_param(params, "quote_token_amount_limit", parse_u64)?,
+            points: parse_param(params, "points", parse_u256)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "buyTokens" => RpcMethod::BuyTokens {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+            base_token_amount: parse_param(params, "base_token_amount", parse_u64)?,
+            quote_token_amount_limit: parse_param(params, "quote_token_amount_limit", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "sellTokens" => RpcMethod::SellTokens {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+            base_token_amount_limit: parse_param(params, "base_token_amount_limit", parse_u64)?,
+            quote_token_amount: parse_param(params, "quote_token_amount", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "getQuote" => RpcMethod::GetQuote {
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+            side: parse_param(params, "side", parse_swap_side)?,
+            amount: parse_param(params, "amount", parse_u64)?,
+        },
+
+        "swapRoute" => RpcMethod::SwapRoute {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            path: parse_param(params, "path", parse_path)?,
+            token_amount_in: parse_param(params, "token_amount_in", parse_u64)?,
+            token_amount_out_limit: parse_param(params, "token_amount_out_limit", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "getRoute" => RpcMethod::GetRoute {
+            token_in_id: parse_param(params, "token_in_id", parse_u256)?,
+            token_out_id: parse_param(params, "token_out_id", parse_u256)?,
+            token_amount_in: parse_param(params, "token_amount_in", parse_u64)?,
+            max_hops: match params.get("max_hops") {
+                Some(max_hops) => parse_u64(max_hops).ok_or(JsonRpcError::INVALID_PARAMS)?,
+                None => 0,
+            },
+        },
+
+        "transferTokens" => RpcMethod::TransferTokens {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            receiver_address: parse_param(params, "receiver_address", parse_address)?,
+            token_id: parse_param(params, "token_id", parse_u256)?,
+            token_amount: parse_param(params, "token_amount", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "placeOrder" => RpcMethod::PlaceOrder {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            token_in_id: parse_param(params, "token_in_id", parse_u256)?,
+            token_out_id: parse_param(params, "token_out_id", parse_u256)?,
+            token_amount_in: parse_param(params, "token_amount_in", parse_u64)?,
+            token_amount_out_limit: parse_param(params, "token_amount_out_limit", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "cancelOrder" => RpcMethod::CancelOrder {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            order_id: parse_param(params, "order_id", parse_u64)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "claimProtocolFees" => RpcMethod::ClaimProtocolFees {
+            address: parse_param(params, "address", parse_address)?,
+            signature: parse_param(params, "signature", parse_signature)?,
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+            nonce: parse_param(params, "nonce", parse_u64)?,
+        },
+
+        "getOrders" => RpcMethod::GetOrders {
+            address: parse_param(params, "address", parse_address)?,
+        },
+
+        "getTwap" => RpcMethod::GetTwap {
+            base_token_id: parse_param(params, "base_token_id", parse_u256)?,
+            quote_token_id: parse_param(params, "quote_token_id", parse_u256)?,
+            window: parse_param(params, "window", parse_u64)?,
+        },
+
+        "simulateTx" => {
+            let kind = params
+                .get("kind")
+                .and_then(Value::as_str)
+                .ok_or(JsonRpcError::INVALID_PARAMS)?;
+
+            let method = match kind {
+                "burn_tokens" => "burnTokens",
+                "create_pool" => "createPool",
+                "provide_liquidity" => "provideLiquidity",
+                "remove_liquidity" => "removeLiquidity",
+                "buy_tokens" => "buyTokens",
+                "sell_tokens" => "sellTokens",
+                "swap_route" => "swapRoute",
+                "transfer_tokens" => "transferTokens",
+                "place_order" => "placeOrder",
+                "cancel_order" => "cancelOrder",
+                "claim_protocol_fees" => "claimProtocolFees",
+                _ => return Err(JsonRpcError::INVALID_PARAMS),
+            };
+
+            // Simulated transactions aren't signed, so their signatures and nonces are optional.
+            let mut params = params.clone();
+            params
+                .entry("signature")
+                .or_insert(json!({ "r": "0", "s": "0" }));
+            params.entry("nonce").or_insert(json!(0));
+
+            RpcMethod::SimulateTx {
+                tx: parse_method(method, Some(&Value::Object(params)))?
+                    .into_transaction()
+                    .ok_or(JsonRpcError::INVALID_PARAMS)?,
+            }
+        }
+
+        _ => return Err(JsonRpcError::METHOD_NOT_FOUND),
+    })
+}
+
+pub(crate) fn response_to_json(rpc_response: RpcResponse) -> Result<Value, JsonRpcError> {
+    Ok(match rpc_response {
+        RpcResponse::ClientError => return Err(JsonRpcError::INVALID_REQUEST),
+        RpcResponse::ServerError => return Err(JsonRpcError::SERVER_ERROR),
+        RpcResponse::RateLimited => return Err(JsonRpcError::RATE_LIMITED),
+        RpcResponse::Rejected(reason) => return Err(JsonRpcError::rejected(reason)),
+        RpcResponse::TotalTxCount(total_tx_count) => json!(total_tx_count),
+        RpcResponse::TxStatus(tx_status, maybe_reason) => json!({
+            "status": tx_status_to_str(tx_status),
+            "rejection_reason": maybe_reason.map(rejection_reason_to_str),
+        }),
+        RpcResponse::Tx(tx) => tx_to_json(&tx),
+        RpcResponse::TxHistory(history) => Value::Array(
+            history
+                .into_iter()
+                .map(|(tx_id, tx_kind, tx_status)| {
+                    json!({
+                        "tx_id": tx_id,
+                        "kind": tx_kind_to_str(tx_kind),
+                        "status": tx_status_to_str(tx_status),
+                    })
+                })
+                .collect(),
+        ),
+        RpcResponse::Nonce(nonce) => json!(nonce),
+        RpcResponse::Balances(balances) => Value::Array(
+            balances
+                .into_iter()
+                .map(|(token_id, token_amount)| {
+                    json!({
+                        "token_id": u256_to_json(&token_id),
+                        "token_amount": token_amount,
+                    })
+                })
+                .collect(),
+        ),
+        RpcResponse::Pools(pools) => Value::Array(
+            pools
+                .into_iter()
+                .map(
+                    |(
+                        base_token_id,
+                        quote_token_id,
+                        base_token_amount,
+                        quote_token_amount,
+                        total_liquidity_points,
+                        fee_rate,
+                        amplification,
+                    )| {
+                        json!({
+                            "base_token_id": u256_to_json(&base_token_id),
+                            "quote_token_id": u256_to_json(&quote_token_id),
+                            "base_token_amount": base_token_amount,
+                            "quote_token_amount": quote_token_amount,
+                            "total_liquidity_points": u256_to_json(&total_liquidity_points),
+                            "fee_rate": fee_rate,
+                            "amplification": amplification,
+                        })
+                    },
+                )
+                .collect(),
+        ),
+        RpcResponse::Liquidites(liquidities) => Value::Array(
+            liquidities
+                .into_iter()
+                .map(|(base_token_id, quote_token_id, points)| {
+                    json!({
+                        "base_token_id": u256_to_json(&base_token_id),
+                        "quote_token_id": u256_to_json(&quote_token_id),
+                        "points": u256_to_json(&points),
+                    })
+                })
+                .collect(),
+        ),
+        RpcResponse::Burns(burns) => Value::Array(
+            burns
+                .into_iter()
+                .map(|(token_id, token_amount)| {
+                    json!({
+                        "token_id": u256_to_json(&token_id),
+                        "token_amount": token_amount,
+                    })
+                })
+                .collect(),
+        ),
+        RpcResponse::BridgeWitnesses(burn_witness, withdrawal_witness) => json!({
+            "burn_witness": witness_to_json(&burn_witness),
+            "withdrawal_witness": witness_to_json(&withdrawal_witness),
+        }),
+        RpcResponse::TxId(tx_id) => json!(tx_id),
+        RpcResponse::Simulation(balance_changes, pools, maybe_points_change) => json!({
+            "balance_changes": balance_changes
+                .into_iter()
+                .map(|(token_id, before, after)| {
+                    json!({
+                        "token_id": u256_to_json(&token_id),
+                        "before": before,
+                        "after": after,
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "pools": pools
+                .into_iter()
+                .map(
+                    |(
+                        base_token_id,
+                        quote_token_id,
+                        base_token_amount,
+                        quote_token_amount,
+                        total_liquidity_points,
+                        fee_rate,
+                        amplification,
+                    )| {
+                        json!({
+                            "base_token_id": u256_to_json(&base_token_id),
+                            "quote_token_id": u256_to_json(&quote_token_id),
+                            "base_token_amount": base_token_amount,
+                            "quote_token_amount": quote_token_amount,
+                            "total_liquidity_points": u256_to_json(&total_liquidity_points),
+                            "fee_rate": fee_rate,
+                            "amplification": amplification,
+                        })
+                    },
+                )
+                .collect::<Vec<_>>(),
+            "liquidity_points": maybe_points_change.map(|(before, after)| {
+                json!({
+                    "before": u256_to_json(&before),
+                    "after": u256_to_json(&after),
+                })
+            }),
+        }),
+        RpcResponse::Quote(
+            amount_in,
+            amount_out,
+            fee,
+            spot_price,
+            effective_price,
+            price_impact,
+        ) => json!({
+            "amount_in": amount_in,
+            "amount_out": amount_out,
+            "fee": fee,
+            "spot_price": spot_price,
+            "effective_price": effective_price,
+            "price_impact": price_impact,
+        }),
+        RpcResponse::Route(path, amount_out) => json!({
+            "path": path.iter().map(u256_to_json).collect::<Vec<_>>(),
+            "amount_out": amount_out,
+        }),
+        RpcResponse::ProtocolFees(protocol_fees) => Value::Array(
+            protocol_fees
+                .into_iter()
+                .map(
+                    |(base_token_id, quote_token_id, base_token_amount, quote_token_amount)| {
+                        json!({
+                            "base_token_id": u256_to_json(&base_token_id),
+                            "quote_token_id": u256_to_json(&quote_token_id),
+                            "base_token_amount": base_token_amount,
+                            "quote_token_amount": quote_token_amount,
+                        })
+                    },
+                )
+                .collect(),
+        ),
+        RpcResponse::Orders(orders) => Value::Array(
+            orders
+                .into_iter()
+                .map(
+                    |(
+                        order_id,
+                        token_in_id,
+                        token_out_id,
+                        token_amount_in,
+                        token_amount_out_limit,
+                        status,
+                    )| {
+                        json!({
+                            "order_id": order_id,
+                            "token_in_id": u256_to_json(&token_in_id),
+                            "token_out_id": u256_to_json(&token_out_id),
+                            "token_amount_in": token_amount_in,
+                            "token_amount_out_limit": token_amount_out_limit,
+                            "status": order_status_to_str(status),
+                        })
+                    },
+                )
+                .collect(),
+        ),
+        RpcResponse::Twap(price) => json!({ "price": price.to_string() }),
+        RpcResponse::Positions(positions) => Value::Array(
+            positions
+                .into_iter()
+                .map(
+                    |(
+                        base_token_id,
+                        quote_token_id,
+                        points,
+                        share,
+                        base_token_amount,
+                        quote_token_amount,
+                        fee_base_token_amount,
+                        fee_quote_token_amount,
+                    )| {
+                        json!({
+                            "base_token_id": u256_to_json(&base_token_id),
+                            "quote_token_id": u256_to_json(&quote_token_id),
+                            "points": u256_to_json(&points),
+                            "share": share,
+                            "base_token_amount": base_token_amount,
+                            "quote_token_amount": quote_token_amount,
+                            "fee_base_token_amount": fee_base_token_amount,
+                            "fee_quote_token_amount": fee_quote_token_amount,
+                        })
+                    },
+                )
+                .collect(),
+        ),
+        RpcResponse::PoolStats(daily_stats, weekly_stats) => json!({
+            "day": stats_to_json(daily_stats),
+            "week": stats_to_json(weekly_stats),
+        }),
+        RpcResponse::TopPools(top_pools) => Value::Array(
+            top_pools
+                .into_iter()
+                .map(|(base_token_id, quote_token_id, daily_stats)| {
+                    json!({
+                        "base_token_id": u256_to_json(&base_token_id),
+                        "quote_token_id": u256_to_json(&quote_token_id),
+                        "day": stats_to_json(daily_stats),
+                    })
+                })
+                .collect(),
+        ),
+    })
+}
+
+fn parse_param<T>(
+    params: &Map<String, Value>,
+    name: &str,
+    parse: fn(&Value) -> Option<T>,
+) -> Result<T, JsonRpcError> {
+    params
+        .get(name)
+        .and_then(parse)
+        .ok_or(JsonRpcError::INVALID_PARAMS)
+}
+
+fn parse_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(number) => number.as_u64(),
+        Value::String(string) => string.parse().ok(),
+        _ => None,
+    }
+}
+
+fn parse_u256(value: &Value) -> Option<U256> {
+    match value {
+        Value::Number(number) => number.as_u64().map(U256::from),
+        Value::String(string) => PU256::from_dec_str(string)
+            .ok()
+            .map(|pu256| U256::from(&pu256)),
+        _ => None,
+    }
+}
+
+fn parse_path(value: &Value) -> Option<[U256; 3]> {
+    match value.as_array()?.as_slice() {
+        [token_in_id, token_via_id, token_out_id] => Some([
+            parse_u256(token_in_id)?,
+            parse_u256(token_via_id)?,
+            parse_u256(token_out_id)?,
+        ]),
+        _ => None,
+    }
+}
+
+fn parse_swap_side(value: &Value) -> Option<SwapSide> {
+    match value.as_str()? {
+        "buy" => Some(SwapSide::Buy),
+        "sell" => Some(SwapSide::Sell),
+        _ => None,
+    }
+}
+
+fn parse_pool_kind(value: &Value) -> Option<PoolKind> {
+    match value.as_str()? {
+        "constant_product" => Some(PoolKind::ConstantProduct),
+        "stable_swap" => Some(PoolKind::StableSwap),
+        _ => None,
+    }
+}
+
+fn parse_address(value: &Value) -> Option<Address> {
+    let address = value.as_str()?;
+
+    if !Address::is_valid(address) {
+        return None;
+    }
+
+    Some(Address::from_bytes(address.as_bytes().try_into().ok()?))
+}
+
+fn parse_signature(value: &Value) -> Option<Signature> {
+    let r = parse_u256(value.get("r")?)?;
+    let s = parse_u256(value.get("s")?)?;
+
+    let mut buf = [0u8; 64];
+    buf[0..32].copy_from_slice(&r.to_bytes());
+    buf[32..64].copy_from_slice(&s.to_bytes());
+
+    Some(Signature::from_bytes(&buf))
+}
+
+fn u256_to_json(value: &U256) -> Value {
+    Value::String(PU256::from(value).to_string())
+}
+
+fn pool_kind_to_json(pool_kind: PoolKind) -> Value {
+    match pool_kind {
+        PoolKind::ConstantProduct => json!("constant_product"),
+        PoolKind::StableSwap => json!("stable_swap"),
+    }
+}
+
+fn stats_to_json(
+    (base_token_volume, quote_token_volume, base_token_fees, quote_token_fees, trade_count): (
+        u64,
+        u64,
+        u64,
+        u64,
+        u64,
+    ),
+) -> Value {
+    json!({
+        "base_token_volume": base_token_volume,
+        "quote_token_volume": quote_token_volume,
+        "base_token_fees": base_token_fees,
+        "quote_token_fees": quote_token_fees,
+        "trade_count": trade_count,
+    })
+}
+
+fn order_status_to_str(order_status: OrderStatus) -> &'static str {
+    match order_status {
+        OrderStatus::Open => "open",
+        OrderStatus::Filled => "filled",
+        OrderStatus::Cancelled => "cancelled",
+    }
+}
+
+fn address_to_json(address: &Address) -> Value {
+    Value::String(String::from_utf8_lossy(&address.to_bytes()).into_owned())
+}
+
+fn signature_to_json(signature: &Signature) -> Value {
+    let buf = signature.to_bytes();
+
+    json!({
+        "r": u256_to_json(&U256::from_bytes(buf[0..32].try_into().unwrap())),
+        "s": u256_to_json(&U256::from_bytes(buf[32..64].try_into().unwrap())),
+    })
+}
+
+fn witness_to_json<const L: usize>(witness: &SingleMerkleWitness<L>) -> Value {
+    json!({
+        "siblings": witness
+            .siblings
+            .iter()
+            .map(|sibling| {
+                json!({
+                    "value": u256_to_json(&sibling.value),
+                    "is_left": sibling.is_left,
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn tx_to_json(tx: &Transaction) -> Value {
+    let kind = tx_kind_to_str(tx.to_bytes()[0]);
+
+    match tx {
+        Transaction::CreateGenesis() => json!({ "kind": kind }),
+        Transaction::DepositTokens(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.user_address),
+            "token_id": u256_to_json(&tx.token_id),
+            "token_amount": tx.token_amount,
+        }),
+        Transaction::BurnTokens(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "token_id": u256_to_json(&tx.token_id),
+            "token_amount": tx.token_amount,
+            "nonce": tx.nonce,
+        }),
+        Transaction::CreatePool(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "base_token_id": u256_to_json(&tx.base_token_id),
+            "quote_token_id": u256_to_json(&tx.quote_token_id),
+            "base_token_amount": tx.base_token_amount,
+            "quote_token_amount": tx.quote_token_amount,
+            "fee_rate": tx.fee_rate,
+            "pool_kind": pool_kind_to_json(tx.pool_kind),
+            "amplification": tx.amplification,
+            "nonce": tx.nonce,
+        }),
+        Transaction::ProvideLiquidity(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "base_token_id": u256_to_json(&tx.base_token_id),
+            "quote_token_id": u256_to_json(&tx.quote_token_id),
+            "base_token_amount": tx.base_token_amount,
+            "quote_token_amount_limit": tx.quote_token_amount_limit,
+            "nonce": tx.nonce,
+        }),
+        Transaction::RemoveLiquidity(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "base_token_id": u256_to_json(&tx.base_token_id),
+            "quote_token_id": u256_to_json(&tx.quote_token_id),
+            "base_token_amount_limit": tx.base_token_amount_limit,
+            "quote_token_amount_limit": tx.quote_token_amount_limit,
+            "points": u256_to_json(&tx.points),
+            "nonce": tx.nonce,
+        }),
+        Transaction::BuyTokens(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "base_token_id": u256_to_json(&tx.base_token_id),
+            "quote_token_id": u256_to_json(&tx.quote_token_id),
+            "base_token_amount": tx.base_token_amount,
+            "quote_token_amount_limit": tx.quote_token_amount_limit,
+            "nonce": tx.nonce,
+        }),
+        Transaction::SellTokens(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "base_token_id": u256_to_json(&tx.base_token_id),
+            "quote_token_id": u256_to_json(&tx.quote_token_id),
+            "base_token_amount_limit": tx.base_token_amount_limit,
+            "quote_token_amount": tx.quote_token_amount,
+            "nonce": tx.nonce,
+        }),
+        Transaction::SwapRoute(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "path": tx.path.iter().map(u256_to_json).collect::<Vec<_>>(),
+            "token_amount_in": tx.token_amount_in,
+            "token_amount_out_limit": tx.token_amount_out_limit,
+            "nonce": tx.nonce,
+        }),
+        Transaction::TransferTokens(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "receiver_address": address_to_json(&tx.receiver_address),
+            "token_id": u256_to_json(&tx.token_id),
+            "token_amount": tx.token_amount,
+            "nonce": tx.nonce,
+        }),
+        Transaction::PlaceOrder(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "token_in_id": u256_to_json(&tx.token_in_id),
+            "token_out_id": u256_to_json(&tx.token_out_id),
+            "token_amount_in": tx.token_amount_in,
+            "token_amount_out_limit": tx.token_amount_out_limit,
+            "nonce": tx.nonce,
+        }),
+        Transaction::CancelOrder(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "order_id": tx.order_id,
+            "nonce": tx.nonce,
+        }),
+        Transaction::FillOrder(tx) => json!({
+            "kind": kind,
+            "order_id": tx.order_id,
+        }),
+        Transaction::ClaimProtocolFees(tx) => json!({
+            "kind": kind,
+            "address": address_to_json(&tx.address),
+            "signature": signature_to_json(&tx.signature),
+            "base_token_id": u256_to_json(&tx.base_token_id),
+            "quote_token_id": u256_to_json(&tx.quote_token_id),
+            "nonce": tx.nonce,
+        }),
+    }
+}
+
+fn tx_kind_to_str(tx_kind: u8) -> &'static str {
+    match tx_kind {
+        0 => "create_genesis",
+        1 => "deposit_tokens",
+        2 => "burn_tokens",
+        3 => "create_pool",
+        4 => "provide_liquidity",
+        5 => "remove_liquidity",
+        6 => "buy_tokens",
+        7 => "sell_tokens",
+        8 => "swap_route",
+        9 => "transfer_tokens",
+        10 => "place_order",
+        11 => "cancel_order",
+        12 => "fill_order",
+        _ => "claim_protocol_fees",
+    }
+}
+
+fn tx_status_to_str(tx_status: TxStatus) -> &'static str {
+    match tx_status {
+        TxStatus::Pending => "pending",
+        TxStatus::Rejected => "rejected",
+        TxStatus::Executed => "executed",
+        TxStatus::Proved => "proved",
+        TxStatus::Settled => "settled",
+    }
+}
+
+fn rejection_reason_to_str(reason: RejectionReason) -> &'static str {
+    match reason {
+        RejectionReason::Internal => "internal",
+        RejectionReason::InvalidSignature => "invalid_signature",
+        RejectionReason::InvalidNonce => "invalid_nonce",
+        RejectionReason::NotEnoughBalance => "not_enough_balance",
+        RejectionReason::NotEnoughLiquidity => "not_enough_liquidity",
+        RejectionReason::NotEnoughInPool => "not_enough_in_pool",
+        RejectionReason::Overflow => "overflow",
+        RejectionReason::LimitExceeded => "limit_exceeded",
+        RejectionReason::BalanceDoesntExist => "balance_doesnt_exist",
+        RejectionReason::PoolDoesntExist => "pool_doesnt_exist",
+        RejectionReason::PoolAlreadyExists => "pool_already_exists",
+        RejectionReason::LiquidityDoesntExist => "liquidity_doesnt_exist",
+        RejectionReason::InvalidAddress => "invalid_address",
+        RejectionReason::ZeroAmount => "zero_amount",
+        RejectionReason::IdenticalTokenIds => "identical_token_ids",
+        RejectionReason::InvalidRoute => "invalid_route",
+        RejectionReason::IdenticalAddresses => "identical_addresses",
+        RejectionReason::InvalidFeeRate => "invalid_fee_rate",
+        RejectionReason::InvalidAmplification => "invalid_amplification",
+        RejectionReason::OrderDoesntExist => "order_doesnt_exist",
+        RejectionReason::OrderIsntOpen => "order_isnt_open",
+        RejectionReason::NotOrderOwner => "not_order_owner",
+        RejectionReason::NotEnoughPriceHistory => "not_enough_price_history",
+        RejectionReason::RateLimited => "rate_limited",
+        RejectionReason::NotTreasury => "not_treasury",
+        RejectionReason::NoProtocolFees => "no_protocol_fees",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::Pool;
+
+    async fn echo_handler(rpc_method: RpcMethod) -> RpcResponse {
+        match rpc_method {
+            RpcMethod::GetTotalTxCount => RpcResponse::TotalTxCount(42),
+            RpcMethod::GetTxStatus { tx_id } => RpcResponse::TxStatus(
+                if tx_id == 0 {
+                    TxStatus::Rejected
+                } else {
+                    TxStatus::Executed
+                },
+                if tx_id == 0 {
+                    Some(RejectionReason::LimitExceeded)
+                } else {
+                    None
+                },
+            ),
+            RpcMethod::GetBalances { address: _ } => {
+                RpcResponse::Balances(vec![(U256::from(7u64), 100)])
+            }
+            RpcMethod::GetTx { tx_id: _ } => RpcResponse::Rejected(RejectionReason::ZeroAmount),
+            RpcMethod::SimulateTx {
+                tx: Transaction::BurnTokens(tx),
+            } => RpcResponse::Simulation(
+                vec![(tx.token_id, 100, 100 - tx.token_amount)],
+                Vec::new(),
+                None,
+            ),
+            RpcMethod::GetQuote {
+                side: SwapSide::Buy,
+                amount,
+                ..
+            } => RpcResponse::Quote(amount * 2 + 1, amount, 1, 2.0, 2.5, 0.25),
+            RpcMethod::GetRoute {
+                token_in_id,
+                token_out_id,
+                token_amount_in,
+                max_hops: 1,
+            } => RpcResponse::Route(vec![token_in_id, token_out_id], token_amount_in),
+            RpcMethod::GetRoute {
+                token_in_id,
+                token_out_id,
+                token_amount_in,
+                ..
+            } => RpcResponse::Route(
+                vec![token_in_id, U256::from(2u64), token_out_id],
+                token_amount_in * 2,
+            ),
+            RpcMethod::CreatePool {
+                fee_rate,
+                pool_kind,
+                amplification,
+                ..
+            } => {
+                if !Pool::FEE_RATES.contains(&fee_rate) {
+                    RpcResponse::Rejected(RejectionReason::InvalidFeeRate)
+                } else if !pool_kind.accepts_amplification(amplification) {
+                    RpcResponse::Rejected(RejectionReason::InvalidAmplification)
+                } else {
+                    RpcResponse::TxId(fee_rate)
+                }
+            }
+            RpcMethod::SwapRoute { path, .. } => RpcResponse::TxId(path.len() as u64),
+            RpcMethod::TransferTokens {
+                address,
+                receiver_address,
+                token_amount,
+                ..
+            } => {
+                if address == receiver_address {
+                    RpcResponse::Rejected(RejectionReason::IdenticalAddresses)
+                } else {
+                    RpcResponse::TxId(token_amount)
+                }
+            }
+            RpcMethod::GetProtocolFees => {
+                RpcResponse::ProtocolFees(vec![(U256::from(1u64), U256::from(2u64), 3, 4)])
+            }
+            RpcMethod::PlaceOrder {
+                token_amount_in, ..
+            } => RpcResponse::TxId(token_amount_in),
+            RpcMethod::CancelOrder { order_id, .. } => {
+                if order_id == 0 {
+                    RpcResponse::Rejected(RejectionReason::OrderIsntOpen)
+                } else {
+                    RpcResponse::TxId(order_id)
+                }
+            }
+            RpcMethod::GetOrders { address: _ } => RpcResponse::Orders(vec![(
+                0,
+                U256::from(1u64),
+                U256::from(2u64),
+                10,
+                20,
+                OrderStatus::Filled,
+            )]),
+            RpcMethod::GetTwap { window, .. } => {
+                if window > 3600 {
+                    RpcResponse::Rejected(RejectionReason::NotEnoughPriceHistory)
+                } else {
+                    RpcResponse::Twap(5 << 63)
+                }
+            }
+            RpcMethod::GetPositions { address: _ } => RpcResponse::Positions(vec![(
+                U256::from(1u64),
+                U256::from(2u64),
+                U256::from(100u64),
+                0.25,
+                30,
+                40,
+                3,
+                4,
+            )]),
+            RpcMethod::GetPoolStats { .. } => {
+                RpcResponse::PoolStats((10, 20, 0, 1, 2), (70, 140, 1, 4, 9))
+            }
+            RpcMethod::GetTopPools => RpcResponse::TopPools(vec![(
+                U256::from(1u64),
+                U256::from(0u64),
+                (10, 20, 0, 1, 2),
+            )]),
+            RpcMethod::ClaimProtocolFees { nonce, .. } => {
+                if nonce == 0 {
+                    RpcResponse::Rejected(RejectionReason::NotTreasury)
+                } else {
+                    RpcResponse::TxId(nonce)
+                }
+            }
+            _ => RpcResponse::ServerError,
+        }
+    }
+
+    #[tokio::test]
+    async fn handles_single_requests() {
+        let body = br#"{"jsonrpc":"2.0","method":"getTotalTxCount","id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(response, json!({"jsonrpc": "2.0", "result": 42, "id": 1}));
+
+        let body = br#"{"jsonrpc":"2.0","method":"getTxStatus","params":{"tx_id":0},"id":"a"}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response,
+            json!({
+                "jsonrpc": "2.0",
+                "result": {"status": "rejected", "rejection_reason": "limit_exceeded"},
+                "id": "a",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn handles_batch_requests() {
+        let body = br#"[
+            {"jsonrpc":"2.0","method":"getTxStatus","params":{"tx_id":"5"},"id":1},
+            {"jsonrpc":"2.0","method":"getTotalTxCount"},
+            {"jsonrpc":"2.0","method":"getBalances","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb"},"id":2}
+        ]"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response,
+            json!([
+                {
+                    "jsonrpc": "2.0",
+                    "result": {"status": "executed", "rejection_reason": null},
+                    "id": 1,
+                },
+                {
+                    "jsonrpc": "2.0",
+                    "result": [{"token_id": "7", "token_amount": 100}],
+                    "id": 2,
+                },
+            ])
+        );
+
+        let body = br#"[{"jsonrpc":"2.0","method":"getTotalTxCount"}]"#;
+
+        let response = handle_json_rpc(body, echo_handler).await;
+
+        assert_eq!(response, None);
+    }
+
+    #[tokio::test]
+    async fn simulates_unsigned_transactions() {
+        let body = br#"{"jsonrpc":"2.0","method":"simulateTx","params":{"kind":"burn_tokens","address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","token_id":"7","token_amount":30},"id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response,
+            json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "balance_changes": [{"token_id": "7", "before": 100, "after": 70}],
+                    "pools": [],
+                    "liquidity_points": null,
+                },
+                "id": 1,
+            })
+        );
+
+        let body =
+            br#"{"jsonrpc":"2.0","method":"simulateTx","params":{"kind":"deposit_tokens"},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32602);
+    }
+
+    #[tokio::test]
+    async fn quotes_swaps() {
+        let body = br#"{"jsonrpc":"2.0","method":"getQuote","params":{"base_token_id":"1","quote_token_id":"0","side":"buy","amount":"10"},"id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response["result"],
+            json!({
+                "amount_in": 21,
+                "amount_out": 10,
+                "fee": 1,
+                "spot_price": 2.0,
+                "effective_price": 2.5,
+                "price_impact": 0.25,
+            })
+        );
+
+        let body = br#"{"jsonrpc":"2.0","method":"getQuote","params":{"base_token_id":"1","quote_token_id":"0","side":"long","amount":"10"},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32602);
+    }
+
+    #[tokio::test]
+    async fn finds_and_swaps_routes() {
+        let body = br#"{"jsonrpc":"2.0","method":"getRoute","params":{"token_in_id":"1","token_out_id":"3","token_amount_in":"10"},"id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response["result"],
+            json!({
+                "path": ["1", "2", "3"],
+                "amount_out": 20,
+            })
+        );
+
+        let body = br#"{"jsonrpc":"2.0","method":"getRoute","params":{"token_in_id":"1","token_out_id":"3","token_amount_in":"10","max_hops":1},"id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response["result"],
+            json!({
+                "path": ["1", "3"],
+                "amount_out": 10,
+            })
+        );
+
+        let body = br#"{"jsonrpc":"2.0","method":"getRoute","params":{"token_in_id":"1","token_out_id":"3","token_amount_in":"10","max_hops":"two"},"id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(response["error"]["code"], -32602);
+
+        let body = br#"{"jsonrpc":"2.0","method":"swapRoute","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"path":["1","2","3"],"token_amount_in":10,"token_amount_out_limit":19,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["result"], 3);
+
+        let body = br#"{"jsonrpc":"2.0","method":"swapRoute","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"path":["1","3"],"token_amount_in":10,"token_amount_out_limit":19,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32602);
+    }
+
+    #[tokio::test]
+    async fn transfers_tokens() {
+        let body = br#"{"jsonrpc":"2.0","method":"transferTokens","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"receiver_address":"B62qkGSBuLmqYApYoWTmAzUtwFVx6Fe9ZStJVPzCwLjWZ5NQDYTiqEU","token_id":"1","token_amount":10,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["result"], 10);
+
+        let body = br#"{"jsonrpc":"2.0","method":"transferTokens","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"receiver_address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","token_id":"1","token_amount":10,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32003);
+        assert_eq!(response["error"]["data"], "identical_addresses");
+    }
+
+    #[tokio::test]
+    async fn creates_pools_with_fee_rates() {
+        let body = br#"{"jsonrpc":"2.0","method":"createPool","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"base_token_id":"1","quote_token_id":"2","base_token_amount":1000,"quote_token_amount":2000,"fee_rate":30,"pool_kind":"constant_product","amplification":0,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["result"], 30);
+
+        let body = br#"{"jsonrpc":"2.0","method":"createPool","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"base_token_id":"1","quote_token_id":"2","base_token_amount":1000,"quote_token_amount":2000,"fee_rate":25,"pool_kind":"constant_product","amplification":0,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32003);
+        assert_eq!(response["error"]["data"], "invalid_fee_rate");
+    }
+
+    #[tokio::test]
+    async fn creates_stable_swap_pools_with_amplifications() {
+        let body = br#"{"jsonrpc":"2.0","method":"createPool","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"base_token_id":"1","quote_token_id":"2","base_token_amount":1000,"quote_token_amount":1000,"fee_rate":5,"pool_kind":"stable_swap","amplification":100,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["result"], 5);
+
+        let body = br#"{"jsonrpc":"2.0","method":"createPool","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"base_token_id":"1","quote_token_id":"2","base_token_amount":1000,"quote_token_amount":1000,"fee_rate":5,"pool_kind":"stable_swap","amplification":0,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32003);
+        assert_eq!(response["error"]["data"], "invalid_amplification");
+    }
+
+    #[tokio::test]
+    async fn reports_protocol_fees() {
+        let body = br#"{"jsonrpc":"2.0","method":"getProtocolFees","id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response["result"],
+            json!([{
+                "base_token_id": "1",
+                "quote_token_id": "2",
+                "base_token_amount": 3,
+                "quote_token_amount": 4,
+            }])
+        );
+
+        let body = br#"{"jsonrpc":"2.0","method":"claimProtocolFees","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"base_token_id":"1","quote_token_id":"2","nonce":3},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["result"], 3);
+
+        let body = br#"{"jsonrpc":"2.0","method":"claimProtocolFees","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"base_token_id":"1","quote_token_id":"2","nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32003);
+        assert_eq!(response["error"]["data"], "not_treasury");
+    }
+
+    #[tokio::test]
+    async fn reports_twaps() {
+        let body = br#"{"jsonrpc":"2.0","method":"getTwap","params":{"base_token_id":"1","quote_token_id":"0","window":600},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(
+            response["result"],
+            json!({ "price": "46116860184273879040" })
+        );
+
+        let body = br#"{"jsonrpc":"2.0","method":"getTwap","params":{"base_token_id":"1","quote_token_id":"0","window":7200},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32003);
+        assert_eq!(response["error"]["data"], "not_enough_price_history");
+    }
+
+    #[tokio::test]
+    async fn reports_positions() {
+        let body = br#"{"jsonrpc":"2.0","method":"getPositions","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb"},"id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response["result"],
+            json!([{
+                "base_token_id": "1",
+                "quote_token_id": "2",
+                "points": "100",
+                "share": 0.25,
+                "base_token_amount": 30,
+                "quote_token_amount": 40,
+                "fee_base_token_amount": 3,
+                "fee_quote_token_amount": 4,
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_pool_stats() {
+        let body = br#"{"jsonrpc":"2.0","method":"getPoolStats","params":{"base_token_id":"1","quote_token_id":"0"},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["result"]["day"]["quote_token_volume"], 20);
+        assert_eq!(response["result"]["week"]["trade_count"], 9);
+
+        let body = br#"{"jsonrpc":"2.0","method":"getTopPools","id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(
+            response["result"],
+            json!([{
+                "base_token_id": "1",
+                "quote_token_id": "0",
+                "day": {
+                    "base_token_volume": 10,
+                    "quote_token_volume": 20,
+                    "base_token_fees": 0,
+                    "quote_token_fees": 1,
+                    "trade_count": 2,
+                },
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn places_cancels_and_reports_orders() {
+        let body = br#"{"jsonrpc":"2.0","method":"placeOrder","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"token_in_id":"1","token_out_id":"2","token_amount_in":10,"token_amount_out_limit":20,"nonce":0},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["result"], 10);
+
+        let body = br#"{"jsonrpc":"2.0","method":"cancelOrder","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb","signature":{"r":"1","s":"2"},"order_id":0,"nonce":1},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32003);
+        assert_eq!(response["error"]["data"], "order_isnt_open");
+
+        let body = br#"{"jsonrpc":"2.0","method":"getOrders","params":{"address":"B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb"},"id":1}"#;
+
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+
+        assert_eq!(
+            response["result"],
+            json!([{
+                "order_id": 0,
+                "token_in_id": "1",
+                "token_out_id": "2",
+                "token_amount_in": 10,
+                "token_amount_out_limit": 20,
+                "status": "filled",
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn responds_with_error_objects() {
+        let response = handle_json_rpc(b"{", echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32700);
+        assert_eq!(response["id"], Value::Null);
+
+        let response = handle_json_rpc(b"[]", echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32600);
+
+        let body = br#"{"jsonrpc":"1.0","method":"getTotalTxCount","id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32600);
+        assert_eq!(response["id"], 1);
+
+        let body = br#"{"jsonrpc":"2.0","method":"getEverything","id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32601);
+
+        let body = br#"{"jsonrpc":"2.0","method":"getBalances","params":{"address":"0x"},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32602);
+
+        let body = br#"{"jsonrpc":"2.0","method":"getPools","id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32000);
+        assert_eq!(response["error"].get("data"), None);
+
+        let body = br#"{"jsonrpc":"2.0","method":"getTx","params":{"tx_id":3},"id":1}"#;
+        let response = handle_json_rpc(body, echo_handler).await.unwrap();
+        assert_eq!(response["error"]["code"], -32003);
+        assert_eq!(response["error"]["data"], "zero_amount");
+    }
+}
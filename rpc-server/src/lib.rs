@@ -1,7 +1,13 @@
+mod json_rpc;
+mod rate_limiter;
 mod rpc_method;
 mod rpc_response;
+mod rpc_server_config;
 mod start_rpc_server;
 
+pub use json_rpc::JSON_RPC_PATH;
+pub use rate_limiter::SignerRateLimiter;
 pub use rpc_method::RpcMethod;
 pub use rpc_response::RpcResponse;
+pub use rpc_server_config::RpcServerConfig;
 pub use start_rpc_server::start_rpc_server;
@@ -0,0 +1,384 @@
+use crate::{
+    rpc_server_config::{parse_env, DEFAULT_WRITE_RATE_LIMIT},
+    RpcMethod,
+};
+use nacho_data_structures::Address;
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    io::Error,
+    net::{IpAddr, Ipv6Addr},
+    sync::Mutex,
+    time::Instant,
+};
+
+/// The maximum number of buckets a limiter keeps, the least recently used one is dropped to make room for a new one.
+const MAX_BUCKETS: usize = 100_000;
+
+/// Returns the key of the buckets of the given client IP.
+///
+/// IPv6 clients are keyed by their /64 prefix, as a single client usually holds a whole /64 and could rotate through its addresses.
+/// IPv4 mapped IPv6 addresses are keyed by their IPv4 address.
+fn client_key(ip_address: IpAddr) -> IpAddr {
+    match ip_address {
+        IpAddr::V4(_) => ip_address,
+        IpAddr::V6(ipv6_address) => match ipv6_address.to_ipv4_mapped() {
+            Some(ipv4_address) => IpAddr::V4(ipv4_address),
+            None => {
+                let prefix = u128::from(ipv6_address) & !(u64::MAX as u128);
+                IpAddr::V6(Ipv6Addr::from(prefix))
+            }
+        },
+    }
+}
+
+/// The struct that represents a token bucket.
+///
+/// It holds up to `capacity` tokens and refills them evenly over a minute.
+#[derive(Clone, Copy, Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(capacity: u32, now: Instant) -> Bucket {
+        Bucket {
+            tokens: capacity as f64,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, capacity: u32, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let refilled = elapsed.as_secs_f64() * capacity as f64 / 60.0;
+
+        self.tokens = (self.tokens + refilled).min(capacity as f64);
+        self.last_refill = now;
+    }
+}
+
+/// The struct that represents the buckets of a limiter in the order they are used.
+///
+/// Each bucket is stored with the stamp of its last use, and `recency` maps the stamps back to the keys.
+#[derive(Debug)]
+struct Buckets<K> {
+    buckets: HashMap<K, (Bucket, u64)>,
+    recency: BTreeMap<u64, K>,
+    next_stamp: u64,
+}
+
+/// The struct that represents a set of token buckets with the same capacity keyed by `K`.
+#[derive(Debug)]
+struct Limiter<K> {
+    capacity: u32,
+    max_buckets: usize,
+    buckets: Mutex<Buckets<K>>,
+}
+
+impl<K: Eq + Hash + Clone> Limiter<K> {
+    fn new(capacity: u32) -> Limiter<K> {
+        Limiter::with_max_buckets(capacity, MAX_BUCKETS)
+    }
+
+    fn with_max_buckets(capacity: u32, max_buckets: usize) -> Limiter<K> {
+        Limiter {
+            capacity,
+            max_buckets,
+            buckets: Mutex::new(Buckets {
+                buckets: HashMap::new(),
+                recency: BTreeMap::new(),
+                next_stamp: 0,
+            }),
+        }
+    }
+
+    /// Takes a token from the bucket of the given key and returns `false` if the bucket is empty.
+    fn try_acquire(&self, key: K, now: Instant) -> bool {
+        self.update(key, now, |bucket| {
+            if bucket.tokens < 1.0 {
+                return false;
+            }
+
+            bucket.tokens -= 1.0;
+            true
+        })
+    }
+
+    /// Gives back a token taken from the bucket of the given key.
+    fn release(&self, key: K, now: Instant) {
+        let capacity = self.capacity as f64;
+
+        self.update(key, now, |bucket| {
+            bucket.tokens = (bucket.tokens + 1.0).min(capacity);
+            true
+        });
+    }
+
+    /// Refills the bucket of the given key, marks it as the most recently used one and runs the given function on it.
+    ///
+    /// The bucket is created if it doesn't exist, and the least recently used bucket is dropped if there are already `max_buckets` buckets.
+    fn update(&self, key: K, now: Instant, f: impl FnOnce(&mut Bucket) -> bool) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        let Buckets {
+            buckets,
+            recency,
+            next_stamp,
+        } = &mut *buckets;
+
+        let stamp = *next_stamp;
+        *next_stamp += 1;
+
+        match buckets.get(&key) {
+            Some((_, last_stamp)) => {
+                recency.remove(last_stamp);
+            }
+            None if buckets.len() >= self.max_buckets => {
+                if let Some((_, least_recent_key)) = recency.pop_first() {
+                    buckets.remove(&least_recent_key);
+                }
+            }
+            None => (),
+        }
+
+        let (bucket, last_stamp) = buckets
+            .entry(key.clone())
+            .or_insert((Bucket::new(self.capacity, now), stamp));
+        *last_stamp = stamp;
+
+        recency.insert(stamp, key);
+
+        bucket.refill(self.capacity, now);
+
+        f(bucket)
+    }
+}
+
+/// The struct that represents the rate limiter of an RPC server.
+///
+/// Read and state changing requests are limited per client IP with separate quotas, IPv6 clients share the quotas of their /64.
+/// State changing requests are also limited per signer address by `SignerRateLimiter`, after their signatures are verified.
+///
+/// It is created inside `start_rpc_server` under the hood.
+pub(crate) struct RateLimiter {
+    reads_per_ip: Limiter<IpAddr>,
+    writes_per_ip: Limiter<IpAddr>,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter with the given per minute quotas. A quota of `0` disables the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let rate_limiter = RateLimiter::new(600, 60);
+    /// ```
+    ///
+    pub(crate) fn new(read_rate_limit: u32, write_rate_limit: u32) -> RateLimiter {
+        RateLimiter {
+            reads_per_ip: Limiter::new(read_rate_limit),
+            writes_per_ip: Limiter::new(write_rate_limit),
+        }
+    }
+
+    /// Returns `true` if the given client IP is allowed to make the given RPC method request.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let is_allowed = rate_limiter.check(ip_address, &rpc_method);
+    /// ```
+    ///
+    pub(crate) fn check(&self, ip_address: IpAddr, rpc_method: &RpcMethod) -> bool {
+        self.check_at(ip_address, rpc_method.signer().is_some(), Instant::now())
+    }
+
+    /// Gives back the token of a state changing request of the given client IP, which is used when its signer is rate limited.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// rate_limiter.refund_write(ip_address);
+    /// ```
+    ///
+    pub(crate) fn refund_write(&self, ip_address: IpAddr) {
+        self.writes_per_ip
+            .release(client_key(ip_address), Instant::now())
+    }
+
+    fn check_at(&self, ip_address: IpAddr, is_write: bool, now: Instant) -> bool {
+        let ip_address = client_key(ip_address);
+
+        match is_write {
+            false => self.reads_per_ip.try_acquire(ip_address, now),
+            true => self.writes_per_ip.try_acquire(ip_address, now),
+        }
+    }
+}
+
+/// The struct that represents the rate limiter of the state changing requests per signer address.
+///
+/// It must only be checked after a transaction's signature is verified, so a client can't use up the quota of another user.
+/// A handler rejects the transactions of the rate limited signers with `RejectionReason::RateLimited`,
+/// which the RPC server responds with as `RpcResponse::RateLimited`.
+///
+/// # Examples
+///
+/// ```rs
+/// let signer_rate_limiter = SignerRateLimiter::from_env()?;
+///
+/// let is_allowed = signer_rate_limiter.check(&address);
+/// ```
+///
+#[derive(Debug)]
+pub struct SignerRateLimiter {
+    writes_per_address: Limiter<Address>,
+}
+
+impl SignerRateLimiter {
+    /// Creates a new signer rate limiter with the given per minute quota. A quota of `0` disables the limit.
+    pub fn new(write_rate_limit: u32) -> SignerRateLimiter {
+        SignerRateLimiter {
+            writes_per_address: Limiter::new(write_rate_limit),
+        }
+    }
+
+    /// Creates a new signer rate limiter with the quota in `NACHO_RPC_SERVER_WRITE_RATE_LIMIT`, like `RpcServerConfig::from_env`.
+    pub fn from_env() -> Result<SignerRateLimiter, Error> {
+        let write_rate_limit =
+            parse_env("NACHO_RPC_SERVER_WRITE_RATE_LIMIT")?.unwrap_or(DEFAULT_WRITE_RATE_LIMIT);
+
+        Ok(SignerRateLimiter::new(write_rate_limit))
+    }
+
+    /// Returns `true` if the given signer is allowed to make another state changing request.
+    pub fn check(&self, signer: &Address) -> bool {
+        self.writes_per_address
+            .try_acquire(signer.clone(), Instant::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::ByteConversion;
+    use std::{
+        net::{Ipv4Addr, Ipv6Addr},
+        time::Duration,
+    };
+
+    #[test]
+    fn limits_requests_correctly() {
+        let rate_limiter = RateLimiter::new(3, 2);
+
+        let ip_0 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let ip_1 = IpAddr::V6(Ipv6Addr::LOCALHOST);
+
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(rate_limiter.check_at(ip_0, false, now));
+        }
+        assert!(!rate_limiter.check_at(ip_0, false, now));
+        assert!(rate_limiter.check_at(ip_1, false, now));
+
+        assert!(rate_limiter.check_at(ip_0, true, now));
+        assert!(rate_limiter.check_at(ip_0, true, now));
+        assert!(!rate_limiter.check_at(ip_0, true, now));
+        assert!(rate_limiter.check_at(ip_1, true, now));
+
+        rate_limiter.writes_per_ip.release(ip_0, now);
+
+        assert!(rate_limiter.check_at(ip_0, true, now));
+        assert!(!rate_limiter.check_at(ip_0, true, now));
+
+        let later = now + Duration::from_secs(20);
+
+        assert!(rate_limiter.check_at(ip_0, false, later));
+        assert!(!rate_limiter.check_at(ip_0, false, later));
+
+        let much_later = now + Duration::from_secs(60);
+
+        assert!(rate_limiter.check_at(ip_0, true, much_later));
+        assert!(rate_limiter.check_at(ip_0, true, much_later));
+        assert!(!rate_limiter.check_at(ip_0, true, much_later));
+    }
+
+    #[test]
+    fn limits_ipv6_clients_by_prefix() {
+        let rate_limiter = RateLimiter::new(2, 0);
+
+        let ip_0 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 1));
+        let ip_1 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0xffff, 0, 0, 2));
+        let ip_2 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 2, 0, 0, 0, 1));
+        let ip_3 = IpAddr::V6(Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped());
+        let ip_4 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+
+        let now = Instant::now();
+
+        assert!(rate_limiter.check_at(ip_0, false, now));
+        assert!(rate_limiter.check_at(ip_1, false, now));
+        assert!(!rate_limiter.check_at(ip_0, false, now));
+        assert!(!rate_limiter.check_at(ip_1, false, now));
+        assert!(rate_limiter.check_at(ip_2, false, now));
+
+        assert!(rate_limiter.check_at(ip_3, false, now));
+        assert!(rate_limiter.check_at(ip_4, false, now));
+        assert!(!rate_limiter.check_at(ip_3, false, now));
+    }
+
+    #[test]
+    fn limits_signers_correctly() {
+        let signer_rate_limiter = SignerRateLimiter::new(2);
+
+        let address_0 = Address::from_bytes(&[1u8; 55]);
+        let address_1 = Address::from_bytes(&[2u8; 55]);
+
+        assert!(signer_rate_limiter.check(&address_0));
+        assert!(signer_rate_limiter.check(&address_0));
+        assert!(!signer_rate_limiter.check(&address_0));
+        assert!(signer_rate_limiter.check(&address_1));
+    }
+
+    #[test]
+    fn drops_least_recently_used_buckets() {
+        let limiter = Limiter::<u8>::with_max_buckets(1, 2);
+
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire(0, now));
+        assert!(limiter.try_acquire(1, now));
+        assert!(!limiter.try_acquire(0, now));
+
+        // The bucket of `1` is the least recently used one, so it is dropped for `2`.
+        assert!(limiter.try_acquire(2, now));
+        assert!(!limiter.try_acquire(0, now));
+        assert!(limiter.try_acquire(1, now));
+
+        let buckets = limiter.buckets.lock().unwrap();
+
+        assert_eq!(buckets.buckets.len(), 2);
+        assert_eq!(buckets.recency.len(), 2);
+    }
+
+    #[test]
+    fn does_not_limit_disabled_quotas() {
+        let rate_limiter = RateLimiter::new(0, 0);
+        let signer_rate_limiter = SignerRateLimiter::new(0);
+
+        let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let address = Address::from_bytes(&[1u8; 55]);
+
+        let now = Instant::now();
+
+        for _ in 0..1000 {
+            assert!(rate_limiter.check_at(ip, false, now));
+            assert!(rate_limiter.check_at(ip, true, now));
+            assert!(signer_rate_limiter.check(&address));
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use nacho_data_structures::{Address, ByteConversion, Signature, U256};
+use nacho_data_structures::{
+    Address, BurnTokensTransaction, BuyTokensTransaction, ByteConversion, CancelOrderTransaction,
+    ClaimProtocolFeesTransaction, CreatePoolTransaction, PlaceOrderTransaction, PoolKind,
+    ProvideLiquidityTransaction, RemoveLiquidityTransaction, SellTokensTransaction, Signature,
+    SwapRouteTransaction, SwapSide, Transaction, TransferTokensTransaction, U256,
+};
 
 /// The enum that represents RPC methods.
 ///
@@ -17,6 +22,19 @@ pub enum RpcMethod {
     /// Requests a transaction's status.
     GetTxStatus { tx_id: u64 },
 
+    /// Requests a transaction's full payload.
+    GetTx { tx_id: u64 },
+
+    /// Requests a page of a user's transactions starting from the given transaction ID, pages have at most 100 transactions.
+    GetTxHistory {
+        address: Address,
+        from_tx_id: u64,
+        limit: u64,
+    },
+
+    /// Requests the next nonce a user has to sign a transaction with.
+    GetNonce { address: Address },
+
     /// Requests all the balances of a user.
     GetBalances { address: Address },
 
@@ -38,6 +56,7 @@ pub enum RpcMethod {
         signature: Signature,
         token_id: U256,
         token_amount: u64,
+        nonce: u64,
     },
 
     /// Requests to create an AMM pool.
@@ -48,6 +67,10 @@ pub enum RpcMethod {
         quote_token_id: U256,
         base_token_amount: u64,
         quote_token_amount: u64,
+        fee_rate: u64,
+        pool_kind: PoolKind,
+        amplification: u64,
+        nonce: u64,
     },
 
     /// Requests to provide liquidity for an AMM pool.
@@ -58,6 +81,7 @@ pub enum RpcMethod {
         quote_token_id: U256,
         base_token_amount: u64,
         quote_token_amount_limit: u64,
+        nonce: u64,
     },
 
     /// Requests to remove liquidity from an AMM pool.
@@ -69,6 +93,7 @@ pub enum RpcMethod {
         base_token_amount_limit: u64,
         quote_token_amount_limit: u64,
         points: U256,
+        nonce: u64,
     },
 
     /// Requests to buy the base token of an AMM pool.
@@ -79,6 +104,7 @@ pub enum RpcMethod {
         quote_token_id: U256,
         base_token_amount: u64,
         quote_token_amount_limit: u64,
+        nonce: u64,
     },
 
     /// Requests to sell the base token of an AMM pool.
@@ -89,12 +115,337 @@ pub enum RpcMethod {
         quote_token_id: U256,
         base_token_amount_limit: u64,
         quote_token_amount: u64,
+        nonce: u64,
+    },
+
+    /// Requests to execute a transaction against the current state without persisting anything.
+    ///
+    /// The transaction is sent as the first 239 bytes of `Transaction::to_bytes`, its signature and nonce aren't checked.
+    SimulateTx { tx: Transaction },
+
+    /// Requests the outcome of a swap in an AMM pool. The amount is always in the base token.
+    GetQuote {
+        base_token_id: U256,
+        quote_token_id: U256,
+        side: SwapSide,
+        amount: u64,
+    },
+
+    /// Requests to swap an exact amount of tokens through the AMM pools between the token IDs of the path.
+    SwapRoute {
+        address: Address,
+        signature: Signature,
+        path: [U256; 3],
+        token_amount_in: u64,
+        token_amount_out_limit: u64,
+        nonce: u64,
+    },
+
+    /// Requests the path through the AMM pools that gives the most tokens for the given amount.
+    ///
+    /// The path goes through at most `max_hops` pools, which is capped at the hops a swap route can make, and `0` means the cap.
+    GetRoute {
+        token_in_id: U256,
+        token_out_id: U256,
+        token_amount_in: u64,
+        max_hops: u64,
+    },
+
+    /// Requests to move tokens from the user's balance to the balance of another user.
+    TransferTokens {
+        address: Address,
+        signature: Signature,
+        receiver_address: Address,
+        token_id: U256,
+        token_amount: u64,
+        nonce: u64,
+    },
+
+    /// Requests the protocol fees accrued in all the AMM pools.
+    GetProtocolFees,
+
+    /// Requests to place a limit order that sells an exact amount of tokens once an AMM pool's price gives at least the limit amount.
+    PlaceOrder {
+        address: Address,
+        signature: Signature,
+        token_in_id: U256,
+        token_out_id: U256,
+        token_amount_in: u64,
+        token_amount_out_limit: u64,
+        nonce: u64,
+    },
+
+    /// Requests to cancel an open limit order and refund its escrowed tokens.
+    CancelOrder {
+        address: Address,
+        signature: Signature,
+        order_id: u64,
+        nonce: u64,
+    },
+
+    /// Requests all the limit orders of a user.
+    GetOrders { address: Address },
+
+    /// Requests the time-weighted average price of an AMM pool over the last `window` seconds.
+    GetTwap {
+        base_token_id: U256,
+        quote_token_id: U256,
+        window: u64,
+    },
+
+    /// Requests the AMM liquidities of a user with their share of the pools and the token amounts they are worth.
+    GetPositions { address: Address },
+
+    /// Requests the volumes, fees and trade counts of an AMM pool over the last 24 hours and the last 7 days.
+    GetPoolStats {
+        base_token_id: U256,
+        quote_token_id: U256,
+    },
+
+    /// Requests the AMM pools with the most swaps over the last 24 hours.
+    GetTopPools,
+
+    /// Requests to move the protocol fees accrued in an AMM pool to the treasury's balances, it is only accepted from the treasury.
+    ClaimProtocolFees {
+        address: Address,
+        signature: Signature,
+        base_token_id: U256,
+        quote_token_id: U256,
+        nonce: u64,
     },
 }
 
 impl RpcMethod {
     /// The size of an RPC method over the network.
-    pub const SIZE_IN_BYTES: usize = 232;
+    pub const SIZE_IN_BYTES: usize = 240;
+
+    /// Returns `true` if the RPC method modifies the state.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let is_state_changing = rpc_method.is_state_changing();
+    /// ```
+    ///
+    pub fn is_state_changing(&self) -> bool {
+        self.signer().is_some()
+    }
+
+    /// Returns the address of the user that signs the RPC method, if it modifies the state.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let maybe_signer = rpc_method.signer();
+    /// ```
+    ///
+    pub fn signer(&self) -> Option<&Address> {
+        match self {
+            RpcMethod::BurnTokens { address, .. }
+            | RpcMethod::CreatePool { address, .. }
+            | RpcMethod::ProvideLiquidity { address, .. }
+            | RpcMethod::RemoveLiquidity { address, .. }
+            | RpcMethod::BuyTokens { address, .. }
+            | RpcMethod::SellTokens { address, .. }
+            | RpcMethod::SwapRoute { address, .. }
+            | RpcMethod::TransferTokens { address, .. }
+            | RpcMethod::PlaceOrder { address, .. }
+            | RpcMethod::CancelOrder { address, .. }
+            | RpcMethod::ClaimProtocolFees { address, .. } => Some(address),
+            _ => None,
+        }
+    }
+
+    /// Returns the transaction that the RPC method represents, if it modifies the state.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let maybe_tx = rpc_method.into_transaction();
+    /// ```
+    ///
+    pub fn into_transaction(self) -> Option<Transaction> {
+        Some(match self {
+            RpcMethod::BurnTokens {
+                address,
+                signature,
+                token_id,
+                token_amount,
+                nonce,
+            } => Transaction::BurnTokens(BurnTokensTransaction {
+                address,
+                signature,
+                token_id,
+                token_amount,
+                nonce,
+            }),
+            RpcMethod::CreatePool {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount,
+                quote_token_amount,
+                fee_rate,
+                pool_kind,
+                amplification,
+                nonce,
+            } => Transaction::CreatePool(CreatePoolTransaction {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount,
+                quote_token_amount,
+                fee_rate,
+                pool_kind,
+                amplification,
+                nonce,
+            }),
+            RpcMethod::ProvideLiquidity {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount,
+                quote_token_amount_limit,
+                nonce,
+            } => Transaction::ProvideLiquidity(ProvideLiquidityTransaction {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount,
+                quote_token_amount_limit,
+                nonce,
+            }),
+            RpcMethod::RemoveLiquidity {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount_limit,
+                quote_token_amount_limit,
+                points,
+                nonce,
+            } => Transaction::RemoveLiquidity(RemoveLiquidityTransaction {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount_limit,
+                quote_token_amount_limit,
+                points,
+                nonce,
+            }),
+            RpcMethod::BuyTokens {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount,
+                quote_token_amount_limit,
+                nonce,
+            } => Transaction::BuyTokens(BuyTokensTransaction {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount,
+                quote_token_amount_limit,
+                nonce,
+            }),
+            RpcMethod::SellTokens {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount_limit,
+                quote_token_amount,
+                nonce,
+            } => Transaction::SellTokens(SellTokensTransaction {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                base_token_amount_limit,
+                quote_token_amount,
+                nonce,
+            }),
+            RpcMethod::SwapRoute {
+                address,
+                signature,
+                path,
+                token_amount_in,
+                token_amount_out_limit,
+                nonce,
+            } => Transaction::SwapRoute(SwapRouteTransaction {
+                address,
+                signature,
+                path,
+                token_amount_in,
+                token_amount_out_limit,
+                nonce,
+            }),
+            RpcMethod::TransferTokens {
+                address,
+                signature,
+                receiver_address,
+                token_id,
+                token_amount,
+                nonce,
+            } => Transaction::TransferTokens(TransferTokensTransaction {
+                address,
+                signature,
+                receiver_address,
+                token_id,
+                token_amount,
+                nonce,
+            }),
+            RpcMethod::PlaceOrder {
+                address,
+                signature,
+                token_in_id,
+                token_out_id,
+                token_amount_in,
+                token_amount_out_limit,
+                nonce,
+            } => Transaction::PlaceOrder(PlaceOrderTransaction {
+                address,
+                signature,
+                token_in_id,
+                token_out_id,
+                token_amount_in,
+                token_amount_out_limit,
+                nonce,
+            }),
+            RpcMethod::CancelOrder {
+                address,
+                signature,
+                order_id,
+                nonce,
+            } => Transaction::CancelOrder(CancelOrderTransaction {
+                address,
+                signature,
+                order_id,
+                nonce,
+            }),
+            RpcMethod::ClaimProtocolFees {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                nonce,
+            } => Transaction::ClaimProtocolFees(ClaimProtocolFeesTransaction {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                nonce,
+            }),
+            _ => return None,
+        })
+    }
 }
 
 impl ByteConversion<{ RpcMethod::SIZE_IN_BYTES }> for RpcMethod {
@@ -134,6 +485,7 @@ impl ByteConversion<{ RpcMethod::SIZE_IN_BYTES }> for RpcMethod {
                 signature: Signature::from_bytes(bytes[56..120].try_into().unwrap()),
                 token_id: U256::from_bytes(bytes[120..152].try_into().unwrap()),
                 token_amount: u64::from_bytes(bytes[152..160].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[160..168].try_into().unwrap()),
             },
 
             9 => RpcMethod::CreatePool {
@@ -143,6 +495,10 @@ impl ByteConversion<{ RpcMethod::SIZE_IN_BYTES }> for RpcMethod {
                 quote_token_id: U256::from_bytes(bytes[152..184].try_into().unwrap()),
                 base_token_amount: u64::from_bytes(bytes[184..192].try_into().unwrap()),
                 quote_token_amount: u64::from_bytes(bytes[192..200].try_into().unwrap()),
+                fee_rate: u64::from_bytes(bytes[200..208].try_into().unwrap()),
+                pool_kind: PoolKind::from_bytes(bytes[208..209].try_into().unwrap()),
+                amplification: u64::from_bytes(bytes[209..217].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[217..225].try_into().unwrap()),
             },
 
             10 => RpcMethod::ProvideLiquidity {
@@ -152,6 +508,7 @@ impl ByteConversion<{ RpcMethod::SIZE_IN_BYTES }> for RpcMethod {
                 quote_token_id: U256::from_bytes(bytes[152..184].try_into().unwrap()),
                 base_token_amount: u64::from_bytes(bytes[184..192].try_into().unwrap()),
                 quote_token_amount_limit: u64::from_bytes(bytes[192..200].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[200..208].try_into().unwrap()),
             },
 
             11 => RpcMethod::RemoveLiquidity {
@@ -162,6 +519,7 @@ impl ByteConversion<{ RpcMethod::SIZE_IN_BYTES }> for RpcMethod {
                 base_token_amount_limit: u64::from_bytes(bytes[184..192].try_into().unwrap()),
                 quote_token_amount_limit: u64::from_bytes(bytes[192..200].try_into().unwrap()),
                 points: U256::from_bytes(bytes[200..232].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[232..240].try_into().unwrap()),
             },
 
             12 => RpcMethod::BuyTokens {
@@ -171,6 +529,7 @@ impl ByteConversion<{ RpcMethod::SIZE_IN_BYTES }> for RpcMethod {
                 quote_token_id: U256::from_bytes(bytes[152..184].try_into().unwrap()),
                 base_token_amount: u64::from_bytes(bytes[184..192].try_into().unwrap()),
                 quote_token_amount_limit: u64::from_bytes(bytes[192..200].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[200..208].try_into().unwrap()),
             },
 
             13 => RpcMethod::SellTokens {
@@ -180,6 +539,114 @@ impl ByteConversion<{ RpcMethod::SIZE_IN_BYTES }> for RpcMethod {
                 quote_token_id: U256::from_bytes(bytes[152..184].try_into().unwrap()),
                 base_token_amount_limit: u64::from_bytes(bytes[184..192].try_into().unwrap()),
                 quote_token_amount: u64::from_bytes(bytes[192..200].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[200..208].try_into().unwrap()),
+            },
+
+            14 => RpcMethod::GetTx {
+                tx_id: u64::from_bytes(bytes[1..9].try_into().unwrap()),
+            },
+
+            15 => RpcMethod::GetTxHistory {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+                from_tx_id: u64::from_bytes(bytes[56..64].try_into().unwrap()),
+                limit: u64::from_bytes(bytes[64..72].try_into().unwrap()),
+            },
+
+            16 => RpcMethod::GetNonce {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+            },
+
+            17 => {
+                let mut tx_bytes = [0u8; 240];
+                tx_bytes[0..239].copy_from_slice(&bytes[1..240]);
+
+                RpcMethod::SimulateTx {
+                    tx: Transaction::from_bytes(&tx_bytes),
+                }
+            }
+
+            18 => RpcMethod::GetQuote {
+                base_token_id: U256::from_bytes(bytes[1..33].try_into().unwrap()),
+                quote_token_id: U256::from_bytes(bytes[33..65].try_into().unwrap()),
+                side: SwapSide::from_bytes(bytes[65..66].try_into().unwrap()),
+                amount: u64::from_bytes(bytes[66..74].try_into().unwrap()),
+            },
+
+            19 => RpcMethod::SwapRoute {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+                signature: Signature::from_bytes(bytes[56..120].try_into().unwrap()),
+                path: [
+                    U256::from_bytes(bytes[120..152].try_into().unwrap()),
+                    U256::from_bytes(bytes[152..184].try_into().unwrap()),
+                    U256::from_bytes(bytes[184..216].try_into().unwrap()),
+                ],
+                token_amount_in: u64::from_bytes(bytes[216..224].try_into().unwrap()),
+                token_amount_out_limit: u64::from_bytes(bytes[224..232].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[232..240].try_into().unwrap()),
+            },
+
+            20 => RpcMethod::GetRoute {
+                token_in_id: U256::from_bytes(bytes[1..33].try_into().unwrap()),
+                token_out_id: U256::from_bytes(bytes[33..65].try_into().unwrap()),
+                token_amount_in: u64::from_bytes(bytes[65..73].try_into().unwrap()),
+                max_hops: bytes[73] as u64,
+            },
+
+            21 => RpcMethod::TransferTokens {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+                signature: Signature::from_bytes(bytes[56..120].try_into().unwrap()),
+                receiver_address: Address::from_bytes(bytes[120..175].try_into().unwrap()),
+                token_id: U256::from_bytes(bytes[175..207].try_into().unwrap()),
+                token_amount: u64::from_bytes(bytes[207..215].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[215..223].try_into().unwrap()),
+            },
+
+            22 => RpcMethod::GetProtocolFees,
+
+            23 => RpcMethod::PlaceOrder {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+                signature: Signature::from_bytes(bytes[56..120].try_into().unwrap()),
+                token_in_id: U256::from_bytes(bytes[120..152].try_into().unwrap()),
+                token_out_id: U256::from_bytes(bytes[152..184].try_into().unwrap()),
+                token_amount_in: u64::from_bytes(bytes[184..192].try_into().unwrap()),
+                token_amount_out_limit: u64::from_bytes(bytes[192..200].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[200..208].try_into().unwrap()),
+            },
+
+            24 => RpcMethod::CancelOrder {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+                signature: Signature::from_bytes(bytes[56..120].try_into().unwrap()),
+                order_id: u64::from_bytes(bytes[120..128].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[128..136].try_into().unwrap()),
+            },
+
+            25 => RpcMethod::GetOrders {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+            },
+
+            26 => RpcMethod::GetTwap {
+                base_token_id: U256::from_bytes(bytes[1..33].try_into().unwrap()),
+                quote_token_id: U256::from_bytes(bytes[33..65].try_into().unwrap()),
+                window: u64::from_bytes(bytes[65..73].try_into().unwrap()),
+            },
+
+            27 => RpcMethod::GetPositions {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+            },
+
+            28 => RpcMethod::GetPoolStats {
+                base_token_id: U256::from_bytes(bytes[1..33].try_into().unwrap()),
+                quote_token_id: U256::from_bytes(bytes[33..65].try_into().unwrap()),
+            },
+
+            29 => RpcMethod::GetTopPools,
+
+            30 => RpcMethod::ClaimProtocolFees {
+                address: Address::from_bytes(bytes[1..56].try_into().unwrap()),
+                signature: Signature::from_bytes(bytes[56..120].try_into().unwrap()),
+                base_token_id: U256::from_bytes(bytes[120..152].try_into().unwrap()),
+                quote_token_id: U256::from_bytes(bytes[152..184].try_into().unwrap()),
+                nonce: u64::from_bytes(bytes[184..192].try_into().unwrap()),
             },
 
             _ => RpcMethod::Unknown,
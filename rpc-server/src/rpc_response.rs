@@ -1,7 +1,9 @@
 use http_body_util::Full;
-use hyper::{body::Bytes, Response};
+use hyper::{body::Bytes, Response, StatusCode};
 use nacho_burns_db::SingleBurnWitness;
-use nacho_data_structures::{ByteConversion, TxStatus, U256};
+use nacho_data_structures::{
+    ByteConversion, OrderStatus, RejectionReason, Transaction, TxStatus, U256,
+};
 use nacho_withdrawals_db::SingleWithdrawalWitness;
 
 /// The alias that represents the type of token IDs.
@@ -10,6 +12,61 @@ type TokenId = U256;
 type TokenAmount = u64;
 /// The alias that represents the type of AMM liquidity points.
 type LiquidityPoints = U256;
+/// The alias that represents the type of AMM pool fee rates in basis points.
+type FeeRate = u64;
+/// The alias that represents the type of amplification coefficients.
+type Amplification = u64;
+/// The alias that represents the type of transaction IDs.
+type TxId = u64;
+/// The alias that represents the type of limit order IDs.
+type OrderId = u64;
+/// The alias that represents the type of transaction kinds which is the first byte of a transaction.
+type TxKind = u8;
+/// The alias that represents the type of AMM pools which are the token IDs, the token amounts,
+/// the total liquidity points, the fee rate and the amplification coefficient.
+type PoolEntry = (
+    TokenId,
+    TokenId,
+    TokenAmount,
+    TokenAmount,
+    LiquidityPoints,
+    FeeRate,
+    Amplification,
+);
+/// The alias that represents the type of limit orders which are the order ID, the token IDs sold and bought,
+/// the token amount sold, the minimum token amount bought and the status.
+type OrderEntry = (
+    OrderId,
+    TokenId,
+    TokenId,
+    TokenAmount,
+    TokenAmount,
+    OrderStatus,
+);
+/// The alias that represents the type of AMM liquidity positions which are the token IDs, the liquidity points,
+/// the share of the pool's liquidity points, the token amounts they can be removed for and the part of those amounts
+/// estimated to come from swap fees.
+type PositionEntry = (
+    TokenId,
+    TokenId,
+    LiquidityPoints,
+    f64,
+    TokenAmount,
+    TokenAmount,
+    TokenAmount,
+    TokenAmount,
+);
+/// The alias that represents the type of swap counts.
+type TradeCount = u64;
+/// The alias that represents the type of AMM pool stats over a period which are the base and quote token volumes,
+/// the base and quote token fees and the trade count.
+type StatsEntry = (
+    TokenAmount,
+    TokenAmount,
+    TokenAmount,
+    TokenAmount,
+    TradeCount,
+);
 
 /// The enum that represents RPC responses.
 ///
@@ -19,12 +76,18 @@ pub enum RpcResponse {
     ClientError,
     /// Represents the total transaction count.
     TotalTxCount(u64),
-    /// Represents the status of a transaction.
-    TxStatus(TxStatus),
+    /// Represents the status of a transaction and the reason it is rejected, if it is.
+    TxStatus(TxStatus, Option<RejectionReason>),
+    /// Represents the full payload of a transaction.
+    Tx(Transaction),
+    /// Represents a page of a user's transactions.
+    TxHistory(Vec<(TxId, TxKind, TxStatus)>),
+    /// Represents the next nonce a user has to sign a transaction with.
+    Nonce(u64),
     /// Represents the balances of a user.
     Balances(Vec<(TokenId, TokenAmount)>),
     /// Represents all the AMM pools.
-    Pools(Vec<(TokenId, TokenId, TokenAmount, TokenAmount, LiquidityPoints)>),
+    Pools(Vec<PoolEntry>),
     /// Represents the AMM liquidites of a user.
     Liquidites(Vec<(TokenId, TokenId, LiquidityPoints)>),
     /// Represents the burns of a user.
@@ -35,12 +98,47 @@ pub enum RpcResponse {
     TxId(u64),
     /// Used for unknown RPC methods.
     ServerError,
+    /// Used for the requests that exceed the rate limits of the client.
+    RateLimited,
+    /// Represents the reason a transaction is or would be rejected.
+    Rejected(RejectionReason),
+    /// Represents the outcome of a simulated transaction, which are the user's balances before and after it,
+    /// the AMM pools after it and the user's liquidity points before and after it.
+    Simulation(
+        Vec<(TokenId, TokenAmount, TokenAmount)>,
+        Vec<PoolEntry>,
+        Option<(LiquidityPoints, LiquidityPoints)>,
+    ),
+    /// Represents the outcome of a swap which is the amount paid including the fee, the amount received, the fee,
+    /// the spot price, the effective price and the price impact.
+    Quote(TokenAmount, TokenAmount, TokenAmount, f64, f64, f64),
+    /// Represents the best path found through the AMM pools and the amount of the last token it gives.
+    Route(Vec<TokenId>, TokenAmount),
+    /// Represents the protocol fees accrued in all the AMM pools.
+    ProtocolFees(Vec<(TokenId, TokenId, TokenAmount, TokenAmount)>),
+    /// Represents the limit orders of a user.
+    Orders(Vec<OrderEntry>),
+    /// Represents the time-weighted average price of an AMM pool in quote tokens per base token as a 64.64 fixed point number.
+    Twap(u128),
+    /// Represents the AMM liquidity positions of a user.
+    Positions(Vec<PositionEntry>),
+    /// Represents the stats of an AMM pool over the last 24 hours and the last 7 days.
+    PoolStats(StatsEntry, StatsEntry),
+    /// Represents the AMM pools with the most swaps and their stats over the last 24 hours.
+    TopPools(Vec<(TokenId, TokenId, StatsEntry)>),
 }
 
 impl From<RpcResponse> for Result<Response<Full<Bytes>>, String> {
     fn from(value: RpcResponse) -> Self {
+        let status = match value {
+            RpcResponse::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            _ => StatusCode::OK,
+        };
         let res: Vec<u8> = value.into();
-        Ok(Response::new(Full::from(res)))
+        Response::builder()
+            .status(status)
+            .body(Full::from(res))
+            .map_err(|err| err.to_string())
     }
 }
 
@@ -56,12 +154,16 @@ impl From<RpcResponse> for Vec<u8> {
 
                 bytes
             }
-            RpcResponse::TxStatus(tx_status) => {
-                let mut bytes = Vec::with_capacity(1 + 1);
+            RpcResponse::TxStatus(tx_status, maybe_reason) => {
+                let mut bytes = Vec::with_capacity(1 + 1 + 1);
                 bytes.push(2);
 
                 bytes.push(tx_status as u8);
 
+                if let Some(reason) = maybe_reason {
+                    bytes.push(reason as u8);
+                }
+
                 bytes
             }
             RpcResponse::Balances(balances) => {
@@ -76,7 +178,7 @@ impl From<RpcResponse> for Vec<u8> {
                 bytes
             }
             RpcResponse::Pools(pools) => {
-                let mut bytes = Vec::with_capacity(1 + (112 * pools.len()));
+                let mut bytes = Vec::with_capacity(1 + (128 * pools.len()));
                 bytes.push(4);
 
                 for (
@@ -85,6 +187,8 @@ impl From<RpcResponse> for Vec<u8> {
                     base_token_amount,
                     quote_token_amount,
                     total_liquidity_points,
+                    fee_rate,
+                    amplification,
                 ) in pools
                 {
                     bytes.extend_from_slice(&base_token_id.to_bytes());
@@ -92,6 +196,8 @@ impl From<RpcResponse> for Vec<u8> {
                     bytes.extend_from_slice(&base_token_amount.to_bytes());
                     bytes.extend_from_slice(&quote_token_amount.to_bytes());
                     bytes.extend_from_slice(&total_liquidity_points.to_bytes());
+                    bytes.extend_from_slice(&fee_rate.to_bytes());
+                    bytes.extend_from_slice(&amplification.to_bytes());
                 }
 
                 bytes
@@ -137,6 +243,221 @@ impl From<RpcResponse> for Vec<u8> {
                 bytes
             }
             RpcResponse::ServerError => vec![9u8; 1],
+            RpcResponse::Tx(tx) => {
+                let mut bytes = Vec::with_capacity(1 + 240);
+                bytes.push(10);
+
+                bytes.extend_from_slice(&tx.to_bytes());
+
+                bytes
+            }
+            RpcResponse::TxHistory(history) => {
+                let mut bytes = Vec::with_capacity(1 + (10 * history.len()));
+                bytes.push(11);
+
+                for (tx_id, tx_kind, tx_status) in history {
+                    bytes.extend_from_slice(&tx_id.to_bytes());
+                    bytes.push(tx_kind);
+                    bytes.push(tx_status as u8);
+                }
+
+                bytes
+            }
+            RpcResponse::Nonce(nonce) => {
+                let mut bytes = Vec::with_capacity(1 + 8);
+                bytes.push(12);
+
+                bytes.extend_from_slice(&nonce.to_bytes());
+
+                bytes
+            }
+            RpcResponse::RateLimited => vec![13u8; 1],
+            RpcResponse::Rejected(reason) => vec![14u8, reason as u8],
+            RpcResponse::Simulation(balance_changes, pools, maybe_points_change) => {
+                let mut bytes = Vec::with_capacity(
+                    1 + 1 + (48 * balance_changes.len()) + 1 + (128 * pools.len()) + 65,
+                );
+                bytes.push(15);
+
+                bytes.push(balance_changes.len() as u8);
+
+                for (token_id, before, after) in balance_changes {
+                    bytes.extend_from_slice(&token_id.to_bytes());
+                    bytes.extend_from_slice(&before.to_bytes());
+                    bytes.extend_from_slice(&after.to_bytes());
+                }
+
+                bytes.push(pools.len() as u8);
+
+                for (
+                    base_token_id,
+                    quote_token_id,
+                    base_token_amount,
+                    quote_token_amount,
+                    total_liquidity_points,
+                    fee_rate,
+                    amplification,
+                ) in pools
+                {
+                    bytes.extend_from_slice(&base_token_id.to_bytes());
+                    bytes.extend_from_slice(&quote_token_id.to_bytes());
+                    bytes.extend_from_slice(&base_token_amount.to_bytes());
+                    bytes.extend_from_slice(&quote_token_amount.to_bytes());
+                    bytes.extend_from_slice(&total_liquidity_points.to_bytes());
+                    bytes.extend_from_slice(&fee_rate.to_bytes());
+                    bytes.extend_from_slice(&amplification.to_bytes());
+                }
+
+                match maybe_points_change {
+                    Some((before, after)) => {
+                        bytes.push(1);
+                        bytes.extend_from_slice(&before.to_bytes());
+                        bytes.extend_from_slice(&after.to_bytes());
+                    }
+                    None => bytes.push(0),
+                }
+
+                bytes
+            }
+            RpcResponse::Quote(
+                amount_in,
+                amount_out,
+                fee,
+                spot_price,
+                effective_price,
+                price_impact,
+            ) => {
+                let mut bytes = Vec::with_capacity(1 + 48);
+                bytes.push(16);
+
+                bytes.extend_from_slice(&amount_in.to_bytes());
+                bytes.extend_from_slice(&amount_out.to_bytes());
+                bytes.extend_from_slice(&fee.to_bytes());
+                bytes.extend_from_slice(&spot_price.to_le_bytes());
+                bytes.extend_from_slice(&effective_price.to_le_bytes());
+                bytes.extend_from_slice(&price_impact.to_le_bytes());
+
+                bytes
+            }
+            RpcResponse::Route(path, amount_out) => {
+                let mut bytes = Vec::with_capacity(1 + 1 + (32 * path.len()) + 8);
+                bytes.push(17);
+
+                bytes.push(path.len() as u8);
+
+                for token_id in path {
+                    bytes.extend_from_slice(&token_id.to_bytes());
+                }
+
+                bytes.extend_from_slice(&amount_out.to_bytes());
+
+                bytes
+            }
+            RpcResponse::ProtocolFees(protocol_fees) => {
+                let mut bytes = Vec::with_capacity(1 + (80 * protocol_fees.len()));
+                bytes.push(18);
+
+                for (base_token_id, quote_token_id, base_token_amount, quote_token_amount) in
+                    protocol_fees
+                {
+                    bytes.extend_from_slice(&base_token_id.to_bytes());
+                    bytes.extend_from_slice(&quote_token_id.to_bytes());
+                    bytes.extend_from_slice(&base_token_amount.to_bytes());
+                    bytes.extend_from_slice(&quote_token_amount.to_bytes());
+                }
+
+                bytes
+            }
+            RpcResponse::Orders(orders) => {
+                let mut bytes = Vec::with_capacity(1 + (89 * orders.len()));
+                bytes.push(19);
+
+                for (
+                    order_id,
+                    token_in_id,
+                    token_out_id,
+                    token_amount_in,
+                    token_amount_out_limit,
+                    status,
+                ) in orders
+                {
+                    bytes.extend_from_slice(&order_id.to_bytes());
+                    bytes.extend_from_slice(&token_in_id.to_bytes());
+                    bytes.extend_from_slice(&token_out_id.to_bytes());
+                    bytes.extend_from_slice(&token_amount_in.to_bytes());
+                    bytes.extend_from_slice(&token_amount_out_limit.to_bytes());
+                    bytes.extend_from_slice(&status.to_bytes());
+                }
+
+                bytes
+            }
+            RpcResponse::Twap(price) => {
+                let mut bytes = Vec::with_capacity(1 + 16);
+                bytes.push(20);
+
+                bytes.extend_from_slice(&price.to_bytes());
+
+                bytes
+            }
+            RpcResponse::Positions(positions) => {
+                let mut bytes = Vec::with_capacity(1 + (136 * positions.len()));
+                bytes.push(21);
+
+                for (
+                    base_token_id,
+                    quote_token_id,
+                    liquidity_points,
+                    share,
+                    base_token_amount,
+                    quote_token_amount,
+                    fee_base_token_amount,
+                    fee_quote_token_amount,
+                ) in positions
+                {
+                    bytes.extend_from_slice(&base_token_id.to_bytes());
+                    bytes.extend_from_slice(&quote_token_id.to_bytes());
+                    bytes.extend_from_slice(&liquidity_points.to_bytes());
+                    bytes.extend_from_slice(&share.to_le_bytes());
+                    bytes.extend_from_slice(&base_token_amount.to_bytes());
+                    bytes.extend_from_slice(&quote_token_amount.to_bytes());
+                    bytes.extend_from_slice(&fee_base_token_amount.to_bytes());
+                    bytes.extend_from_slice(&fee_quote_token_amount.to_bytes());
+                }
+
+                bytes
+            }
+            RpcResponse::PoolStats(daily_stats, weekly_stats) => {
+                let mut bytes = Vec::with_capacity(1 + 40 + 40);
+                bytes.push(22);
+
+                extend_with_stats(&mut bytes, daily_stats);
+                extend_with_stats(&mut bytes, weekly_stats);
+
+                bytes
+            }
+            RpcResponse::TopPools(top_pools) => {
+                let mut bytes = Vec::with_capacity(1 + (104 * top_pools.len()));
+                bytes.push(23);
+
+                for (base_token_id, quote_token_id, daily_stats) in top_pools {
+                    bytes.extend_from_slice(&base_token_id.to_bytes());
+                    bytes.extend_from_slice(&quote_token_id.to_bytes());
+                    extend_with_stats(&mut bytes, daily_stats);
+                }
+
+                bytes
+            }
         }
     }
 }
+
+fn extend_with_stats(
+    bytes: &mut Vec<u8>,
+    (base_token_volume, quote_token_volume, base_token_fees, quote_token_fees, trade_count): StatsEntry,
+) {
+    bytes.extend_from_slice(&base_token_volume.to_bytes());
+    bytes.extend_from_slice(&quote_token_volume.to_bytes());
+    bytes.extend_from_slice(&base_token_fees.to_bytes());
+    bytes.extend_from_slice(&quote_token_fees.to_bytes());
+    bytes.extend_from_slice(&trade_count.to_bytes());
+}
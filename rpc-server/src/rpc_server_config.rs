@@ -0,0 +1,176 @@
+use std::{
+    io::{Error, ErrorKind},
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    str::FromStr,
+    time::Duration,
+};
+
+/// The default maximum size of a request body in bytes.
+pub const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// The default duration a client has to send a request's headers or body in seconds.
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 60;
+
+/// The default number of read requests a client IP can make per minute.
+pub const DEFAULT_READ_RATE_LIMIT: u32 = 600;
+
+/// The default number of state changing requests a client IP or an address can make per minute.
+pub const DEFAULT_WRITE_RATE_LIMIT: u32 = 60;
+
+/// The struct that represents the configuration of an RPC server.
+///
+/// It is created from environment variables inside `start_rpc_server` under the hood.
+///
+/// # Examples
+///
+/// Create a configuration from the environment:
+///
+/// ```rs
+/// let config = RpcServerConfig::from_env()?;
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RpcServerConfig {
+    /// The socket address the server listens on. Both IPv4 and IPv6 addresses are supported.
+    pub socket_address: SocketAddr,
+    /// The origins allowed to make cross origin requests. `*` allows any origin.
+    pub cors_allowed_origins: Vec<String>,
+    /// The maximum size of a request body in bytes.
+    pub max_body_size: usize,
+    /// The duration a client has to send a request's headers and then its body.
+    ///
+    /// An idle HTTP/1 connection is closed when it passes without a new request,
+    /// and an HTTP/2 connection is closed when a ping isn't answered within it.
+    pub request_timeout: Duration,
+    /// The number of read requests a client IP can make per minute. `0` disables the limit.
+    pub read_rate_limit: u32,
+    /// The number of state changing requests a client IP or an address can make per minute. `0` disables the limit.
+    pub write_rate_limit: u32,
+}
+
+impl RpcServerConfig {
+    /// Creates a configuration by reading the environment variables below:
+    ///
+    /// - `NACHO_RPC_SERVER_PORT` (required)
+    /// - `NACHO_RPC_SERVER_ADDRESS` (default: `127.0.0.1`)
+    /// - `NACHO_RPC_SERVER_CORS_ALLOWED_ORIGINS` (comma separated, default: none)
+    /// - `NACHO_RPC_SERVER_MAX_BODY_SIZE` (in bytes, default: `DEFAULT_MAX_BODY_SIZE`)
+    /// - `NACHO_RPC_SERVER_REQUEST_TIMEOUT` (in seconds, default: `DEFAULT_REQUEST_TIMEOUT_SECS`)
+    /// - `NACHO_RPC_SERVER_READ_RATE_LIMIT` (per minute, default: `DEFAULT_READ_RATE_LIMIT`)
+    /// - `NACHO_RPC_SERVER_WRITE_RATE_LIMIT` (per minute, default: `DEFAULT_WRITE_RATE_LIMIT`)
+    ///
+    /// Returns an error if the port is missing or any of the variables is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let config = RpcServerConfig::from_env()?;
+    /// ```
+    ///
+    pub fn from_env() -> Result<RpcServerConfig, Error> {
+        let port: u16 = parse_env("NACHO_RPC_SERVER_PORT")?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                "NACHO_RPC_SERVER_PORT environment variable is not set",
+            )
+        })?;
+
+        let ip_address: IpAddr =
+            parse_env("NACHO_RPC_SERVER_ADDRESS")?.unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST));
+
+        let cors_allowed_origins = std::env::var("NACHO_RPC_SERVER_CORS_ALLOWED_ORIGINS")
+            .map(|origins| {
+                origins
+                    .split(',')
+                    .map(|origin| origin.trim().to_owned())
+                    .filter(|origin| !origin.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let max_body_size =
+            parse_env("NACHO_RPC_SERVER_MAX_BODY_SIZE")?.unwrap_or(DEFAULT_MAX_BODY_SIZE);
+
+        let request_timeout = Duration::from_secs(
+            parse_env("NACHO_RPC_SERVER_REQUEST_TIMEOUT")?.unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS),
+        );
+
+        let read_rate_limit =
+            parse_env("NACHO_RPC_SERVER_READ_RATE_LIMIT")?.unwrap_or(DEFAULT_READ_RATE_LIMIT);
+
+        let write_rate_limit =
+            parse_env("NACHO_RPC_SERVER_WRITE_RATE_LIMIT")?.unwrap_or(DEFAULT_WRITE_RATE_LIMIT);
+
+        Ok(RpcServerConfig {
+            socket_address: SocketAddr::new(ip_address, port),
+            cors_allowed_origins,
+            max_body_size,
+            request_timeout,
+            read_rate_limit,
+            write_rate_limit,
+        })
+    }
+
+    /// Returns the value of the `Access-Control-Allow-Origin` header for the given origin.
+    ///
+    /// Returns `None` if the origin isn't allowed to make cross origin requests.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// let maybe_allowed_origin = config.allowed_origin("http://localhost:5173");
+    /// ```
+    ///
+    pub fn allowed_origin<'a>(&'a self, origin: &'a str) -> Option<&'a str> {
+        self.cors_allowed_origins
+            .iter()
+            .find(|allowed_origin| *allowed_origin == "*" || *allowed_origin == origin)
+            .map(|allowed_origin| match allowed_origin.as_str() {
+                "*" => "*",
+                _ => origin,
+            })
+    }
+}
+
+pub(crate) fn parse_env<T: FromStr>(name: &str) -> Result<Option<T>, Error> {
+    match std::env::var(name) {
+        Ok(value) => value.trim().parse().map(Some).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("{name} environment variable is malformed"),
+            )
+        }),
+        Err(_) => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_configured_origins() {
+        let mut config = RpcServerConfig {
+            socket_address: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 2345),
+            cors_allowed_origins: vec!["http://localhost:5173".to_owned()],
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            request_timeout: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS),
+            read_rate_limit: DEFAULT_READ_RATE_LIMIT,
+            write_rate_limit: DEFAULT_WRITE_RATE_LIMIT,
+        };
+
+        assert_eq!(
+            config.allowed_origin("http://localhost:5173"),
+            Some("http://localhost:5173")
+        );
+        assert_eq!(config.allowed_origin("http://example.com"), None);
+
+        config.cors_allowed_origins = vec!["*".to_owned()];
+
+        assert_eq!(config.allowed_origin("http://example.com"), Some("*"));
+
+        config.cors_allowed_origins = Vec::new();
+
+        assert_eq!(config.allowed_origin("http://localhost:5173"), None);
+    }
+}
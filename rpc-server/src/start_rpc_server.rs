@@ -1,15 +1,32 @@
-use crate::{RpcMethod, RpcResponse};
-use http_body_util::BodyExt;
-use hyper::{body::Buf, server::conn::http1, service::service_fn};
-use hyper_util::rt::TokioIo;
-use nacho_data_structures::ByteConversion;
-use std::{
-    future::Future,
-    net::{Ipv4Addr, SocketAddrV4},
+use crate::{
+    json_rpc::handle_json_rpc, rate_limiter::RateLimiter, RpcMethod, RpcResponse, RpcServerConfig,
+    JSON_RPC_PATH,
+};
+use http_body_util::{BodyExt, Collected, Full, LengthLimitError, Limited};
+use hyper::{
+    body::{Buf, Bytes, Incoming},
+    header::{
+        HeaderValue, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+        ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_MAX_AGE, CONTENT_TYPE, ORIGIN, VARY,
+    },
+    service::service_fn,
+    Method, Request, Response, StatusCode,
+};
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo, TokioTimer},
+    server::conn::auto::Builder,
+};
+use nacho_data_structures::{ByteConversion, RejectionReason};
+use std::{future::Future, time::Duration};
+use tokio::{
+    net::TcpListener,
+    time::{sleep, timeout},
 };
-use tokio::net::TcpListener;
 
-/// Starts an RPC Server at the given socket address and uses the given handler function to handle RPC requests.
+/// The duration the server waits after failing to accept a connection, so errors like running out of file descriptors don't spin the loop.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Starts an RPC Server at the configured socket address and uses the given handler function to handle RPC requests.
 ///
 /// The handler function takes an `RpcMethod` and returns an `RpcResponse` asynchronously.
 ///
@@ -17,6 +34,15 @@ use tokio::net::TcpListener;
 ///
 /// It runs the handler function for each valid request and responds with the handler's result.
 ///
+/// Requests sent to `JSON_RPC_PATH` are handled as JSON-RPC 2.0 requests using the same handler function.
+///
+/// The server is configured from environment variables, see `RpcServerConfig::from_env`.
+/// It serves both HTTP/1 and HTTP/2 connections and answers CORS preflight requests.
+/// Connections that fail to be accepted are logged and don't stop the server.
+///
+/// It responds with `RpcResponse::RateLimited` for the requests that exceed the configured rate limits,
+/// and for the requests the handler rejects with `RejectionReason::RateLimited` as their signers exceed them, see `SignerRateLimiter`.
+///
 /// # Examples
 ///
 /// Define a handler:
@@ -38,47 +64,144 @@ where
     F: Fn(RpcMethod) -> Fut + Send + Sync + Copy + 'static,
     Fut: Future<Output = RpcResponse> + Send + 'static,
 {
-    let port = std::env::var("NACHO_RPC_SERVER_PORT")
-        .map_err(|_| {
-            std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "NACHO_RPC_SERVER_PORT environment variable is not set",
-            )
-        })?
-        .parse()
-        .unwrap();
-
-    let listener = TcpListener::bind(SocketAddrV4::new(Ipv4Addr::LOCALHOST, port)).await?;
+    let config: &'static RpcServerConfig = Box::leak(Box::new(RpcServerConfig::from_env()?));
+
+    let rate_limiter: &'static RateLimiter = Box::leak(Box::new(RateLimiter::new(
+        config.read_rate_limit,
+        config.write_rate_limit,
+    )));
+
+    let listener = TcpListener::bind(config.socket_address).await?;
 
     loop {
-        let (socket, _) = listener.accept().await?;
+        let (socket, peer_address) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(err) => {
+                println!("{}", err);
+                sleep(ACCEPT_ERROR_BACKOFF).await;
+                continue;
+            }
+        };
+        let ip_address = peer_address.ip();
 
         let io = TokioIo::new(socket);
 
         tokio::task::spawn(async move {
-            if let Err(err) = http1::Builder::new()
-                .serve_connection(
-                    io,
-                    service_fn(|req| async {
-                        let body = req.collect().await;
-
-                        let rpc_method = parse_body(body);
-
-                        let rpc_response = rpc_method_handler(rpc_method).await;
-                        rpc_response.into()
-                    }),
-                )
-                .await
-            {
-                println!("{}", err)
+            let mut builder = Builder::new(TokioExecutor::new());
+
+            // NOTE: Connections aren't closed after a fixed duration, so long lived keep-alive connections and slow handlers aren't cut off.
+            builder
+                .http1()
+                .timer(TokioTimer::new())
+                .header_read_timeout(config.request_timeout)
+                .keep_alive(true);
+            builder
+                .http2()
+                .timer(TokioTimer::new())
+                .keep_alive_interval(config.request_timeout)
+                .keep_alive_timeout(config.request_timeout);
+
+            let connection = builder.serve_connection(
+                io,
+                service_fn(move |req| async move {
+                    let allowed_origin = req
+                        .headers()
+                        .get(ORIGIN)
+                        .and_then(|origin| origin.to_str().ok())
+                        .and_then(|origin| config.allowed_origin(origin))
+                        .and_then(|origin| HeaderValue::from_str(origin).ok());
+
+                    let rate_limited_rpc_method_handler = move |rpc_method: RpcMethod| async move {
+                        if !rate_limiter.check(ip_address, &rpc_method) {
+                            return RpcResponse::RateLimited;
+                        }
+
+                        match rpc_method_handler(rpc_method).await {
+                            // The signer is rate limited, so the request doesn't count against the client IP's quota.
+                            RpcResponse::Rejected(RejectionReason::RateLimited) => {
+                                rate_limiter.refund_write(ip_address);
+
+                                RpcResponse::RateLimited
+                            }
+                            rpc_response => rpc_response,
+                        }
+                    };
+
+                    let response = respond(req, config, rate_limited_rpc_method_handler).await;
+
+                    response.map(|mut response| {
+                        if let Some(allowed_origin) = allowed_origin {
+                            let headers = response.headers_mut();
+                            headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, allowed_origin);
+                            headers.insert(VARY, HeaderValue::from_static("Origin"));
+                        }
+                        response
+                    })
+                }),
+            );
+
+            if let Err(err) = connection.await {
+                println!("{}", err);
             }
         });
     }
 }
 
-fn parse_body(
-    body: Result<http_body_util::Collected<hyper::body::Bytes>, hyper::Error>,
-) -> RpcMethod {
+async fn respond<F, Fut>(
+    req: Request<Incoming>,
+    config: &RpcServerConfig,
+    rpc_method_handler: F,
+) -> Result<Response<Full<Bytes>>, String>
+where
+    F: Fn(RpcMethod) -> Fut + Copy,
+    Fut: Future<Output = RpcResponse>,
+{
+    if req.method() == Method::OPTIONS {
+        return Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .header(ACCESS_CONTROL_ALLOW_METHODS, "POST, OPTIONS")
+            .header(ACCESS_CONTROL_ALLOW_HEADERS, "Content-Type")
+            .header(ACCESS_CONTROL_MAX_AGE, "86400")
+            .body(Full::default())
+            .map_err(|err| err.to_string());
+    }
+
+    let is_json_rpc = req.uri().path() == JSON_RPC_PATH;
+
+    // Only reading the body is timed out, as a handler that is dropped midway could leave a transaction half stored.
+    let body = match timeout(
+        config.request_timeout,
+        Limited::new(req.into_body(), config.max_body_size).collect(),
+    )
+    .await
+    {
+        Ok(Ok(body)) => Ok(body),
+        Ok(Err(err)) if err.is::<LengthLimitError>() => {
+            return Response::builder()
+                .status(StatusCode::PAYLOAD_TOO_LARGE)
+                .body(Full::default())
+                .map_err(|err| err.to_string())
+        }
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(_) => {
+            return Response::builder()
+                .status(StatusCode::REQUEST_TIMEOUT)
+                .body(Full::default())
+                .map_err(|err| err.to_string())
+        }
+    };
+
+    if is_json_rpc {
+        return respond_json_rpc(body, rpc_method_handler).await;
+    }
+
+    let rpc_method = parse_body(body);
+
+    let rpc_response = rpc_method_handler(rpc_method).await;
+    rpc_response.into()
+}
+
+fn parse_body(body: Result<Collected<Bytes>, String>) -> RpcMethod {
     let body = match body {
         Ok(body) => body,
         Err(_) => return RpcMethod::Unknown,
@@ -97,3 +220,28 @@ fn parse_body(
 
     rpc_method
 }
+
+async fn respond_json_rpc<F, Fut>(
+    body: Result<Collected<Bytes>, String>,
+    rpc_method_handler: F,
+) -> Result<Response<Full<Bytes>>, String>
+where
+    F: Fn(RpcMethod) -> Fut + Copy,
+    Fut: Future<Output = RpcResponse>,
+{
+    let body = match body {
+        Ok(body) => body.to_bytes(),
+        Err(err) => return Err(err),
+    };
+
+    match handle_json_rpc(&body, rpc_method_handler).await {
+        Some(response) => Response::builder()
+            .header(CONTENT_TYPE, "application/json")
+            .body(Full::from(response.to_string()))
+            .map_err(|err| err.to_string()),
+        None => Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(Full::default())
+            .map_err(|err| err.to_string()),
+    }
+}
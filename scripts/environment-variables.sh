@@ -9,12 +9,17 @@ export NACHO_PROOFS_PATH="/nacho/state/proofs/"
 export NACHO_BALANCES_DB_PATH="/nacho/state/balances-db/"
 export NACHO_LIQUIDITIES_DB_PATH="/nacho/state/liquidities-db/"
 export NACHO_POOLS_DB_PATH="/nacho/state/pools-db/"
+export NACHO_ORDERS_DB_PATH="/nacho/state/orders-db/"
+export NACHO_OBSERVATIONS_DB_PATH="/nacho/state/observations-db/"
+export NACHO_ANALYTICS_DB_PATH="/nacho/state/analytics-db/"
 export NACHO_BURNS_DB_PATH="/nacho/state/burns-db/"
 export NACHO_WITHRAWALS_DB_PATH="/nacho/state/withdrawals-db/"
 export NACHO_TRANSACTIONS_DB_PATH="/nacho/state/transactions-db/"
 export NACHO_EVENTS_DB_PATH="/nacho/state/events-db/"
 export NACHO_MEMPOOL_PATH="/nacho/state/mempool/"
 export NACHO_PROOFPOOL_PATH="/nacho/state/proofpool/"
+export NACHO_WRITE_AHEAD_LOG_PATH="/nacho/state/write-ahead-log/"
+export NACHO_SNAPSHOTS_PATH="/nacho/snapshots/"
 export NACHO_EVENT_FETCHER_PROCESS_SCRIPT_PATH="/nacho/dependencies/node_modules/nacho-event-fetcher-process/build/index.mjs"
 export NACHO_PROOF_GENERATOR_PROCESS_SCRIPT_PATH="/nacho/dependencies/node_modules/nacho-proof-generator-process/build/index.mjs"
 export NACHO_PROOF_MERGER_PROCESS_SCRIPT_PATH="/nacho/dependencies/node_modules/nacho-proof-merger-process/build/index.mjs"
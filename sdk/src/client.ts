@@ -26,6 +26,35 @@ import { unparseProvideLiquidity } from "./unparse/unparse-provide-liquidity.js"
 import { unparseRemoveLiquidity } from "./unparse/unparse-remove-liquidity.js"
 import { unparseBuyTokens } from "./unparse/unparse-buy-tokens.js"
 import { unparseSellTokens } from "./unparse/unparse-sell-tokens.js"
+import { unparseGetNonce } from "./unparse/unparse-get-nonce.js"
+import { parseGetNonce } from "./parse/parse-get-nonce.js"
+import { unparseGetQuote } from "./unparse/unparse-get-quote.js"
+import { parseGetQuote } from "./parse/parse-get-quote.js"
+import { Quote } from "./types/quote.js"
+import { unparseGetRoute } from "./unparse/unparse-get-route.js"
+import { parseGetRoute } from "./parse/parse-get-route.js"
+import { Route } from "./types/route.js"
+import { unparseSwapRoute } from "./unparse/unparse-swap-route.js"
+import { unparseTransferTokens } from "./unparse/unparse-transfer-tokens.js"
+import { unparseGetProtocolFees } from "./unparse/unparse-get-protocol-fees.js"
+import { parseGetProtocolFees } from "./parse/parse-get-protocol-fees.js"
+import { ProtocolFee } from "./types/protocol-fee.js"
+import { unparseGetOrders } from "./unparse/unparse-get-orders.js"
+import { parseGetOrders } from "./parse/parse-get-orders.js"
+import { Order } from "./types/order.js"
+import { unparseGetTwap } from "./unparse/unparse-get-twap.js"
+import { parseGetTwap } from "./parse/parse-get-twap.js"
+import { unparseGetPositions } from "./unparse/unparse-get-positions.js"
+import { parseGetPositions } from "./parse/parse-get-positions.js"
+import { Position } from "./types/position.js"
+import { unparseGetPoolStats } from "./unparse/unparse-get-pool-stats.js"
+import { parseGetPoolStats } from "./parse/parse-get-pool-stats.js"
+import { unparseGetTopPools } from "./unparse/unparse-get-top-pools.js"
+import { parseGetTopPools } from "./parse/parse-get-top-pools.js"
+import { PoolStats, TopPool } from "./types/pool-stats.js"
+import { unparsePlaceOrder } from "./unparse/unparse-place-order.js"
+import { unparseCancelOrder } from "./unparse/unparse-cancel-order.js"
+import { unparseClaimProtocolFees } from "./unparse/unparse-claim-protocol-fees.js"
 
 declare function setInterval(handler: TimerHandler, timeout?: number, ...arguments: any[]): number
 
@@ -34,14 +63,42 @@ interface ClientReadMethods {
 
     getTxStatus: (txId: number) => Promise<TxStatus>
 
+    getNonce: (address: string) => Promise<bigint>
+
     getBalances: (address: string) => Promise<Array<Balance>>
 
     getPools: () => Promise<Array<Pool>>
 
+    getProtocolFees: () => Promise<Array<ProtocolFee>>
+
+    getQuote: (
+        baseTokenId: bigint,
+        quoteTokenId: bigint,
+        side: "buy" | "sell",
+        amount: bigint,
+    ) => Promise<Quote>
+
+    getRoute: (
+        tokenInId: bigint,
+        tokenOutId: bigint,
+        tokenAmountIn: bigint,
+        maxHops?: number,
+    ) => Promise<Route>
+
+    getTwap: (baseTokenId: bigint, quoteTokenId: bigint, window: bigint) => Promise<bigint>
+
+    getPoolStats: (baseTokenId: bigint, quoteTokenId: bigint) => Promise<PoolStats>
+
+    getTopPools: () => Promise<Array<TopPool>>
+
     getLiquidities: (address: string) => Promise<Array<Liquidity>>
 
+    getPositions: (address: string) => Promise<Array<Position>>
+
     getBurns: (address: string) => Promise<Array<Burn>>
 
+    getOrders: (address: string) => Promise<Array<Order>>
+
     getBridgeWitnesses: (burnId: number) => Promise<BridgeWitnesses>
 }
 
@@ -51,6 +108,7 @@ interface ClientWriteMethods {
         signature: [bigint, bigint],
         tokenId: bigint,
         amount: bigint,
+        nonce: bigint,
     ) => Promise<void>
 
     createPool: (
@@ -60,6 +118,10 @@ interface ClientWriteMethods {
         quoteTokenId: bigint,
         baseTokenAmount: bigint,
         quoteTokenAmount: bigint,
+        feeRate: bigint,
+        poolKind: "constant_product" | "stable_swap",
+        amplification: bigint,
+        nonce: bigint,
     ) => Promise<void>
 
     provideLiquidity: (
@@ -69,6 +131,7 @@ interface ClientWriteMethods {
         quoteTokenId: bigint,
         baseTokenAmount: bigint,
         quoteTokenAmountLimit: bigint,
+        nonce: bigint,
     ) => Promise<void>
 
     removeLiquidity: (
@@ -79,6 +142,7 @@ interface ClientWriteMethods {
         baseTokenAmountLimit: bigint,
         quoteTokenAmountLimit: bigint,
         liquidityPointAmount: bigint,
+        nonce: bigint,
     ) => Promise<void>
 
     buyTokens: (
@@ -88,6 +152,7 @@ interface ClientWriteMethods {
         quoteTokenId: bigint,
         baseTokenAmount: bigint,
         quoteTokenAmountLimit: bigint,
+        nonce: bigint,
     ) => Promise<void>
 
     sellTokens: (
@@ -97,6 +162,50 @@ interface ClientWriteMethods {
         quoteTokenId: bigint,
         baseTokenAmountLimit: bigint,
         quoteTokenAmount: bigint,
+        nonce: bigint,
+    ) => Promise<void>
+
+    swapRoute: (
+        address: string,
+        signature: [bigint, bigint],
+        path: [bigint, bigint, bigint],
+        tokenAmountIn: bigint,
+        tokenAmountOutLimit: bigint,
+        nonce: bigint,
+    ) => Promise<void>
+
+    transferTokens: (
+        address: string,
+        signature: [bigint, bigint],
+        receiverAddress: string,
+        tokenId: bigint,
+        amount: bigint,
+        nonce: bigint,
+    ) => Promise<void>
+
+    placeOrder: (
+        address: string,
+        signature: [bigint, bigint],
+        tokenInId: bigint,
+        tokenOutId: bigint,
+        tokenAmountIn: bigint,
+        tokenAmountOutLimit: bigint,
+        nonce: bigint,
+    ) => Promise<void>
+
+    cancelOrder: (
+        address: string,
+        signature: [bigint, bigint],
+        orderId: bigint,
+        nonce: bigint,
+    ) => Promise<void>
+
+    claimProtocolFees: (
+        address: string,
+        signature: [bigint, bigint],
+        baseTokenId: bigint,
+        quoteTokenId: bigint,
+        nonce: bigint,
     ) => Promise<void>
 }
 
@@ -112,6 +221,14 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
 
         const responseBody = await httpResponse.arrayBuffer()
 
+        if (new Uint8Array(responseBody)[0] === 13) {
+            throw Error("Rate limited.")
+        }
+
+        if (new Uint8Array(responseBody)[0] === 14) {
+            throw Error("Transaction rejected.")
+        }
+
         return responseBody
     }
 
@@ -147,6 +264,12 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
         return parseTxStatus(response)
     }
 
+    public async getNonce(address: string): Promise<bigint> {
+        const request = unparseGetNonce(address)
+        const response = await this.request(request)
+        return parseGetNonce(response)
+    }
+
     public async getBalances(address: string): Promise<Array<Balance>> {
         const request = unparseGetBalances(address)
         const response = await this.request(request)
@@ -159,18 +282,76 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
         return parseGetPools(response)
     }
 
+    public async getProtocolFees(): Promise<Array<ProtocolFee>> {
+        const request = unparseGetProtocolFees()
+        const response = await this.request(request)
+        return parseGetProtocolFees(response)
+    }
+
+    public async getQuote(
+        baseTokenId: bigint,
+        quoteTokenId: bigint,
+        side: "buy" | "sell",
+        amount: bigint,
+    ): Promise<Quote> {
+        const request = unparseGetQuote(baseTokenId, quoteTokenId, side, amount)
+        const response = await this.request(request)
+        return parseGetQuote(response)
+    }
+
+    public async getRoute(
+        tokenInId: bigint,
+        tokenOutId: bigint,
+        tokenAmountIn: bigint,
+        maxHops = 0,
+    ): Promise<Route> {
+        const request = unparseGetRoute(tokenInId, tokenOutId, tokenAmountIn, maxHops)
+        const response = await this.request(request)
+        return parseGetRoute(response)
+    }
+
+    public async getTwap(baseTokenId: bigint, quoteTokenId: bigint, window: bigint): Promise<bigint> {
+        const request = unparseGetTwap(baseTokenId, quoteTokenId, window)
+        const response = await this.request(request)
+        return parseGetTwap(response)
+    }
+
+    public async getPoolStats(baseTokenId: bigint, quoteTokenId: bigint): Promise<PoolStats> {
+        const request = unparseGetPoolStats(baseTokenId, quoteTokenId)
+        const response = await this.request(request)
+        return parseGetPoolStats(response)
+    }
+
+    public async getTopPools(): Promise<Array<TopPool>> {
+        const request = unparseGetTopPools()
+        const response = await this.request(request)
+        return parseGetTopPools(response)
+    }
+
     public async getLiquidities(address: string): Promise<Array<Liquidity>> {
         const request = unparseGetLiquidities(address)
         const response = await this.request(request)
         return parseGetLiquidities(response)
     }
 
+    public async getPositions(address: string): Promise<Array<Position>> {
+        const request = unparseGetPositions(address)
+        const response = await this.request(request)
+        return parseGetPositions(response)
+    }
+
     public async getBurns(address: string): Promise<Array<Burn>> {
         const request = unparseGetBurns(address)
         const response = await this.request(request)
         return parseGetBurns(response)
     }
 
+    public async getOrders(address: string): Promise<Array<Order>> {
+        const request = unparseGetOrders(address)
+        const response = await this.request(request)
+        return parseGetOrders(response)
+    }
+
     public async getBridgeWitnesses(burnId: number): Promise<BridgeWitnesses> {
         const request = unparseGetBridgeWitnesses(burnId)
         const response = await this.request(request)
@@ -182,8 +363,9 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
         signature: [bigint, bigint],
         tokenId: bigint,
         amount: bigint,
+        nonce: bigint,
     ): Promise<void> {
-        const request = unparseBurnTokens(address, signature, tokenId, amount)
+        const request = unparseBurnTokens(address, signature, tokenId, amount, nonce)
         const response = await this.request(request)
         const txId = parseTxId(response)
         await this.waitTransaction(txId)
@@ -196,6 +378,10 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
         quoteTokenId: bigint,
         baseTokenAmount: bigint,
         quoteTokenAmount: bigint,
+        feeRate: bigint,
+        poolKind: "constant_product" | "stable_swap",
+        amplification: bigint,
+        nonce: bigint,
     ): Promise<void> {
         const request = unparseCreatePool(
             address,
@@ -204,6 +390,10 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
             quoteTokenId,
             baseTokenAmount,
             quoteTokenAmount,
+            feeRate,
+            poolKind,
+            amplification,
+            nonce,
         )
         const response = await this.request(request)
         const txId = parseTxId(response)
@@ -217,6 +407,7 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
         quoteTokenId: bigint,
         baseTokenAmount: bigint,
         quoteTokenAmountLimit: bigint,
+        nonce: bigint,
     ): Promise<void> {
         const request = unparseProvideLiquidity(
             address,
@@ -225,6 +416,7 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
             quoteTokenId,
             baseTokenAmount,
             quoteTokenAmountLimit,
+            nonce,
         )
         const response = await this.request(request)
         const txId = parseTxId(response)
@@ -239,6 +431,7 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
         baseTokenAmountLimit: bigint,
         quoteTokenAmountLimit: bigint,
         liquidityPointAmount: bigint,
+        nonce: bigint,
     ): Promise<void> {
         const request = unparseRemoveLiquidity(
             address,
@@ -248,6 +441,7 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
             baseTokenAmountLimit,
             quoteTokenAmountLimit,
             liquidityPointAmount,
+            nonce,
         )
         const response = await this.request(request)
         const txId = parseTxId(response)
@@ -261,6 +455,7 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
         quoteTokenId: bigint,
         baseTokenAmount: bigint,
         quoteTokenAmountLimit: bigint,
+        nonce: bigint,
     ): Promise<void> {
         const request = unparseBuyTokens(
             address,
@@ -269,6 +464,7 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
             quoteTokenId,
             baseTokenAmount,
             quoteTokenAmountLimit,
+            nonce,
         )
         const response = await this.request(request)
         const txId = parseTxId(response)
@@ -282,6 +478,7 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
         quoteTokenId: bigint,
         baseTokenAmountLimit: bigint,
         quoteTokenAmount: bigint,
+        nonce: bigint,
     ): Promise<void> {
         const request = unparseSellTokens(
             address,
@@ -290,6 +487,103 @@ export class Client implements ClientReadMethods, ClientWriteMethods {
             quoteTokenId,
             baseTokenAmountLimit,
             quoteTokenAmount,
+            nonce,
+        )
+        const response = await this.request(request)
+        const txId = parseTxId(response)
+        await this.waitTransaction(txId)
+    }
+
+    public async swapRoute(
+        address: string,
+        signature: [bigint, bigint],
+        path: [bigint, bigint, bigint],
+        tokenAmountIn: bigint,
+        tokenAmountOutLimit: bigint,
+        nonce: bigint,
+    ): Promise<void> {
+        const request = unparseSwapRoute(
+            address,
+            signature,
+            path,
+            tokenAmountIn,
+            tokenAmountOutLimit,
+            nonce,
+        )
+        const response = await this.request(request)
+        const txId = parseTxId(response)
+        await this.waitTransaction(txId)
+    }
+
+    public async transferTokens(
+        address: string,
+        signature: [bigint, bigint],
+        receiverAddress: string,
+        tokenId: bigint,
+        amount: bigint,
+        nonce: bigint,
+    ): Promise<void> {
+        const request = unparseTransferTokens(
+            address,
+            signature,
+            receiverAddress,
+            tokenId,
+            amount,
+            nonce,
+        )
+        const response = await this.request(request)
+        const txId = parseTxId(response)
+        await this.waitTransaction(txId)
+    }
+
+    public async placeOrder(
+        address: string,
+        signature: [bigint, bigint],
+        tokenInId: bigint,
+        tokenOutId: bigint,
+        tokenAmountIn: bigint,
+        tokenAmountOutLimit: bigint,
+        nonce: bigint,
+    ): Promise<void> {
+        const request = unparsePlaceOrder(
+            address,
+            signature,
+            tokenInId,
+            tokenOutId,
+            tokenAmountIn,
+            tokenAmountOutLimit,
+            nonce,
+        )
+        const response = await this.request(request)
+        const txId = parseTxId(response)
+        await this.waitTransaction(txId)
+    }
+
+    public async cancelOrder(
+        address: string,
+        signature: [bigint, bigint],
+        orderId: bigint,
+        nonce: bigint,
+    ): Promise<void> {
+        const request = unparseCancelOrder(address, signature, orderId, nonce)
+        const response = await this.request(request)
+        const txId = parseTxId(response)
+        await this.waitTransaction(txId)
+    }
+
+    public async claimProtocolFees(
+        address: string,
+        signature: [bigint, bigint],
+        baseTokenId: bigint,
+        quoteTokenId: bigint,
+        nonce: bigint,
+    ): Promise<void> {
+        const request = unparseClaimProtocolFees(
+            address,
+            signature,
+            baseTokenId,
+            quoteTokenId,
+            nonce,
         )
         const response = await this.request(request)
         const txId = parseTxId(response)
@@ -1,6 +1,6 @@
 export const RPC_SERVER_URL = "http://localhost:2345"
 
-export const RPC_REQUEST_SIZE = 264
+export const RPC_REQUEST_SIZE = 240
 
 /**
  * Example: `B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb`
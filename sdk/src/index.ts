@@ -3,6 +3,12 @@ export { Balance } from "./types/balance.js"
 export { BridgeWitnesses } from "./types/bridge-witnesses.js"
 export { Burn } from "./types/burn.js"
 export { Liquidity } from "./types/liquidity.js"
+export { Order } from "./types/order.js"
 export { Pool } from "./types/pool.js"
+export { PoolStats, TopPool, TradeStats } from "./types/pool-stats.js"
+export { Position } from "./types/position.js"
+export { ProtocolFee } from "./types/protocol-fee.js"
+export { Quote } from "./types/quote.js"
+export { Route } from "./types/route.js"
 export { Sibling } from "./types/siblings.js"
 export { TxStatus } from "./types/tx-status.js"
@@ -0,0 +1,17 @@
+import { uint64DecodeFrom } from "../utils/uint64.js"
+
+export const parseGetNonce = (buffer: ArrayBuffer): bigint => {
+    if (buffer.byteLength < 9) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+
+    if (array[0] !== 12) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    const nonce = uint64DecodeFrom(array.subarray(1, 9))
+
+    return nonce
+}
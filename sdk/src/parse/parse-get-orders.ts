@@ -0,0 +1,40 @@
+import { Order } from "../types/order.js"
+import { uint256DecodeFrom } from "../utils/uint256.js"
+import { uint64DecodeFrom } from "../utils/uint64.js"
+
+export const parseGetOrders = (buffer: ArrayBuffer): Array<Order> => {
+    if (buffer.byteLength === 0) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+
+    const ordersLength = (buffer.byteLength - 1) / 89
+    const orders: Array<Order> = []
+
+    if (array[0] !== 19) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    for (let i = 0; i < ordersLength; i++) {
+        const padding = i * 89 + 1
+        const orderId = uint64DecodeFrom(array.subarray(padding, padding + 8))
+        const tokenInId = uint256DecodeFrom(array.subarray(padding + 8, padding + 40))
+        const tokenOutId = uint256DecodeFrom(array.subarray(padding + 40, padding + 72))
+        const tokenAmountIn = uint64DecodeFrom(array.subarray(padding + 72, padding + 80))
+        const tokenAmountOutLimit = uint64DecodeFrom(array.subarray(padding + 80, padding + 88))
+        const status =
+            array[padding + 88] === 0 ? "open" : array[padding + 88] === 1 ? "filled" : "cancelled"
+
+        orders.push({
+            orderId,
+            tokenInId,
+            tokenOutId,
+            tokenAmountIn,
+            tokenAmountOutLimit,
+            status,
+        })
+    }
+
+    return orders
+}
@@ -0,0 +1,29 @@
+import { PoolStats, TradeStats } from "../types/pool-stats.js"
+import { uint64DecodeFrom } from "../utils/uint64.js"
+
+export const parseGetPoolStats = (buffer: ArrayBuffer): PoolStats => {
+    if (buffer.byteLength < 81) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+
+    if (array[0] !== 22) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    return {
+        day: parseTradeStats(array.subarray(1, 41)),
+        week: parseTradeStats(array.subarray(41, 81)),
+    }
+}
+
+export const parseTradeStats = (array: Uint8Array): TradeStats => {
+    return {
+        baseTokenVolume: uint64DecodeFrom(array.subarray(0, 8)),
+        quoteTokenVolume: uint64DecodeFrom(array.subarray(8, 16)),
+        baseTokenFees: uint64DecodeFrom(array.subarray(16, 24)),
+        quoteTokenFees: uint64DecodeFrom(array.subarray(24, 32)),
+        tradeCount: uint64DecodeFrom(array.subarray(32, 40)),
+    }
+}
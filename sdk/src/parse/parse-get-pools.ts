@@ -9,7 +9,7 @@ export const parseGetPools = (buffer: ArrayBuffer): Array<Pool> => {
 
     const array = new Uint8Array(buffer)
 
-    const poolsLength = (buffer.byteLength - 1) / 112
+    const poolsLength = (buffer.byteLength - 1) / 128
     const pools: Array<Pool> = new Array(poolsLength)
 
     if (array[0] !== 4) {
@@ -17,12 +17,14 @@ export const parseGetPools = (buffer: ArrayBuffer): Array<Pool> => {
     }
 
     for (let i = 0; i < poolsLength; i++) {
-        const padding = i * 112 + 1
+        const padding = i * 128 + 1
         const baseTokenId = uint256DecodeFrom(array.subarray(padding, padding + 32))
         const quoteTokenId = uint256DecodeFrom(array.subarray(padding + 32, padding + 64))
         const baseTokenAmount = uint64DecodeFrom(array.subarray(padding + 64, padding + 72))
         const quoteTokenAmount = uint64DecodeFrom(array.subarray(padding + 72, padding + 80))
         const totalLiquidityPoints = uint256DecodeFrom(array.subarray(padding + 80, padding + 112))
+        const feeRate = uint64DecodeFrom(array.subarray(padding + 112, padding + 120))
+        const amplification = uint64DecodeFrom(array.subarray(padding + 120, padding + 128))
 
         pools.push({
             baseTokenId,
@@ -30,6 +32,8 @@ export const parseGetPools = (buffer: ArrayBuffer): Array<Pool> => {
             baseTokenAmount,
             quoteTokenAmount,
             totalLiquidityPoints,
+            feeRate,
+            amplification,
         })
     }
 
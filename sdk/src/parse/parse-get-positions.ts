@@ -0,0 +1,44 @@
+import { Position } from "../types/position.js"
+import { uint256DecodeFrom } from "../utils/uint256.js"
+import { uint64DecodeFrom } from "../utils/uint64.js"
+
+export const parseGetPositions = (buffer: ArrayBuffer): Array<Position> => {
+    if (buffer.byteLength === 0) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+    const view = new DataView(buffer)
+
+    const positionsLength = (buffer.byteLength - 1) / 136
+    const positions: Array<Position> = []
+
+    if (array[0] !== 21) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    for (let i = 0; i < positionsLength; i++) {
+        const padding = i * 136 + 1
+        const baseTokenId = uint256DecodeFrom(array.subarray(padding, padding + 32))
+        const quoteTokenId = uint256DecodeFrom(array.subarray(padding + 32, padding + 64))
+        const points = uint256DecodeFrom(array.subarray(padding + 64, padding + 96))
+        const share = view.getFloat64(padding + 96, true)
+        const baseTokenAmount = uint64DecodeFrom(array.subarray(padding + 104, padding + 112))
+        const quoteTokenAmount = uint64DecodeFrom(array.subarray(padding + 112, padding + 120))
+        const feeBaseTokenAmount = uint64DecodeFrom(array.subarray(padding + 120, padding + 128))
+        const feeQuoteTokenAmount = uint64DecodeFrom(array.subarray(padding + 128, padding + 136))
+
+        positions.push({
+            baseTokenId,
+            quoteTokenId,
+            points,
+            share,
+            baseTokenAmount,
+            quoteTokenAmount,
+            feeBaseTokenAmount,
+            feeQuoteTokenAmount,
+        })
+    }
+
+    return positions
+}
@@ -0,0 +1,35 @@
+import { ProtocolFee } from "../types/protocol-fee.js"
+import { uint256DecodeFrom } from "../utils/uint256.js"
+import { uint64DecodeFrom } from "../utils/uint64.js"
+
+export const parseGetProtocolFees = (buffer: ArrayBuffer): Array<ProtocolFee> => {
+    if (buffer.byteLength === 0 || (buffer.byteLength - 1) % 80 !== 0) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+
+    if (array[0] !== 18) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    const protocolFeesLength = (buffer.byteLength - 1) / 80
+    const protocolFees: Array<ProtocolFee> = []
+
+    for (let i = 0; i < protocolFeesLength; i++) {
+        const padding = i * 80 + 1
+        const baseTokenId = uint256DecodeFrom(array.subarray(padding, padding + 32))
+        const quoteTokenId = uint256DecodeFrom(array.subarray(padding + 32, padding + 64))
+        const baseTokenAmount = uint64DecodeFrom(array.subarray(padding + 64, padding + 72))
+        const quoteTokenAmount = uint64DecodeFrom(array.subarray(padding + 72, padding + 80))
+
+        protocolFees.push({
+            baseTokenId,
+            quoteTokenId,
+            baseTokenAmount,
+            quoteTokenAmount,
+        })
+    }
+
+    return protocolFees
+}
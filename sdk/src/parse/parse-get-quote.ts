@@ -0,0 +1,24 @@
+import { Quote } from "../types/quote.js"
+import { uint64DecodeFrom } from "../utils/uint64.js"
+
+export const parseGetQuote = (buffer: ArrayBuffer): Quote => {
+    if (buffer.byteLength < 49) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+    const view = new DataView(buffer)
+
+    if (array[0] !== 16) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    return {
+        amountIn: uint64DecodeFrom(array.subarray(1, 9)),
+        amountOut: uint64DecodeFrom(array.subarray(9, 17)),
+        fee: uint64DecodeFrom(array.subarray(17, 25)),
+        spotPrice: view.getFloat64(25, true),
+        effectivePrice: view.getFloat64(33, true),
+        priceImpact: view.getFloat64(41, true),
+    }
+}
@@ -0,0 +1,32 @@
+import { Route } from "../types/route.js"
+import { uint256DecodeFrom } from "../utils/uint256.js"
+import { uint64DecodeFrom } from "../utils/uint64.js"
+
+export const parseGetRoute = (buffer: ArrayBuffer): Route => {
+    if (buffer.byteLength < 2) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+
+    if (array[0] !== 17) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    const count = array[1]
+
+    if (buffer.byteLength !== 2 + count * 32 + 8) {
+        throw Error("Mistaken response data.")
+    }
+
+    const path: Array<bigint> = []
+
+    for (let i = 0; i < count; i++) {
+        path.push(uint256DecodeFrom(array.subarray(2 + i * 32, 2 + (i + 1) * 32)))
+    }
+
+    return {
+        path,
+        amountOut: uint64DecodeFrom(array.subarray(2 + count * 32, 2 + count * 32 + 8)),
+    }
+}
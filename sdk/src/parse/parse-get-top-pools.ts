@@ -0,0 +1,33 @@
+import { TopPool } from "../types/pool-stats.js"
+import { uint256DecodeFrom } from "../utils/uint256.js"
+import { parseTradeStats } from "./parse-get-pool-stats.js"
+
+export const parseGetTopPools = (buffer: ArrayBuffer): Array<TopPool> => {
+    if (buffer.byteLength === 0) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+
+    const topPoolsLength = (buffer.byteLength - 1) / 104
+    const topPools: Array<TopPool> = []
+
+    if (array[0] !== 23) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    for (let i = 0; i < topPoolsLength; i++) {
+        const padding = i * 104 + 1
+        const baseTokenId = uint256DecodeFrom(array.subarray(padding, padding + 32))
+        const quoteTokenId = uint256DecodeFrom(array.subarray(padding + 32, padding + 64))
+        const day = parseTradeStats(array.subarray(padding + 64, padding + 104))
+
+        topPools.push({
+            baseTokenId,
+            quoteTokenId,
+            day,
+        })
+    }
+
+    return topPools
+}
@@ -0,0 +1,14 @@
+export const parseGetTwap = (buffer: ArrayBuffer): bigint => {
+    if (buffer.byteLength < 17) {
+        throw Error("Mistaken response data.")
+    }
+
+    const array = new Uint8Array(buffer)
+    const view = new DataView(buffer)
+
+    if (array[0] !== 20) {
+        throw Error("Mistaken RPC response.")
+    }
+
+    return (view.getBigUint64(9, true) << 64n) | view.getBigUint64(1, true)
+}
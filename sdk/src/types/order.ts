@@ -0,0 +1,8 @@
+export interface Order {
+    orderId: bigint
+    tokenInId: bigint
+    tokenOutId: bigint
+    tokenAmountIn: bigint
+    tokenAmountOutLimit: bigint
+    status: "open" | "filled" | "cancelled"
+}
@@ -0,0 +1,18 @@
+export interface TradeStats {
+    baseTokenVolume: bigint
+    quoteTokenVolume: bigint
+    baseTokenFees: bigint
+    quoteTokenFees: bigint
+    tradeCount: bigint
+}
+
+export interface PoolStats {
+    day: TradeStats
+    week: TradeStats
+}
+
+export interface TopPool {
+    baseTokenId: bigint
+    quoteTokenId: bigint
+    day: TradeStats
+}
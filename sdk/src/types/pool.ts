@@ -4,4 +4,6 @@ export interface Pool {
     baseTokenAmount: bigint
     quoteTokenAmount: bigint
     totalLiquidityPoints: bigint
+    feeRate: bigint
+    amplification: bigint
 }
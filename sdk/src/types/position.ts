@@ -0,0 +1,10 @@
+export interface Position {
+    baseTokenId: bigint
+    quoteTokenId: bigint
+    points: bigint
+    share: number
+    baseTokenAmount: bigint
+    quoteTokenAmount: bigint
+    feeBaseTokenAmount: bigint
+    feeQuoteTokenAmount: bigint
+}
@@ -0,0 +1,6 @@
+export interface ProtocolFee {
+    baseTokenId: bigint
+    quoteTokenId: bigint
+    baseTokenAmount: bigint
+    quoteTokenAmount: bigint
+}
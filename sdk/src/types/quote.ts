@@ -0,0 +1,8 @@
+export interface Quote {
+    amountIn: bigint
+    amountOut: bigint
+    fee: bigint
+    spotPrice: number
+    effectivePrice: number
+    priceImpact: number
+}
@@ -0,0 +1,4 @@
+export interface Route {
+    path: Array<bigint>
+    amountOut: bigint
+}
@@ -9,6 +9,7 @@ export const unparseBurnTokens = (
     signature: [bigint, bigint],
     tokenId: bigint,
     amount: bigint,
+    nonce: bigint,
 ): ArrayBuffer => {
     const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
     const array = new Uint8Array(buffer)
@@ -19,6 +20,7 @@ export const unparseBurnTokens = (
     signatureEncodeInto(signature, array.subarray(56, 120))
     uint256EncodeInto(tokenId, array.subarray(120, 152))
     uint64EncodeInto(amount, array.subarray(152, 160))
+    uint64EncodeInto(nonce, array.subarray(160, 168))
 
     return buffer
 }
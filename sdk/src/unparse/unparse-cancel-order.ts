@@ -0,0 +1,23 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+import { addressEncodeInto } from "../utils/address.js"
+import { signatureEncodeInto } from "../utils/signature.js"
+import { uint64EncodeInto } from "../utils/uint64.js"
+
+export const unparseCancelOrder = (
+    address: string,
+    signature: [bigint, bigint],
+    orderId: bigint,
+    nonce: bigint,
+): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 24
+
+    addressEncodeInto(address, array.subarray(1, 56))
+    signatureEncodeInto(signature, array.subarray(56, 120))
+    uint64EncodeInto(orderId, array.subarray(120, 128))
+    uint64EncodeInto(nonce, array.subarray(128, 136))
+
+    return buffer
+}
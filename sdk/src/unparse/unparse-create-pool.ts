@@ -11,6 +11,10 @@ export const unparseCreatePool = (
     quoteTokenId: bigint,
     baseTokenAmount: bigint,
     quoteTokenAmount: bigint,
+    feeRate: bigint,
+    poolKind: "constant_product" | "stable_swap",
+    amplification: bigint,
+    nonce: bigint,
 ): ArrayBuffer => {
     const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
     const array = new Uint8Array(buffer)
@@ -23,6 +27,10 @@ export const unparseCreatePool = (
     uint256EncodeInto(quoteTokenId, array.subarray(152, 184))
     uint64EncodeInto(baseTokenAmount, array.subarray(184, 192))
     uint64EncodeInto(quoteTokenAmount, array.subarray(192, 200))
+    uint64EncodeInto(feeRate, array.subarray(200, 208))
+    array[208] = poolKind === "constant_product" ? 0 : 1
+    uint64EncodeInto(amplification, array.subarray(209, 217))
+    uint64EncodeInto(nonce, array.subarray(217, 225))
 
     return buffer
 }
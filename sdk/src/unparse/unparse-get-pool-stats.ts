@@ -0,0 +1,14 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+import { uint256EncodeInto } from "../utils/uint256.js"
+
+export const unparseGetPoolStats = (baseTokenId: bigint, quoteTokenId: bigint): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 28
+
+    uint256EncodeInto(baseTokenId, array.subarray(1, 33))
+    uint256EncodeInto(quoteTokenId, array.subarray(33, 65))
+
+    return buffer
+}
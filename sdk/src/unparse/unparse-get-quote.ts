@@ -0,0 +1,22 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+import { uint256EncodeInto } from "../utils/uint256.js"
+import { uint64EncodeInto } from "../utils/uint64.js"
+
+export const unparseGetQuote = (
+    baseTokenId: bigint,
+    quoteTokenId: bigint,
+    side: "buy" | "sell",
+    amount: bigint,
+): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 18
+
+    uint256EncodeInto(baseTokenId, array.subarray(1, 33))
+    uint256EncodeInto(quoteTokenId, array.subarray(33, 65))
+    array[65] = side === "buy" ? 0 : 1
+    uint64EncodeInto(amount, array.subarray(66, 74))
+
+    return buffer
+}
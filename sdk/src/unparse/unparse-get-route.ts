@@ -0,0 +1,22 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+import { uint256EncodeInto } from "../utils/uint256.js"
+import { uint64EncodeInto } from "../utils/uint64.js"
+
+export const unparseGetRoute = (
+    tokenInId: bigint,
+    tokenOutId: bigint,
+    tokenAmountIn: bigint,
+    maxHops: number,
+): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 20
+
+    uint256EncodeInto(tokenInId, array.subarray(1, 33))
+    uint256EncodeInto(tokenOutId, array.subarray(33, 65))
+    uint64EncodeInto(tokenAmountIn, array.subarray(65, 73))
+    array[73] = maxHops
+
+    return buffer
+}
@@ -0,0 +1,10 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+
+export const unparseGetTopPools = (): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 29
+
+    return buffer
+}
@@ -0,0 +1,20 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+import { uint256EncodeInto } from "../utils/uint256.js"
+import { uint64EncodeInto } from "../utils/uint64.js"
+
+export const unparseGetTwap = (
+    baseTokenId: bigint,
+    quoteTokenId: bigint,
+    window: bigint,
+): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 26
+
+    uint256EncodeInto(baseTokenId, array.subarray(1, 33))
+    uint256EncodeInto(quoteTokenId, array.subarray(33, 65))
+    uint64EncodeInto(window, array.subarray(65, 73))
+
+    return buffer
+}
@@ -0,0 +1,30 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+import { addressEncodeInto } from "../utils/address.js"
+import { signatureEncodeInto } from "../utils/signature.js"
+import { uint256EncodeInto } from "../utils/uint256.js"
+import { uint64EncodeInto } from "../utils/uint64.js"
+
+export const unparsePlaceOrder = (
+    address: string,
+    signature: [bigint, bigint],
+    tokenInId: bigint,
+    tokenOutId: bigint,
+    tokenAmountIn: bigint,
+    tokenAmountOutLimit: bigint,
+    nonce: bigint,
+): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 23
+
+    addressEncodeInto(address, array.subarray(1, 56))
+    signatureEncodeInto(signature, array.subarray(56, 120))
+    uint256EncodeInto(tokenInId, array.subarray(120, 152))
+    uint256EncodeInto(tokenOutId, array.subarray(152, 184))
+    uint64EncodeInto(tokenAmountIn, array.subarray(184, 192))
+    uint64EncodeInto(tokenAmountOutLimit, array.subarray(192, 200))
+    uint64EncodeInto(nonce, array.subarray(200, 208))
+
+    return buffer
+}
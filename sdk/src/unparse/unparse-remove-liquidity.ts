@@ -12,6 +12,7 @@ export const unparseRemoveLiquidity = (
     baseTokenAmountLimit: bigint,
     quoteTokenAmountLimit: bigint,
     liquidityPointAmount: bigint,
+    nonce: bigint,
 ): ArrayBuffer => {
     const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
     const array = new Uint8Array(buffer)
@@ -25,6 +26,7 @@ export const unparseRemoveLiquidity = (
     uint64EncodeInto(baseTokenAmountLimit, array.subarray(184, 192))
     uint64EncodeInto(quoteTokenAmountLimit, array.subarray(192, 200))
     uint256EncodeInto(liquidityPointAmount, array.subarray(200, 232))
+    uint64EncodeInto(nonce, array.subarray(232, 240))
 
     return buffer
 }
@@ -11,6 +11,7 @@ export const unparseSellTokens = (
     quoteTokenId: bigint,
     baseTokenAmountLimit: bigint,
     quoteTokenAmount: bigint,
+    nonce: bigint,
 ): ArrayBuffer => {
     const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
     const array = new Uint8Array(buffer)
@@ -23,6 +24,7 @@ export const unparseSellTokens = (
     uint256EncodeInto(quoteTokenId, array.subarray(152, 184))
     uint64EncodeInto(baseTokenAmountLimit, array.subarray(184, 192))
     uint64EncodeInto(quoteTokenAmount, array.subarray(192, 200))
+    uint64EncodeInto(nonce, array.subarray(200, 208))
 
     return buffer
 }
@@ -0,0 +1,30 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+import { addressEncodeInto } from "../utils/address.js"
+import { signatureEncodeInto } from "../utils/signature.js"
+import { uint256EncodeInto } from "../utils/uint256.js"
+import { uint64EncodeInto } from "../utils/uint64.js"
+
+export const unparseSwapRoute = (
+    address: string,
+    signature: [bigint, bigint],
+    path: [bigint, bigint, bigint],
+    tokenAmountIn: bigint,
+    tokenAmountOutLimit: bigint,
+    nonce: bigint,
+): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 19
+
+    addressEncodeInto(address, array.subarray(1, 56))
+    signatureEncodeInto(signature, array.subarray(56, 120))
+    uint256EncodeInto(path[0], array.subarray(120, 152))
+    uint256EncodeInto(path[1], array.subarray(152, 184))
+    uint256EncodeInto(path[2], array.subarray(184, 216))
+    uint64EncodeInto(tokenAmountIn, array.subarray(216, 224))
+    uint64EncodeInto(tokenAmountOutLimit, array.subarray(224, 232))
+    uint64EncodeInto(nonce, array.subarray(232, 240))
+
+    return buffer
+}
@@ -0,0 +1,28 @@
+import { RPC_REQUEST_SIZE } from "../constants.js"
+import { addressEncodeInto } from "../utils/address.js"
+import { signatureEncodeInto } from "../utils/signature.js"
+import { uint256EncodeInto } from "../utils/uint256.js"
+import { uint64EncodeInto } from "../utils/uint64.js"
+
+export const unparseTransferTokens = (
+    address: string,
+    signature: [bigint, bigint],
+    receiverAddress: string,
+    tokenId: bigint,
+    amount: bigint,
+    nonce: bigint,
+): ArrayBuffer => {
+    const buffer = new ArrayBuffer(RPC_REQUEST_SIZE)
+    const array = new Uint8Array(buffer)
+
+    array[0] = 21
+
+    addressEncodeInto(address, array.subarray(1, 56))
+    signatureEncodeInto(signature, array.subarray(56, 120))
+    addressEncodeInto(receiverAddress, array.subarray(120, 175))
+    uint256EncodeInto(tokenId, array.subarray(175, 207))
+    uint64EncodeInto(amount, array.subarray(207, 215))
+    uint64EncodeInto(nonce, array.subarray(215, 223))
+
+    return buffer
+}
@@ -0,0 +1,114 @@
+// Generates the message vectors checked by the signature verifier process of `nacho-processes`.
+//
+// Each line of the output is the kind of a transaction, the request the SDK encodes for it in hex,
+// the `r` and `s` of the signature in the request and the fields the JS verifier checks the signature against.
+// The signature isn't a real one, so the vectors can be generated without o1js.
+//
+// Usage (after building the SDK with `npm run build` in `sdk/`):
+//
+//     node scripts/generate-message-vectors.mjs > ../processes/src/verifier/message_vectors.txt
+
+import { createHash } from "node:crypto"
+import { unparseBurnTokens } from "../../sdk/build/unparse/unparse-burn-tokens.js"
+import { unparseBuyTokens } from "../../sdk/build/unparse/unparse-buy-tokens.js"
+import { unparseCancelOrder } from "../../sdk/build/unparse/unparse-cancel-order.js"
+import { unparseClaimProtocolFees } from "../../sdk/build/unparse/unparse-claim-protocol-fees.js"
+import { unparseCreatePool } from "../../sdk/build/unparse/unparse-create-pool.js"
+import { unparsePlaceOrder } from "../../sdk/build/unparse/unparse-place-order.js"
+import { unparseProvideLiquidity } from "../../sdk/build/unparse/unparse-provide-liquidity.js"
+import { unparseRemoveLiquidity } from "../../sdk/build/unparse/unparse-remove-liquidity.js"
+import { unparseSellTokens } from "../../sdk/build/unparse/unparse-sell-tokens.js"
+import { unparseSwapRoute } from "../../sdk/build/unparse/unparse-swap-route.js"
+import { unparseTransferTokens } from "../../sdk/build/unparse/unparse-transfer-tokens.js"
+
+const address = "B62qoTFrus93Ryi1VzbFakzErBBmcikHEq27vhMkU4FfjGfCovv41fb"
+const receiverAddress = "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+
+// The signature only has to use every byte of its encoding, as it isn't verified.
+const signature = [2n ** 250n + 0x0123456789abcdefn, 2n ** 249n + 0xfedcba9876543210n]
+
+const BASE58_ALPHABET = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+
+// Returns the fields of a public key the way o1js' `PublicKey.toFields` does, which are its `x` and `isOdd`.
+//
+// The address is the Base58Check encoding of a version byte, two bytes of prefix, `x` in little-endian and `isOdd`.
+const publicKeyFields = (address) => {
+    let number = 0n
+    for (const char of address) {
+        number = number * 58n + BigInt(BASE58_ALPHABET.indexOf(char))
+    }
+
+    const bytes = Buffer.from(number.toString(16).padStart(80, "0"), "hex")
+    const payload = bytes.subarray(0, 36)
+    const checksum = createHash("sha256")
+        .update(createHash("sha256").update(payload).digest())
+        .digest()
+        .subarray(0, 4)
+
+    if (!checksum.equals(bytes.subarray(36, 40))) {
+        throw Error("Cannot decode invalid address.")
+    }
+
+    let x = 0n
+    for (let i = 34; i >= 3; i--) {
+        x = (x << 8n) | BigInt(payload[i])
+    }
+
+    return [x, BigInt(payload[35])]
+}
+
+const hex = (buffer) => Buffer.from(buffer).toString("hex")
+
+const vectors = [
+    ["burn_tokens", unparseBurnTokens(address, signature, 1n, 500n, 0n), [1n, 500n, 0n]],
+    [
+        "create_pool",
+        unparseCreatePool(address, signature, 0n, 1n, 1000n, 2000n, 30n, "stable_swap", 100n, 1n),
+        [0n, 1n, 1000n, 2000n, 30n, 1n, 100n, 1n],
+    ],
+    [
+        "provide_liquidity",
+        unparseProvideLiquidity(address, signature, 0n, 1n, 100n, 250n, 2n),
+        [0n, 1n, 100n, 250n, 2n],
+    ],
+    [
+        "remove_liquidity",
+        unparseRemoveLiquidity(address, signature, 0n, 1n, 10n, 20n, 12345n, 3n),
+        [0n, 1n, 10n, 20n, 12345n, 3n],
+    ],
+    [
+        "buy_tokens",
+        unparseBuyTokens(address, signature, 0n, 1n, 40n, 90n, 4n),
+        [0n, 1n, 40n, 90n, 4n],
+    ],
+    [
+        "sell_tokens",
+        unparseSellTokens(address, signature, 0n, 1n, 35n, 80n, 5n),
+        [0n, 1n, 35n, 80n, 5n],
+    ],
+    [
+        "swap_route",
+        unparseSwapRoute(address, signature, [0n, 1n, 2n], 60n, 55n, 6n),
+        [0n, 1n, 2n, 60n, 55n, 6n],
+    ],
+    [
+        "transfer_tokens",
+        unparseTransferTokens(address, signature, receiverAddress, 1n, 70n, 7n),
+        [...publicKeyFields(receiverAddress), 1n, 70n, 7n],
+    ],
+    [
+        "place_order",
+        unparsePlaceOrder(address, signature, 0n, 1n, 25n, 45n, 8n),
+        [0n, 1n, 25n, 45n, 8n],
+    ],
+    ["cancel_order", unparseCancelOrder(address, signature, 11n, 9n), [11n, 9n]],
+    [
+        "claim_protocol_fees",
+        unparseClaimProtocolFees(address, signature, 0n, 1n, 10n),
+        [0n, 1n, 10n],
+    ],
+]
+
+for (const [kind, buffer, fields] of vectors) {
+    console.log(`${kind} ${hex(buffer)} ${signature[0]} ${signature[1]} ${fields.join(",")}`)
+}
@@ -2,10 +2,15 @@ import { Field, PublicKey, Signature, UInt64 } from "o1js"
 import {
     BurnTokens,
     BuyTokens,
+    CancelOrder,
+    ClaimProtocolFees,
     CreatePool,
+    PlaceOrder,
     ProvideLiquidity,
     RemoveLiquidity,
     SellTokens,
+    SwapRoute,
+    TransferTokens,
 } from "./input"
 
 export const isBurnTokensSignatureValid = ({
@@ -13,13 +18,18 @@ export const isBurnTokensSignatureValid = ({
     address,
     token_id,
     token_amount,
+    nonce,
 }: BurnTokens) => {
     try {
         const $signature = Signature.fromFields(signature.map((field) => Field(field)))
         const $address = PublicKey.fromBase58(address)
 
         return $signature
-            .verify($address, [Field(token_id), UInt64.from(token_amount).value])
+            .verify($address, [
+                Field(token_id),
+                UInt64.from(token_amount).value,
+                UInt64.from(nonce).value,
+            ])
             .toBoolean()
     } catch {
         return false
@@ -33,6 +43,10 @@ export const isCreatePoolSignatureValid = ({
     quote_token_id,
     base_token_amount,
     quote_token_amount,
+    fee_rate,
+    pool_kind,
+    amplification,
+    nonce,
 }: CreatePool) => {
     try {
         const $signature = Signature.fromFields(signature.map((field) => Field(field)))
@@ -44,6 +58,10 @@ export const isCreatePoolSignatureValid = ({
                 Field(quote_token_id),
                 UInt64.from(base_token_amount).value,
                 UInt64.from(quote_token_amount).value,
+                UInt64.from(fee_rate).value,
+                UInt64.from(pool_kind).value,
+                UInt64.from(amplification).value,
+                UInt64.from(nonce).value,
             ])
             .toBoolean()
     } catch {
@@ -58,6 +76,7 @@ export const isProvideLiquditySignatureValid = ({
     quote_token_id,
     base_token_amount,
     quote_token_amount_limit,
+    nonce,
 }: ProvideLiquidity) => {
     try {
         const $signature = Signature.fromFields(signature.map((field) => Field(field)))
@@ -69,6 +88,7 @@ export const isProvideLiquditySignatureValid = ({
                 Field(quote_token_id),
                 UInt64.from(base_token_amount).value,
                 UInt64.from(quote_token_amount_limit).value,
+                UInt64.from(nonce).value,
             ])
             .toBoolean()
     } catch {
@@ -84,6 +104,7 @@ export const isRemoveLiquditySignatureValid = ({
     base_token_amount_limit,
     quote_token_amount_limit,
     liquidity_point_amount,
+    nonce,
 }: RemoveLiquidity) => {
     try {
         const $signature = Signature.fromFields(signature.map((field) => Field(field)))
@@ -96,6 +117,7 @@ export const isRemoveLiquditySignatureValid = ({
                 UInt64.from(base_token_amount_limit).value,
                 UInt64.from(quote_token_amount_limit).value,
                 Field(liquidity_point_amount),
+                UInt64.from(nonce).value,
             ])
             .toBoolean()
     } catch {
@@ -110,6 +132,7 @@ export const isBuyTokensSignatureValid = ({
     quote_token_id,
     base_token_amount,
     quote_token_amount_limit,
+    nonce,
 }: BuyTokens) => {
     try {
         const $signature = Signature.fromFields(signature.map((field) => Field(field)))
@@ -121,6 +144,7 @@ export const isBuyTokensSignatureValid = ({
                 Field(quote_token_id),
                 UInt64.from(base_token_amount).value,
                 UInt64.from(quote_token_amount_limit).value,
+                UInt64.from(nonce).value,
             ])
             .toBoolean()
     } catch {
@@ -135,6 +159,7 @@ export const isSellTokensSignatureValid = ({
     quote_token_id,
     base_token_amount_limit,
     quote_token_amount,
+    nonce,
 }: SellTokens) => {
     try {
         const $signature = Signature.fromFields(signature.map((field) => Field(field)))
@@ -146,6 +171,129 @@ export const isSellTokensSignatureValid = ({
                 Field(quote_token_id),
                 UInt64.from(base_token_amount_limit).value,
                 UInt64.from(quote_token_amount).value,
+                UInt64.from(nonce).value,
+            ])
+            .toBoolean()
+    } catch {
+        return false
+    }
+}
+
+export const isSwapRouteSignatureValid = ({
+    signature,
+    address,
+    path,
+    token_amount_in,
+    token_amount_out_limit,
+    nonce,
+}: SwapRoute) => {
+    try {
+        const $signature = Signature.fromFields(signature.map((field) => Field(field)))
+        const $address = PublicKey.fromBase58(address)
+
+        return $signature
+            .verify($address, [
+                Field(path[0]),
+                Field(path[1]),
+                Field(path[2]),
+                UInt64.from(token_amount_in).value,
+                UInt64.from(token_amount_out_limit).value,
+                UInt64.from(nonce).value,
+            ])
+            .toBoolean()
+    } catch {
+        return false
+    }
+}
+
+export const isTransferTokensSignatureValid = ({
+    signature,
+    address,
+    receiver_address,
+    token_id,
+    token_amount,
+    nonce,
+}: TransferTokens) => {
+    try {
+        const $signature = Signature.fromFields(signature.map((field) => Field(field)))
+        const $address = PublicKey.fromBase58(address)
+        const $receiverAddress = PublicKey.fromBase58(receiver_address)
+
+        return $signature
+            .verify($address, [
+                $receiverAddress.x,
+                $receiverAddress.isOdd.toField(),
+                Field(token_id),
+                UInt64.from(token_amount).value,
+                UInt64.from(nonce).value,
+            ])
+            .toBoolean()
+    } catch {
+        return false
+    }
+}
+
+export const isPlaceOrderSignatureValid = ({
+    signature,
+    address,
+    token_in_id,
+    token_out_id,
+    token_amount_in,
+    token_amount_out_limit,
+    nonce,
+}: PlaceOrder) => {
+    try {
+        const $signature = Signature.fromFields(signature.map((field) => Field(field)))
+        const $address = PublicKey.fromBase58(address)
+
+        return $signature
+            .verify($address, [
+                Field(token_in_id),
+                Field(token_out_id),
+                UInt64.from(token_amount_in).value,
+                UInt64.from(token_amount_out_limit).value,
+                UInt64.from(nonce).value,
+            ])
+            .toBoolean()
+    } catch {
+        return false
+    }
+}
+
+export const isCancelOrderSignatureValid = ({
+    signature,
+    address,
+    order_id,
+    nonce,
+}: CancelOrder) => {
+    try {
+        const $signature = Signature.fromFields(signature.map((field) => Field(field)))
+        const $address = PublicKey.fromBase58(address)
+
+        return $signature
+            .verify($address, [UInt64.from(order_id).value, UInt64.from(nonce).value])
+            .toBoolean()
+    } catch {
+        return false
+    }
+}
+
+export const isClaimProtocolFeesSignatureValid = ({
+    signature,
+    address,
+    base_token_id,
+    quote_token_id,
+    nonce,
+}: ClaimProtocolFees) => {
+    try {
+        const $signature = Signature.fromFields(signature.map((field) => Field(field)))
+        const $address = PublicKey.fromBase58(address)
+
+        return $signature
+            .verify($address, [
+                Field(base_token_id),
+                Field(quote_token_id),
+                UInt64.from(nonce).value,
             ])
             .toBoolean()
     } catch {
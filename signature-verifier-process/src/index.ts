@@ -3,10 +3,15 @@ import { parseInput } from "./parse"
 import {
     isBurnTokensSignatureValid,
     isBuyTokensSignatureValid,
+    isCancelOrderSignatureValid,
+    isClaimProtocolFeesSignatureValid,
     isCreatePoolSignatureValid,
+    isPlaceOrderSignatureValid,
     isProvideLiquditySignatureValid,
     isRemoveLiquditySignatureValid,
     isSellTokensSignatureValid,
+    isSwapRouteSignatureValid,
+    isTransferTokensSignatureValid,
 } from "./check"
 
 stdin.on("data", async (chunk) => {
@@ -25,6 +30,16 @@ stdin.on("data", async (chunk) => {
             ? isBuyTokensSignatureValid(input)
             : input.kind === "SellTokens"
             ? isSellTokensSignatureValid(input)
+            : input.kind === "SwapRoute"
+            ? isSwapRouteSignatureValid(input)
+            : input.kind === "TransferTokens"
+            ? isTransferTokensSignatureValid(input)
+            : input.kind === "PlaceOrder"
+            ? isPlaceOrderSignatureValid(input)
+            : input.kind === "CancelOrder"
+            ? isCancelOrderSignatureValid(input)
+            : input.kind === "ClaimProtocolFees"
+            ? isClaimProtocolFeesSignatureValid(input)
             : false
 
     const buffer = new ArrayBuffer(1)
@@ -4,6 +4,7 @@ export type BurnTokens = {
     signature: [bigint, bigint]
     token_id: bigint
     token_amount: bigint
+    nonce: bigint
 }
 
 export type CreatePool = {
@@ -14,6 +15,10 @@ export type CreatePool = {
     quote_token_id: bigint
     base_token_amount: bigint
     quote_token_amount: bigint
+    fee_rate: bigint
+    pool_kind: number
+    amplification: bigint
+    nonce: bigint
 }
 
 export type ProvideLiquidity = {
@@ -24,6 +29,7 @@ export type ProvideLiquidity = {
     quote_token_id: bigint
     base_token_amount: bigint
     quote_token_amount_limit: bigint
+    nonce: bigint
 }
 
 export type RemoveLiquidity = {
@@ -35,6 +41,7 @@ export type RemoveLiquidity = {
     base_token_amount_limit: bigint
     quote_token_amount_limit: bigint
     liquidity_point_amount: bigint
+    nonce: bigint
 }
 
 export type BuyTokens = {
@@ -45,6 +52,7 @@ export type BuyTokens = {
     quote_token_id: bigint
     base_token_amount: bigint
     quote_token_amount_limit: bigint
+    nonce: bigint
 }
 
 export type SellTokens = {
@@ -55,6 +63,55 @@ export type SellTokens = {
     quote_token_id: bigint
     base_token_amount_limit: bigint
     quote_token_amount: bigint
+    nonce: bigint
+}
+
+export type SwapRoute = {
+    kind: "SwapRoute"
+    address: string
+    signature: [bigint, bigint]
+    path: [bigint, bigint, bigint]
+    token_amount_in: bigint
+    token_amount_out_limit: bigint
+    nonce: bigint
+}
+
+export type TransferTokens = {
+    kind: "TransferTokens"
+    address: string
+    signature: [bigint, bigint]
+    receiver_address: string
+    token_id: bigint
+    token_amount: bigint
+    nonce: bigint
+}
+
+export type PlaceOrder = {
+    kind: "PlaceOrder"
+    address: string
+    signature: [bigint, bigint]
+    token_in_id: bigint
+    token_out_id: bigint
+    token_amount_in: bigint
+    token_amount_out_limit: bigint
+    nonce: bigint
+}
+
+export type CancelOrder = {
+    kind: "CancelOrder"
+    address: string
+    signature: [bigint, bigint]
+    order_id: bigint
+    nonce: bigint
+}
+
+export type ClaimProtocolFees = {
+    kind: "ClaimProtocolFees"
+    address: string
+    signature: [bigint, bigint]
+    base_token_id: bigint
+    quote_token_id: bigint
+    nonce: bigint
 }
 
 export type MistakenInput = {
@@ -68,4 +125,9 @@ export type Input =
     | RemoveLiquidity
     | BuyTokens
     | SellTokens
+    | SwapRoute
+    | TransferTokens
+    | PlaceOrder
+    | CancelOrder
+    | ClaimProtocolFees
     | MistakenInput
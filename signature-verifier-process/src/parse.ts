@@ -1,18 +1,23 @@
 import {
     BurnTokens,
     BuyTokens,
+    CancelOrder,
+    ClaimProtocolFees,
     CreatePool,
     Input,
     MistakenInput,
+    PlaceOrder,
     ProvideLiquidity,
     RemoveLiquidity,
     SellTokens,
+    SwapRoute,
+    TransferTokens,
 } from "./input"
 
 export const parseInput = (buffer: Buffer): Input => {
     const array = new Uint8Array(buffer)
 
-    if (array.length !== 232) {
+    if (array.length !== 240) {
         return {
             kind: "MistakenInput",
         } satisfies MistakenInput
@@ -26,6 +31,7 @@ export const parseInput = (buffer: Buffer): Input => {
                 signature: parseSignature(buffer.subarray(56, 120)),
                 token_id: parseUint256(buffer.subarray(120, 152)),
                 token_amount: parseUint64(buffer.subarray(152, 160)),
+                nonce: parseUint64(buffer.subarray(160, 168)),
             } satisfies BurnTokens
         }
         case 1: {
@@ -37,6 +43,10 @@ export const parseInput = (buffer: Buffer): Input => {
                 quote_token_id: parseUint256(buffer.subarray(152, 184)),
                 base_token_amount: parseUint64(buffer.subarray(184, 192)),
                 quote_token_amount: parseUint64(buffer.subarray(192, 200)),
+                fee_rate: parseUint64(buffer.subarray(200, 208)),
+                pool_kind: buffer[208],
+                amplification: parseUint64(buffer.subarray(209, 217)),
+                nonce: parseUint64(buffer.subarray(217, 225)),
             } satisfies CreatePool
         }
         case 2: {
@@ -48,6 +58,7 @@ export const parseInput = (buffer: Buffer): Input => {
                 quote_token_id: parseUint256(buffer.subarray(152, 184)),
                 base_token_amount: parseUint64(buffer.subarray(184, 192)),
                 quote_token_amount_limit: parseUint64(buffer.subarray(192, 200)),
+                nonce: parseUint64(buffer.subarray(200, 208)),
             } satisfies ProvideLiquidity
         }
         case 3: {
@@ -60,6 +71,7 @@ export const parseInput = (buffer: Buffer): Input => {
                 base_token_amount_limit: parseUint64(buffer.subarray(184, 192)),
                 quote_token_amount_limit: parseUint64(buffer.subarray(192, 200)),
                 liquidity_point_amount: parseUint256(buffer.subarray(200, 232)),
+                nonce: parseUint64(buffer.subarray(232, 240)),
             } satisfies RemoveLiquidity
         }
         case 4: {
@@ -71,6 +83,7 @@ export const parseInput = (buffer: Buffer): Input => {
                 quote_token_id: parseUint256(buffer.subarray(152, 184)),
                 base_token_amount: parseUint64(buffer.subarray(184, 192)),
                 quote_token_amount_limit: parseUint64(buffer.subarray(192, 200)),
+                nonce: parseUint64(buffer.subarray(200, 208)),
             } satisfies BuyTokens
         }
         case 5: {
@@ -82,8 +95,66 @@ export const parseInput = (buffer: Buffer): Input => {
                 quote_token_id: parseUint256(buffer.subarray(152, 184)),
                 base_token_amount_limit: parseUint64(buffer.subarray(184, 192)),
                 quote_token_amount: parseUint64(buffer.subarray(192, 200)),
+                nonce: parseUint64(buffer.subarray(200, 208)),
             } satisfies SellTokens
         }
+        case 6: {
+            return {
+                kind: "SwapRoute",
+                address: parseAddress(buffer.subarray(1, 56)),
+                signature: parseSignature(buffer.subarray(56, 120)),
+                path: [
+                    parseUint256(buffer.subarray(120, 152)),
+                    parseUint256(buffer.subarray(152, 184)),
+                    parseUint256(buffer.subarray(184, 216)),
+                ],
+                token_amount_in: parseUint64(buffer.subarray(216, 224)),
+                token_amount_out_limit: parseUint64(buffer.subarray(224, 232)),
+                nonce: parseUint64(buffer.subarray(232, 240)),
+            } satisfies SwapRoute
+        }
+        case 7: {
+            return {
+                kind: "TransferTokens",
+                address: parseAddress(buffer.subarray(1, 56)),
+                signature: parseSignature(buffer.subarray(56, 120)),
+                receiver_address: parseAddress(buffer.subarray(120, 175)),
+                token_id: parseUint256(buffer.subarray(175, 207)),
+                token_amount: parseUint64(buffer.subarray(207, 215)),
+                nonce: parseUint64(buffer.subarray(215, 223)),
+            } satisfies TransferTokens
+        }
+        case 8: {
+            return {
+                kind: "PlaceOrder",
+                address: parseAddress(buffer.subarray(1, 56)),
+                signature: parseSignature(buffer.subarray(56, 120)),
+                token_in_id: parseUint256(buffer.subarray(120, 152)),
+                token_out_id: parseUint256(buffer.subarray(152, 184)),
+                token_amount_in: parseUint64(buffer.subarray(184, 192)),
+                token_amount_out_limit: parseUint64(buffer.subarray(192, 200)),
+                nonce: parseUint64(buffer.subarray(200, 208)),
+            } satisfies PlaceOrder
+        }
+        case 9: {
+            return {
+                kind: "CancelOrder",
+                address: parseAddress(buffer.subarray(1, 56)),
+                signature: parseSignature(buffer.subarray(56, 120)),
+                order_id: parseUint64(buffer.subarray(120, 128)),
+                nonce: parseUint64(buffer.subarray(128, 136)),
+            } satisfies CancelOrder
+        }
+        case 10: {
+            return {
+                kind: "ClaimProtocolFees",
+                address: parseAddress(buffer.subarray(1, 56)),
+                signature: parseSignature(buffer.subarray(56, 120)),
+                base_token_id: parseUint256(buffer.subarray(120, 152)),
+                quote_token_id: parseUint256(buffer.subarray(152, 184)),
+                nonce: parseUint64(buffer.subarray(184, 192)),
+            } satisfies ClaimProtocolFees
+        }
         default: {
             return {
                 kind: "MistakenInput",
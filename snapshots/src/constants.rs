@@ -0,0 +1,22 @@
+/// The state directories that are copied into snapshots, as the environment variables of their paths and their names in a snapshot.
+///
+/// The write-ahead log is empty at transaction boundaries, it is included so a restore also clears the current one.
+pub const STATE_DIRS: [(&str, &str); 14] = [
+    ("NACHO_PROOFS_PATH", "proofs"),
+    ("NACHO_BALANCES_DB_PATH", "balances-db"),
+    ("NACHO_LIQUIDITIES_DB_PATH", "liquidities-db"),
+    ("NACHO_POOLS_DB_PATH", "pools-db"),
+    ("NACHO_ORDERS_DB_PATH", "orders-db"),
+    ("NACHO_OBSERVATIONS_DB_PATH", "observations-db"),
+    ("NACHO_ANALYTICS_DB_PATH", "analytics-db"),
+    ("NACHO_BURNS_DB_PATH", "burns-db"),
+    ("NACHO_WITHRAWALS_DB_PATH", "withdrawals-db"),
+    ("NACHO_TRANSACTIONS_DB_PATH", "transactions-db"),
+    ("NACHO_EVENTS_DB_PATH", "events-db"),
+    ("NACHO_MEMPOOL_PATH", "mempool"),
+    ("NACHO_PROOFPOOL_PATH", "proofpool"),
+    ("NACHO_WRITE_AHEAD_LOG_PATH", "write-ahead-log"),
+];
+
+/// The count of snapshots that are kept, older snapshots are removed after a new one is taken.
+pub const SNAPSHOTS_TO_KEEP: usize = 3;
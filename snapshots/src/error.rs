@@ -0,0 +1,30 @@
+use nacho_balances_db::BalancesDbError;
+use nacho_burns_db::BurnsDbError;
+use nacho_liquidities_db::LiquiditiesDbError;
+use nacho_orders_db::OrdersDbError;
+use nacho_pools_db::PoolsDbError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SnapshotsError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    BalancesDb(#[from] BalancesDbError),
+    #[error(transparent)]
+    BurnsDb(#[from] BurnsDbError),
+    #[error(transparent)]
+    LiquiditiesDb(#[from] LiquiditiesDbError),
+    #[error(transparent)]
+    OrdersDb(#[from] OrdersDbError),
+    #[error(transparent)]
+    PoolsDb(#[from] PoolsDbError),
+    #[error("Snapshot doesn't exist.")]
+    SnapshotDoesntExist,
+    #[error("Snapshot manifest is corrupted.")]
+    CorruptedManifest,
+    #[error("Snapshot is missing the state directory `{0}`.")]
+    StateDirectoryMissing(String),
+    #[error("Snapshot's {0} root doesn't match its manifest.")]
+    RootMismatch(&'static str),
+}
@@ -0,0 +1,9 @@
+mod constants;
+mod error;
+mod manifest;
+mod snapshots;
+
+pub use constants::{SNAPSHOTS_TO_KEEP, STATE_DIRS};
+pub use error::SnapshotsError;
+pub use manifest::SnapshotManifest;
+pub use snapshots::Snapshots;
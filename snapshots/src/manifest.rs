@@ -0,0 +1,33 @@
+use nacho_data_structures::{ByteConversion, StateRoots};
+
+/// The data structure that describes the state a snapshot is taken at.
+///
+/// - The `executed_until` and `proved_until` properties are the transaction counters of Transactions DB.
+/// - The `state_roots` property holds the Merkle roots of the state databases, which are checked before a restore.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotManifest {
+    pub executed_until: u64,
+    pub proved_until: u64,
+    pub state_roots: StateRoots,
+}
+
+impl ByteConversion<208> for SnapshotManifest {
+    fn to_bytes(&self) -> [u8; 208] {
+        let mut buf = [0u8; 208];
+
+        buf[0..8].copy_from_slice(&self.executed_until.to_bytes());
+        buf[8..16].copy_from_slice(&self.proved_until.to_bytes());
+        buf[16..208].copy_from_slice(&self.state_roots.to_bytes());
+
+        buf
+    }
+
+    fn from_bytes(bytes: &[u8; 208]) -> Self {
+        SnapshotManifest {
+            executed_until: u64::from_bytes(bytes[0..8].try_into().unwrap()),
+            proved_until: u64::from_bytes(bytes[8..16].try_into().unwrap()),
+            state_roots: StateRoots::from_bytes(bytes[16..208].try_into().unwrap()),
+        }
+    }
+}
@@ -0,0 +1,419 @@
+use crate::{SnapshotManifest, SnapshotsError};
+use nacho_balances_db::BalancesDb;
+use nacho_burns_db::BurnsDb;
+use nacho_data_structures::{ByteConversion, U256};
+use nacho_liquidities_db::LiquiditiesDb;
+use nacho_orders_db::OrdersDb;
+use nacho_pools_db::PoolsDb;
+use std::path::{Path, PathBuf};
+use tokio::fs::{copy, create_dir_all, read_dir, remove_dir_all, rename, try_exists, write, File};
+
+type Result<T> = std::result::Result<T, SnapshotsError>;
+
+/// The store of the point-in-time copies of the node's state directories.
+///
+/// Each snapshot is a directory named after its version, which holds a copy of each state directory and a manifest.
+/// A snapshot is copied into a temporary directory that is renamed afterwards, so unfinished snapshots are never listed.
+///
+/// Files are copied rather than hard linked, as the databases overwrite their files in place.
+///
+/// # Examples
+///
+/// Create a store:
+///
+/// ```rs
+/// let mut snapshots = Snapshots::new("/nacho/snapshots").await?;
+/// ```
+///
+/// Take a snapshot while nothing writes to the state:
+///
+/// ```rs
+/// let version = snapshots.take(&manifest, &state_dirs).await?;
+/// ```
+///
+/// Restore the last snapshot while the node is stopped:
+///
+/// ```rs
+/// let version = snapshots.get_versions().await?.pop().unwrap();
+/// let manifest = snapshots.restore(version, &state_dirs).await?;
+/// ```
+///
+pub struct Snapshots {
+    dir_path: PathBuf,
+}
+
+impl Snapshots {
+    pub async fn new(path: impl AsRef<Path>) -> Result<Snapshots> {
+        let dir_path = path.as_ref().to_path_buf();
+
+        create_dir_all(&dir_path).await?;
+
+        Ok(Snapshots { dir_path })
+    }
+
+    /// Copies the given state directories into a new snapshot with the given manifest and returns its version.
+    ///
+    /// The state directories are given with their names in the snapshot, nothing must write to them until it returns.
+    pub async fn take(
+        &mut self,
+        manifest: &SnapshotManifest,
+        state_dirs: &[(&str, PathBuf)],
+    ) -> Result<u64> {
+        let version = self.get_versions().await?.last().map_or(0, |last| last + 1);
+
+        let temp_dir_path = self.dir_path.join(format!("{version}.tmp"));
+
+        if try_exists(&temp_dir_path).await? {
+            remove_dir_all(&temp_dir_path).await?;
+        }
+
+        for (name, path) in state_dirs {
+            copy_dir(path, &temp_dir_path.join(name)).await?;
+        }
+
+        let manifest_path = temp_dir_path.join("manifest");
+
+        write(&manifest_path, manifest.to_bytes()).await?;
+        File::open(&manifest_path).await?.sync_all().await?;
+
+        rename(&temp_dir_path, self.dir_path.join(version.to_string())).await?;
+
+        File::open(&self.dir_path).await?.sync_all().await?;
+
+        Ok(version)
+    }
+
+    /// Returns the versions of the snapshots from the oldest to the latest.
+    pub async fn get_versions(&self) -> Result<Vec<u64>> {
+        let mut entries = read_dir(&self.dir_path).await?;
+        let mut versions = Vec::new();
+
+        while let Some(entry) = entries.next_entry().await? {
+            if let Some(version) = entry.file_name().to_str().and_then(|n| n.parse().ok()) {
+                versions.push(version);
+            }
+        }
+
+        versions.sort_unstable();
+
+        Ok(versions)
+    }
+
+    pub async fn get_manifest(&self, version: u64) -> Result<SnapshotManifest> {
+        let snapshot_path = self.snapshot_path(version).await?;
+
+        let bytes = tokio::fs::read(snapshot_path.join("manifest")).await?;
+
+        let bytes = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| SnapshotsError::CorruptedManifest)?;
+
+        Ok(SnapshotManifest::from_bytes(bytes))
+    }
+
+    /// Removes the oldest snapshots so that at most `count` of them are left.
+    pub async fn keep_latest(&mut self, count: usize) -> Result<()> {
+        let versions = self.get_versions().await?;
+
+        for version in &versions[..versions.len().saturating_sub(count)] {
+            remove_dir_all(self.dir_path.join(version.to_string())).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the given state directories with their copies in the snapshot and returns the snapshot's manifest.
+    ///
+    /// The copies are staged next to the state directories and the state roots of the staged databases are checked against the manifest,
+    /// the state directories are only replaced if all of them match.
+    ///
+    /// The node must be stopped while a snapshot is restored.
+    pub async fn restore(
+        &self,
+        version: u64,
+        state_dirs: &[(&str, PathBuf)],
+    ) -> Result<SnapshotManifest> {
+        let snapshot_path = self.snapshot_path(version).await?;
+        let manifest = self.get_manifest(version).await?;
+
+        for (name, path) in state_dirs {
+            let snapshot_dir_path = snapshot_path.join(name);
+
+            if !try_exists(&snapshot_dir_path).await? {
+                return Err(SnapshotsError::StateDirectoryMissing(name.to_string()));
+            }
+
+            let staging_path = staging_path(path);
+
+            if try_exists(&staging_path).await? {
+                remove_dir_all(&staging_path).await?;
+            }
+
+            copy_dir(&snapshot_dir_path, &staging_path).await?;
+        }
+
+        if let Err(error) = check_roots(&manifest, state_dirs).await {
+            for (_, path) in state_dirs {
+                remove_dir_all(staging_path(path)).await?;
+            }
+
+            return Err(error);
+        }
+
+        // NOTE: If a restore stops while swapping, running it again replaces all the state directories.
+        for (_, path) in state_dirs {
+            if try_exists(path).await? {
+                remove_dir_all(path).await?;
+            }
+
+            rename(staging_path(path), path).await?;
+        }
+
+        Ok(manifest)
+    }
+
+    async fn snapshot_path(&self, version: u64) -> Result<PathBuf> {
+        let snapshot_path = self.dir_path.join(version.to_string());
+
+        if !try_exists(&snapshot_path).await? {
+            return Err(SnapshotsError::SnapshotDoesntExist);
+        }
+
+        Ok(snapshot_path)
+    }
+}
+
+/// Compares the roots of the staged state databases with the roots in the manifest.
+async fn check_roots(manifest: &SnapshotManifest, state_dirs: &[(&str, PathBuf)]) -> Result<()> {
+    let staging_path_of = |db_name: &str| {
+        state_dirs
+            .iter()
+            .find(|(name, _)| *name == db_name)
+            .map(|(_, path)| staging_path(path))
+            .ok_or_else(|| SnapshotsError::StateDirectoryMissing(db_name.to_string()))
+    };
+
+    let roots = &manifest.state_roots;
+
+    let mut balances_db = BalancesDb::new(staging_path_of("balances-db")?).await?;
+    check_root(
+        "balances",
+        balances_db.get_root().await?.into(),
+        &roots.balances,
+    )?;
+    check_root(
+        "nonces",
+        balances_db.get_nonces_root().await?.into(),
+        &roots.nonces,
+    )?;
+
+    let mut liquidities_db = LiquiditiesDb::new(staging_path_of("liquidities-db")?).await?;
+    check_root(
+        "liquidities",
+        liquidities_db.get_root().await?.into(),
+        &roots.liquidities,
+    )?;
+
+    let mut pools_db = PoolsDb::new(staging_path_of("pools-db")?).await?;
+    check_root("pools", pools_db.get_root().await?.into(), &roots.pools)?;
+
+    let mut burns_db = BurnsDb::new(staging_path_of("burns-db")?).await?;
+    check_root("burns", burns_db.get_root().await?.into(), &roots.burns)?;
+
+    let mut orders_db = OrdersDb::new(staging_path_of("orders-db")?).await?;
+    check_root("orders", orders_db.get_root().await?.into(), &roots.orders)?;
+
+    Ok(())
+}
+
+fn check_root(name: &'static str, root: U256, expected_root: &U256) -> Result<()> {
+    if root != *expected_root {
+        return Err(SnapshotsError::RootMismatch(name));
+    }
+
+    Ok(())
+}
+
+/// Returns the path a state directory's copy is staged at before it replaces the state directory.
+fn staging_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+
+    file_name.push(".restoring");
+
+    path.with_file_name(file_name)
+}
+
+/// Copies the directory and its subdirectories, files are synced to disk before it returns.
+///
+/// If the directory doesn't exist, an empty one is created.
+async fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    let mut dirs = vec![(from.to_path_buf(), to.to_path_buf())];
+
+    while let Some((from, to)) = dirs.pop() {
+        create_dir_all(&to).await?;
+
+        if !try_exists(&from).await? {
+            continue;
+        }
+
+        let mut entries = read_dir(&from).await?;
+
+        while let Some(entry) = entries.next_entry().await? {
+            let to = to.join(entry.file_name());
+
+            if entry.file_type().await?.is_dir() {
+                dirs.push((entry.path(), to));
+            } else {
+                copy(entry.path(), &to).await?;
+                File::open(&to).await?.sync_all().await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::StateRoots;
+    use tokio::fs::read;
+
+    async fn create_state(dir: &str) -> (Vec<(&'static str, PathBuf)>, StateRoots) {
+        let state_dirs = [
+            "balances-db",
+            "liquidities-db",
+            "pools-db",
+            "burns-db",
+            "orders-db",
+            "mempool",
+        ]
+        .map(|name| (name, PathBuf::from(format!("{dir}/state/{name}"))))
+        .to_vec();
+
+        let roots = StateRoots {
+            balances: BalancesDb::new(&state_dirs[0].1)
+                .await
+                .unwrap()
+                .get_root()
+                .await
+                .unwrap()
+                .into(),
+            liquidities: LiquiditiesDb::new(&state_dirs[1].1)
+                .await
+                .unwrap()
+                .get_root()
+                .await
+                .unwrap()
+                .into(),
+            pools: PoolsDb::new(&state_dirs[2].1)
+                .await
+                .unwrap()
+                .get_root()
+                .await
+                .unwrap()
+                .into(),
+            burns: BurnsDb::new(&state_dirs[3].1)
+                .await
+                .unwrap()
+                .get_root()
+                .await
+                .unwrap()
+                .into(),
+            orders: OrdersDb::new(&state_dirs[4].1)
+                .await
+                .unwrap()
+                .get_root()
+                .await
+                .unwrap()
+                .into(),
+            nonces: BalancesDb::new(&state_dirs[0].1)
+                .await
+                .unwrap()
+                .get_nonces_root()
+                .await
+                .unwrap()
+                .into(),
+        };
+
+        create_dir_all(&state_dirs[5].1).await.unwrap();
+        write(state_dirs[5].1.join("file"), [1, 2, 3])
+            .await
+            .unwrap();
+
+        (state_dirs, roots)
+    }
+
+    #[tokio::test]
+    async fn takes_and_restores_snapshots() {
+        let dir = "/tmp/nacho/tests/snapshots/takes_and_restores_snapshots";
+
+        let (state_dirs, state_roots) = create_state(dir).await;
+
+        let mut snapshots = Snapshots::new(format!("{dir}/snapshots")).await.unwrap();
+
+        let manifest = SnapshotManifest {
+            executed_until: 5,
+            proved_until: 3,
+            state_roots,
+        };
+
+        assert_eq!(snapshots.take(&manifest, &state_dirs).await.unwrap(), 0);
+        assert_eq!(snapshots.take(&manifest, &state_dirs).await.unwrap(), 1);
+        assert_eq!(snapshots.get_versions().await.unwrap(), vec![0, 1]);
+
+        write(state_dirs[5].1.join("file"), [4]).await.unwrap();
+        write(state_dirs[5].1.join("other_file"), [5])
+            .await
+            .unwrap();
+
+        assert_eq!(snapshots.restore(1, &state_dirs).await.unwrap(), manifest);
+
+        assert_eq!(read(state_dirs[5].1.join("file")).await.unwrap(), [1, 2, 3]);
+        assert!(!try_exists(state_dirs[5].1.join("other_file"))
+            .await
+            .unwrap());
+
+        snapshots.keep_latest(1).await.unwrap();
+
+        assert_eq!(snapshots.get_versions().await.unwrap(), vec![1]);
+        assert!(matches!(
+            snapshots.get_manifest(0).await,
+            Err(SnapshotsError::SnapshotDoesntExist)
+        ));
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn doesnt_restore_snapshots_with_wrong_roots() {
+        let dir = "/tmp/nacho/tests/snapshots/doesnt_restore_snapshots_with_wrong_roots";
+
+        let (state_dirs, mut state_roots) = create_state(dir).await;
+
+        state_roots.pools = U256([7; 32]);
+
+        let mut snapshots = Snapshots::new(format!("{dir}/snapshots")).await.unwrap();
+
+        let manifest = SnapshotManifest {
+            executed_until: 5,
+            proved_until: 3,
+            state_roots,
+        };
+
+        let version = snapshots.take(&manifest, &state_dirs).await.unwrap();
+
+        write(state_dirs[5].1.join("file"), [4]).await.unwrap();
+
+        assert!(matches!(
+            snapshots.restore(version, &state_dirs).await,
+            Err(SnapshotsError::RootMismatch("pools"))
+        ));
+
+        assert_eq!(read(state_dirs[5].1.join("file")).await.unwrap(), [4]);
+        assert!(!try_exists(staging_path(&state_dirs[5].1)).await.unwrap());
+
+        remove_dir_all(dir).await.unwrap();
+    }
+}
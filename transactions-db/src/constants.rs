@@ -0,0 +1,4 @@
+pub const TRANSACTION_SIZE_IN_BYTES: usize = 240;
+pub const REJECTION_SIZE_IN_BYTES: usize = 9;
+pub const HISTORY_ENTRY_SIZE_IN_BYTES: usize = 63;
+pub const MAX_HISTORY_LIMIT: u64 = 100;
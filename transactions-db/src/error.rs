@@ -1,3 +1,4 @@
+use nacho_dynamic_list::DynamicListError;
 use thiserror::Error;
 
 /// The error type that is used for Merkle tree errors.
@@ -5,6 +6,12 @@ use thiserror::Error;
 pub enum TransactionsDbError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error(transparent)]
+    DynamicList(#[from] DynamicListError),
     #[error("Transaction doesn't exist.")]
     TxDoesntExist,
+    #[error("Transaction log is out of sync with transaction count.")]
+    TxLogOutOfSync,
+    #[error("Transaction log is missing, the database was created before transactions were stored and must be recreated.")]
+    TxLogMissing,
 }
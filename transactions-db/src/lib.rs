@@ -1,5 +1,10 @@
+mod constants;
 mod error;
 mod transactions_db;
 
+pub use constants::{
+    HISTORY_ENTRY_SIZE_IN_BYTES, MAX_HISTORY_LIMIT, REJECTION_SIZE_IN_BYTES,
+    TRANSACTION_SIZE_IN_BYTES,
+};
 pub use error::TransactionsDbError;
 pub use transactions_db::TransactionsDb;
@@ -1,17 +1,28 @@
-use std::{io::SeekFrom, path::Path};
+use std::{collections::HashMap, io::SeekFrom, path::Path};
 
-use nacho_data_structures::TxStatus;
+use nacho_data_structures::{Address, ByteConversion, RejectionReason, Transaction, TxStatus};
+use nacho_dynamic_list::DynamicList;
 use tokio::{
     fs::{create_dir_all, File, OpenOptions},
     io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
 };
 
-use crate::TransactionsDbError;
+use crate::{
+    TransactionsDbError, HISTORY_ENTRY_SIZE_IN_BYTES, MAX_HISTORY_LIMIT, REJECTION_SIZE_IN_BYTES,
+    TRANSACTION_SIZE_IN_BYTES,
+};
 
 type Result<T> = std::result::Result<T, TransactionsDbError>;
 
 pub struct TransactionsDb {
     file: File,
+    processed_until_file: File,
+    list: DynamicList<TRANSACTION_SIZE_IN_BYTES>,
+    history_list: DynamicList<HISTORY_ENTRY_SIZE_IN_BYTES>,
+    indexes: HashMap<Address, Vec<u64>>,
+    rejections_list: DynamicList<REJECTION_SIZE_IN_BYTES>,
+    rejections: HashMap<u64, RejectionReason>,
+    rejected_tx_ids: Vec<u64>,
 }
 
 impl TransactionsDb {
@@ -27,7 +38,44 @@ impl TransactionsDb {
             .open(path.join("file"))
             .await?;
 
-        let mut db = Self { file };
+        let processed_until_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path.join("processed_until"))
+            .await?;
+
+        let list = DynamicList::new(path.join("dynamic_list")).await?;
+        let history_list = DynamicList::new(path.join("history_dynamic_list")).await?;
+
+        let mut rejections_list = DynamicList::new(path.join("rejections_dynamic_list")).await?;
+        let mut rejections = HashMap::<u64, RejectionReason>::new();
+
+        rejections_list
+            .for_each(&mut rejections, |buf, _, rejections| {
+                let tx_id = u64::from_bytes(buf[0..8].try_into().unwrap());
+                let reason = RejectionReason::from_bytes(buf[8..9].try_into().unwrap());
+
+                rejections.insert(tx_id, reason);
+
+                Ok::<(), TransactionsDbError>(())
+            })
+            .await?;
+
+        let mut rejected_tx_ids: Vec<u64> = rejections.keys().copied().collect();
+        rejected_tx_ids.sort_unstable();
+
+        let mut db = Self {
+            file,
+            processed_until_file,
+            list,
+            history_list,
+            indexes: HashMap::new(),
+            rejections_list,
+            rejections,
+            rejected_tx_ids,
+        };
 
         if db.file.metadata().await?.len() == 0 {
             db.set_tx_count(0).await?;
@@ -37,6 +85,73 @@ impl TransactionsDb {
             db.set_merged_until(0).await?;
         }
 
+        // The databases created before `processed_until` was stored processed every executed and every rejected transaction.
+        if db.processed_until_file.metadata().await?.len() == 0 {
+            let executed_until = db.get_executed_until().await?;
+            let rejection_count = db.rejections_list.get_item_count().await?;
+
+            db.set_processed_until(executed_until + rejection_count)
+                .await?;
+        }
+
+        let tx_count = db.get_tx_count().await?;
+        let mut item_count = db.list.get_item_count().await?;
+
+        if item_count == 0 && tx_count > 0 {
+            return Err(TransactionsDbError::TxLogMissing);
+        }
+
+        // A crash between storing a transaction and incrementing the count leaves orphaned transactions at the end of the log.
+        while item_count > tx_count {
+            db.list.pop().await?;
+            item_count -= 1;
+        }
+
+        if item_count != tx_count {
+            return Err(TransactionsDbError::TxLogOutOfSync);
+        }
+
+        // The history entries of a transaction are stored before the count is incremented, so a crash can leave orphaned entries too.
+        loop {
+            let entry_count = db.history_list.get_item_count().await?;
+
+            if entry_count == 0 {
+                break;
+            }
+
+            let buf = db.history_list.get(entry_count - 1).await?;
+            let tx_id = u64::from_bytes(buf[55..63].try_into().unwrap());
+
+            if tx_id < tx_count {
+                break;
+            }
+
+            db.history_list.pop().await?;
+        }
+
+        // The databases created before the history was stored get it built from the transaction log once.
+        if db.history_list.get_item_count().await? == 0 {
+            for tx_id in 0..tx_count {
+                let buf = db.list.get(tx_id).await?;
+                let tx = Transaction::from_bytes(&buf);
+
+                for address in Self::parties_of(&tx) {
+                    db.add_to_history(tx_id, address).await?;
+                }
+            }
+        } else {
+            db.history_list
+                .for_each(&mut db.indexes, |buf, _, indexes| {
+                    let address = Address::from_bytes(buf[0..55].try_into().unwrap());
+                    let tx_id = u64::from_bytes(buf[55..63].try_into().unwrap());
+
+                    Self::insert_tx_id(indexes.entry(address).or_default(), tx_id);
+
+                    Ok::<(), TransactionsDbError>(())
+                })
+                .await?;
+        }
+
         Ok(db)
     }
 
@@ -60,6 +175,17 @@ impl TransactionsDb {
         Ok(())
     }
 
+    /// Sets the ID of the next transaction the executor executes or rejects.
+    ///
+    /// Unlike `executed_until`, which counts executed transactions, it is a transaction ID and also advances on rejections.
+    pub async fn set_processed_until(&mut self, until_tx_id: u64) -> Result<()> {
+        self.processed_until_file.seek(SeekFrom::Start(0)).await?;
+        self.processed_until_file.write_u64(until_tx_id).await?;
+        self.processed_until_file.flush().await?;
+
+        Ok(())
+    }
+
     pub async fn set_proved_until(&mut self, until_tx_id: u64) -> Result<()> {
         let padding = 16;
 
@@ -110,6 +236,14 @@ impl TransactionsDb {
         Ok(tx_count)
     }
 
+    pub async fn get_processed_until(&mut self) -> Result<u64> {
+        self.processed_until_file.seek(SeekFrom::Start(0)).await?;
+
+        let processed_until = self.processed_until_file.read_u64().await?;
+
+        Ok(processed_until)
+    }
+
     pub async fn get_proved_until(&mut self) -> Result<u64> {
         let padding = 16;
 
@@ -140,24 +274,99 @@ impl TransactionsDb {
         Ok(tx_count)
     }
 
-    pub async fn add_new_tx(&mut self) -> Result<u64> {
+    pub async fn add_new_tx(&mut self, tx: &Transaction) -> Result<u64> {
         let len = self.file.metadata().await?.len();
         let tx_count = self.get_tx_count().await?;
 
         let padding = 40 + (tx_count / 8);
 
+        if self.list.get_item_count().await? != tx_count {
+            return Err(TransactionsDbError::TxLogOutOfSync);
+        }
+
+        self.list.push(tx.to_bytes()).await?;
+
         if len < padding + 1 {
             self.write_byte(padding, 0b0000_0000).await?;
         }
 
-        self.set_tx_count(tx_count + 1).await?;
-
         let tx_id = tx_count;
 
+        for address in Self::parties_of(tx) {
+            self.add_to_history(tx_id, address).await?;
+        }
+
+        self.set_tx_count(tx_count + 1).await?;
+
         Ok(tx_id)
     }
 
-    pub async fn set_rejected(&mut self, tx_id: u64) -> Result<()> {
+    /// Adds the given transaction to the history of the given address, if it isn't already there.
+    ///
+    /// The transactions are added to the histories of the addresses they are signed by when they are stored,
+    /// this is used for the other addresses they change the state of, like the owners of the orders they fill.
+    pub async fn add_to_history(&mut self, tx_id: u64, address: &Address) -> Result<()> {
+        let tx_ids = self.indexes.entry(address.clone()).or_default();
+
+        if tx_ids.binary_search(&tx_id).is_ok() {
+            return Ok(());
+        }
+
+        let mut buf = [0u8; HISTORY_ENTRY_SIZE_IN_BYTES];
+        buf[0..55].copy_from_slice(&address.to_bytes());
+        buf[55..63].copy_from_slice(&tx_id.to_bytes());
+
+        self.history_list.push(buf).await?;
+
+        Self::insert_tx_id(tx_ids, tx_id);
+
+        Ok(())
+    }
+
+    pub async fn get_tx(&mut self, tx_id: u64) -> Result<Transaction> {
+        let tx_count = self.get_tx_count().await?;
+
+        if tx_id >= tx_count {
+            return Err(TransactionsDbError::TxDoesntExist);
+        }
+
+        let buf = self.list.get(tx_id).await?;
+
+        let tx = Transaction::from_bytes(&buf);
+
+        Ok(tx)
+    }
+
+    pub async fn get_history(
+        &mut self,
+        address: &Address,
+        from_tx_id: u64,
+        limit: u64,
+    ) -> Result<Vec<(u64, Transaction, TxStatus)>> {
+        let limit = limit.min(MAX_HISTORY_LIMIT) as usize;
+
+        let tx_ids: Vec<u64> = match self.indexes.get(address) {
+            Some(tx_ids) => {
+                let start = tx_ids.partition_point(|&tx_id| tx_id < from_tx_id);
+
+                tx_ids[start..].iter().take(limit).copied().collect()
+            }
+            None => return Ok(Vec::new()),
+        };
+
+        let mut history = Vec::with_capacity(tx_ids.len());
+
+        for tx_id in tx_ids {
+            let tx = self.get_tx(tx_id).await?;
+            let tx_status = self.get_status(tx_id).await?;
+
+            history.push((tx_id, tx, tx_status));
+        }
+
+        Ok(history)
+    }
+
+    pub async fn set_rejected(&mut self, tx_id: u64, reason: RejectionReason) -> Result<()> {
         let tx_count = self.get_tx_count().await?;
 
         if tx_id >= tx_count {
@@ -174,11 +383,35 @@ impl TransactionsDb {
 
         let new_byte = byte | bitmask;
 
+        let mut buf = [0u8; REJECTION_SIZE_IN_BYTES];
+        buf[0..8].copy_from_slice(&tx_id.to_bytes());
+        buf[8..9].copy_from_slice(&reason.to_bytes());
+
+        self.rejections_list.push(buf).await?;
+        self.rejections.insert(tx_id, reason);
+        Self::insert_tx_id(&mut self.rejected_tx_ids, tx_id);
+
         self.write_byte(padding, new_byte).await?;
 
         Ok(())
     }
 
+    pub async fn get_rejection_reason(&mut self, tx_id: u64) -> Result<Option<RejectionReason>> {
+        let tx_count = self.get_tx_count().await?;
+
+        if tx_id >= tx_count {
+            return Err(TransactionsDbError::TxDoesntExist);
+        }
+
+        let reason = self.rejections.get(&tx_id).copied();
+
+        Ok(reason)
+    }
+
+    /// Returns the status of the given transaction.
+    ///
+    /// `executed_until`, `proved_until` and `settled_until` count the executed transactions, which are the ones that have proofs,
+    /// so they are compared with the count of the executed transactions before the given one, which skips the rejected ones.
     pub async fn get_status(&mut self, tx_id: u64) -> Result<TxStatus> {
         let tx_count = self.get_tx_count().await?;
 
@@ -200,27 +433,54 @@ impl TransactionsDb {
             return Ok(TxStatus::Rejected);
         }
 
+        let processed_until = self.get_processed_until().await?;
+
+        if tx_id >= processed_until {
+            return Ok(TxStatus::Pending);
+        }
+
+        let rejected_count =
+            self.rejected_tx_ids
+                .partition_point(|&rejected_tx_id| rejected_tx_id < tx_id) as u64;
+
+        let executed_count = tx_id - rejected_count;
+
         let settled_until = self.get_settled_until().await?;
 
-        if tx_id < settled_until {
+        if executed_count < settled_until {
             return Ok(TxStatus::Settled);
         }
 
         let proved_until = self.get_proved_until().await?;
 
-        if tx_id < proved_until {
+        if executed_count < proved_until {
             return Ok(TxStatus::Proved);
         }
 
         let executed_until = self.get_executed_until().await?;
 
-        if tx_id < executed_until {
+        if executed_count < executed_until {
             return Ok(TxStatus::Executed);
         }
 
         Ok(TxStatus::Pending)
     }
 
+    /// Returns the addresses whose histories the given transaction is added to when it is stored.
+    fn parties_of(tx: &Transaction) -> Vec<&Address> {
+        match tx {
+            Transaction::TransferTokens(tx) => vec![&tx.address, &tx.receiver_address],
+            tx => tx.address().into_iter().collect(),
+        }
+    }
+
+    /// Inserts the given transaction ID to the given sorted transaction IDs.
+    fn insert_tx_id(tx_ids: &mut Vec<u64>, tx_id: u64) {
+        if let Err(position) = tx_ids.binary_search(&tx_id) {
+            tx_ids.insert(position, tx_id);
+        }
+    }
+
     async fn write_byte(&mut self, padding: u64, byte: u8) -> Result<()> {
         self.file.seek(SeekFrom::Start(padding)).await?;
         self.file.write_u8(byte).await?;
@@ -240,7 +500,10 @@ impl TransactionsDb {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tokio::fs::remove_dir_all;
+    use nacho_data_structures::{
+        Address, DepositTokensTransaction, Signature, TransferTokensTransaction, U256,
+    };
+    use tokio::fs::{remove_dir_all, remove_file};
 
     #[tokio::test]
     async fn creates_tx_statuses_db_correctly() {
@@ -253,12 +516,46 @@ mod tests {
         let proved_until = tx_db.get_proved_until().await.unwrap();
         let settled_until = tx_db.get_settled_until().await.unwrap();
         let merged_until = tx_db.get_merged_until().await.unwrap();
+        let processed_until = tx_db.get_processed_until().await.unwrap();
 
         assert_eq!(tx_count, 0);
         assert_eq!(executed_until, 0);
         assert_eq!(proved_until, 0);
         assert_eq!(settled_until, 0);
         assert_eq!(merged_until, 0);
+        assert_eq!(processed_until, 0);
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn counts_processed_txs_of_old_databases() {
+        let dir = "/tmp/nacho/tests/tx_statuses_db/counts_processed_txs_of_old_databases";
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let tx = Transaction::CreateGenesis();
+
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+
+        tx_db.set_executed_until(2).await.unwrap();
+        tx_db
+            .set_rejected(2, RejectionReason::Internal)
+            .await
+            .unwrap();
+
+        drop(tx_db);
+
+        remove_file(Path::new(dir).join("processed_until"))
+            .await
+            .unwrap();
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let processed_until = tx_db.get_processed_until().await.unwrap();
+        assert_eq!(processed_until, 3);
 
         remove_dir_all(dir).await.unwrap();
     }
@@ -269,50 +566,52 @@ mod tests {
 
         let mut tx_db = TransactionsDb::new(dir).await.unwrap();
 
+        let tx = Transaction::CreateGenesis();
+
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 0);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 1);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 2);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 3);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 4);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 5);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 6);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 7);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 8);
 
-        tx_db.add_new_tx().await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_count = tx_db.get_tx_count().await.unwrap();
         assert_eq!(tx_count, 9);
@@ -326,24 +625,30 @@ mod tests {
 
         let mut tx_db = TransactionsDb::new(dir).await.unwrap();
 
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
-        tx_db.add_new_tx().await.unwrap();
+        let tx = Transaction::CreateGenesis();
+
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
 
         let tx_status = tx_db.get_status(5).await.unwrap();
         assert_eq!(tx_status, TxStatus::Pending);
 
+        tx_db.set_processed_until(8).await.unwrap();
         tx_db.set_executed_until(7).await.unwrap();
         tx_db.set_proved_until(4).await.unwrap();
         tx_db.set_settled_until(2).await.unwrap();
-        tx_db.set_rejected(3).await.unwrap();
+        tx_db
+            .set_rejected(3, RejectionReason::Internal)
+            .await
+            .unwrap();
 
         let tx_status = tx_db.get_status(0).await.unwrap();
         assert_eq!(tx_status, TxStatus::Settled);
@@ -357,8 +662,9 @@ mod tests {
         let tx_status = tx_db.get_status(3).await.unwrap();
         assert_eq!(tx_status, TxStatus::Rejected);
 
+        // It is the fourth executed transaction, as the one before it is rejected.
         let tx_status = tx_db.get_status(4).await.unwrap();
-        assert_eq!(tx_status, TxStatus::Executed);
+        assert_eq!(tx_status, TxStatus::Proved);
 
         let tx_status = tx_db.get_status(5).await.unwrap();
         assert_eq!(tx_status, TxStatus::Executed);
@@ -367,7 +673,7 @@ mod tests {
         assert_eq!(tx_status, TxStatus::Executed);
 
         let tx_status = tx_db.get_status(7).await.unwrap();
-        assert_eq!(tx_status, TxStatus::Pending);
+        assert_eq!(tx_status, TxStatus::Executed);
 
         let tx_status = tx_db.get_status(8).await.unwrap();
         assert_eq!(tx_status, TxStatus::Pending);
@@ -377,4 +683,267 @@ mod tests {
 
         remove_dir_all(dir).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn stores_and_gets_rejection_reasons_correctly() {
+        let dir = "/tmp/nacho/tests/tx_statuses_db/stores_and_gets_rejection_reasons_correctly";
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let tx = Transaction::CreateGenesis();
+
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+
+        tx_db
+            .set_rejected(0, RejectionReason::InvalidSignature)
+            .await
+            .unwrap();
+        tx_db
+            .set_rejected(2, RejectionReason::LimitExceeded)
+            .await
+            .unwrap();
+
+        let reason = tx_db.get_rejection_reason(0).await.unwrap();
+        assert_eq!(reason, Some(RejectionReason::InvalidSignature));
+
+        let reason = tx_db.get_rejection_reason(1).await.unwrap();
+        assert_eq!(reason, None);
+
+        let err = tx_db.get_rejection_reason(3).await.unwrap_err();
+        assert!(matches!(err, TransactionsDbError::TxDoesntExist));
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let reason = tx_db.get_rejection_reason(2).await.unwrap();
+        assert_eq!(reason, Some(RejectionReason::LimitExceeded));
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn stores_and_gets_txs_correctly() {
+        let dir = "/tmp/nacho/tests/tx_statuses_db/stores_and_gets_txs_correctly";
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let tx_0 = Transaction::CreateGenesis();
+
+        let tx_1 = Transaction::DepositTokens(DepositTokensTransaction {
+            user_address: Address::from_bytes(
+                "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+                    .as_bytes()
+                    .try_into()
+                    .unwrap(),
+            ),
+            token_id: U256([7; 32]),
+            token_amount: 450,
+        });
+
+        let tx_id = tx_db.add_new_tx(&tx_0).await.unwrap();
+        assert_eq!(tx_id, 0);
+
+        let tx_id = tx_db.add_new_tx(&tx_1).await.unwrap();
+        assert_eq!(tx_id, 1);
+
+        let tx = tx_db.get_tx(0).await.unwrap();
+        assert_eq!(tx.to_bytes(), tx_0.to_bytes());
+
+        let tx = tx_db.get_tx(1).await.unwrap();
+        assert_eq!(tx.to_bytes(), tx_1.to_bytes());
+
+        let err = tx_db.get_tx(2).await.unwrap_err();
+        assert!(matches!(err, TransactionsDbError::TxDoesntExist));
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn gets_tx_history_correctly() {
+        let dir = "/tmp/nacho/tests/tx_statuses_db/gets_tx_history_correctly";
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let address_1 = Address::from_bytes(
+            "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let address_2 = Address::from_bytes(
+            "B62qiiGxLsqNemiKFKiD19JdTHmqbE5YKAkMuXGachSdYkTi8xR2dfY"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let tx_0 = Transaction::CreateGenesis();
+
+        let deposit = |address: &Address| {
+            Transaction::DepositTokens(DepositTokensTransaction {
+                user_address: address.clone(),
+                token_id: U256([0; 32]),
+                token_amount: 100,
+            })
+        };
+
+        tx_db.add_new_tx(&tx_0).await.unwrap();
+        tx_db.add_new_tx(&deposit(&address_1)).await.unwrap();
+        tx_db.add_new_tx(&deposit(&address_2)).await.unwrap();
+        tx_db.add_new_tx(&deposit(&address_1)).await.unwrap();
+        tx_db.add_new_tx(&deposit(&address_1)).await.unwrap();
+
+        tx_db.set_processed_until(4).await.unwrap();
+        tx_db.set_executed_until(3).await.unwrap();
+        tx_db
+            .set_rejected(3, RejectionReason::Internal)
+            .await
+            .unwrap();
+
+        let history = tx_db.get_history(&address_1, 0, 10).await.unwrap();
+        let history: Vec<_> = history
+            .into_iter()
+            .map(|(tx_id, _, tx_status)| (tx_id, tx_status))
+            .collect();
+        assert_eq!(
+            history,
+            vec![
+                (1, TxStatus::Executed),
+                (3, TxStatus::Rejected),
+                (4, TxStatus::Pending)
+            ]
+        );
+
+        let history = tx_db.get_history(&address_1, 2, 1).await.unwrap();
+        let tx_ids: Vec<_> = history.into_iter().map(|(tx_id, _, _)| tx_id).collect();
+        assert_eq!(tx_ids, vec![3]);
+
+        tx_db.add_to_history(2, &address_1).await.unwrap();
+        tx_db.add_to_history(2, &address_1).await.unwrap();
+
+        drop(tx_db);
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let history = tx_db.get_history(&address_2, 0, 10).await.unwrap();
+        let tx_ids: Vec<_> = history.into_iter().map(|(tx_id, _, _)| tx_id).collect();
+        assert_eq!(tx_ids, vec![2]);
+
+        let history = tx_db.get_history(&address_1, 0, 10).await.unwrap();
+        let tx_ids: Vec<_> = history.into_iter().map(|(tx_id, _, _)| tx_id).collect();
+        assert_eq!(tx_ids, vec![1, 2, 3, 4]);
+
+        for _ in 0..MAX_HISTORY_LIMIT {
+            tx_db.add_new_tx(&deposit(&address_2)).await.unwrap();
+        }
+
+        let history = tx_db.get_history(&address_2, 0, u64::MAX).await.unwrap();
+        assert_eq!(history.len() as u64, MAX_HISTORY_LIMIT);
+
+        remove_file(Path::new(dir).join("history_dynamic_list"))
+            .await
+            .unwrap();
+
+        drop(tx_db);
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let history = tx_db.get_history(&address_1, 0, 10).await.unwrap();
+        let tx_ids: Vec<_> = history.into_iter().map(|(tx_id, _, _)| tx_id).collect();
+        assert_eq!(tx_ids, vec![1, 3, 4]);
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reconciles_tx_log_on_startup() {
+        let dir = "/tmp/nacho/tests/tx_statuses_db/reconciles_tx_log_on_startup";
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let tx = Transaction::CreateGenesis();
+
+        tx_db.add_new_tx(&tx).await.unwrap();
+        tx_db.add_new_tx(&tx).await.unwrap();
+
+        // Simulates a crash after storing a transaction but before incrementing the count.
+        tx_db.list.push(tx.to_bytes()).await.unwrap();
+
+        let err = tx_db.add_new_tx(&tx).await.unwrap_err();
+        assert!(matches!(err, TransactionsDbError::TxLogOutOfSync));
+
+        drop(tx_db);
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        assert_eq!(tx_db.list.get_item_count().await.unwrap(), 2);
+
+        let tx_id = tx_db.add_new_tx(&tx).await.unwrap();
+        assert_eq!(tx_id, 2);
+
+        drop(tx_db);
+
+        remove_file(Path::new(dir).join("dynamic_list"))
+            .await
+            .unwrap();
+
+        let err = TransactionsDb::new(dir).await.err().unwrap();
+        assert!(matches!(err, TransactionsDbError::TxLogMissing));
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn adds_transfers_to_histories_of_both_parties() {
+        let dir = "/tmp/nacho/tests/tx_statuses_db/adds_transfers_to_histories_of_both_parties";
+
+        let mut tx_db = TransactionsDb::new(dir).await.unwrap();
+
+        let sender = Address::from_bytes(
+            "B62qjw5GLgrAZ3U7jWzhTXwnE3URwYmqxDoMzV2P9X1dacY6eJrCm88"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let receiver = Address::from_bytes(
+            "B62qiiGxLsqNemiKFKiD19JdTHmqbE5YKAkMuXGachSdYkTi8xR2dfY"
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+        );
+
+        let transfer = |address: &Address, receiver_address: &Address| {
+            Transaction::TransferTokens(TransferTokensTransaction {
+                address: address.clone(),
+                signature: Signature::from_bytes(&[0; 64]),
+                receiver_address: receiver_address.clone(),
+                token_id: U256([0; 32]),
+                token_amount: 100,
+                nonce: 0,
+            })
+        };
+
+        tx_db
+            .add_new_tx(&Transaction::CreateGenesis())
+            .await
+            .unwrap();
+        tx_db
+            .add_new_tx(&transfer(&sender, &receiver))
+            .await
+            .unwrap();
+        tx_db.add_new_tx(&transfer(&sender, &sender)).await.unwrap();
+
+        let history = tx_db.get_history(&sender, 0, 10).await.unwrap();
+        let tx_ids: Vec<_> = history.into_iter().map(|(tx_id, _, _)| tx_id).collect();
+        assert_eq!(tx_ids, vec![1, 2]);
+
+        let history = tx_db.get_history(&receiver, 0, 10).await.unwrap();
+        let tx_ids: Vec<_> = history.into_iter().map(|(tx_id, _, _)| tx_id).collect();
+        assert_eq!(tx_ids, vec![1]);
+
+        remove_dir_all(dir).await.unwrap();
+    }
 }
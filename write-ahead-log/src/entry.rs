@@ -0,0 +1,133 @@
+use nacho_data_structures::{
+    Address, Balance, Burn, ByteConversion, Liquidity, Order, Pool, StatefulTransaction,
+};
+
+use crate::WriteAheadLogError;
+
+/// The enum that represents a single write of a transaction that is recorded in the write-ahead log.
+///
+/// Each entry holds the value a record has after the transaction, so applying an entry twice gives the same state.
+///
+/// - `Balance`, `Pool`, `Liquidity`, `Burn` and `Order` entries are pushed if the record doesn't exist, and updated otherwise.
+/// - `Nonce` entries hold the address's nonce after the transaction.
+/// - `ExecutedUntil` entries hold the value of `executed_until` after the transaction.
+/// - `ProcessedUntil` entries hold the value of `processed_until` after the transaction, which is the ID of the transaction after it.
+/// - `StatefulTransaction` entries hold the stateful transaction that is pushed to the proofpool and the proofpool's push count before it.
+#[derive(Clone, Debug)]
+pub enum WalEntry {
+    Balance(Balance),
+    Nonce(Address, u64),
+    Pool(Pool),
+    Liquidity(Liquidity),
+    Burn(Burn),
+    Order(Order),
+    ExecutedUntil(u64),
+    ProcessedUntil(u64),
+    StatefulTransaction(Box<StatefulTransaction>, u64),
+}
+
+impl WalEntry {
+    /// Appends the entry's bytes to the given buffer, which are a one byte tag and the entry's value.
+    pub fn write_bytes(&self, buf: &mut Vec<u8>) {
+        match self {
+            WalEntry::Balance(balance) => {
+                buf.push(0);
+                buf.extend_from_slice(&balance.to_bytes());
+            }
+            WalEntry::Nonce(address, nonce) => {
+                buf.push(1);
+                buf.extend_from_slice(&address.to_bytes());
+                buf.extend_from_slice(&nonce.to_bytes());
+            }
+            WalEntry::Pool(pool) => {
+                buf.push(2);
+                buf.extend_from_slice(&pool.to_bytes());
+            }
+            WalEntry::Liquidity(liquidity) => {
+                buf.push(3);
+                buf.extend_from_slice(&liquidity.to_bytes());
+            }
+            WalEntry::Burn(burn) => {
+                buf.push(4);
+                buf.extend_from_slice(&burn.to_bytes());
+            }
+            WalEntry::Order(order) => {
+                buf.push(5);
+                buf.extend_from_slice(&order.to_bytes());
+            }
+            WalEntry::ExecutedUntil(executed_until) => {
+                buf.push(6);
+                buf.extend_from_slice(&executed_until.to_bytes());
+            }
+            WalEntry::StatefulTransaction(stateful_tx, push_count) => {
+                buf.push(7);
+                buf.extend_from_slice(&stateful_tx.to_bytes());
+                buf.extend_from_slice(&push_count.to_bytes());
+            }
+            WalEntry::ProcessedUntil(processed_until) => {
+                buf.push(8);
+                buf.extend_from_slice(&processed_until.to_bytes());
+            }
+        }
+    }
+
+    /// Reads an entry from the start of the given bytes and returns it with the count of bytes it takes.
+    pub fn read_bytes(bytes: &[u8]) -> Result<(WalEntry, usize), WriteAheadLogError> {
+        let (&tag, bytes) = bytes.split_first().ok_or(WriteAheadLogError::Corrupted)?;
+
+        let entry = match tag {
+            0 => WalEntry::Balance(Balance::from_bytes(value(bytes)?)),
+            1 => {
+                let bytes: &[u8; 63] = value(bytes)?;
+
+                WalEntry::Nonce(
+                    Address::from_bytes(bytes[0..55].try_into().unwrap()),
+                    u64::from_bytes(bytes[55..63].try_into().unwrap()),
+                )
+            }
+            2 => WalEntry::Pool(Pool::from_bytes(value(bytes)?)),
+            3 => WalEntry::Liquidity(Liquidity::from_bytes(value(bytes)?)),
+            4 => WalEntry::Burn(Burn::from_bytes(value(bytes)?)),
+            5 => WalEntry::Order(Order::from_bytes(value(bytes)?)),
+            6 => WalEntry::ExecutedUntil(u64::from_bytes(value(bytes)?)),
+            7 => {
+                let bytes: &[u8; 560] = value(bytes)?;
+
+                WalEntry::StatefulTransaction(
+                    Box::new(StatefulTransaction::from_bytes(
+                        bytes[0..552].try_into().unwrap(),
+                    )),
+                    u64::from_bytes(bytes[552..560].try_into().unwrap()),
+                )
+            }
+            8 => WalEntry::ProcessedUntil(u64::from_bytes(value(bytes)?)),
+            _ => return Err(WriteAheadLogError::Corrupted),
+        };
+
+        let size = 1 + entry.value_size();
+
+        Ok((entry, size))
+    }
+
+    /// Returns the size of the entry's value in bytes, excluding the tag.
+    fn value_size(&self) -> usize {
+        match self {
+            WalEntry::Balance(_) => 95,
+            WalEntry::Nonce(_, _) => 63,
+            WalEntry::Pool(_) => 144,
+            WalEntry::Liquidity(_) => 151,
+            WalEntry::Burn(_) => 95,
+            WalEntry::Order(_) => 144,
+            WalEntry::ExecutedUntil(_) => 8,
+            WalEntry::ProcessedUntil(_) => 8,
+            WalEntry::StatefulTransaction(_, _) => 560,
+        }
+    }
+}
+
+fn value<const L: usize>(bytes: &[u8]) -> Result<&[u8; L], WriteAheadLogError> {
+    bytes
+        .get(0..L)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(WriteAheadLogError::Corrupted)
+}
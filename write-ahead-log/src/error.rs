@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WriteAheadLogError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Write-ahead log is corrupted.")]
+    Corrupted,
+}
@@ -0,0 +1,7 @@
+mod entry;
+mod error;
+mod write_ahead_log;
+
+pub use entry::WalEntry;
+pub use error::WriteAheadLogError;
+pub use write_ahead_log::WriteAheadLog;
@@ -0,0 +1,201 @@
+use std::path::{Path, PathBuf};
+use tokio::{
+    fs::{create_dir_all, remove_file, rename, try_exists, File},
+    io::AsyncWriteExt,
+};
+
+use crate::{WalEntry, WriteAheadLogError};
+
+type Result<T> = std::result::Result<T, WriteAheadLogError>;
+
+/// The log that makes the writes of a transaction to the state databases atomic.
+///
+/// The writes of a transaction are written to the log as a single batch before they are applied.
+/// The batch is written to a temporary file that is renamed afterwards, so the log holds either the whole batch or nothing.
+///
+/// The log is cleared after all the writes are applied.
+/// If the node stops before that, the batch is found in the log on restart and applied again, which is safe as each entry is idempotent.
+///
+/// # Examples
+///
+/// Create a log:
+///
+/// ```rs
+/// let mut wal = WriteAheadLog::new("/nacho/state/write-ahead-log").await?;
+/// ```
+///
+/// Write a batch, apply it and clear the log:
+///
+/// ```rs
+/// wal.write(&entries).await?;
+/// // Apply the entries.
+/// wal.clear().await?;
+/// ```
+///
+pub struct WriteAheadLog {
+    dir_path: PathBuf,
+}
+
+impl WriteAheadLog {
+    pub async fn new(path: impl AsRef<Path>) -> Result<WriteAheadLog> {
+        let dir_path = path.as_ref().to_path_buf();
+
+        create_dir_all(&dir_path).await?;
+
+        Ok(WriteAheadLog { dir_path })
+    }
+
+    /// Replaces the batch in the log with the given entries, it returns after they are synced to disk.
+    pub async fn write(&mut self, entries: &[WalEntry]) -> Result<()> {
+        let mut buf = Vec::new();
+
+        buf.extend_from_slice(&(entries.len() as u64).to_le_bytes());
+
+        for entry in entries {
+            entry.write_bytes(&mut buf);
+        }
+
+        let temp_file_path = self.dir_path.join("file.tmp");
+
+        let mut temp_file = File::create(&temp_file_path).await?;
+        temp_file.write_all(&buf).await?;
+        temp_file.sync_all().await?;
+
+        rename(&temp_file_path, self.dir_path.join("file")).await?;
+
+        self.sync_dir().await
+    }
+
+    /// Returns the batch in the log, it returns `None` if the log is clear.
+    pub async fn read(&mut self) -> Result<Option<Vec<WalEntry>>> {
+        let file_path = self.dir_path.join("file");
+
+        if !try_exists(&file_path).await? {
+            return Ok(None);
+        }
+
+        let buf = tokio::fs::read(file_path).await?;
+
+        let (count, mut bytes) = buf
+            .split_first_chunk::<8>()
+            .ok_or(WriteAheadLogError::Corrupted)?;
+
+        let count = u64::from_le_bytes(*count);
+
+        let mut entries = Vec::new();
+
+        for _ in 0..count {
+            let (entry, size) = WalEntry::read_bytes(bytes)?;
+
+            entries.push(entry);
+            bytes = &bytes[size..];
+        }
+
+        if !bytes.is_empty() {
+            return Err(WriteAheadLogError::Corrupted);
+        }
+
+        Ok(Some(entries))
+    }
+
+    /// Removes the batch from the log, which marks its entries as applied.
+    pub async fn clear(&mut self) -> Result<()> {
+        let file_path = self.dir_path.join("file");
+
+        if try_exists(&file_path).await? {
+            remove_file(file_path).await?;
+
+            self.sync_dir().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Syncs the log's directory, which makes renames and removals in it durable.
+    async fn sync_dir(&self) -> Result<()> {
+        File::open(&self.dir_path).await?.sync_all().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nacho_data_structures::{Address, Balance, ByteConversion, Order, StatefulTransaction};
+    use tokio::fs::remove_dir_all;
+
+    #[tokio::test]
+    async fn writes_reads_and_clears_batches() {
+        let dir = "/tmp/nacho/tests/write_ahead_log/writes_reads_and_clears_batches";
+
+        let mut wal = WriteAheadLog::new(dir).await.unwrap();
+
+        assert!(wal.read().await.unwrap().is_none());
+
+        let balance = Balance::from_bytes(&[3; 95]);
+        let order = Order::from_bytes(&[0; 144]);
+        let stateful_tx = StatefulTransaction::CreateGenesis {
+            transaction: (),
+            state: (),
+        };
+
+        wal.write(&[
+            WalEntry::Balance(balance.clone()),
+            WalEntry::Nonce(Address::from_bytes(&[1; 55]), 7),
+            WalEntry::Order(order.clone()),
+            WalEntry::ExecutedUntil(42),
+            WalEntry::ProcessedUntil(45),
+            WalEntry::StatefulTransaction(Box::new(stateful_tx.clone()), 9),
+        ])
+        .await
+        .unwrap();
+
+        drop(wal);
+
+        let mut wal = WriteAheadLog::new(dir).await.unwrap();
+
+        let entries = wal.read().await.unwrap().unwrap();
+
+        assert_eq!(entries.len(), 6);
+        assert!(matches!(&entries[0], WalEntry::Balance(b) if *b == balance));
+        assert!(matches!(&entries[1], WalEntry::Nonce(_, 7)));
+        assert!(matches!(&entries[2], WalEntry::Order(o) if *o == order));
+        assert!(matches!(&entries[3], WalEntry::ExecutedUntil(42)));
+        assert!(matches!(&entries[4], WalEntry::ProcessedUntil(45)));
+        assert!(matches!(
+            &entries[5],
+            WalEntry::StatefulTransaction(tx, 9) if tx.to_bytes() == stateful_tx.to_bytes()
+        ));
+
+        wal.write(&[WalEntry::ExecutedUntil(43)]).await.unwrap();
+
+        let entries = wal.read().await.unwrap().unwrap();
+
+        assert_eq!(entries.len(), 1);
+
+        wal.clear().await.unwrap();
+
+        assert!(wal.read().await.unwrap().is_none());
+
+        remove_dir_all(dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_corrupted_batches() {
+        let dir = "/tmp/nacho/tests/write_ahead_log/rejects_corrupted_batches";
+
+        let mut wal = WriteAheadLog::new(dir).await.unwrap();
+
+        tokio::fs::write(format!("{dir}/file"), [2, 0, 0, 0, 0, 0, 0, 0, 6, 1])
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            wal.read().await,
+            Err(WriteAheadLogError::Corrupted)
+        ));
+
+        remove_dir_all(dir).await.unwrap();
+    }
+}
@@ -0,0 +1,226 @@
+use nacho_data_structures::{RejectionReason, StateRoots, U256};
+use nacho_processes::{
+    balances, burns,
+    executor::{execute_tx, protocol_fee_share, treasury_address, StateBatch},
+    generator::update_trees,
+    liquidities, orders, pools,
+};
+use nacho_snapshots::STATE_DIRS;
+use nacho_transactions_db::TransactionsDb;
+use nacho_write_ahead_log::WriteAheadLog;
+use primitive_types::U256 as PU256;
+use std::{path::PathBuf, process::ExitCode};
+
+/// The environment variables of the state directories that are rebuilt in the scratch directory.
+const SCRATCH_DIR_KEYS: [&str; 8] = [
+    "NACHO_BALANCES_DB_PATH",
+    "NACHO_BURNS_DB_PATH",
+    "NACHO_LIQUIDITIES_DB_PATH",
+    "NACHO_ORDERS_DB_PATH",
+    "NACHO_POOLS_DB_PATH",
+    "NACHO_PROOFPOOL_PATH",
+    "NACHO_TRANSACTIONS_DB_PATH",
+    "NACHO_WRITE_AHEAD_LOG_PATH",
+];
+
+/// Rebuilds the state databases from genesis by replaying the transaction log, the node must be stopped meanwhile.
+///
+/// The transactions in `NACHO_TRANSACTIONS_DB_PATH` are executed in their order into empty databases in the scratch directory,
+/// and the state roots are printed after every `interval` transactions, which is 100 by default.
+/// If `until` is given, only the transactions before it are replayed.
+///
+/// ```sh
+/// replay <scratch_dir> [interval] [until]
+/// ```
+#[tokio::main]
+async fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+
+    let scratch_path = match args.next() {
+        Some(path) => PathBuf::from(path),
+        None => {
+            eprintln!("A scratch directory must be given.");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let interval: u64 = args
+        .next()
+        .map(|interval| interval.parse().expect("Interval must be a number."))
+        .unwrap_or(100);
+
+    let until: Option<u64> = args
+        .next()
+        .map(|until| until.parse().expect("Transaction count must be a number."));
+
+    if interval == 0 {
+        eprintln!("Interval must be greater than zero.");
+        return ExitCode::FAILURE;
+    }
+
+    if scratch_path
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some())
+    {
+        eprintln!("The scratch directory must be empty.");
+        return ExitCode::FAILURE;
+    }
+
+    let mut log = TransactionsDb::new(std::env::var("NACHO_TRANSACTIONS_DB_PATH").unwrap())
+        .await
+        .unwrap();
+
+    let tx_count = log.get_tx_count().await.unwrap();
+    let until = until.map_or(tx_count, |until| until.min(tx_count));
+
+    // The processes read the paths of their databases from the environment, so they are pointed to the scratch directory before they are spawned.
+    for (key, name) in STATE_DIRS {
+        if SCRATCH_DIR_KEYS.contains(&key) {
+            std::env::set_var(key, scratch_path.join(name));
+        }
+    }
+
+    let balances = nacho_processes::balances::process();
+    let burns = nacho_processes::burns::process();
+    let liquidities = nacho_processes::liquidities::process();
+    let orders = nacho_processes::orders::process();
+    let pools = nacho_processes::pools::process();
+    let proofpool = nacho_processes::proofpool::process();
+    let transactions = nacho_processes::transactions::process();
+    let verifier = nacho_processes::verifier::process();
+
+    let protocol_fee_share = protocol_fee_share();
+    let treasury_address = treasury_address();
+
+    let mut wal = WriteAheadLog::new(std::env::var("NACHO_WRITE_AHEAD_LOG_PATH").unwrap())
+        .await
+        .unwrap();
+
+    for tx_id in 0..until {
+        let tx = log.get_tx(tx_id).await.unwrap();
+
+        // The fill transactions the executor queues are in the log, so the replay doesn't queue them again.
+        transactions
+            .add_new_tx(tx.clone())
+            .await
+            .expect("Couldn't add the transaction to the scratch Transactions DB.");
+
+        let mut batch = StateBatch::new(balances, burns, liquidities, orders, pools);
+
+        let tx_result = execute_tx(
+            tx,
+            protocol_fee_share,
+            treasury_address,
+            verifier,
+            &mut batch,
+        )
+        .await;
+
+        let executed_until = transactions.get_executed_until().await.unwrap();
+
+        let tx_result = match tx_result {
+            Ok(stateful_tx) => batch
+                .commit(
+                    stateful_tx,
+                    tx_id,
+                    executed_until,
+                    &mut wal,
+                    proofpool,
+                    transactions,
+                )
+                .await
+                .expect("Couldn't apply or undo the batch."),
+            Err(reason) => Err(reason),
+        };
+
+        match tx_result {
+            Ok(()) => {
+                // The trees are updated right away, as no proofs are generated for the replayed transactions.
+                let stateful_tx = proofpool.pop().await.unwrap();
+
+                update_trees(stateful_tx, balances, pools, liquidities, burns, orders)
+                    .await
+                    .expect("Couldn't update the Merkle trees.");
+            }
+            Err(reason) => {
+                transactions.set_rejected(tx_id, reason).await;
+                transactions.set_processed_until(tx_id + 1).await;
+            }
+        }
+
+        if (tx_id + 1) % interval == 0 || tx_id + 1 == until {
+            let state_roots = get_state_roots(balances, burns, liquidities, orders, pools)
+                .await
+                .unwrap();
+
+            print_state_roots(tx_id + 1, &state_roots);
+        }
+    }
+
+    // Rejections are recorded at the IDs of the rejected transactions, so each transaction's outcome is compared with its outcome in the log.
+    // The transactions the node hasn't executed or rejected yet have no outcome in the log.
+    let processed_until = log.get_processed_until().await.unwrap();
+    let mut mismatch_count = 0;
+
+    for tx_id in 0..until.min(processed_until) {
+        let logged_reason = log.get_rejection_reason(tx_id).await.unwrap();
+        let replayed_reason = transactions.get_rejection_reason(tx_id).await.unwrap();
+
+        if logged_reason != replayed_reason {
+            println!(
+                "Transaction {tx_id} is {} in the log, but {} in the replay.",
+                outcome(logged_reason),
+                outcome(replayed_reason)
+            );
+            mismatch_count += 1;
+        }
+    }
+
+    if mismatch_count == 0 {
+        println!("Replayed {until} transactions, their outcomes match the log.");
+        ExitCode::SUCCESS
+    } else {
+        println!("Replayed {until} transactions, {mismatch_count} outcomes don't match the log.");
+        ExitCode::FAILURE
+    }
+}
+
+async fn get_state_roots(
+    balances: balances::Processor,
+    burns: burns::Processor,
+    liquidities: liquidities::Processor,
+    orders: orders::Processor,
+    pools: pools::Processor,
+) -> Option<StateRoots> {
+    Some(StateRoots {
+        balances: balances.get_root().await?,
+        liquidities: liquidities.get_root().await?,
+        pools: pools.get_root().await?,
+        burns: burns.get_root().await?,
+        orders: orders.get_root().await?,
+        nonces: balances.get_nonces_root().await?,
+    })
+}
+
+fn print_state_roots(tx_count: u64, state_roots: &StateRoots) {
+    println!(
+        "After {tx_count} transactions: balances {}, liquidities {}, pools {}, burns {}, orders {}, nonces {}.",
+        to_decimal(&state_roots.balances),
+        to_decimal(&state_roots.liquidities),
+        to_decimal(&state_roots.pools),
+        to_decimal(&state_roots.burns),
+        to_decimal(&state_roots.orders),
+        to_decimal(&state_roots.nonces)
+    );
+}
+
+fn to_decimal(value: &U256) -> String {
+    PU256::from(value).to_string()
+}
+
+fn outcome(reason: Option<RejectionReason>) -> String {
+    match reason {
+        Some(reason) => format!("rejected with {reason:?}"),
+        None => "executed".to_string(),
+    }
+}
@@ -0,0 +1,44 @@
+use nacho_snapshots::{Snapshots, STATE_DIRS};
+use std::{path::PathBuf, process::ExitCode};
+
+/// Restores the state directories from a snapshot, the node must be stopped meanwhile.
+///
+/// It restores the given snapshot version, or the latest snapshot if no version is given.
+///
+/// ```sh
+/// restore [version]
+/// ```
+#[tokio::main]
+async fn main() -> ExitCode {
+    let snapshots_path = std::env::var("NACHO_SNAPSHOTS_PATH").unwrap();
+
+    let state_dirs =
+        STATE_DIRS.map(|(key, name)| (name, PathBuf::from(std::env::var(key).unwrap())));
+
+    let snapshots = Snapshots::new(snapshots_path).await.unwrap();
+
+    let version = match std::env::args().nth(1) {
+        Some(version) => version.parse().expect("Snapshot version must be a number."),
+        None => match snapshots.get_versions().await.unwrap().pop() {
+            Some(version) => version,
+            None => {
+                eprintln!("There are no snapshots to restore.");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    match snapshots.restore(version, &state_dirs).await {
+        Ok(manifest) => {
+            println!(
+                "Restored snapshot {version}, executed until {} and proved until {}.",
+                manifest.executed_until, manifest.proved_until
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Couldn't restore snapshot {version}: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,29 +1,56 @@
 use nacho_data_structures::{
-    BurnTokensTransaction, BuyTokensTransaction, CreatePoolTransaction,
-    ProvideLiquidityTransaction, RemoveLiquidityTransaction, SellTokensTransaction, Transaction,
+    BurnTokensTransaction, BuyTokensTransaction, ByteConversion, CancelOrderTransaction,
+    ClaimProtocolFeesTransaction, CreatePoolTransaction, PlaceOrderTransaction,
+    ProvideLiquidityTransaction, RejectionReason, RemoveLiquidityTransaction,
+    SellTokensTransaction, SwapRouteTransaction, TradeStats, Transaction,
+    TransferTokensTransaction,
 };
 use nacho_rpc_server::{start_rpc_server, RpcMethod::*, RpcResponse::*};
 
 #[tokio::main]
 async fn main() {
+    let analytics = nacho_processes::analytics::process();
     let balances = nacho_processes::balances::process();
     let burns = nacho_processes::burns::process();
     let liquidities = nacho_processes::liquidities::process();
     let mempool = nacho_processes::mempool::process();
+    let observations = nacho_processes::observations::process();
+    let orders = nacho_processes::orders::process();
     let pools = nacho_processes::pools::process();
     let proofpool = nacho_processes::proofpool::process();
     let _submitter = nacho_processes::submitter::process();
     let transactions = nacho_processes::transactions::process();
     let verifier = nacho_processes::verifier::process();
+    let snapshots = nacho_processes::snapshots::process(
+        balances,
+        burns,
+        liquidities,
+        orders,
+        pools,
+        transactions,
+    );
+    let admission = nacho_processes::admission::process(balances, verifier);
+    let simulator = nacho_processes::simulator::process(
+        balances,
+        burns,
+        liquidities,
+        observations,
+        orders,
+        pools,
+    );
     let withdrawals = nacho_processes::withdrawals::process();
-    let _merger = nacho_processes::merger::process(transactions);
+    let _merger = nacho_processes::merger::process(snapshots, transactions);
     let executor = nacho_processes::executor::process(
+        analytics,
         balances,
         burns,
         liquidities,
         mempool,
+        observations,
+        orders,
         pools,
         proofpool,
+        snapshots,
         transactions,
         verifier,
     );
@@ -31,8 +58,10 @@ async fn main() {
         balances,
         burns,
         liquidities,
+        orders,
         pools,
         proofpool,
+        snapshots,
         transactions,
     );
     let _fetcher = nacho_processes::fetcher::process(
@@ -40,6 +69,7 @@ async fn main() {
         executor,
         generator,
         mempool,
+        snapshots,
         transactions,
         withdrawals,
     );
@@ -57,13 +87,52 @@ async fn main() {
             }
 
             GetTxStatus { tx_id } => {
-                let maybe_tx_status = transactions.get_tx_status(tx_id).await;
+                let tx_status = match transactions.get_tx_status(tx_id).await {
+                    Some(tx_status) => tx_status,
+                    None => return ServerError,
+                };
+
+                let maybe_reason = match transactions.get_rejection_reason(tx_id).await {
+                    Some(maybe_reason) => maybe_reason,
+                    None => return ServerError,
+                };
 
-                maybe_tx_status
-                    .map(|tx_status| TxStatus(tx_status))
+                TxStatus(tx_status, maybe_reason)
+            }
+
+            GetTx { tx_id } => {
+                let maybe_tx = transactions.get_tx(tx_id).await;
+
+                maybe_tx.map(|tx| Tx(tx)).unwrap_or(ServerError)
+            }
+
+            GetTxHistory {
+                address,
+                from_tx_id,
+                limit,
+            } => {
+                let maybe_history = transactions
+                    .get_tx_history(address, from_tx_id, limit)
+                    .await;
+
+                maybe_history
+                    .map(|history| {
+                        TxHistory(
+                            history
+                                .into_iter()
+                                .map(|(tx_id, tx, tx_status)| (tx_id, tx.to_bytes()[0], tx_status))
+                                .collect::<_>(),
+                        )
+                    })
                     .unwrap_or(ServerError)
             }
 
+            GetNonce { address } => {
+                let maybe_nonce = balances.get_nonce(address).await;
+
+                maybe_nonce.map(|nonce| Nonce(nonce)).unwrap_or(ServerError)
+            }
+
             GetBalances { address } => {
                 let maybe_balances = balances.get_balances(address).await;
 
@@ -94,6 +163,66 @@ async fn main() {
                                         pool.base_token_amount,
                                         pool.quote_token_amount,
                                         pool.total_liqudity_points,
+                                        pool.fee_rate,
+                                        pool.amplification,
+                                    )
+                                })
+                                .collect::<_>(),
+                        )
+                    })
+                    .unwrap_or(ServerError)
+            }
+
+            GetProtocolFees => {
+                let maybe_pools = pools.get_pools().await;
+
+                maybe_pools
+                    .map(|pools| {
+                        ProtocolFees(
+                            pools
+                                .into_iter()
+                                .map(|pool| {
+                                    (
+                                        pool.base_token_id,
+                                        pool.quote_token_id,
+                                        pool.protocol_fee_base_token_amount,
+                                        pool.protocol_fee_quote_token_amount,
+                                    )
+                                })
+                                .collect::<_>(),
+                        )
+                    })
+                    .unwrap_or(ServerError)
+            }
+
+            GetTwap {
+                base_token_id,
+                quote_token_id,
+                window,
+            } => match observations
+                .get_twap(base_token_id, quote_token_id, window)
+                .await
+            {
+                Ok(price) => Twap(price),
+                Err(reason) => Rejected(reason),
+            },
+
+            GetOrders { address } => {
+                let maybe_orders = orders.get_orders(address).await;
+
+                maybe_orders
+                    .map(|orders| {
+                        Orders(
+                            orders
+                                .into_iter()
+                                .map(|order| {
+                                    (
+                                        order.id,
+                                        order.token_in_id,
+                                        order.token_out_id,
+                                        order.token_amount_in,
+                                        order.token_amount_out_limit,
+                                        order.status,
                                     )
                                 })
                                 .collect::<_>(),
@@ -123,6 +252,64 @@ async fn main() {
                     .unwrap_or(ServerError)
             }
 
+            GetPoolStats {
+                base_token_id,
+                quote_token_id,
+            } => {
+                if pools
+                    .get_pool(base_token_id.clone(), quote_token_id.clone())
+                    .await
+                    .is_none()
+                {
+                    return Rejected(RejectionReason::PoolDoesntExist);
+                }
+
+                analytics
+                    .get_pool_stats(base_token_id, quote_token_id)
+                    .await
+                    .map(|(daily_stats, weekly_stats)| {
+                        PoolStats(stats_entry(&daily_stats), stats_entry(&weekly_stats))
+                    })
+                    .unwrap_or(ServerError)
+            }
+
+            // At most 10 pools are returned, as the ranking is meant for pool listings.
+            GetTopPools => analytics
+                .get_top_pools(10)
+                .await
+                .map(|top_pools| {
+                    TopPools(
+                        top_pools
+                            .into_iter()
+                            .map(|(base_token_id, quote_token_id, daily_stats)| {
+                                (base_token_id, quote_token_id, stats_entry(&daily_stats))
+                            })
+                            .collect::<_>(),
+                    )
+                })
+                .unwrap_or(ServerError),
+
+            GetPositions { address } => match simulator.positions(address).await {
+                Ok(positions) => Positions(
+                    positions
+                        .into_iter()
+                        .map(|(liquidity, position)| {
+                            (
+                                liquidity.base_token_id,
+                                liquidity.quote_token_id,
+                                liquidity.points,
+                                position.share,
+                                position.base_token_amount,
+                                position.quote_token_amount,
+                                position.fee_base_token_amount,
+                                position.fee_quote_token_amount,
+                            )
+                        })
+                        .collect::<_>(),
+                ),
+                Err(reason) => Rejected(reason),
+            },
+
             GetBurns { address } => {
                 let maybe_liquidities = liquidities.get_liquidities(address).await;
 
@@ -158,25 +345,97 @@ async fn main() {
                 BridgeWitnesses(burn_witness, withdrawal_witness)
             }
 
+            SimulateTx { tx } => match simulator.simulate(tx).await {
+                Ok(simulation) => Simulation(
+                    simulation.balance_changes,
+                    simulation
+                        .pools
+                        .into_iter()
+                        .map(|pool| {
+                            (
+                                pool.base_token_id,
+                                pool.quote_token_id,
+                                pool.base_token_amount,
+                                pool.quote_token_amount,
+                                pool.total_liqudity_points,
+                                pool.fee_rate,
+                                pool.amplification,
+                            )
+                        })
+                        .collect(),
+                    simulation.liquidity_points_change,
+                ),
+                Err(reason) => Rejected(reason),
+            },
+
+            GetQuote {
+                base_token_id,
+                quote_token_id,
+                side,
+                amount,
+            } => match simulator
+                .quote(base_token_id, quote_token_id, side, amount)
+                .await
+            {
+                Ok(quote) => Quote(
+                    quote.amount_in,
+                    quote.amount_out,
+                    quote.fee,
+                    quote.spot_price,
+                    quote.effective_price,
+                    quote.price_impact,
+                ),
+                Err(reason) => Rejected(reason),
+            },
+
+            // Routes are searched up to the hops a swap route transaction can go through, or fewer if requested.
+            GetRoute {
+                token_in_id,
+                token_out_id,
+                token_amount_in,
+                max_hops,
+            } => {
+                let max_hops = match max_hops {
+                    0 => SwapRouteTransaction::MAX_HOPS,
+                    max_hops => max_hops.min(SwapRouteTransaction::MAX_HOPS as u64) as usize,
+                };
+
+                match simulator
+                    .route(token_in_id, token_out_id, token_amount_in, max_hops)
+                    .await
+                {
+                    Ok(route) => Route(route.path, route.amount_out),
+                    Err(reason) => Rejected(reason),
+                }
+            }
+
             BurnTokens {
                 address,
                 signature,
                 token_id,
                 token_amount,
+                nonce,
             } => {
-                let tx_id = match transactions.add_new_tx().await {
-                    Some(tx_id) => tx_id,
-                    None => return ServerError,
-                };
-
                 let transaction = Transaction::BurnTokens(BurnTokensTransaction {
                     address,
                     signature,
                     token_id,
                     token_amount,
+                    nonce,
                 });
 
-                if mempool.push(transaction).await.is_none() {
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
                     return ServerError;
                 };
 
@@ -193,12 +452,11 @@ async fn main() {
                 quote_token_id,
                 base_token_amount,
                 quote_token_amount,
+                fee_rate,
+                pool_kind,
+                amplification,
+                nonce,
             } => {
-                let tx_id = match transactions.add_new_tx().await {
-                    Some(tx_id) => tx_id,
-                    None => return ServerError,
-                };
-
                 let transaction = Transaction::CreatePool(CreatePoolTransaction {
                     address,
                     signature,
@@ -206,9 +464,24 @@ async fn main() {
                     quote_token_id,
                     base_token_amount,
                     quote_token_amount,
+                    fee_rate,
+                    pool_kind,
+                    amplification,
+                    nonce,
                 });
 
-                if mempool.push(transaction).await.is_none() {
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
                     return ServerError;
                 };
 
@@ -225,12 +498,8 @@ async fn main() {
                 quote_token_id,
                 base_token_amount,
                 quote_token_amount_limit,
+                nonce,
             } => {
-                let tx_id = match transactions.add_new_tx().await {
-                    Some(tx_id) => tx_id,
-                    None => return ServerError,
-                };
-
                 let transaction = Transaction::ProvideLiquidity(ProvideLiquidityTransaction {
                     address,
                     signature,
@@ -238,9 +507,21 @@ async fn main() {
                     quote_token_id,
                     base_token_amount,
                     quote_token_amount_limit,
+                    nonce,
                 });
 
-                if mempool.push(transaction).await.is_none() {
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
                     return ServerError;
                 };
 
@@ -258,12 +539,8 @@ async fn main() {
                 base_token_amount_limit,
                 quote_token_amount_limit,
                 points,
+                nonce,
             } => {
-                let tx_id = match transactions.add_new_tx().await {
-                    Some(tx_id) => tx_id,
-                    None => return ServerError,
-                };
-
                 let transaction = Transaction::RemoveLiquidity(RemoveLiquidityTransaction {
                     address,
                     signature,
@@ -272,9 +549,21 @@ async fn main() {
                     base_token_amount_limit,
                     quote_token_amount_limit,
                     points,
+                    nonce,
                 });
 
-                if mempool.push(transaction).await.is_none() {
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
                     return ServerError;
                 };
 
@@ -291,12 +580,8 @@ async fn main() {
                 quote_token_id,
                 base_token_amount,
                 quote_token_amount_limit,
+                nonce,
             } => {
-                let tx_id = match transactions.add_new_tx().await {
-                    Some(tx_id) => tx_id,
-                    None => return ServerError,
-                };
-
                 let transaction = Transaction::BuyTokens(BuyTokensTransaction {
                     address,
                     signature,
@@ -304,9 +589,21 @@ async fn main() {
                     quote_token_id,
                     base_token_amount,
                     quote_token_amount_limit,
+                    nonce,
                 });
 
-                if mempool.push(transaction).await.is_none() {
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
                     return ServerError;
                 };
 
@@ -323,22 +620,213 @@ async fn main() {
                 quote_token_id,
                 base_token_amount_limit,
                 quote_token_amount,
+                nonce,
             } => {
-                let tx_id = match transactions.add_new_tx().await {
+                let transaction = Transaction::SellTokens(SellTokensTransaction {
+                    address,
+                    signature,
+                    base_token_id,
+                    quote_token_id,
+                    base_token_amount_limit,
+                    quote_token_amount,
+                    nonce,
+                });
+
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
                     Some(tx_id) => tx_id,
                     None => return ServerError,
                 };
 
-                let transaction = Transaction::SellTokens(SellTokensTransaction {
+                if mempool.push(tx_id, transaction).await.is_none() {
+                    return ServerError;
+                };
+
+                executor.keep_executing();
+                generator.keep_generating();
+
+                TxId(tx_id)
+            }
+
+            SwapRoute {
+                address,
+                signature,
+                path,
+                token_amount_in,
+                token_amount_out_limit,
+                nonce,
+            } => {
+                let transaction = Transaction::SwapRoute(SwapRouteTransaction {
+                    address,
+                    signature,
+                    path,
+                    token_amount_in,
+                    token_amount_out_limit,
+                    nonce,
+                });
+
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
+                    return ServerError;
+                };
+
+                executor.keep_executing();
+                generator.keep_generating();
+
+                TxId(tx_id)
+            }
+
+            TransferTokens {
+                address,
+                signature,
+                receiver_address,
+                token_id,
+                token_amount,
+                nonce,
+            } => {
+                let transaction = Transaction::TransferTokens(TransferTokensTransaction {
+                    address,
+                    signature,
+                    receiver_address,
+                    token_id,
+                    token_amount,
+                    nonce,
+                });
+
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
+                    return ServerError;
+                };
+
+                executor.keep_executing();
+                generator.keep_generating();
+
+                TxId(tx_id)
+            }
+            PlaceOrder {
+                address,
+                signature,
+                token_in_id,
+                token_out_id,
+                token_amount_in,
+                token_amount_out_limit,
+                nonce,
+            } => {
+                let transaction = Transaction::PlaceOrder(PlaceOrderTransaction {
+                    address,
+                    signature,
+                    token_in_id,
+                    token_out_id,
+                    token_amount_in,
+                    token_amount_out_limit,
+                    nonce,
+                });
+
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
+                    return ServerError;
+                };
+
+                executor.keep_executing();
+                generator.keep_generating();
+
+                TxId(tx_id)
+            }
+            CancelOrder {
+                address,
+                signature,
+                order_id,
+                nonce,
+            } => {
+                let transaction = Transaction::CancelOrder(CancelOrderTransaction {
+                    address,
+                    signature,
+                    order_id,
+                    nonce,
+                });
+
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
+                    return ServerError;
+                };
+
+                executor.keep_executing();
+                generator.keep_generating();
+
+                TxId(tx_id)
+            }
+            ClaimProtocolFees {
+                address,
+                signature,
+                base_token_id,
+                quote_token_id,
+                nonce,
+            } => {
+                let transaction = Transaction::ClaimProtocolFees(ClaimProtocolFeesTransaction {
                     address,
                     signature,
                     base_token_id,
                     quote_token_id,
-                    base_token_amount_limit,
-                    quote_token_amount,
+                    nonce,
                 });
 
-                if mempool.push(transaction).await.is_none() {
+                if let Err(reason) = admission.admit(transaction.clone()).await {
+                    return Rejected(reason);
+                }
+
+                let _state_guard = snapshots.hold_state().await;
+
+                let tx_id = match transactions.add_new_tx(transaction.clone()).await {
+                    Some(tx_id) => tx_id,
+                    None => return ServerError,
+                };
+
+                if mempool.push(tx_id, transaction).await.is_none() {
                     return ServerError;
                 };
 
@@ -352,3 +840,14 @@ async fn main() {
     .await
     .unwrap();
 }
+
+/// Returns the volumes, the fees and the trade count of the given stats in the order RPC responses have them.
+fn stats_entry(stats: &TradeStats) -> (u64, u64, u64, u64, u64) {
+    (
+        stats.base_token_volume,
+        stats.quote_token_volume,
+        stats.base_token_fees,
+        stats.quote_token_fees,
+        stats.trade_count,
+    )
+}